@@ -0,0 +1,3 @@
+DROP TABLE study_session_videos;
+DROP TABLE study_sessions;
+DROP TABLE study_schedules;
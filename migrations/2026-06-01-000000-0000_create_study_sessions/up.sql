@@ -0,0 +1,26 @@
+-- Study schedules: one persisted session plan per course
+CREATE TABLE study_schedules (
+    course_id TEXT PRIMARY KEY NOT NULL REFERENCES courses(id) ON DELETE CASCADE,
+    start_date TEXT NOT NULL,
+    study_days_per_week INTEGER NOT NULL DEFAULT 5,
+    cognitive_limit_minutes INTEGER NOT NULL DEFAULT 45
+);
+
+CREATE TABLE study_sessions (
+    id TEXT PRIMARY KEY NOT NULL,
+    course_id TEXT NOT NULL REFERENCES study_schedules(course_id) ON DELETE CASCADE,
+    day INTEGER NOT NULL,
+    scheduled_date TEXT NOT NULL,
+    total_duration_secs INTEGER NOT NULL
+);
+
+CREATE TABLE study_session_videos (
+    session_id TEXT NOT NULL REFERENCES study_sessions(id) ON DELETE CASCADE,
+    video_id TEXT NOT NULL REFERENCES videos(id) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    PRIMARY KEY (session_id, video_id)
+);
+
+CREATE INDEX idx_study_sessions_course_id ON study_sessions(course_id);
+CREATE INDEX idx_study_sessions_scheduled_date ON study_sessions(scheduled_date);
+CREATE INDEX idx_study_session_videos_session_id ON study_session_videos(session_id);
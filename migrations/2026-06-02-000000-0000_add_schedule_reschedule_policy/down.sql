@@ -0,0 +1,3 @@
+-- study_schedules is referenced by study_sessions, so recreating it would cascade-delete
+-- every session. DROP COLUMN is available from SQLite 3.35 onwards.
+ALTER TABLE study_schedules DROP COLUMN reschedule_policy;
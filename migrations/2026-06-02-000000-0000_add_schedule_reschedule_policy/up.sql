@@ -0,0 +1,3 @@
+-- Policy applied when a schedule drifts from the calendar
+ALTER TABLE study_schedules
+ADD COLUMN reschedule_policy TEXT NOT NULL DEFAULT 'keep_daily_load';
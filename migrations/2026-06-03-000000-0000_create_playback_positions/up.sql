@@ -0,0 +1,8 @@
+-- Last playback position per video, for resuming where the learner left off
+CREATE TABLE playback_positions (
+    video_id TEXT PRIMARY KEY NOT NULL REFERENCES videos(id) ON DELETE CASCADE,
+    position_ms BIGINT NOT NULL DEFAULT 0,
+    updated_at TEXT NOT NULL
+);
+
+CREATE INDEX idx_playback_positions_updated_at ON playback_positions(updated_at);
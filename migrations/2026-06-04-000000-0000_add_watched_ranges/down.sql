@@ -0,0 +1,2 @@
+-- DROP COLUMN is available from SQLite 3.35 onwards.
+ALTER TABLE playback_positions DROP COLUMN watched_ranges;
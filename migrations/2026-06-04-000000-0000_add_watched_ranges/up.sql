@@ -0,0 +1,3 @@
+-- Played intervals per video, stored as "start-end" millisecond pairs separated by commas
+ALTER TABLE playback_positions
+ADD COLUMN watched_ranges TEXT NOT NULL DEFAULT '';
@@ -0,0 +1,2 @@
+ALTER TABLE user_preferences
+ADD COLUMN auto_complete_percent INTEGER NOT NULL DEFAULT 90;
@@ -0,0 +1,11 @@
+-- Continuous stretches of playback, for study history and streaks
+CREATE TABLE watch_sessions (
+    id TEXT PRIMARY KEY NOT NULL,
+    video_id TEXT NOT NULL REFERENCES videos(id) ON DELETE CASCADE,
+    started_at TEXT NOT NULL,
+    ended_at TEXT NOT NULL,
+    played_secs INTEGER NOT NULL DEFAULT 0
+);
+
+CREATE INDEX idx_watch_sessions_started_at ON watch_sessions(started_at);
+CREATE INDEX idx_watch_sessions_video_id ON watch_sessions(video_id);
@@ -0,0 +1,17 @@
+-- Exam questions promoted into spaced repetition, with their SM-2 state
+CREATE TABLE review_cards (
+    id TEXT PRIMARY KEY NOT NULL,
+    exam_id TEXT NOT NULL REFERENCES exams(id) ON DELETE CASCADE,
+    video_id TEXT NOT NULL REFERENCES videos(id) ON DELETE CASCADE,
+    question_index INTEGER NOT NULL,
+    question_json TEXT NOT NULL,
+    ease DOUBLE NOT NULL DEFAULT 2.5,
+    interval_days INTEGER NOT NULL DEFAULT 0,
+    repetitions INTEGER NOT NULL DEFAULT 0,
+    lapses INTEGER NOT NULL DEFAULT 0,
+    due_date TEXT NOT NULL,
+    last_reviewed TEXT,
+    UNIQUE (exam_id, question_index)
+);
+
+CREATE INDEX idx_review_cards_due_date ON review_cards(due_date);
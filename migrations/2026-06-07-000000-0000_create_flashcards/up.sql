@@ -0,0 +1,11 @@
+-- Term/definition and cloze flashcards, per video
+CREATE TABLE flashcards (
+    id TEXT PRIMARY KEY NOT NULL,
+    video_id TEXT NOT NULL REFERENCES videos(id) ON DELETE CASCADE,
+    kind TEXT NOT NULL DEFAULT 'basic',
+    front TEXT NOT NULL,
+    back TEXT NOT NULL DEFAULT '',
+    position INTEGER NOT NULL DEFAULT 0
+);
+
+CREATE INDEX idx_flashcards_video_id ON flashcards(video_id);
@@ -0,0 +1,7 @@
+-- Restore the synthetic playlist URLs for non-YouTube courses.
+UPDATE courses
+SET source_url = 'https://www.youtube.com/playlist?list=local-' || id,
+    playlist_id = 'local-' || id
+WHERE source_type != 'youtube';
+
+ALTER TABLE courses DROP COLUMN source_type;
@@ -0,0 +1,10 @@
+ALTER TABLE courses ADD COLUMN source_type TEXT NOT NULL DEFAULT 'youtube';
+
+-- Local courses used to be stored under a synthetic `list=local-<uuid>` playlist URL.
+-- Their folder is left empty here and recovered from the video paths at startup
+-- (`backfill_local_course_roots`), which also turns courses with no local video into manual ones.
+UPDATE courses
+SET source_type = 'local',
+    playlist_id = '',
+    source_url = ''
+WHERE playlist_id LIKE 'local-%';
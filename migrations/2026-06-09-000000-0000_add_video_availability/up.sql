@@ -0,0 +1 @@
+ALTER TABLE videos ADD COLUMN is_available BOOLEAN NOT NULL DEFAULT 1;
@@ -0,0 +1,2 @@
+ALTER TABLE user_preferences
+ADD COLUMN watch_local_folders INTEGER NOT NULL DEFAULT 0;
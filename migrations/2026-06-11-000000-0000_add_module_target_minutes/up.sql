@@ -0,0 +1,2 @@
+ALTER TABLE user_preferences
+ADD COLUMN module_target_minutes INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,2 @@
+ALTER TABLE videos DROP COLUMN clip_end_secs;
+ALTER TABLE videos DROP COLUMN clip_start_secs;
@@ -0,0 +1,2 @@
+ALTER TABLE videos ADD COLUMN clip_start_secs INTEGER;
+ALTER TABLE videos ADD COLUMN clip_end_secs INTEGER;
@@ -0,0 +1,8 @@
+-- FTS5 index of each video's transcript chunks for companion retrieval
+CREATE VIRTUAL TABLE IF NOT EXISTS transcript_chunks USING fts5(
+    video_id UNINDEXED,         -- UUID of the video the chunk belongs to
+    chunk_index UNINDEXED,      -- Position of the chunk in the transcript, from 0
+    transcript_hash UNINDEXED,  -- Hash of the transcript the chunks were cut from
+    content,                    -- Chunk text
+    tokenize = 'porter unicode61'
+);
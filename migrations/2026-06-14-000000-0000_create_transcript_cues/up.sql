@@ -0,0 +1,9 @@
+-- Timed caption cues of video transcripts, for looking up what was said at a playback position
+CREATE TABLE transcript_cues (
+    video_id TEXT NOT NULL REFERENCES videos(id) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    start_ms BIGINT NOT NULL,
+    end_ms BIGINT NOT NULL,
+    text TEXT NOT NULL,
+    PRIMARY KEY (video_id, position)
+);
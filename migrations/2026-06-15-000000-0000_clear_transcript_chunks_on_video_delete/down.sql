@@ -0,0 +1 @@
+DROP TRIGGER IF EXISTS transcript_chunks_video_deleted;
@@ -0,0 +1,9 @@
+-- The FTS5 chunk index can't reference videos, so a trigger clears a deleted video's chunks.
+-- Deleting a module or course cascades to its videos, which fires this trigger as well.
+CREATE TRIGGER transcript_chunks_video_deleted AFTER DELETE ON videos
+BEGIN
+    DELETE FROM transcript_chunks WHERE video_id = OLD.id;
+END;
+
+-- Drop chunks left behind by videos deleted before the trigger existed
+DELETE FROM transcript_chunks WHERE video_id NOT IN (SELECT id FROM videos);
@@ -0,0 +1,2 @@
+ALTER TABLE study_schedules DROP COLUMN peak_session_count;
+ALTER TABLE study_schedules DROP COLUMN updated_at;
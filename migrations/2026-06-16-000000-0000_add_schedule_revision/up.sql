@@ -0,0 +1,12 @@
+-- When a schedule was last (re)planned, exported as the calendar SEQUENCE
+ALTER TABLE study_schedules
+ADD COLUMN updated_at TEXT NOT NULL DEFAULT '1970-01-01T00:00:00Z';
+
+-- Most sessions a schedule has had, so calendar exports can cancel the ones a re-plan dropped
+ALTER TABLE study_schedules
+ADD COLUMN peak_session_count INTEGER NOT NULL DEFAULT 0;
+
+UPDATE study_schedules
+SET peak_session_count = (
+    SELECT COUNT(*) FROM study_sessions WHERE study_sessions.course_id = study_schedules.course_id
+);
@@ -0,0 +1 @@
+ALTER TABLE transcript_cues DROP COLUMN source_hash;
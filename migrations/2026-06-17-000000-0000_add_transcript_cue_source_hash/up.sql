@@ -0,0 +1,3 @@
+-- Hash of the captions the cues were parsed from; cues stored before it never match and are refetched
+ALTER TABLE transcript_cues
+ADD COLUMN source_hash TEXT NOT NULL DEFAULT '';
@@ -3,27 +3,39 @@
 //! Wires all infrastructure adapters to application use cases.
 
 use parking_lot::Mutex;
+use std::str::FromStr;
 use std::sync::Arc;
 
 use crate::application::use_cases::{
-    AskCompanionUseCase, ChatUseCase, CreateModuleUseCase, DeleteModuleUseCase, IngestLocalUseCase,
-    IngestPlaylistUseCase, LoadDashboardUseCase, NotesUseCase, PreferencesUseCase,
-    SummarizeVideoUseCase, TakeExamUseCase, UpdatePresenceUseCase,
+    AskCompanionUseCase, ChatUseCase, CreateModuleUseCase, DeleteModuleUseCase,
+    ExportManifestUseCase, ExportScheduleUseCase, FlashcardsUseCase, GenerateFlashcardsUseCase,
+    GradeExamUseCase, IngestLocalUseCase, IngestManifestUseCase, IngestPlaylistUseCase,
+    LoadDashboardUseCase, LoadStudyHistoryUseCase, NotesUseCase, PlanCourseScheduleUseCase,
+    PlaybackUseCase, PreferencesUseCase, RelinkLocalUseCase, RescanLocalUseCase,
+    RescheduleCourseUseCase, ReviewUseCase, SummarizeVideoUseCase, SyncPlaylistUseCase,
+    TakeExamUseCase, UpdatePresenceUseCase,
 };
 use crate::domain::ports::{
-    ChatMessageRepository, CourseRepository, ExamRepository, ModuleRepository,
-    ModuleTitleGenerator, NoteRepository, PresenceProvider, SearchRepository, SecretStore,
-    TagRepository, UserPreferencesRepository, VideoRepository,
+    ChatMessageRepository, CourseRepository, ExamRepository, FlashcardRepository, ModuleRepository,
+    ModuleTitleGenerator, NoteRepository, PlaybackPositionRepository, PresenceProvider,
+    ReviewCardRepository, SearchRepository, SecretStore, StudyScheduleRepository, TagRepository,
+    TranscriptChunkRepository, TranscriptCueRepository, UserPreferencesRepository, VideoRepository,
+    WatchSessionRepository,
 };
+use crate::domain::services::GroupingStrategy;
 use crate::infrastructure::{
+    calendar::IcsCalendarExporter,
     discord::DiscordPresenceAdapter,
     keystore::NativeKeystore,
-    llm::GeminiAdapter,
+    llm::LlmAdapter,
     local_media::LocalMediaScannerAdapter,
     persistence::{
         DbPool, SqliteChatMessageRepository, SqliteCourseRepository, SqliteExamRepository,
-        SqliteModuleRepository, SqliteNoteRepository, SqliteSearchRepository, SqliteTagRepository,
-        SqliteUserPreferencesRepository, SqliteVideoRepository,
+        SqliteFlashcardRepository, SqliteModuleRepository, SqliteNoteRepository,
+        SqlitePlaybackPositionRepository, SqliteReviewCardRepository, SqliteSearchRepository,
+        SqliteStudyScheduleRepository, SqliteTagRepository, SqliteTranscriptChunkRepository,
+        SqliteTranscriptCueRepository, SqliteUserPreferencesRepository, SqliteVideoRepository,
+        SqliteWatchSessionRepository,
     },
     transcript::TranscriptAdapter,
     youtube::RustyYtdlAdapter,
@@ -32,6 +44,50 @@ use crate::infrastructure::{
 /// Default LLM model used when none is configured.
 const DEFAULT_LLM_MODEL: &str = "gemini-3.1-flash-lite";
 
+/// Default model for OpenAI-compatible servers (Ollama's naming).
+const DEFAULT_LOCAL_LLM_MODEL: &str = "llama3.2";
+
+/// Which LLM service backs the AI features.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LlmProvider {
+    /// Google Gemini, authenticated with an API key.
+    #[default]
+    Gemini,
+    /// Any server speaking the OpenAI chat completions API, e.g. a local Ollama,
+    /// llama.cpp server or vLLM.
+    OpenAiCompatible,
+}
+
+impl LlmProvider {
+    /// Value stored in the keystore and accepted by `LLM_PROVIDER`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Gemini => "gemini",
+            Self::OpenAiCompatible => "openai",
+        }
+    }
+
+    /// Model used when none is configured.
+    pub fn default_model(&self) -> &'static str {
+        match self {
+            Self::Gemini => DEFAULT_LLM_MODEL,
+            Self::OpenAiCompatible => DEFAULT_LOCAL_LLM_MODEL,
+        }
+    }
+}
+
+impl FromStr for LlmProvider {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "gemini" => Ok(Self::Gemini),
+            "openai" | "openai-compatible" | "ollama" => Ok(Self::OpenAiCompatible),
+            other => Err(format!("Unknown LLM provider: {other} (expected gemini or openai)")),
+        }
+    }
+}
+
 /// Configuration for the application.
 /// Load from environment with `AppConfig::from_env()`.
 #[derive(Debug, Clone)]
@@ -42,8 +98,14 @@ pub struct AppConfig {
     pub gemini_api_key: Option<String>,
     /// Discord Rich Presence client ID (optional).
     pub discord_client_id: Option<String>,
-    /// LLM model identifier (default: gemini/gemini-2.5-flash).
+    /// LLM model identifier (default: the provider's default model).
     pub llm_model: String,
+    /// LLM provider. When unset, the one chosen in settings is used (Gemini by default).
+    pub llm_provider: Option<LlmProvider>,
+    /// Base URL of an OpenAI-compatible server, e.g. `http://localhost:11434/v1`.
+    pub llm_base_url: Option<String>,
+    /// API key for an OpenAI-compatible server (optional - local servers need none).
+    pub llm_api_key: Option<String>,
 }
 
 impl Default for AppConfig {
@@ -53,6 +115,9 @@ impl Default for AppConfig {
             gemini_api_key: None,
             discord_client_id: None,
             llm_model: DEFAULT_LLM_MODEL.to_string(),
+            llm_provider: None,
+            llm_base_url: None,
+            llm_api_key: None,
         }
     }
 }
@@ -61,6 +126,16 @@ impl AppConfig {
     /// Loads configuration from environment variables.
     /// Falls back to defaults if not set.
     pub fn from_env() -> Self {
+        let env = |name: &str| {
+            std::env::var(name).ok().map(|s| s.trim().to_string()).filter(|s| !s.is_empty())
+        };
+        let llm_provider = env("LLM_PROVIDER").and_then(|p| match p.parse::<LlmProvider>() {
+            Ok(provider) => Some(provider),
+            Err(e) => {
+                log::warn!("{e}");
+                None
+            },
+        });
         Self {
             database_url: std::env::var("DATABASE_URL")
                 .map(|s| s.trim().to_string())
@@ -73,9 +148,11 @@ impl AppConfig {
                 .ok()
                 .map(|s| s.trim().to_string())
                 .filter(|s| !s.is_empty()),
-            llm_model: std::env::var("LLM_MODEL")
-                .map(|s| s.trim().to_string())
-                .unwrap_or_else(|_| DEFAULT_LLM_MODEL.to_string()),
+            llm_model: env("LLM_MODEL")
+                .unwrap_or_else(|| llm_provider.unwrap_or_default().default_model().to_string()),
+            llm_provider,
+            llm_base_url: env("LLM_BASE_URL"),
+            llm_api_key: env("LLM_API_KEY"),
         }
     }
 
@@ -112,6 +189,18 @@ impl AppConfigBuilder {
         self
     }
 
+    /// Uses an OpenAI-compatible server (e.g. Ollama) instead of Gemini.
+    pub fn openai_compatible(mut self, base_url: impl Into<String>) -> Self {
+        self.config.llm_provider = Some(LlmProvider::OpenAiCompatible);
+        self.config.llm_base_url = Some(base_url.into().trim().to_string());
+        self
+    }
+
+    pub fn llm_api_key(mut self, key: impl Into<String>) -> Self {
+        self.config.llm_api_key = Some(key.into().trim().to_string());
+        self
+    }
+
     pub fn build(self) -> AppConfig {
         self.config
     }
@@ -130,16 +219,24 @@ pub struct AppContext {
     pub note_repo: Arc<dyn NoteRepository>,
     pub tag_repo: Arc<dyn TagRepository>,
     pub search_repo: Arc<dyn SearchRepository>,
+    pub transcript_chunk_repo: Arc<dyn TranscriptChunkRepository>,
+    pub transcript_cue_repo: Arc<dyn TranscriptCueRepository>,
     pub preferences_repo: Arc<dyn UserPreferencesRepository>,
     pub chat_repo: Arc<dyn ChatMessageRepository>,
+    pub schedule_repo: Arc<dyn StudyScheduleRepository>,
+    pub playback_repo: Arc<dyn PlaybackPositionRepository>,
+    pub watch_session_repo: Arc<dyn WatchSessionRepository>,
+    pub review_repo: Arc<dyn ReviewCardRepository>,
+    pub flashcard_repo: Arc<dyn FlashcardRepository>,
 
     // Infrastructure adapters
     pub local_media: Arc<LocalMediaScannerAdapter>,
     pub youtube: Arc<RustyYtdlAdapter>,
     pub transcript: Arc<TranscriptAdapter>,
-    pub llm: Mutex<Option<Arc<GeminiAdapter>>>,
+    pub llm: Mutex<Option<Arc<LlmAdapter>>>,
     pub presence: Arc<dyn PresenceProvider>,
     pub keystore: Arc<NativeKeystore>,
+    pub calendar: Arc<IcsCalendarExporter>,
 
     // Database pool
     pub db_pool: Arc<DbPool>,
@@ -161,8 +258,15 @@ impl AppContext {
         let note_repo = Arc::new(SqliteNoteRepository::new(db_pool.clone()));
         let tag_repo = Arc::new(SqliteTagRepository::new(db_pool.clone()));
         let search_repo = Arc::new(SqliteSearchRepository::new(db_pool.clone()));
+        let transcript_chunk_repo = Arc::new(SqliteTranscriptChunkRepository::new(db_pool.clone()));
+        let transcript_cue_repo = Arc::new(SqliteTranscriptCueRepository::new(db_pool.clone()));
         let preferences_repo = Arc::new(SqliteUserPreferencesRepository::new(db_pool.clone()));
         let chat_repo = Arc::new(SqliteChatMessageRepository::new(db_pool.clone()));
+        let schedule_repo = Arc::new(SqliteStudyScheduleRepository::new(db_pool.clone()));
+        let playback_repo = Arc::new(SqlitePlaybackPositionRepository::new(db_pool.clone()));
+        let watch_session_repo = Arc::new(SqliteWatchSessionRepository::new(db_pool.clone()));
+        let review_repo = Arc::new(SqliteReviewCardRepository::new(db_pool.clone()));
+        let flashcard_repo = Arc::new(SqliteFlashcardRepository::new(db_pool.clone()));
 
         // Create keystore
         let keystore = Arc::new(NativeKeystore::new());
@@ -194,25 +298,8 @@ impl AppContext {
                 .map_err(|e| AppContextError::Transcript(e.to_string()))?,
         );
 
-        // Get Gemini API key from environment config first, then secure keystore (ensuring no empty strings)
-        let gemini_api_key =
-            if let Some(env_key) = config.gemini_api_key.as_ref().filter(|s| !s.is_empty()) {
-                log::info!("Gemini API key loaded from environment (.env).");
-                Some(env_key.clone())
-            } else if let Ok(Some(store_key)) =
-                keystore.retrieve("gemini_api_key").map(|opt| opt.filter(|s| !s.is_empty()))
-            {
-                log::info!("Gemini API key loaded from secure OS keystore.");
-                Some(store_key)
-            } else {
-                log::info!("No Gemini API key found in environment or keystore.");
-                None
-            };
-
-        // Create LLM adapter if key is available
-        let llm = Mutex::new(
-            gemini_api_key.map(|key| Arc::new(GeminiAdapter::new(key, config.llm_model.clone()))),
-        );
+        // Create LLM adapter if the chosen provider is configured
+        let llm = Mutex::new(build_llm(&config, keystore.as_ref()));
 
         Ok(Self {
             config,
@@ -223,14 +310,22 @@ impl AppContext {
             note_repo,
             tag_repo,
             search_repo,
+            transcript_chunk_repo,
+            transcript_cue_repo,
             preferences_repo,
             chat_repo,
+            schedule_repo,
+            playback_repo,
+            watch_session_repo,
+            review_repo,
+            flashcard_repo,
             local_media,
             youtube,
             transcript,
             llm,
             presence,
             keystore,
+            calendar: Arc::new(IcsCalendarExporter::new()),
             db_pool,
         })
     }
@@ -247,32 +342,68 @@ impl AppContext {
         self.keystore
             .store("gemini_api_key", trimmed)
             .map_err(|e| AppContextError::Keystore(e.to_string()))?;
-        *self.llm.lock() =
-            Some(Arc::new(GeminiAdapter::new(trimmed.to_string(), self.config.llm_model.clone())));
-        Ok(())
+        self.reload_llm()
     }
 
-    /// Reloads the LLM adapter from the keystore or config (for dynamic key updates).
+    /// Reloads the LLM adapter from the keystore or config (for dynamic settings updates).
     /// Takes `&self` because interior mutability via `Mutex` is used.
     pub fn reload_llm(&self) -> Result<(), AppContextError> {
-        let key_opt =
-            self.config.gemini_api_key.as_ref().filter(|s| !s.is_empty()).cloned().or_else(|| {
-                self.keystore.retrieve("gemini_api_key").ok().flatten().filter(|s| !s.is_empty())
-            });
-
-        if let Some(key) = key_opt {
-            let trimmed = key.trim().to_string();
-            log::info!("Reloaded Gemini LLM adapter with key.");
-            *self.llm.lock() =
-                Some(Arc::new(GeminiAdapter::new(trimmed, self.config.llm_model.clone())));
-        } else {
-            log::info!("Gemini LLM adapter cleared (no key available).");
-            *self.llm.lock() = None;
-        }
+        *self.llm.lock() = build_llm(&self.config, self.keystore.as_ref());
         Ok(())
     }
 }
 
+/// Creates the LLM adapter for the configured provider, or `None` when it lacks its key
+/// (Gemini) or base URL (OpenAI-compatible).
+///
+/// Environment config wins over the settings saved in the keystore.
+fn build_llm(config: &AppConfig, keystore: &dyn SecretStore) -> Option<Arc<LlmAdapter>> {
+    let stored = |key: &str| {
+        keystore
+            .retrieve(key)
+            .ok()
+            .flatten()
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+    };
+    let provider = config
+        .llm_provider
+        .or_else(|| stored("llm_provider").and_then(|p| p.parse().ok()))
+        .unwrap_or_default();
+
+    match provider {
+        LlmProvider::Gemini => {
+            let key = config.gemini_api_key.clone().filter(|s| !s.is_empty());
+            let Some(key) = key.or_else(|| stored("gemini_api_key")) else {
+                log::info!(
+                    "No Gemini API key found in environment or keystore; AI features are off \
+                     until one is set or another provider is chosen in Settings."
+                );
+                return None;
+            };
+            log::info!("Gemini LLM adapter ready ({}).", config.llm_model);
+            Some(Arc::new(LlmAdapter::gemini(key, config.llm_model.clone())))
+        },
+        LlmProvider::OpenAiCompatible => {
+            let base_url = config.llm_base_url.clone().filter(|s| !s.is_empty());
+            let Some(base_url) = base_url.or_else(|| stored("llm_base_url")) else {
+                log::info!("No base URL configured for the OpenAI-compatible LLM provider.");
+                return None;
+            };
+            // LLM_MODEL only applies when the provider itself comes from the environment;
+            // otherwise it still holds the Gemini default.
+            let model = if config.llm_provider.is_some() {
+                config.llm_model.clone()
+            } else {
+                stored("llm_model").unwrap_or_else(|| provider.default_model().to_string())
+            };
+            let api_key = config.llm_api_key.clone().or_else(|| stored("llm_api_key"));
+            log::info!("OpenAI-compatible LLM adapter ready ({model} at {base_url}).");
+            Some(Arc::new(LlmAdapter::openai_compatible(&base_url, api_key, model)))
+        },
+    }
+}
+
 /// Errors that can occur during context creation.
 #[derive(Debug, thiserror::Error)]
 pub enum AppContextError {
@@ -290,37 +421,103 @@ pub struct ServiceFactory;
 impl ServiceFactory {
     /// Creates the playlist ingestion use case.
     pub fn ingest_playlist(ctx: &AppContext) -> IngestPlaylistUseCase {
-        let batch_size = ServiceFactory::preferences(ctx)
-            .load()
-            .map(|p| p.boundary_batch_size() as usize)
-            .unwrap_or(5);
+        let prefs = ServiceFactory::preferences(ctx).load().ok();
+        let batch_size = prefs.as_ref().map_or(5, |p| p.boundary_batch_size() as usize);
+        let target_minutes = prefs.as_ref().map_or(0, |p| p.module_target_minutes());
+        let grouping = match prefs {
+            Some(p) if p.ml_boundary_enabled() => GroupingStrategy::TopicShift,
+            _ => GroupingStrategy::TitlePatterns,
+        };
 
         IngestPlaylistUseCase::new(
             ctx.youtube.clone(),
             ctx.course_repo.clone(),
-            ctx.module_repo.clone(),
-            ctx.video_repo.clone(),
             ctx.search_repo.clone(),
             ctx.llm.lock().as_ref().map(|a| Arc::clone(a) as Arc<dyn ModuleTitleGenerator>),
             batch_size,
+            grouping,
+            target_minutes,
         )
     }
 
     /// Creates the local library ingestion use case.
     pub fn ingest_local(ctx: &AppContext) -> IngestLocalUseCase {
-        let batch_size = ServiceFactory::preferences(ctx)
-            .load()
-            .map(|p| p.boundary_batch_size() as usize)
-            .unwrap_or(5);
+        let prefs = ServiceFactory::preferences(ctx).load().ok();
+        let batch_size = prefs.as_ref().map_or(5, |p| p.boundary_batch_size() as usize);
+        let target_minutes = prefs.as_ref().map_or(0, |p| p.module_target_minutes());
+        let grouping = match prefs {
+            Some(p) if p.ml_boundary_enabled() => GroupingStrategy::TopicShift,
+            _ => GroupingStrategy::TitlePatterns,
+        };
 
         IngestLocalUseCase::new(
+            ctx.local_media.clone(),
+            ctx.course_repo.clone(),
+            ctx.search_repo.clone(),
+            ctx.llm.lock().as_ref().map(|a| Arc::clone(a) as Arc<dyn ModuleTitleGenerator>),
+            batch_size,
+            grouping,
+            target_minutes,
+        )
+    }
+
+    /// Creates the use case that builds a course from a manifest file.
+    pub fn ingest_manifest(ctx: &AppContext) -> IngestManifestUseCase {
+        IngestManifestUseCase::new(
+            ctx.youtube.clone(),
+            ctx.local_media.clone(),
+            ctx.course_repo.clone(),
+            ctx.tag_repo.clone(),
+            ctx.search_repo.clone(),
+        )
+    }
+
+    /// Creates the use case that writes a course out as a manifest.
+    pub fn export_manifest(ctx: &AppContext) -> ExportManifestUseCase {
+        ExportManifestUseCase::new(
+            ctx.course_repo.clone(),
+            ctx.module_repo.clone(),
+            ctx.video_repo.clone(),
+            ctx.tag_repo.clone(),
+        )
+    }
+
+    /// Creates the local folder rescan use case.
+    pub fn rescan_local(ctx: &AppContext) -> RescanLocalUseCase {
+        RescanLocalUseCase::new(
             ctx.local_media.clone(),
             ctx.course_repo.clone(),
             ctx.module_repo.clone(),
             ctx.video_repo.clone(),
             ctx.search_repo.clone(),
             ctx.llm.lock().as_ref().map(|a| Arc::clone(a) as Arc<dyn ModuleTitleGenerator>),
+        )
+    }
+
+    /// Creates the use case that relinks a moved local course folder.
+    pub fn relink_local(ctx: &AppContext) -> RelinkLocalUseCase {
+        RelinkLocalUseCase::new(
+            ctx.local_media.clone(),
+            ctx.course_repo.clone(),
+            ctx.video_repo.clone(),
+        )
+    }
+
+    /// Creates the playlist re-sync use case.
+    pub fn sync_playlist(ctx: &AppContext) -> SyncPlaylistUseCase {
+        let prefs = ServiceFactory::preferences(ctx).load().ok();
+        let batch_size = prefs.as_ref().map_or(5, |p| p.boundary_batch_size() as usize);
+        let target_minutes = prefs.as_ref().map_or(0, |p| p.module_target_minutes());
+
+        SyncPlaylistUseCase::new(
+            ctx.youtube.clone(),
+            ctx.course_repo.clone(),
+            ctx.module_repo.clone(),
+            ctx.video_repo.clone(),
+            ctx.search_repo.clone(),
+            ctx.llm.lock().as_ref().map(|a| Arc::clone(a) as Arc<dyn ModuleTitleGenerator>),
             batch_size,
+            target_minutes,
         )
     }
 
@@ -339,6 +536,10 @@ impl ServiceFactory {
             ctx.module_repo.clone(),
             ctx.course_repo.clone(),
             ctx.note_repo.clone(),
+            ctx.chat_repo.clone(),
+            ctx.transcript_chunk_repo.clone(),
+            ctx.transcript_cue_repo.clone(),
+            ctx.transcript.clone(),
         ))
     }
 
@@ -392,6 +593,53 @@ impl ServiceFactory {
         )
     }
 
+    /// Creates the study history use case.
+    pub fn study_history(ctx: &AppContext) -> LoadStudyHistoryUseCase {
+        LoadStudyHistoryUseCase::new(
+            ctx.course_repo.clone(),
+            ctx.video_repo.clone(),
+            ctx.watch_session_repo.clone(),
+        )
+    }
+
+    /// Creates the course schedule planning use case.
+    pub fn plan_course_schedule(ctx: &AppContext) -> PlanCourseScheduleUseCase {
+        PlanCourseScheduleUseCase::new(
+            ctx.course_repo.clone(),
+            ctx.video_repo.clone(),
+            ctx.schedule_repo.clone(),
+        )
+    }
+
+    /// Creates the study schedule rescheduling use case.
+    pub fn reschedule_course(ctx: &AppContext) -> RescheduleCourseUseCase {
+        RescheduleCourseUseCase::new(ctx.video_repo.clone(), ctx.schedule_repo.clone())
+    }
+
+    /// Creates the study schedule calendar export use case.
+    pub fn export_schedule(ctx: &AppContext) -> ExportScheduleUseCase {
+        ExportScheduleUseCase::new(
+            ctx.course_repo.clone(),
+            ctx.video_repo.clone(),
+            ctx.schedule_repo.clone(),
+            ctx.calendar.clone(),
+        )
+    }
+
+    /// Creates the playback position use case.
+    pub fn playback(ctx: &AppContext) -> PlaybackUseCase {
+        PlaybackUseCase::new(
+            ctx.playback_repo.clone(),
+            ctx.watch_session_repo.clone(),
+            ctx.video_repo.clone(),
+        )
+    }
+
+    /// Creates the spaced-repetition review use case.
+    pub fn review(ctx: &AppContext) -> ReviewUseCase {
+        ReviewUseCase::new(ctx.exam_repo.clone(), ctx.review_repo.clone(), ctx.video_repo.clone())
+    }
+
     /// Creates the preferences use case.
     pub fn preferences(ctx: &AppContext) -> PreferencesUseCase {
         PreferencesUseCase::new(ctx.preferences_repo.clone())
@@ -404,12 +652,33 @@ impl ServiceFactory {
         Some(SummarizeVideoUseCase::new(llm, ctx.transcript.clone(), ctx.video_repo.clone()))
     }
 
+    /// Creates the flashcard editing use case.
+    pub fn flashcards(ctx: &AppContext) -> FlashcardsUseCase {
+        FlashcardsUseCase::new(ctx.video_repo.clone(), ctx.flashcard_repo.clone())
+    }
+
+    /// Creates the flashcard generation use case.
+    pub fn generate_flashcards(ctx: &AppContext) -> Option<GenerateFlashcardsUseCase> {
+        let llm = ctx.llm.lock().as_ref()?.clone();
+
+        Some(GenerateFlashcardsUseCase::new(
+            llm,
+            ctx.video_repo.clone(),
+            ctx.flashcard_repo.clone(),
+        ))
+    }
+
     /// Creates the exam use case.
     pub fn take_exam(ctx: &AppContext) -> Option<TakeExamUseCase> {
         let llm = ctx.llm.lock().as_ref()?.clone();
 
         Some(TakeExamUseCase::new(llm, ctx.video_repo.clone(), ctx.exam_repo.clone()))
     }
+
+    /// Creates the exam grading use case, which works without an LLM.
+    pub fn grade_exam(ctx: &AppContext) -> GradeExamUseCase {
+        GradeExamUseCase::new(ctx.video_repo.clone(), ctx.exam_repo.clone())
+    }
 }
 
 #[cfg(test)]
@@ -417,14 +686,14 @@ mod tests {
     use parking_lot::Mutex;
     use std::sync::Arc;
 
-    use crate::infrastructure::llm::GeminiAdapter;
+    use crate::infrastructure::llm::LlmAdapter;
 
     #[test]
     fn mutex_llm_starts_none_and_becomes_some_after_set() {
-        let llm: Mutex<Option<Arc<GeminiAdapter>>> = Mutex::new(None);
+        let llm: Mutex<Option<Arc<LlmAdapter>>> = Mutex::new(None);
         assert!(llm.lock().is_none(), "LLM should start as None");
 
-        *llm.lock() = Some(Arc::new(GeminiAdapter::new(
+        *llm.lock() = Some(Arc::new(LlmAdapter::gemini(
             "test-key".to_string(),
             "gemini-3.1-flash-lite".to_string(),
         )));
@@ -433,9 +702,10 @@ mod tests {
 
     #[test]
     fn mutex_llm_can_be_reset_to_none() {
-        let llm: Mutex<Option<Arc<GeminiAdapter>>> = Mutex::new(Some(Arc::new(
-            GeminiAdapter::new("test-key".to_string(), "gemini-3.1-flash-lite".to_string()),
-        )));
+        let llm: Mutex<Option<Arc<LlmAdapter>>> = Mutex::new(Some(Arc::new(LlmAdapter::gemini(
+            "test-key".to_string(),
+            "gemini-3.1-flash-lite".to_string(),
+        ))));
         assert!(llm.lock().is_some(), "LLM should start as Some");
 
         *llm.lock() = None;
@@ -447,5 +717,28 @@ mod tests {
         let cfg = super::AppConfig::default();
         assert!(cfg.gemini_api_key.is_none());
         assert_eq!(cfg.llm_model, "gemini-3.1-flash-lite");
+        assert!(cfg.llm_provider.is_none());
+    }
+
+    #[test]
+    fn llm_provider_round_trips_and_accepts_aliases() {
+        use super::LlmProvider;
+
+        for provider in [LlmProvider::Gemini, LlmProvider::OpenAiCompatible] {
+            assert_eq!(provider.as_str().parse::<LlmProvider>(), Ok(provider));
+        }
+        assert_eq!(" Ollama ".parse::<LlmProvider>(), Ok(LlmProvider::OpenAiCompatible));
+        assert!("claude".parse::<LlmProvider>().is_err());
+    }
+
+    #[test]
+    fn builder_switches_to_an_openai_compatible_server() {
+        let cfg = super::AppConfig::builder()
+            .openai_compatible(" http://localhost:11434/v1 ")
+            .llm_model("qwen2.5")
+            .build();
+        assert_eq!(cfg.llm_provider, Some(super::LlmProvider::OpenAiCompatible));
+        assert_eq!(cfg.llm_base_url.as_deref(), Some("http://localhost:11434/v1"));
+        assert!(cfg.llm_api_key.is_none());
     }
 }
@@ -1,13 +1,17 @@
 //! Application Layer - Use cases and orchestration.
 
+use std::future::Future;
 use std::sync::Arc;
+use std::time::Duration;
 
-use crate::domain::ports::ModuleTitleGenerator;
+use crate::domain::ports::{CancellationToken, ModuleTitleGenerator};
 
 pub mod context;
 pub mod use_cases;
 
-pub use context::{AppConfig, AppConfigBuilder, AppContext, AppContextError, ServiceFactory};
+pub use context::{
+    AppConfig, AppConfigBuilder, AppContext, AppContextError, LlmProvider, ServiceFactory,
+};
 
 /// Generate a module title using the LLM-backed generator, falling back to
 /// the first video title or a generic "Module N" label.
@@ -25,3 +29,28 @@ pub(crate) async fn generate_module_title(
     }
     titles.first().cloned().unwrap_or_else(|| format!("Module {}", module_idx + 1))
 }
+
+/// How often [`until_cancelled`] checks its token.
+const CANCEL_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Runs `work` until it completes, or returns `None` once `cancel` is set.
+///
+/// The token is polled, so cancellation takes effect within [`CANCEL_POLL_INTERVAL`].
+/// Dropping the unfinished future stops it; subprocess-backed adapters kill their child.
+pub(crate) async fn until_cancelled<T>(
+    cancel: &CancellationToken,
+    work: impl Future<Output = T>,
+) -> Option<T> {
+    if cancel.is_cancelled() {
+        return None;
+    }
+    let cancelled = async {
+        while !cancel.is_cancelled() {
+            tokio::time::sleep(CANCEL_POLL_INTERVAL).await;
+        }
+    };
+    tokio::select! {
+        output = work => Some(output),
+        () = cancelled => None,
+    }
+}
@@ -1,16 +1,39 @@
 //! Ask Companion Use Case
 //!
-//! Handles Q&A with the AI companion in video context.
+//! Handles Q&A with the AI companion in video context, following on from the video's
+//! saved chat history and grounded in the transcript passages most relevant to the question.
+//! "Explain this moment" requests are grounded in what was said just before the playback
+//! position instead.
 
+use std::path::Path;
 use std::sync::Arc;
 
+use parking_lot::Mutex;
+
+use crate::application::until_cancelled;
 use crate::domain::{
+    entities::Video,
     ports::{
-        CompanionAI, CompanionContext, CourseRepository, LLMError, ModuleRepository,
-        NoteRepository, RepositoryError, VideoRepository,
+        CancellationToken, ChatMessageRepository, ChatRole, CompanionAI, CompanionContext,
+        ConversationHistory, ConversationTurn, CourseRepository, LLMError, ModuleRepository,
+        NoteRepository, PlaybackMoment, RepositoryError, TranscriptChunk,
+        TranscriptChunkRepository, TranscriptCue, TranscriptCueRepository, TranscriptProvider,
+        VideoRepository,
     },
+    services::{ConversationWindow, SubtitleCleaner, TranscriptChunker, TranscriptWindow},
     value_objects::VideoId,
 };
+use crate::infrastructure::media_hash;
+
+/// Size in characters of the transcript chunks indexed for retrieval. Smaller than the
+/// summarizer's chunks so that each cited passage stays on one point.
+const RETRIEVAL_CHUNK_SIZE: usize = 1200;
+/// Overlap in characters between neighbouring retrieval chunks.
+const RETRIEVAL_CHUNK_OVERLAP: usize = 200;
+/// Number of transcript chunks sent with each question.
+const RETRIEVED_CHUNKS: usize = 4;
+/// Subtitle formats looked for next to a local video, in order of preference.
+const SIDECAR_SUBTITLE_EXTENSIONS: &[&str] = &["vtt", "srt"];
 
 /// Error type for companion queries.
 #[derive(Debug, thiserror::Error)]
@@ -19,6 +42,8 @@ pub enum CompanionError {
     AI(#[from] LLMError),
     #[error(transparent)]
     Repository(#[from] RepositoryError),
+    #[error("No transcript covers this part of the video")]
+    NoTranscript,
 }
 
 /// Input for the ask companion use case.
@@ -29,6 +54,33 @@ pub struct AskCompanionInput {
     pub local_context: Option<String>,
 }
 
+/// Input for [`AskCompanionUseCase::explain_moment_streaming`].
+#[derive(Debug, Clone)]
+pub struct ExplainMomentInput {
+    pub video_id: VideoId,
+    /// Playback position in seconds, relative to the start of the lesson.
+    pub position_secs: u32,
+    pub local_context: Option<String>,
+}
+
+impl ExplainMomentInput {
+    /// How a request to explain `position_secs` appears in the conversation, e.g.
+    /// `Explain this moment (4:05)`.
+    pub fn question_at(position_secs: u32) -> String {
+        format!("Explain this moment ({})", PlaybackMoment::clock(position_secs))
+    }
+}
+
+/// Answer produced by [`AskCompanionUseCase::execute_streaming`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StreamedAnswer {
+    pub text: String,
+    /// Generation was stopped before it finished; `text` holds what arrived until then.
+    pub stopped: bool,
+    /// Transcript passages the companion was given, in the order it cites them (`[1]` first).
+    pub sources: Vec<TranscriptChunk>,
+}
+
 /// Use case for asking questions to the AI companion.
 pub struct AskCompanionUseCase {
     companion: Arc<dyn CompanionAI>,
@@ -36,26 +88,166 @@ pub struct AskCompanionUseCase {
     module_repo: Arc<dyn ModuleRepository>,
     course_repo: Arc<dyn CourseRepository>,
     note_repo: Arc<dyn NoteRepository>,
+    chat_repo: Arc<dyn ChatMessageRepository>,
+    chunk_repo: Arc<dyn TranscriptChunkRepository>,
+    cue_repo: Arc<dyn TranscriptCueRepository>,
+    transcripts: Arc<dyn TranscriptProvider>,
+    window: ConversationWindow,
+    moment_window: TranscriptWindow,
 }
 
 impl AskCompanionUseCase {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         companion: Arc<dyn CompanionAI>,
         video_repo: Arc<dyn VideoRepository>,
         module_repo: Arc<dyn ModuleRepository>,
         course_repo: Arc<dyn CourseRepository>,
         note_repo: Arc<dyn NoteRepository>,
+        chat_repo: Arc<dyn ChatMessageRepository>,
+        chunk_repo: Arc<dyn TranscriptChunkRepository>,
+        cue_repo: Arc<dyn TranscriptCueRepository>,
+        transcripts: Arc<dyn TranscriptProvider>,
     ) -> Self {
-        Self { companion, video_repo, module_repo, course_repo, note_repo }
+        Self {
+            companion,
+            video_repo,
+            module_repo,
+            course_repo,
+            note_repo,
+            chat_repo,
+            chunk_repo,
+            cue_repo,
+            transcripts,
+            window: ConversationWindow::new(),
+            moment_window: TranscriptWindow::new(),
+        }
+    }
+
+    /// Sets how many tokens of earlier conversation are sent with each question.
+    pub fn with_history_budget(mut self, tokens: usize) -> Self {
+        self.window = ConversationWindow::with_budget(tokens);
+        self
+    }
+
+    /// Sets how many seconds before the playback position an explained moment reaches back.
+    pub fn with_moment_lookback(mut self, secs: u32) -> Self {
+        self.moment_window = TranscriptWindow::with_lookback(secs);
+        self
     }
 
     /// Executes the Q&A request.
     pub async fn execute(&self, input: AskCompanionInput) -> Result<String, CompanionError> {
-        // Get video
-        let video = self.video_repo.find_by_id(&input.video_id)?.ok_or_else(|| {
-            RepositoryError::NotFound { entity: "Video", id: input.video_id.to_string() }
-        })?;
+        let video = self.video(&input.video_id)?;
+        let mut context = self.context(&video, input.local_context.clone())?;
+        context.transcript_excerpts = self.retrieve(&video, &input.question)?;
+        let history = self.history(&input.video_id, &input.question)?;
+        self.companion.ask(&input.question, &context, &history).await.map_err(CompanionError::from)
+    }
+
+    /// Executes the Q&A request, passing the answer to `on_delta` piece by piece as it is
+    /// generated.
+    ///
+    /// Cancelling stops generation and returns the partial answer marked as `stopped`.
+    pub async fn execute_streaming(
+        &self,
+        input: AskCompanionInput,
+        on_delta: &(dyn Fn(&str) + Send + Sync),
+        cancel: &CancellationToken,
+    ) -> Result<StreamedAnswer, CompanionError> {
+        let video = self.video(&input.video_id)?;
+        let mut context = self.context(&video, input.local_context.clone())?;
+        context.transcript_excerpts = self.retrieve(&video, &input.question)?;
+        let history = self.history(&input.video_id, &input.question)?;
+        self.stream(&input.question, context, &history, on_delta, cancel).await
+    }
+
+    /// Asks the companion to explain what was said just before `input.position_secs`, passing
+    /// the answer to `on_delta` piece by piece as it is generated.
+    ///
+    /// The request is posed as [`ExplainMomentInput::question_at`]. Timed captions are fetched
+    /// on first use and kept; without them the moment is estimated from the plain transcript.
+    pub async fn explain_moment_streaming(
+        &self,
+        input: ExplainMomentInput,
+        on_delta: &(dyn Fn(&str) + Send + Sync),
+        cancel: &CancellationToken,
+    ) -> Result<StreamedAnswer, CompanionError> {
+        let video = self.video(&input.video_id)?;
+        let Some(moment) = until_cancelled(cancel, self.moment(&video, input.position_secs)).await
+        else {
+            return Ok(StreamedAnswer { text: String::new(), stopped: true, sources: vec![] });
+        };
+        let mut context = self.context(&video, input.local_context.clone())?;
+        context.moment = Some(moment?);
+        let question = ExplainMomentInput::question_at(input.position_secs);
+        let history = self.history(&input.video_id, &question)?;
+        self.stream(&question, context, &history, on_delta, cancel).await
+    }
+
+    /// Streams the answer to `question`, keeping what arrived if `cancel` stops it.
+    async fn stream(
+        &self,
+        question: &str,
+        context: CompanionContext,
+        history: &ConversationHistory,
+        on_delta: &(dyn Fn(&str) + Send + Sync),
+        cancel: &CancellationToken,
+    ) -> Result<StreamedAnswer, CompanionError> {
+        let partial = Mutex::new(String::new());
+        let collect = |delta: &str| {
+            partial.lock().push_str(delta);
+            on_delta(delta);
+        };
+        let answer = until_cancelled(
+            cancel,
+            self.companion.ask_streaming(question, &context, history, &collect),
+        )
+        .await;
+        let sources = context.transcript_excerpts;
+        match answer {
+            Some(text) => Ok(StreamedAnswer { text: text?, stopped: false, sources }),
+            None => Ok(StreamedAnswer { text: partial.into_inner(), stopped: true, sources }),
+        }
+    }
 
+    /// The video's earlier conversation, fitted to the history budget.
+    ///
+    /// Callers usually save the question before asking, so a trailing copy of it is left out.
+    fn history(
+        &self,
+        video_id: &VideoId,
+        question: &str,
+    ) -> Result<ConversationHistory, CompanionError> {
+        let mut turns: Vec<ConversationTurn> = self
+            .chat_repo
+            .find_by_video(video_id)?
+            .into_iter()
+            .map(|message| ConversationTurn { role: message.role, content: message.content })
+            .collect();
+        if turns
+            .last()
+            .is_some_and(|t| t.role == ChatRole::User && t.content.trim() == question.trim())
+        {
+            turns.pop();
+        }
+        Ok(self.window.build(turns))
+    }
+
+    fn video(&self, video_id: &VideoId) -> Result<Video, CompanionError> {
+        Ok(self.video_repo.find_by_id(video_id)?.ok_or_else(|| RepositoryError::NotFound {
+            entity: "Video",
+            id: video_id.to_string(),
+        })?)
+    }
+
+    /// Gathers what the companion knows about the video being asked about, without any
+    /// transcript passages.
+    fn context(
+        &self,
+        video: &Video,
+        local_context: Option<String>,
+    ) -> Result<CompanionContext, CompanionError> {
         // Get module
         let module = self.module_repo.find_by_id(video.module_id())?.ok_or_else(|| {
             RepositoryError::NotFound { entity: "Module", id: video.module_id().to_string() }
@@ -67,20 +259,135 @@ impl AskCompanionUseCase {
         })?;
 
         let notes =
-            self.note_repo.find_by_video(&input.video_id)?.map(|note| note.content().to_string());
+            self.note_repo.find_by_video(video.id())?.map(|note| note.content().to_string());
 
-        // Build context
-        let context = CompanionContext {
+        Ok(CompanionContext {
             video_title: video.title().to_string(),
             video_description: video.description().map(|s| s.to_string()),
             module_title: module.title().to_string(),
             course_name: course.name().to_string(),
             summary: video.summary().map(|s| s.to_string()),
             notes,
-            local_context: input.local_context.clone(),
+            local_context,
+            transcript_excerpts: vec![],
+            moment: None,
+        })
+    }
+
+    /// The transcript chunks most relevant to `question`.
+    ///
+    /// The video's chunk index is rebuilt first whenever its transcript has changed since it was
+    /// last indexed.
+    fn retrieve(
+        &self,
+        video: &Video,
+        question: &str,
+    ) -> Result<Vec<TranscriptChunk>, CompanionError> {
+        let indexed = self.chunk_repo.indexed_hash(video.id())?;
+        let Some(transcript) = video.transcript().filter(|t| !t.trim().is_empty()) else {
+            if indexed.is_some() {
+                self.chunk_repo.remove_video(video.id())?;
+            }
+            return Ok(vec![]);
         };
 
-        // Ask the AI
-        self.companion.ask(&input.question, &context).await.map_err(CompanionError::from)
+        let hash = media_hash::compute_source_hash(transcript);
+        if indexed.as_deref() != Some(hash.as_str()) {
+            let chunks =
+                TranscriptChunker::with_params(RETRIEVAL_CHUNK_SIZE, RETRIEVAL_CHUNK_OVERLAP)
+                    .chunk(transcript);
+            self.chunk_repo.replace_chunks(video.id(), &hash, &chunks)?;
+        }
+        Ok(self.chunk_repo.search(video.id(), question, RETRIEVED_CHUNKS)?)
     }
+
+    /// What was said in the lookback before `position_secs` into the lesson.
+    ///
+    /// A lesson cut from a longer file shares that file's captions, so they are shifted to the
+    /// lesson's start and trimmed to its range.
+    async fn moment(
+        &self,
+        video: &Video,
+        position_secs: u32,
+    ) -> Result<PlaybackMoment, CompanionError> {
+        let cues = self.cues(video).await?;
+        if !cues.is_empty() {
+            let cues = match video.clip() {
+                Some(clip) => {
+                    let (start_ms, end_ms) =
+                        (u64::from(clip.start_secs()) * 1000, u64::from(clip.end_secs()) * 1000);
+                    cues.into_iter()
+                        .filter(|cue| cue.end_ms > start_ms && cue.start_ms < end_ms)
+                        .map(|cue| TranscriptCue {
+                            start_ms: cue.start_ms.saturating_sub(start_ms),
+                            end_ms: cue.end_ms - start_ms,
+                            text: cue.text,
+                        })
+                        .collect()
+                },
+                None => cues,
+            };
+            return self
+                .moment_window
+                .from_cues(&cues, position_secs)
+                .ok_or(CompanionError::NoTranscript);
+        }
+
+        // The plain transcript of a clip covers the whole file, so it can't be placed in time.
+        video
+            .transcript()
+            .filter(|_| video.clip().is_none())
+            .and_then(|t| self.moment_window.from_text(t, video.duration_secs(), position_secs))
+            .ok_or(CompanionError::NoTranscript)
+    }
+
+    /// The video's timed captions, fetched and stored on first use. Empty when it has none.
+    ///
+    /// Stored cues are reused only while their source is unchanged: the YouTube transcript, or
+    /// the local file's path and its sidecar subtitles.
+    async fn cues(&self, video: &Video) -> Result<Vec<TranscriptCue>, CompanionError> {
+        let sidecar = video.local_path().and_then(sidecar_subtitle);
+        let source = match video.youtube_id() {
+            Some(youtube_id) => format!(
+                "youtube:{}\n{}",
+                youtube_id.as_str(),
+                video.transcript().unwrap_or_default()
+            ),
+            None => format!(
+                "local:{}\n{}",
+                video.local_path().unwrap_or_default(),
+                sidecar.as_deref().unwrap_or_default()
+            ),
+        };
+        let hash = media_hash::compute_source_hash(&source);
+        let stored_hash = self.cue_repo.source_hash(video.id())?;
+        if stored_hash.as_deref() == Some(hash.as_str()) {
+            return Ok(self.cue_repo.find_by_video(video.id())?);
+        }
+
+        let fetched = if let Some(youtube_id) = video.youtube_id() {
+            match self.transcripts.fetch_cues(youtube_id.as_str()).await {
+                Ok(cues) => cues,
+                Err(e) => {
+                    log::debug!("No timed captions for {}: {e}", youtube_id.as_str());
+                    vec![]
+                },
+            }
+        } else {
+            sidecar.map_or_else(Vec::new, |raw| SubtitleCleaner::new().cues(&raw))
+        };
+        // Stale cues are cleared even when the new source has none
+        if !fetched.is_empty() || stored_hash.is_some() {
+            self.cue_repo.replace_for_video(video.id(), &hash, &fetched)?;
+        }
+        Ok(fetched)
+    }
+}
+
+/// Reads the subtitle file saved next to a local video under the same name, if any.
+fn sidecar_subtitle(video_path: &str) -> Option<String> {
+    let path = Path::new(video_path);
+    SIDECAR_SUBTITLE_EXTENSIONS
+        .iter()
+        .find_map(|ext| std::fs::read_to_string(path.with_extension(ext)).ok())
 }
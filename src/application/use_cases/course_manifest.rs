@@ -0,0 +1,273 @@
+//! Course Manifest
+//!
+//! A declarative description of a course (name, tags, modules and lessons) meant to be kept
+//! in version control. `IngestManifestUseCase` builds a course from one and
+//! `ExportManifestUseCase` writes any course back out in the same shape.
+
+use std::str::FromStr;
+
+use crate::domain::ports::{FetchError, LocalMediaError, RepositoryError};
+use crate::domain::value_objects::ClipRange;
+
+/// Error type for manifest import and export.
+#[derive(Debug, thiserror::Error)]
+pub enum ManifestError {
+    #[error("Unsupported manifest format: {0} (expected json or toml)")]
+    UnsupportedFormat(String),
+    #[error("Could not parse manifest: {0}")]
+    Parse(String),
+    #[error("Could not write manifest: {0}")]
+    Render(String),
+    #[error("Invalid manifest: {0}")]
+    Invalid(String),
+    #[error("Course not found")]
+    CourseNotFound,
+    #[error("Course already exists: {0}")]
+    AlreadyExists(String),
+    #[error(transparent)]
+    FetchFailed(#[from] FetchError),
+    #[error(transparent)]
+    ScanFailed(#[from] LocalMediaError),
+    #[error(transparent)]
+    Repository(#[from] RepositoryError),
+}
+
+/// Serialization format of a manifest file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ManifestFormat {
+    Json,
+    Toml,
+}
+
+impl ManifestFormat {
+    /// Picks the format from a file name's extension.
+    pub fn from_path(path: &str) -> Result<Self, ManifestError> {
+        let extension =
+            std::path::Path::new(path).extension().and_then(|ext| ext.to_str()).unwrap_or_default();
+        extension.parse()
+    }
+
+    /// File extension, without the dot.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::Toml => "toml",
+        }
+    }
+}
+
+impl FromStr for ManifestFormat {
+    type Err = ManifestError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "json" => Ok(Self::Json),
+            "toml" => Ok(Self::Toml),
+            other => Err(ManifestError::UnsupportedFormat(other.to_string())),
+        }
+    }
+}
+
+/// A course as described by a manifest file.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CourseManifest {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub modules: Vec<ManifestModule>,
+}
+
+/// A module and its lessons, in order.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ManifestModule {
+    pub title: String,
+    #[serde(default)]
+    pub lessons: Vec<ManifestLesson>,
+}
+
+/// A lesson: exactly one of `youtube_id` or `path`, plus optional overrides.
+///
+/// Relative paths are resolved against the folder the manifest is imported from. Title and
+/// duration are read from the video when left out.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ManifestLesson {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub youtube_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration_secs: Option<u32>,
+    /// Start of the part of the video the lesson covers, for chapter lessons.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_secs: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_secs: Option<u32>,
+}
+
+/// Where a manifest lesson's video lives.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LessonSource<'a> {
+    YouTube(&'a str),
+    Local(&'a str),
+}
+
+impl CourseManifest {
+    /// Parses and validates a manifest.
+    pub fn parse(text: &str, format: ManifestFormat) -> Result<Self, ManifestError> {
+        let manifest: Self = match format {
+            ManifestFormat::Json => {
+                serde_json::from_str(text).map_err(|e| ManifestError::Parse(e.to_string()))?
+            },
+            ManifestFormat::Toml => {
+                toml::from_str(text).map_err(|e| ManifestError::Parse(e.to_string()))?
+            },
+        };
+        manifest.validate()?;
+        Ok(manifest)
+    }
+
+    /// Writes the manifest in the given format.
+    pub fn render(&self, format: ManifestFormat) -> Result<String, ManifestError> {
+        match format {
+            ManifestFormat::Json => {
+                serde_json::to_string_pretty(self).map_err(|e| ManifestError::Render(e.to_string()))
+            },
+            ManifestFormat::Toml => {
+                toml::to_string_pretty(self).map_err(|e| ManifestError::Render(e.to_string()))
+            },
+        }
+    }
+
+    /// Checks the course has a name and every lesson a single source and a valid clip.
+    pub fn validate(&self) -> Result<(), ManifestError> {
+        if self.name.trim().is_empty() {
+            return Err(ManifestError::Invalid("course name is empty".to_string()));
+        }
+        for (m, module) in self.modules.iter().enumerate() {
+            if module.title.trim().is_empty() {
+                return Err(ManifestError::Invalid(format!("module {} has no title", m + 1)));
+            }
+            for (l, lesson) in module.lessons.iter().enumerate() {
+                let at = || format!("module {}, lesson {}", m + 1, l + 1);
+                lesson.source().map_err(|e| ManifestError::Invalid(format!("{}: {e}", at())))?;
+                lesson.clip().map_err(|e| ManifestError::Invalid(format!("{}: {e}", at())))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Total number of lessons across all modules.
+    pub fn lesson_count(&self) -> usize {
+        self.modules.iter().map(|m| m.lessons.len()).sum()
+    }
+}
+
+impl ManifestLesson {
+    /// The lesson's video, or why it does not name exactly one.
+    pub fn source(&self) -> Result<LessonSource<'_>, String> {
+        match (self.youtube_id.as_deref(), self.path.as_deref()) {
+            (Some(id), None) => Ok(LessonSource::YouTube(id.trim())),
+            (None, Some(path)) => Ok(LessonSource::Local(path.trim())),
+            (Some(_), Some(_)) => Err("set either youtube_id or path, not both".to_string()),
+            (None, None) => Err("youtube_id or path is required".to_string()),
+        }
+    }
+
+    /// The part of the video the lesson covers, if it is a clip.
+    pub fn clip(&self) -> Result<Option<ClipRange>, String> {
+        match (self.start_secs, self.end_secs) {
+            (None, None) => Ok(None),
+            (Some(start), Some(end)) => ClipRange::new(start, end)
+                .map(Some)
+                .ok_or_else(|| format!("clip must end after it starts ({start}s..{end}s)")),
+            _ => Err("start_secs and end_secs must be set together".to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manifest() -> CourseManifest {
+        CourseManifest {
+            name: "Rust Basics".to_string(),
+            description: Some("From zero to ownership".to_string()),
+            tags: vec!["rust".to_string()],
+            modules: vec![ManifestModule {
+                title: "Getting Started".to_string(),
+                lessons: vec![
+                    ManifestLesson {
+                        youtube_id: Some("dQw4w9WgXcQ".to_string()),
+                        title: Some("Welcome".to_string()),
+                        ..Default::default()
+                    },
+                    ManifestLesson {
+                        path: Some("01-setup/install.mp4".to_string()),
+                        duration_secs: Some(300),
+                        start_secs: Some(60),
+                        end_secs: Some(360),
+                        ..Default::default()
+                    },
+                ],
+            }],
+        }
+    }
+
+    #[test]
+    fn round_trips_through_both_formats() {
+        for format in [ManifestFormat::Json, ManifestFormat::Toml] {
+            let text = manifest().render(format).unwrap();
+            assert_eq!(CourseManifest::parse(&text, format).unwrap(), manifest());
+        }
+    }
+
+    #[test]
+    fn parses_hand_written_toml() {
+        let text = r#"
+name = "Rust Basics"
+tags = ["rust"]
+
+[[modules]]
+title = "Getting Started"
+
+[[modules.lessons]]
+youtube_id = "dQw4w9WgXcQ"
+"#;
+        let parsed = CourseManifest::parse(text, ManifestFormat::Toml).unwrap();
+        assert_eq!(parsed.lesson_count(), 1);
+        assert_eq!(parsed.modules[0].lessons[0].source(), Ok(LessonSource::YouTube("dQw4w9WgXcQ")));
+    }
+
+    #[test]
+    fn rejects_lessons_without_a_single_source_or_with_a_half_clip() {
+        let mut m = manifest();
+        m.modules[0].lessons[0].path = Some("intro.mp4".to_string());
+        assert!(
+            matches!(m.validate(), Err(ManifestError::Invalid(msg)) if msg.contains("lesson 1"))
+        );
+
+        let mut m = manifest();
+        m.modules[0].lessons[1].end_secs = None;
+        assert!(matches!(m.validate(), Err(ManifestError::Invalid(_))));
+
+        let err =
+            CourseManifest::parse(r#"{"name":"x","modules":[],"colour":1}"#, ManifestFormat::Json);
+        assert!(matches!(err, Err(ManifestError::Parse(_))));
+    }
+
+    #[test]
+    fn format_follows_the_file_extension() {
+        assert_eq!(ManifestFormat::from_path("course.TOML").unwrap(), ManifestFormat::Toml);
+        assert_eq!(ManifestFormat::from_path("/tmp/course.json").unwrap(), ManifestFormat::Json);
+        assert!(ManifestFormat::from_path("course.yaml").is_err());
+    }
+}
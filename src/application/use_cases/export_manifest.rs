@@ -0,0 +1,122 @@
+//! Export Manifest Use Case
+//!
+//! Orchestrates: Load course + modules + videos + tags -> Build manifest -> Serialize (JSON/TOML)
+
+use std::path::Path;
+use std::sync::Arc;
+
+use super::course_manifest::{
+    CourseManifest, ManifestError, ManifestFormat, ManifestLesson, ManifestModule,
+};
+use super::export_schedule::file_stem;
+use crate::domain::{
+    ports::{CourseRepository, ModuleRepository, TagRepository, VideoRepository},
+    value_objects::{CourseId, VideoSource},
+};
+
+/// Input for exporting a course manifest.
+#[derive(Debug, Clone)]
+pub struct ExportManifestInput {
+    pub course_id: CourseId,
+    pub format: ManifestFormat,
+    /// Folder local lesson paths are written relative to. Defaults to the course's own
+    /// folder; files outside it keep their absolute path.
+    pub base_dir: Option<String>,
+}
+
+/// Output of the export: a manifest document ready to be written to disk.
+#[derive(Debug)]
+pub struct ExportManifestOutput {
+    /// Suggested file name, e.g. `rust-basics.toml`.
+    pub file_name: String,
+    pub content: String,
+}
+
+/// Use case for writing an existing course out as a manifest.
+pub struct ExportManifestUseCase {
+    course_repo: Arc<dyn CourseRepository>,
+    module_repo: Arc<dyn ModuleRepository>,
+    video_repo: Arc<dyn VideoRepository>,
+    tag_repo: Arc<dyn TagRepository>,
+}
+
+impl ExportManifestUseCase {
+    pub fn new(
+        course_repo: Arc<dyn CourseRepository>,
+        module_repo: Arc<dyn ModuleRepository>,
+        video_repo: Arc<dyn VideoRepository>,
+        tag_repo: Arc<dyn TagRepository>,
+    ) -> Self {
+        Self { course_repo, module_repo, video_repo, tag_repo }
+    }
+
+    /// Exports the course with its modules and lessons in their current order.
+    ///
+    /// Titles and durations are always written, so re-importing needs no network or media
+    /// probing.
+    pub fn execute(
+        &self,
+        input: ExportManifestInput,
+    ) -> Result<ExportManifestOutput, ManifestError> {
+        let course =
+            self.course_repo.find_by_id(&input.course_id)?.ok_or(ManifestError::CourseNotFound)?;
+        let base_dir = input.base_dir.as_deref().or(course.source().local_root()).map(Path::new);
+
+        let mut modules = self.module_repo.find_by_course(course.id())?;
+        modules.sort_by_key(|m| m.sort_order());
+        let mut manifest_modules = Vec::with_capacity(modules.len());
+        for module in &modules {
+            let mut videos = self.video_repo.find_by_module(module.id())?;
+            videos.sort_by_key(|v| v.sort_order());
+            let lessons = videos
+                .iter()
+                .map(|video| {
+                    let (youtube_id, path) = match video.source() {
+                        VideoSource::YouTube(id) => (Some(id.as_str().to_string()), None),
+                        VideoSource::LocalPath(path) => (None, Some(relative_to(path, base_dir))),
+                    };
+                    ManifestLesson {
+                        youtube_id,
+                        path,
+                        title: Some(video.title().to_string()),
+                        duration_secs: Some(video.duration_secs()),
+                        start_secs: video.clip().map(|clip| clip.start_secs()),
+                        end_secs: video.clip().map(|clip| clip.end_secs()),
+                    }
+                })
+                .collect();
+            manifest_modules.push(ManifestModule { title: module.title().to_string(), lessons });
+        }
+
+        let mut tags: Vec<String> = self
+            .tag_repo
+            .find_by_course(course.id())?
+            .iter()
+            .map(|t| t.name().to_string())
+            .collect();
+        tags.sort();
+
+        let manifest = CourseManifest {
+            name: course.name().to_string(),
+            description: course.description().map(str::to_string),
+            tags,
+            modules: manifest_modules,
+        };
+        Ok(ExportManifestOutput {
+            file_name: format!(
+                "{}.{}",
+                file_stem(course.name(), "course"),
+                input.format.extension()
+            ),
+            content: manifest.render(input.format)?,
+        })
+    }
+}
+
+/// `path` relative to `base_dir` when it lies inside it, otherwise unchanged.
+fn relative_to(path: &str, base_dir: Option<&Path>) -> String {
+    base_dir
+        .and_then(|base| Path::new(path).strip_prefix(base).ok())
+        .map(|relative| relative.to_string_lossy().to_string())
+        .unwrap_or_else(|| path.to_string())
+}
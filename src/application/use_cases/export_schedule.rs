@@ -0,0 +1,141 @@
+//! Export Schedule Use Case
+//!
+//! Orchestrates: Load schedule + videos -> Build calendar events -> Serialize (iCalendar)
+
+use std::sync::Arc;
+
+use chrono::NaiveTime;
+
+use crate::application::use_cases::ScheduleError;
+use crate::domain::{
+    ports::{
+        CalendarEvent, CalendarExporter, CourseRepository, StudyScheduleRepository, VideoRepository,
+    },
+    value_objects::CourseId,
+};
+
+/// Input for exporting a course schedule.
+#[derive(Debug, Clone)]
+pub struct ExportScheduleInput {
+    pub course_id: CourseId,
+    /// Local start time for every session. `None` exports all-day events.
+    pub start_time: Option<NaiveTime>,
+}
+
+/// Output of the export: a calendar document ready to be written to disk.
+#[derive(Debug)]
+pub struct ExportScheduleOutput {
+    /// Suggested file name, e.g. `rust-basics.ics`.
+    pub file_name: String,
+    pub content: String,
+    /// Planned sessions in the calendar.
+    pub events: usize,
+    /// Sessions dropped by earlier re-plans, exported as cancelled events.
+    pub cancelled: usize,
+}
+
+/// Use case for exporting a planned course schedule to a calendar file.
+pub struct ExportScheduleUseCase {
+    course_repo: Arc<dyn CourseRepository>,
+    video_repo: Arc<dyn VideoRepository>,
+    schedule_repo: Arc<dyn StudyScheduleRepository>,
+    exporter: Arc<dyn CalendarExporter>,
+}
+
+impl ExportScheduleUseCase {
+    pub fn new(
+        course_repo: Arc<dyn CourseRepository>,
+        video_repo: Arc<dyn VideoRepository>,
+        schedule_repo: Arc<dyn StudyScheduleRepository>,
+        exporter: Arc<dyn CalendarExporter>,
+    ) -> Self {
+        Self { course_repo, video_repo, schedule_repo, exporter }
+    }
+
+    /// Exports every session of the course's schedule as one calendar event.
+    ///
+    /// Event UIDs are derived from the course id and the session's position in the
+    /// plan, so re-exporting after a re-plan updates events instead of duplicating them.
+    /// The schedule's update time is the `SEQUENCE`, and positions a shrinking re-plan
+    /// dropped are exported as cancelled events so clients remove them.
+    pub fn execute(
+        &self,
+        input: ExportScheduleInput,
+    ) -> Result<ExportScheduleOutput, ScheduleError> {
+        let course =
+            self.course_repo.find_by_id(&input.course_id)?.ok_or(ScheduleError::CourseNotFound)?;
+        let schedule = self
+            .schedule_repo
+            .find_by_course(&input.course_id)?
+            .ok_or(ScheduleError::NotPlanned)?;
+
+        let total = schedule.sessions().len();
+        let peak = (schedule.peak_session_count() as usize).max(total);
+        let sequence = u32::try_from(schedule.updated_at().timestamp()).unwrap_or(0);
+        let uid =
+            |idx: usize| format!("session-{}.{}@course-pilot", idx + 1, course.id().as_uuid());
+        let mut events = Vec::with_capacity(peak);
+        for (idx, session) in schedule.sessions().iter().enumerate() {
+            let mut lines = Vec::with_capacity(session.video_ids().len() + 2);
+            for video_id in session.video_ids() {
+                if let Some(video) = self.video_repo.find_by_id(video_id)? {
+                    lines.push(format!(
+                        "- {} ({})",
+                        video.title(),
+                        format_minutes(video.duration_secs())
+                    ));
+                }
+            }
+            lines.push(String::new());
+            lines.push(format!("Total: {}", format_minutes(session.total_duration_secs())));
+
+            events.push(CalendarEvent {
+                uid: uid(idx),
+                date: session.date(),
+                start_time: input.start_time,
+                duration_secs: session.total_duration_secs(),
+                summary: format!("{}: session {} of {}", course.name(), idx + 1, total),
+                description: lines.join("\n"),
+                sequence,
+                cancelled: false,
+            });
+        }
+
+        // The dropped sessions' dates are gone, so cancelled events sit on the new end date.
+        let cancelled_date = schedule.end_date().unwrap_or(schedule.start_date());
+        for idx in total..peak {
+            events.push(CalendarEvent {
+                uid: uid(idx),
+                date: cancelled_date,
+                start_time: input.start_time,
+                duration_secs: 0,
+                summary: format!("{}: session {} (cancelled)", course.name(), idx + 1),
+                description: "Dropped when the schedule was re-planned.".to_string(),
+                sequence,
+                cancelled: true,
+            });
+        }
+
+        Ok(ExportScheduleOutput {
+            file_name: format!("{}.ics", file_stem(course.name(), "study-schedule")),
+            content: self.exporter.export(course.name(), &events),
+            events: total,
+            cancelled: peak - total,
+        })
+    }
+}
+
+fn format_minutes(secs: u32) -> String {
+    format!("{} min", secs.div_ceil(60))
+}
+
+/// Lowercase, dash-separated file stem from a course name, or `fallback` if nothing is left.
+pub(super) fn file_stem(name: &str, fallback: &str) -> String {
+    let stem = name
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+        .to_lowercase();
+    if stem.is_empty() { fallback.to_string() } else { stem }
+}
@@ -0,0 +1,204 @@
+//! Flashcard use cases.
+//!
+//! Orchestrates: Seed cards from key terms -> Generate cards with the LLM -> Edit/delete per video
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use crate::domain::entities::{Flashcard, FlashcardContentError, Video};
+use crate::domain::ports::{
+    FlashcardGeneratorAI, FlashcardRepository, LLMError, RepositoryError, VideoRepository,
+};
+use crate::domain::value_objects::{FlashcardId, FlashcardKind, KeyTerm, VideoId, parse_glossary};
+
+/// Error type for flashcard operations.
+#[derive(Debug, thiserror::Error)]
+pub enum FlashcardError {
+    #[error("Video not found")]
+    VideoNotFound,
+    #[error("Flashcard not found")]
+    CardNotFound,
+    #[error(transparent)]
+    Invalid(#[from] FlashcardContentError),
+    #[error(transparent)]
+    AI(#[from] LLMError),
+    #[error(transparent)]
+    Repository(#[from] RepositoryError),
+}
+
+/// Input for creating or editing a flashcard.
+#[derive(Debug, Clone)]
+pub struct SaveFlashcardInput {
+    /// `None` creates a new card at the end of the video's deck.
+    pub id: Option<FlashcardId>,
+    pub video_id: VideoId,
+    pub kind: FlashcardKind,
+    pub front: String,
+    pub back: String,
+}
+
+/// Input for generating flashcards with the LLM.
+#[derive(Debug, Clone)]
+pub struct GenerateFlashcardsInput {
+    pub video_id: VideoId,
+    pub max_cards: u8,
+    /// When true, the video's existing cards are deleted first.
+    pub replace_existing: bool,
+}
+
+/// Use case for listing and editing a video's flashcards.
+pub struct FlashcardsUseCase {
+    video_repo: Arc<dyn VideoRepository>,
+    card_repo: Arc<dyn FlashcardRepository>,
+}
+
+impl FlashcardsUseCase {
+    pub fn new(
+        video_repo: Arc<dyn VideoRepository>,
+        card_repo: Arc<dyn FlashcardRepository>,
+    ) -> Self {
+        Self { video_repo, card_repo }
+    }
+
+    /// Lists a video's cards in display order.
+    pub fn list(&self, video_id: &VideoId) -> Result<Vec<Flashcard>, FlashcardError> {
+        Ok(self.card_repo.find_by_video(video_id)?)
+    }
+
+    /// Creates or edits a card.
+    pub fn save(&self, input: SaveFlashcardInput) -> Result<Flashcard, FlashcardError> {
+        let card = match input.id {
+            Some(id) => {
+                let mut card =
+                    self.card_repo.find_by_id(&id)?.ok_or(FlashcardError::CardNotFound)?;
+                card.edit(input.kind, input.front, input.back)?;
+                card
+            },
+            None => {
+                let position = next_position(&self.card_repo.find_by_video(&input.video_id)?);
+                Flashcard::new(
+                    FlashcardId::new(),
+                    input.video_id,
+                    input.kind,
+                    input.front,
+                    input.back,
+                    position,
+                )?
+            },
+        };
+        self.card_repo.save(&card)?;
+        Ok(card)
+    }
+
+    pub fn delete(&self, id: &FlashcardId) -> Result<(), FlashcardError> {
+        Ok(self.card_repo.delete(id)?)
+    }
+
+    /// Adds a basic card for each of the video's key terms that has no card yet.
+    /// Returns the cards created.
+    pub fn seed_from_key_terms(
+        &self,
+        video_id: &VideoId,
+    ) -> Result<Vec<Flashcard>, FlashcardError> {
+        let video = self.video_repo.find_by_id(video_id)?.ok_or(FlashcardError::VideoNotFound)?;
+        let existing = self.card_repo.find_by_video(video_id)?;
+        let mut fronts = card_fronts(&existing);
+        let mut position = next_position(&existing);
+
+        let mut created = Vec::new();
+        for term in video_key_terms(&video) {
+            if !fronts.insert(term.term.to_lowercase()) {
+                continue;
+            }
+            let card = Flashcard::from_key_term(*video_id, &term, position);
+            self.card_repo.save(&card)?;
+            position += 1;
+            created.push(card);
+        }
+        Ok(created)
+    }
+}
+
+/// Use case for generating flashcards from a video's summary and transcript.
+pub struct GenerateFlashcardsUseCase {
+    generator: Arc<dyn FlashcardGeneratorAI>,
+    video_repo: Arc<dyn VideoRepository>,
+    card_repo: Arc<dyn FlashcardRepository>,
+}
+
+impl GenerateFlashcardsUseCase {
+    pub fn new(
+        generator: Arc<dyn FlashcardGeneratorAI>,
+        video_repo: Arc<dyn VideoRepository>,
+        card_repo: Arc<dyn FlashcardRepository>,
+    ) -> Self {
+        Self { generator, video_repo, card_repo }
+    }
+
+    /// Generates cards and appends them to the video's deck, skipping fronts it already has.
+    /// Returns the cards created.
+    pub async fn execute(
+        &self,
+        input: GenerateFlashcardsInput,
+    ) -> Result<Vec<Flashcard>, FlashcardError> {
+        let video =
+            self.video_repo.find_by_id(&input.video_id)?.ok_or(FlashcardError::VideoNotFound)?;
+
+        let generated = self
+            .generator
+            .generate_flashcards(
+                video.title(),
+                video.summary(),
+                video.transcript(),
+                &video_key_terms(&video),
+                input.max_cards,
+            )
+            .await?;
+
+        let mut existing = self.card_repo.find_by_video(&input.video_id)?;
+        if input.replace_existing {
+            for card in &existing {
+                self.card_repo.delete(card.id())?;
+            }
+            existing.clear();
+        }
+        let mut fronts = card_fronts(&existing);
+        let mut position = next_position(&existing);
+
+        let mut created = Vec::new();
+        for card in generated {
+            if !fronts.insert(card.front.trim().to_lowercase()) {
+                continue;
+            }
+            let card = Flashcard::new(
+                FlashcardId::new(),
+                input.video_id,
+                card.kind,
+                card.front,
+                card.back,
+                position,
+            )?;
+            self.card_repo.save(&card)?;
+            position += 1;
+            created.push(card);
+        }
+        Ok(created)
+    }
+}
+
+/// Glossary terms known for a video. Summaries stored before key terms were extracted
+/// separately carry them in a "Key Terms" section instead.
+fn video_key_terms(video: &Video) -> Vec<KeyTerm> {
+    if !video.key_terms().is_empty() {
+        return video.key_terms().to_vec();
+    }
+    video.summary().map(parse_glossary).unwrap_or_default()
+}
+
+fn card_fronts(cards: &[Flashcard]) -> HashSet<String> {
+    cards.iter().map(|c| c.front().to_lowercase()).collect()
+}
+
+fn next_position(cards: &[Flashcard]) -> u32 {
+    cards.iter().map(|c| c.position() + 1).max().unwrap_or(0)
+}
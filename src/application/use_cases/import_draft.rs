@@ -0,0 +1,289 @@
+//! Import Draft
+//!
+//! The proposed structure of a course before it is persisted: the `plan` step of both ingest
+//! use cases produces it, the import dialogs edit it, and `commit` turns it into a course.
+
+use crate::domain::{
+    entities::{Module, Video},
+    ports::{RawChapter, SearchEntry},
+    value_objects::{ClipRange, CourseId, ModuleId, VideoId, VideoSource, YouTubeVideoId},
+};
+
+/// Modules, videos and search entries of a course built from a draft.
+pub(super) type DraftContents = (Vec<Module>, Vec<Video>, Vec<SearchEntry>);
+
+/// Error when editing an import draft.
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum DraftEditError {
+    #[error("No module at position {0}")]
+    ModuleOutOfRange(usize),
+    #[error("Cannot split a module of {len} videos before video {at}")]
+    InvalidSplit { at: usize, len: usize },
+    #[error("Module title cannot be empty")]
+    EmptyTitle,
+}
+
+/// Where the drafted course comes from.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DraftSource {
+    Playlist { url: String },
+    LocalFolder { root: String },
+}
+
+/// A video as it will be imported.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct DraftVideo {
+    /// YouTube video ID for playlist drafts, absolute file path for local drafts.
+    pub source_ref: String,
+    /// Sanitized title.
+    pub title: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    pub duration_secs: u32,
+    /// Cleaned subtitle text found next to a local file.
+    #[serde(default)]
+    pub transcript: Option<String>,
+    #[serde(default)]
+    pub fingerprint: Option<String>,
+    /// Chapter of the source this lesson covers, when a long video was split.
+    #[serde(default)]
+    pub clip: Option<ClipRange>,
+}
+
+/// A proposed module and its videos, in order.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct DraftModule {
+    pub title: String,
+    pub videos: Vec<DraftVideo>,
+}
+
+/// Proposed course structure returned by the ingest `plan` step.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ImportDraft {
+    pub course_name: String,
+    pub source: DraftSource,
+    pub modules: Vec<DraftModule>,
+}
+
+impl ImportDraft {
+    /// Total number of videos across all modules.
+    pub fn video_count(&self) -> usize {
+        self.modules.iter().map(|m| m.videos.len()).sum()
+    }
+
+    /// Renames the module at `index`.
+    pub fn rename_module(&mut self, index: usize, title: &str) -> Result<(), DraftEditError> {
+        let title = title.trim();
+        if title.is_empty() {
+            return Err(DraftEditError::EmptyTitle);
+        }
+        self.module_mut(index)?.title = title.to_string();
+        Ok(())
+    }
+
+    /// Appends the videos of the module after `index` to it, keeping its title.
+    pub fn merge_with_next(&mut self, index: usize) -> Result<(), DraftEditError> {
+        if index + 1 >= self.modules.len() {
+            return Err(DraftEditError::ModuleOutOfRange(index + 1));
+        }
+        let next = self.modules.remove(index + 1);
+        self.modules[index].videos.extend(next.videos);
+        Ok(())
+    }
+
+    /// Moves the videos from `at` onwards into a new module right after `index`.
+    pub fn split_module(
+        &mut self,
+        index: usize,
+        at: usize,
+        title: &str,
+    ) -> Result<(), DraftEditError> {
+        let title = title.trim();
+        if title.is_empty() {
+            return Err(DraftEditError::EmptyTitle);
+        }
+        let module = self.module_mut(index)?;
+        let len = module.videos.len();
+        if at == 0 || at >= len {
+            return Err(DraftEditError::InvalidSplit { at, len });
+        }
+        let videos = module.videos.split_off(at);
+        self.modules.insert(index + 1, DraftModule { title: title.to_string(), videos });
+        Ok(())
+    }
+
+    /// Moves the module at `from` to position `to`.
+    pub fn move_module(&mut self, from: usize, to: usize) -> Result<(), DraftEditError> {
+        if from >= self.modules.len() {
+            return Err(DraftEditError::ModuleOutOfRange(from));
+        }
+        if to >= self.modules.len() {
+            return Err(DraftEditError::ModuleOutOfRange(to));
+        }
+        let module = self.modules.remove(from);
+        self.modules.insert(to, module);
+        Ok(())
+    }
+
+    fn module_mut(&mut self, index: usize) -> Result<&mut DraftModule, DraftEditError> {
+        self.modules.get_mut(index).ok_or(DraftEditError::ModuleOutOfRange(index))
+    }
+
+    /// Builds the modules, videos and search entries of the drafted course.
+    ///
+    /// Empty modules are dropped; the rest are numbered in draft order.
+    pub(super) fn build_contents(&self, course_id: CourseId) -> Result<DraftContents, String> {
+        let mut modules = Vec::with_capacity(self.modules.len());
+        let mut videos = Vec::with_capacity(self.video_count());
+        let mut entries = Vec::with_capacity(self.video_count());
+
+        for draft_module in self.modules.iter().filter(|m| !m.videos.is_empty()) {
+            let module = Module::new(
+                ModuleId::new(),
+                course_id,
+                draft_module.title.clone(),
+                modules.len() as u32,
+            );
+
+            for (sort_order, draft_video) in draft_module.videos.iter().enumerate() {
+                let source = match self.source {
+                    DraftSource::Playlist { .. } => YouTubeVideoId::new(&draft_video.source_ref)
+                        .map(VideoSource::youtube)
+                        .map_err(|e| e.to_string())?,
+                    DraftSource::LocalFolder { .. } => {
+                        VideoSource::local_path(&draft_video.source_ref)
+                            .map_err(|e| e.to_string())?
+                    },
+                };
+                let mut video = Video::with_description(
+                    VideoId::new(),
+                    *module.id(),
+                    source,
+                    draft_video.title.clone(),
+                    draft_video.description.clone(),
+                    draft_video.duration_secs,
+                    sort_order as u32,
+                );
+                if draft_video.transcript.is_some() {
+                    video.update_transcript(draft_video.transcript.clone());
+                }
+                video.set_content_fingerprint(draft_video.fingerprint.clone());
+                video.set_clip(draft_video.clip);
+                entries.push(SearchEntry {
+                    entity_type: "video".to_string(),
+                    entity_id: video.id().as_uuid().to_string(),
+                    title: draft_video.title.clone(),
+                    content: draft_video.description.clone().unwrap_or_default(),
+                    course_id: course_id.as_uuid().to_string(),
+                });
+                videos.push(video);
+            }
+            modules.push(module);
+        }
+
+        Ok((modules, videos, entries))
+    }
+}
+
+/// One lesson per chapter of a video: its title and the part of the video it covers.
+///
+/// Empty unless at least two chapters have a usable range, so the video stays whole.
+pub(super) fn chapter_lessons(chapters: &[RawChapter]) -> Vec<(String, ClipRange)> {
+    let lessons: Vec<(String, ClipRange)> = chapters
+        .iter()
+        .filter_map(|c| Some((c.title.clone(), ClipRange::new(c.start_secs, c.end_secs)?)))
+        .collect();
+    if lessons.len() < 2 { Vec::new() } else { lessons }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn video(title: &str) -> DraftVideo {
+        DraftVideo {
+            source_ref: format!("/course/{title}.mp4"),
+            title: title.to_string(),
+            description: None,
+            duration_secs: 60,
+            transcript: None,
+            fingerprint: None,
+            clip: None,
+        }
+    }
+
+    fn draft() -> ImportDraft {
+        ImportDraft {
+            course_name: "Course".to_string(),
+            source: DraftSource::LocalFolder { root: "/course".to_string() },
+            modules: vec![
+                DraftModule { title: "Intro".to_string(), videos: vec![video("a"), video("b")] },
+                DraftModule { title: "Core".to_string(), videos: vec![video("c")] },
+            ],
+        }
+    }
+
+    fn titles(draft: &ImportDraft) -> Vec<(String, Vec<String>)> {
+        draft
+            .modules
+            .iter()
+            .map(|m| (m.title.clone(), m.videos.iter().map(|v| v.title.clone()).collect()))
+            .collect()
+    }
+
+    #[test]
+    fn merge_then_split_restores_the_structure() {
+        let mut d = draft();
+        d.merge_with_next(0).unwrap();
+        assert_eq!(titles(&d), vec![("Intro".into(), vec!["a".into(), "b".into(), "c".into()])]);
+        assert_eq!(d.merge_with_next(0), Err(DraftEditError::ModuleOutOfRange(1)));
+
+        d.split_module(0, 2, "Core").unwrap();
+        assert_eq!(d, draft());
+        assert_eq!(d.split_module(1, 0, "X"), Err(DraftEditError::InvalidSplit { at: 0, len: 1 }));
+    }
+
+    #[test]
+    fn rename_and_move_modules() {
+        let mut d = draft();
+        d.rename_module(1, "  Fundamentals ").unwrap();
+        assert_eq!(d.rename_module(1, " "), Err(DraftEditError::EmptyTitle));
+        d.move_module(1, 0).unwrap();
+        assert_eq!(d.modules[0].title, "Fundamentals");
+        assert_eq!(d.move_module(0, 2), Err(DraftEditError::ModuleOutOfRange(2)));
+    }
+
+    #[test]
+    fn draft_round_trips_through_json() {
+        let d = draft();
+        let json = serde_json::to_string(&d).unwrap();
+        assert!(json.contains(r#""type":"local_folder""#));
+        assert_eq!(serde_json::from_str::<ImportDraft>(&json).unwrap(), d);
+    }
+
+    #[test]
+    fn build_contents_skips_empty_modules() {
+        let mut d = draft();
+        d.modules.insert(1, DraftModule { title: "Empty".to_string(), videos: vec![] });
+        let (modules, videos, entries) = d.build_contents(CourseId::new()).unwrap();
+        assert_eq!(modules.len(), 2);
+        assert_eq!(modules[1].sort_order(), 1);
+        assert_eq!(videos.len(), 3);
+        assert_eq!(entries.len(), 3);
+    }
+
+    #[test]
+    fn chapter_lessons_need_two_usable_chapters() {
+        let chapter = |title: &str, start_secs, end_secs| RawChapter {
+            title: title.to_string(),
+            start_secs,
+            end_secs,
+        };
+        assert!(chapter_lessons(&[chapter("Intro", 0, 60), chapter("Broken", 90, 90)]).is_empty());
+
+        let lessons = chapter_lessons(&[chapter("Intro", 0, 60), chapter("Setup", 60, 300)]);
+        assert_eq!(lessons.len(), 2);
+        assert_eq!(lessons[1], ("Setup".to_string(), ClipRange::new(60, 300).unwrap()));
+    }
+}
@@ -1,21 +1,23 @@
 //! Ingest Local Library Use Case
 //!
-//! Orchestrates: Scan -> Sanitize -> Group -> Persist
+//! Orchestrates: Scan -> Sanitize -> Group -> Draft, then Draft -> Persist
 
 use std::collections::BTreeMap;
 use std::fs;
 use std::path::Path;
 use std::sync::Arc;
 
+use super::import_draft::{DraftModule, DraftSource, DraftVideo, ImportDraft, chapter_lessons};
 use crate::domain::{
-    entities::{Course, Module, Video},
+    entities::Course,
     ports::{
-        CourseRepository, LocalMediaError, LocalMediaScanner, ModuleRepository,
-        ModuleTitleGenerator, RawLocalMediaMetadata, SearchEntry, SearchRepository,
-        VideoRepository,
+        CancellationToken, CourseRepository, IngestProgress, LocalMediaError, LocalMediaScanner,
+        ModuleTitleGenerator, ProgressSink, RawLocalMediaMetadata, SearchRepository,
     },
-    services::{BoundaryDetector, SubtitleCleaner, TitleSanitizer, title_number_sequence},
-    value_objects::{CourseId, ModuleId, PlaylistUrl, VideoId, VideoSource},
+    services::{
+        BoundaryDetector, GroupingStrategy, SubtitleCleaner, TitleSanitizer, title_number_sequence,
+    },
+    value_objects::{ClipRange, CourseId, CourseSource},
 };
 use crate::infrastructure::media_hash;
 
@@ -30,12 +32,16 @@ pub enum IngestLocalError {
     PersistFailed(String),
     #[error("Course already exists: {0}")]
     AlreadyExists(String),
+    #[error("Import cancelled")]
+    Cancelled,
 }
 
 /// Input for the ingest local library use case.
 pub struct IngestLocalInput {
     pub root_path: String,
     pub course_name: Option<String>,
+    /// Import each chapter of a file as its own lesson.
+    pub split_chapters: bool,
 }
 
 /// Output of the ingest local library use case.
@@ -50,55 +56,81 @@ pub struct IngestLocalOutput {
 pub struct IngestLocalUseCase {
     scanner: Arc<dyn LocalMediaScanner>,
     course_repo: Arc<dyn CourseRepository>,
-    module_repo: Arc<dyn ModuleRepository>,
-    video_repo: Arc<dyn VideoRepository>,
     search_repo: Arc<dyn SearchRepository>,
     sanitizer: TitleSanitizer,
     boundary_batch_size: usize,
+    grouping: GroupingStrategy,
+    module_target_minutes: u32,
     title_generator: Option<Arc<dyn ModuleTitleGenerator>>,
+    progress: Option<Arc<dyn ProgressSink>>,
+    cancel: CancellationToken,
 }
 
 impl IngestLocalUseCase {
-    #[allow(clippy::too_many_arguments)]
     pub fn new(
         scanner: Arc<dyn LocalMediaScanner>,
         course_repo: Arc<dyn CourseRepository>,
-        module_repo: Arc<dyn ModuleRepository>,
-        video_repo: Arc<dyn VideoRepository>,
         search_repo: Arc<dyn SearchRepository>,
         title_generator: Option<Arc<dyn ModuleTitleGenerator>>,
         boundary_batch_size: usize,
+        grouping: GroupingStrategy,
+        module_target_minutes: u32,
     ) -> Self {
         Self {
             scanner,
             course_repo,
-            module_repo,
-            video_repo,
             search_repo,
             sanitizer: TitleSanitizer::new(),
             boundary_batch_size,
+            grouping,
+            module_target_minutes,
             title_generator,
+            progress: None,
+            cancel: CancellationToken::new(),
         }
     }
 
-    /// Executes the local ingestion pipeline.
+    /// Reports each step of [`plan`](Self::plan) and [`commit`](Self::commit) to `progress`.
+    pub fn with_progress(mut self, progress: Arc<dyn ProgressSink>) -> Self {
+        self.progress = Some(progress);
+        self
+    }
+
+    /// Stops the import with [`IngestLocalError::Cancelled`] once `cancel` is set. A
+    /// cancelled import writes nothing to the database.
+    pub fn with_cancellation(mut self, cancel: CancellationToken) -> Self {
+        self.cancel = cancel;
+        self
+    }
+
+    /// Executes the local ingestion pipeline with the proposed grouping.
     pub async fn execute(
         &self,
         input: IngestLocalInput,
     ) -> Result<IngestLocalOutput, IngestLocalError> {
+        let draft = self.plan(input).await?;
+        self.commit(&draft)
+    }
+
+    /// Scans and groups the folder without persisting anything.
+    pub async fn plan(&self, input: IngestLocalInput) -> Result<ImportDraft, IngestLocalError> {
+        CourseSource::local_folder(&input.root_path)
+            .map_err(|e| IngestLocalError::InvalidRoot(e.to_string()))?;
         let root = input.root_path.trim();
-        if root.is_empty() {
-            return Err(IngestLocalError::InvalidRoot("root path is empty".to_string()));
-        }
 
         // 1. Check for duplicate
-        let source_hash = media_hash::compute_source_hash(root);
-        if let Ok(Some(existing)) = self.course_repo.find_by_source_hash(&source_hash) {
-            return Err(IngestLocalError::AlreadyExists(existing.name().to_string()));
-        }
+        self.ensure_new(root)?;
 
         // 2. Scan local media
-        let mut raw_media = self.scanner.scan(root).await?;
+        let no_progress = |_: IngestProgress| {};
+        let progress: &dyn ProgressSink = self.progress.as_deref().unwrap_or(&no_progress);
+        let mut raw_media =
+            self.scanner.scan_with_progress(root, progress, &self.cancel).await.map_err(
+                |e| match e {
+                    LocalMediaError::Cancelled => IngestLocalError::Cancelled,
+                    e => e.into(),
+                },
+            )?;
 
         if raw_media.is_empty() {
             return Err(IngestLocalError::ScanFailed(LocalMediaError::Io(
@@ -106,20 +138,18 @@ impl IngestLocalUseCase {
             )));
         }
 
-        // 2. Sort for deterministic grouping
+        // 3. Sort for deterministic grouping
         raw_media.sort_by(|a, b| a.path.cmp(&b.path));
 
-        // 3. Group by directory
-        let grouped = group_by_folder(root, &raw_media);
+        // 4. Group by directory, then expand files into one lesson per chapter when asked
+        let grouped: BTreeMap<String, Vec<LocalLesson>> = group_by_folder(root, &raw_media)
+            .into_iter()
+            .map(|(folder, items)| (folder, expand_chapters(items, input.split_chapters)))
+            .collect();
+        let detector = BoundaryDetector::with_batch_size(self.boundary_batch_size)
+            .with_target_minutes(self.module_target_minutes);
         let grouped =
-            split_root_group_if_needed(root, &grouped, &self.sanitizer, self.boundary_batch_size);
-
-        // 4. Create course (use synthetic playlist URL for persistence)
-        let course_id = CourseId::new();
-        let list_id = format!("local-{}", course_id.as_uuid());
-        let playlist_url =
-            PlaylistUrl::new(&format!("https://www.youtube.com/playlist?list={list_id}"))
-                .map_err(|e| IngestLocalError::PersistFailed(e.to_string()))?;
+            split_root_group_if_needed(root, &grouped, &self.sanitizer, &detector, self.grouping);
 
         let course_name = input.course_name.unwrap_or_else(|| {
             let base =
@@ -127,114 +157,87 @@ impl IngestLocalUseCase {
             self.sanitizer.sanitize(base)
         });
 
-        let course = Course::new(
-            course_id,
-            course_name.clone(),
-            playlist_url.clone(),
-            playlist_url.playlist_id().to_string(),
-            None,
-            Some(source_hash),
-        );
-
-        // 5. Pre-compute module/video data (async title generation + subtitle reads, outside transaction)
-        struct PendingVideo {
-            path: String,
-            title: String,
-            duration_secs: u32,
-            transcript: Option<String>,
-        }
-
-        struct PendingModule {
-            module_id: ModuleId,
-            title: String,
-            videos: Vec<PendingVideo>,
-        }
-
+        // 5. Generate module titles, read subtitles and fingerprint files
         let cleaner = SubtitleCleaner::new();
-        let mut pending_modules = Vec::new();
-        let mut total_videos = 0;
+        let total = grouped.len();
+        let mut modules = Vec::with_capacity(total);
 
         for (module_idx, (_folder_path, items)) in grouped.into_iter().enumerate() {
-            let module_id = ModuleId::new();
+            self.report(IngestProgress::GeneratingTitles { done: module_idx, total });
             let module_video_titles: Vec<String> =
-                items.iter().map(|item| self.sanitizer.sanitize(&item.title)).collect();
-            let module_title = crate::application::generate_module_title(
-                self.title_generator.as_ref(),
-                &module_video_titles,
-                &course_name,
-                module_idx,
+                items.iter().map(|lesson| self.sanitizer.sanitize(&lesson.title)).collect();
+            let title = crate::application::until_cancelled(
+                &self.cancel,
+                crate::application::generate_module_title(
+                    self.title_generator.as_ref(),
+                    &module_video_titles,
+                    &course_name,
+                    module_idx,
+                ),
             )
-            .await;
-
-            let mut videos = Vec::with_capacity(items.len());
-            for item in items {
-                let title = self.sanitizer.sanitize(&item.title);
-                let transcript = item
-                    .subtitles
-                    .first()
-                    .and_then(|sub| {
-                        fs::read_to_string(&sub.path).ok().map(|raw| cleaner.clean(&raw))
-                    })
-                    .filter(|s| !s.trim().is_empty());
-
-                videos.push(PendingVideo {
-                    path: item.path,
+            .await
+            .ok_or(IngestLocalError::Cancelled)?;
+
+            let videos = items
+                .into_iter()
+                .zip(module_video_titles)
+                .map(|(lesson, title)| DraftVideo {
+                    transcript: match lesson.clip {
+                        None => read_subtitle_transcript(&lesson.item, &cleaner),
+                        // Subtitles cover the whole file, not one chapter of it.
+                        Some(_) => None,
+                    },
+                    fingerprint: media_hash::compute_file_fingerprint(&lesson.item.path).ok(),
+                    source_ref: lesson.item.path,
                     title,
-                    duration_secs: item.duration_secs,
-                    transcript,
-                });
-            }
-            total_videos += videos.len();
-            pending_modules.push(PendingModule { module_id, title: module_title, videos });
+                    description: None,
+                    duration_secs: lesson.duration_secs,
+                    clip: lesson.clip,
+                })
+                .collect();
+            modules.push(DraftModule { title, videos });
         }
+        self.report(IngestProgress::GeneratingTitles { done: total, total });
 
-        // 6. Persist using repository batch methods
-        self.course_repo
-            .save(&course)
-            .map_err(|e| IngestLocalError::PersistFailed(e.to_string()))?;
-        self.search_repo
-            .index_course(course.id(), course.name(), course.description())
-            .map_err(|e| IngestLocalError::PersistFailed(e.to_string()))?;
+        Ok(ImportDraft {
+            course_name,
+            source: DraftSource::LocalFolder { root: root.to_string() },
+            modules,
+        })
+    }
 
-        let mut all_modules = Vec::with_capacity(pending_modules.len());
-        let mut all_videos = Vec::with_capacity(total_videos);
-        let mut video_search_entries = Vec::with_capacity(total_videos);
-
-        for (module_idx, pm) in pending_modules.iter().enumerate() {
-            let module = Module::new(pm.module_id, course_id, pm.title.clone(), module_idx as u32);
-            all_modules.push(module);
-
-            for (sort_order, video_data) in pm.videos.iter().enumerate() {
-                let source = VideoSource::local_path(&video_data.path)
-                    .map_err(|e| IngestLocalError::PersistFailed(e.to_string()))?;
-                let mut video = Video::with_description(
-                    VideoId::new(),
-                    pm.module_id,
-                    source,
-                    video_data.title.clone(),
-                    None,
-                    video_data.duration_secs,
-                    sort_order as u32,
-                );
-                if video_data.transcript.is_some() {
-                    video.update_transcript(video_data.transcript.clone());
-                }
-                video_search_entries.push(SearchEntry {
-                    entity_type: "video".to_string(),
-                    entity_id: video.id().as_uuid().to_string(),
-                    title: video_data.title.clone(),
-                    content: String::new(),
-                    course_id: course_id.as_uuid().to_string(),
-                });
-                all_videos.push(video);
-            }
+    /// Persists a (possibly edited) draft as a new course in one transaction.
+    pub fn commit(&self, draft: &ImportDraft) -> Result<IngestLocalOutput, IngestLocalError> {
+        let DraftSource::LocalFolder { root } = &draft.source else {
+            return Err(IngestLocalError::InvalidRoot("Draft is not a local import".to_string()));
+        };
+        let source = CourseSource::local_folder(root)
+            .map_err(|e| IngestLocalError::InvalidRoot(e.to_string()))?;
+        let source_hash = self.ensure_new(root.trim())?;
+        if draft.video_count() == 0 {
+            return Err(IngestLocalError::ScanFailed(LocalMediaError::Io(
+                "No media files found".to_string(),
+            )));
         }
 
-        self.module_repo
-            .save_batch(&all_modules)
+        let course_id = CourseId::new();
+        let course_name = match draft.course_name.trim() {
+            "" => "Local Course".to_string(),
+            name => name.to_string(),
+        };
+        let course = Course::new(course_id, course_name, source, None, Some(source_hash));
+        let (modules, videos, video_search_entries) =
+            draft.build_contents(course_id).map_err(IngestLocalError::PersistFailed)?;
+
+        if self.cancel.is_cancelled() {
+            return Err(IngestLocalError::Cancelled);
+        }
+        self.report(IngestProgress::Persisting);
+        self.course_repo
+            .save_with_contents(&course, &modules, &videos)
             .map_err(|e| IngestLocalError::PersistFailed(e.to_string()))?;
-        self.video_repo
-            .save_batch(&all_videos)
+        self.search_repo
+            .index_course(course.id(), course.name(), course.description())
             .map_err(|e| IngestLocalError::PersistFailed(e.to_string()))?;
         self.search_repo
             .index_batch(&video_search_entries)
@@ -242,13 +245,76 @@ impl IngestLocalUseCase {
 
         Ok(IngestLocalOutput {
             course_id,
-            modules_count: grouped_len(root, &raw_media, self.boundary_batch_size),
-            videos_count: total_videos,
+            modules_count: modules.len(),
+            videos_count: videos.len(),
         })
     }
+
+    fn report(&self, progress: IngestProgress) {
+        if let Some(sink) = &self.progress {
+            sink.report(progress);
+        }
+    }
+
+    /// Returns the source hash of a folder that has not been imported yet.
+    fn ensure_new(&self, root: &str) -> Result<String, IngestLocalError> {
+        let source_hash = media_hash::compute_source_hash(root);
+        if let Ok(Some(existing)) = self.course_repo.find_by_source_hash(&source_hash) {
+            return Err(IngestLocalError::AlreadyExists(existing.name().to_string()));
+        }
+        Ok(source_hash)
+    }
+}
+
+/// A media file, or one chapter of it, as it will be imported.
+#[derive(Debug, Clone)]
+struct LocalLesson {
+    item: RawLocalMediaMetadata,
+    /// Raw title: the file stem, or the chapter title.
+    title: String,
+    duration_secs: u32,
+    clip: Option<ClipRange>,
 }
 
-fn group_by_folder(
+/// Turns a folder's files into lessons, one per chapter for files that have chapters.
+fn expand_chapters(items: Vec<RawLocalMediaMetadata>, split_chapters: bool) -> Vec<LocalLesson> {
+    let mut lessons = Vec::with_capacity(items.len());
+    for item in items {
+        let chapters = if split_chapters { chapter_lessons(&item.chapters) } else { Vec::new() };
+        if chapters.is_empty() {
+            lessons.push(LocalLesson {
+                title: item.title.clone(),
+                duration_secs: item.duration_secs,
+                clip: None,
+                item,
+            });
+            continue;
+        }
+        for (title, clip) in chapters {
+            lessons.push(LocalLesson {
+                item: item.clone(),
+                title,
+                duration_secs: clip.duration_secs(),
+                clip: Some(clip),
+            });
+        }
+    }
+    lessons
+}
+
+/// Reads and cleans the first subtitle file found next to a video.
+pub(super) fn read_subtitle_transcript(
+    item: &RawLocalMediaMetadata,
+    cleaner: &SubtitleCleaner,
+) -> Option<String> {
+    item.subtitles
+        .first()
+        .and_then(|sub| fs::read_to_string(&sub.path).ok().map(|raw| cleaner.clean(&raw)))
+        .filter(|s| !s.trim().is_empty())
+}
+
+/// Groups media by parent folder, ordering each folder by the numbers in the titles.
+pub(super) fn group_by_folder(
     root: &str,
     items: &[RawLocalMediaMetadata],
 ) -> BTreeMap<String, Vec<RawLocalMediaMetadata>> {
@@ -282,10 +348,11 @@ fn group_by_folder(
 
 fn split_root_group_if_needed(
     root: &str,
-    grouped: &BTreeMap<String, Vec<RawLocalMediaMetadata>>,
+    grouped: &BTreeMap<String, Vec<LocalLesson>>,
     sanitizer: &TitleSanitizer,
-    boundary_batch_size: usize,
-) -> BTreeMap<String, Vec<RawLocalMediaMetadata>> {
+    detector: &BoundaryDetector,
+    grouping: GroupingStrategy,
+) -> BTreeMap<String, Vec<LocalLesson>> {
     if grouped.len() != 1 {
         return grouped.clone();
     }
@@ -305,9 +372,9 @@ fn split_root_group_if_needed(
         return grouped.clone();
     }
 
-    let detector = BoundaryDetector::with_batch_size(boundary_batch_size);
-    let raw_titles: Vec<&str> = items.iter().map(|item| item.title.as_str()).collect();
-    let groups = detector.group_by_titles(&raw_titles);
+    let raw_titles: Vec<&str> = items.iter().map(|lesson| lesson.title.as_str()).collect();
+    let durations: Vec<u32> = items.iter().map(|lesson| lesson.duration_secs).collect();
+    let groups = detector.group(grouping, &raw_titles, &raw_titles, &durations);
     if groups.len() <= 1 {
         return grouped.clone();
     }
@@ -317,7 +384,7 @@ fn split_root_group_if_needed(
         let title = indices
             .first()
             .and_then(|&i| items.get(i))
-            .map(|item| sanitizer.sanitize(&item.title))
+            .map(|lesson| sanitizer.sanitize(&lesson.title))
             .filter(|s| !s.trim().is_empty())
             .unwrap_or_else(|| format!("Module {}", idx + 1));
 
@@ -340,12 +407,6 @@ fn split_root_group_if_needed(
     split
 }
 
-fn grouped_len(root: &str, items: &[RawLocalMediaMetadata], boundary_batch_size: usize) -> usize {
-    let grouped = group_by_folder(root, items);
-    let sanitizer = TitleSanitizer::new();
-    split_root_group_if_needed(root, &grouped, &sanitizer, boundary_batch_size).len()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -359,24 +420,28 @@ mod tests {
                 title: "video1".to_string(),
                 duration_secs: 10,
                 subtitles: Vec::new(),
+                chapters: Vec::new(),
             },
             RawLocalMediaMetadata {
                 path: "/root/folder1/video2.mp4".to_string(),
                 title: "video2".to_string(),
                 duration_secs: 20,
                 subtitles: Vec::new(),
+                chapters: Vec::new(),
             },
             RawLocalMediaMetadata {
                 path: "/root/folder1/video3.mp4".to_string(),
                 title: "video3".to_string(),
                 duration_secs: 30,
                 subtitles: Vec::new(),
+                chapters: Vec::new(),
             },
             RawLocalMediaMetadata {
                 path: "/root/folder2/sub/video4.mp4".to_string(),
                 title: "video4".to_string(),
                 duration_secs: 40,
                 subtitles: Vec::new(),
+                chapters: Vec::new(),
             },
         ];
 
@@ -0,0 +1,255 @@
+//! Ingest Manifest Use Case
+//!
+//! Orchestrates: Validate -> Resolve lessons (fetch/probe missing metadata) -> Persist -> Tag
+
+use std::path::Path;
+use std::sync::Arc;
+
+use super::course_manifest::{CourseManifest, LessonSource, ManifestError, ManifestLesson};
+use crate::domain::{
+    entities::{Course, Module, Tag, Video},
+    ports::{
+        CourseRepository, FetchError, LocalMediaError, LocalMediaScanner, PlaylistFetcher,
+        SearchEntry, SearchRepository, TagRepository,
+    },
+    services::TitleSanitizer,
+    value_objects::{
+        ClipRange, CourseId, CourseSource, ModuleId, PlaylistUrl, TagId, VideoId, VideoSource,
+        YouTubeVideoId,
+    },
+};
+use crate::infrastructure::media_hash;
+
+/// Input for the ingest manifest use case.
+pub struct IngestManifestInput {
+    pub manifest: CourseManifest,
+    /// Folder relative lesson paths are resolved against, usually the manifest's own folder.
+    pub base_dir: String,
+}
+
+/// Output of the ingest manifest use case.
+#[derive(Debug)]
+pub struct IngestManifestOutput {
+    pub course_id: CourseId,
+    pub modules_count: usize,
+    pub videos_count: usize,
+}
+
+/// Use case for building a course from a declarative manifest.
+///
+/// The course keeps the manifest's structure as written: no boundary detection or module
+/// title generation runs. Its source is `Manual`, since lessons may mix YouTube and disk.
+pub struct IngestManifestUseCase {
+    fetcher: Arc<dyn PlaylistFetcher>,
+    scanner: Arc<dyn LocalMediaScanner>,
+    course_repo: Arc<dyn CourseRepository>,
+    tag_repo: Arc<dyn TagRepository>,
+    search_repo: Arc<dyn SearchRepository>,
+    sanitizer: TitleSanitizer,
+}
+
+/// A lesson's video with its metadata filled in.
+struct ResolvedLesson {
+    source: VideoSource,
+    title: String,
+    description: Option<String>,
+    duration_secs: u32,
+    fingerprint: Option<String>,
+    clip: Option<ClipRange>,
+}
+
+impl IngestManifestUseCase {
+    pub fn new(
+        fetcher: Arc<dyn PlaylistFetcher>,
+        scanner: Arc<dyn LocalMediaScanner>,
+        course_repo: Arc<dyn CourseRepository>,
+        tag_repo: Arc<dyn TagRepository>,
+        search_repo: Arc<dyn SearchRepository>,
+    ) -> Self {
+        Self {
+            fetcher,
+            scanner,
+            course_repo,
+            tag_repo,
+            search_repo,
+            sanitizer: TitleSanitizer::new(),
+        }
+    }
+
+    /// Creates the course described by the manifest.
+    pub async fn execute(
+        &self,
+        input: IngestManifestInput,
+    ) -> Result<IngestManifestOutput, ManifestError> {
+        let manifest = &input.manifest;
+        manifest.validate()?;
+        if manifest.lesson_count() == 0 {
+            return Err(ManifestError::Invalid("manifest has no lessons".to_string()));
+        }
+        let base_dir = Path::new(input.base_dir.trim());
+        if !base_dir.is_absolute() {
+            return Err(ManifestError::Invalid(format!(
+                "base folder must be absolute: {}",
+                input.base_dir
+            )));
+        }
+
+        // 1. Resolve every lesson before touching the database
+        let mut resolved = Vec::with_capacity(manifest.modules.len());
+        for module in manifest.modules.iter().filter(|m| !m.lessons.is_empty()) {
+            let mut lessons = Vec::with_capacity(module.lessons.len());
+            for lesson in &module.lessons {
+                lessons.push(self.resolve(lesson, base_dir).await?);
+            }
+            resolved.push((module.title.trim(), lessons));
+        }
+
+        // 2. The same lessons in the same order make the same course
+        let refs: Vec<&str> = resolved
+            .iter()
+            .flat_map(|(_, lessons)| lessons.iter().map(|lesson| lesson.source.source_ref()))
+            .collect();
+        let source_hash = media_hash::compute_source_hash(&format!("manifest:{}", refs.join("\n")));
+        if let Ok(Some(existing)) = self.course_repo.find_by_source_hash(&source_hash) {
+            return Err(ManifestError::AlreadyExists(existing.name().to_string()));
+        }
+
+        // 3. Build the course
+        let course_id = CourseId::new();
+        let course = Course::new(
+            course_id,
+            manifest.name.trim().to_string(),
+            CourseSource::Manual,
+            manifest.description.clone().filter(|d| !d.trim().is_empty()),
+            Some(source_hash),
+        );
+        let mut modules = Vec::with_capacity(resolved.len());
+        let mut videos = Vec::with_capacity(refs.len());
+        let mut entries = Vec::with_capacity(refs.len());
+        for (module_title, lessons) in resolved {
+            let module = Module::new(
+                ModuleId::new(),
+                course_id,
+                module_title.to_string(),
+                modules.len() as u32,
+            );
+            for (sort_order, lesson) in lessons.into_iter().enumerate() {
+                let mut video = Video::with_description(
+                    VideoId::new(),
+                    *module.id(),
+                    lesson.source,
+                    lesson.title,
+                    lesson.description,
+                    lesson.duration_secs,
+                    sort_order as u32,
+                );
+                video.set_content_fingerprint(lesson.fingerprint);
+                video.set_clip(lesson.clip);
+                entries.push(SearchEntry {
+                    entity_type: "video".to_string(),
+                    entity_id: video.id().as_uuid().to_string(),
+                    title: video.title().to_string(),
+                    content: video.description().unwrap_or_default().to_string(),
+                    course_id: course_id.as_uuid().to_string(),
+                });
+                videos.push(video);
+            }
+            modules.push(module);
+        }
+
+        // 4. Persist, index and tag
+        self.course_repo.save_with_contents(&course, &modules, &videos)?;
+        self.search_repo.index_course(course.id(), course.name(), course.description())?;
+        self.search_repo.index_batch(&entries)?;
+        self.tag_course(&course_id, &manifest.tags)?;
+
+        Ok(IngestManifestOutput {
+            course_id,
+            modules_count: modules.len(),
+            videos_count: videos.len(),
+        })
+    }
+
+    /// Fills in the lesson's title and duration, reading the video only when the manifest
+    /// leaves one of them out.
+    async fn resolve(
+        &self,
+        lesson: &ManifestLesson,
+        base_dir: &Path,
+    ) -> Result<ResolvedLesson, ManifestError> {
+        let clip = lesson.clip().map_err(ManifestError::Invalid)?;
+        let title = lesson.title.as_deref().map(str::trim).filter(|t| !t.is_empty());
+        let duration_secs = lesson.duration_secs.or(clip.map(|c| c.duration_secs()));
+        let needs_metadata = title.is_none() || duration_secs.is_none();
+
+        // (source, fingerprint, and the video's own title, description and duration if read)
+        let (source, fingerprint, metadata) =
+            match lesson.source().map_err(ManifestError::Invalid)? {
+                LessonSource::YouTube(id) => {
+                    let youtube_id = YouTubeVideoId::new(id)
+                        .map_err(|e| ManifestError::Invalid(e.to_string()))?;
+                    let metadata = if needs_metadata {
+                        let url =
+                            PlaylistUrl::new(&format!("https://www.youtube.com/watch?v={id}"))
+                                .map_err(|e| ManifestError::Invalid(e.to_string()))?;
+                        let raw = self.fetcher.fetch_playlist(&url).await?.into_iter().next();
+                        let raw = raw.ok_or_else(|| FetchError::NotFound(id.to_string()))?;
+                        Some((raw.title, raw.description, raw.duration_secs))
+                    } else {
+                        None
+                    };
+                    (VideoSource::youtube(youtube_id), None, metadata)
+                },
+                LessonSource::Local(path) => {
+                    let absolute = base_dir.join(path).to_string_lossy().to_string();
+                    let source = VideoSource::local_path(&absolute)
+                        .map_err(|e| ManifestError::Invalid(e.to_string()))?;
+                    let metadata = if needs_metadata {
+                        let raw = self.scanner.scan(&absolute).await?.into_iter().next();
+                        let raw = raw.ok_or_else(|| {
+                            LocalMediaError::Io(format!("not a media file: {absolute}"))
+                        })?;
+                        Some((raw.title, None, raw.duration_secs))
+                    } else {
+                        None
+                    };
+                    (source, media_hash::compute_file_fingerprint(&absolute).ok(), metadata)
+                },
+            };
+
+        let (raw_title, description, raw_duration) = metadata.unwrap_or_default();
+        Ok(ResolvedLesson {
+            title: match title {
+                Some(title) => title.to_string(),
+                None => self.sanitizer.sanitize(&raw_title),
+            },
+            description,
+            duration_secs: duration_secs.unwrap_or(raw_duration),
+            source,
+            fingerprint,
+            clip,
+        })
+    }
+
+    /// Attaches the named tags, reusing existing tags whose name matches ignoring case.
+    fn tag_course(&self, course_id: &CourseId, names: &[String]) -> Result<(), ManifestError> {
+        let mut tags = self.tag_repo.find_all()?;
+        let mut attached: Vec<TagId> = Vec::new();
+        for name in names.iter().map(|n| n.trim()).filter(|n| !n.is_empty()) {
+            let tag = match tags.iter().find(|t| t.name().eq_ignore_ascii_case(name)) {
+                Some(tag) => tag.clone(),
+                None => {
+                    let tag = Tag::new(TagId::new(), name.to_string());
+                    self.tag_repo.save(&tag)?;
+                    tags.push(tag.clone());
+                    tag
+                },
+            };
+            if !attached.contains(tag.id()) {
+                self.tag_repo.add_to_course(course_id, tag.id())?;
+                attached.push(*tag.id());
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,17 +1,18 @@
 //! Ingest Playlist Use Case
 //!
-//! Orchestrates: Fetch -> Group -> Sanitize -> Persist
+//! Orchestrates: Fetch -> Group -> Sanitize -> Draft, then Draft -> Persist
 
 use std::sync::Arc;
 
+use super::import_draft::{DraftModule, DraftSource, DraftVideo, ImportDraft, chapter_lessons};
 use crate::domain::{
-    entities::{Course, Module, Video},
+    entities::Course,
     ports::{
-        CourseRepository, FetchError, ModuleRepository, ModuleTitleGenerator, PlaylistFetcher,
-        SearchEntry, SearchRepository, VideoRepository,
+        CancellationToken, CourseRepository, FetchError, IngestProgress, ModuleTitleGenerator,
+        PlaylistFetcher, ProgressSink, SearchRepository,
     },
-    services::{BoundaryDetector, TitleSanitizer},
-    value_objects::{CourseId, ModuleId, PlaylistUrl, VideoId, VideoSource, YouTubeVideoId},
+    services::{BoundaryDetector, GroupingStrategy, TitleSanitizer},
+    value_objects::{CourseId, CourseSource, PlaylistUrl},
 };
 use crate::infrastructure::media_hash;
 
@@ -26,12 +27,16 @@ pub enum IngestError {
     PersistFailed(String),
     #[error("Course already exists: {0}")]
     AlreadyExists(String),
+    #[error("Import cancelled")]
+    Cancelled,
 }
 
 /// Input for the ingest playlist use case.
 pub struct IngestPlaylistInput {
     pub playlist_url: String,
     pub course_name: Option<String>,
+    /// Import each chapter of a video as its own lesson.
+    pub split_chapters: bool,
 }
 
 /// Output of the ingest playlist use case.
@@ -46,54 +51,83 @@ pub struct IngestPlaylistOutput {
 pub struct IngestPlaylistUseCase {
     fetcher: Arc<dyn PlaylistFetcher>,
     course_repo: Arc<dyn CourseRepository>,
-    module_repo: Arc<dyn ModuleRepository>,
-    video_repo: Arc<dyn VideoRepository>,
     search_repo: Arc<dyn SearchRepository>,
     sanitizer: TitleSanitizer,
     boundary_batch_size: usize,
+    grouping: GroupingStrategy,
+    module_target_minutes: u32,
     title_generator: Option<Arc<dyn ModuleTitleGenerator>>,
+    progress: Option<Arc<dyn ProgressSink>>,
+    cancel: CancellationToken,
 }
 
 impl IngestPlaylistUseCase {
-    #[allow(clippy::too_many_arguments)]
     pub fn new(
         fetcher: Arc<dyn PlaylistFetcher>,
         course_repo: Arc<dyn CourseRepository>,
-        module_repo: Arc<dyn ModuleRepository>,
-        video_repo: Arc<dyn VideoRepository>,
         search_repo: Arc<dyn SearchRepository>,
         title_generator: Option<Arc<dyn ModuleTitleGenerator>>,
         boundary_batch_size: usize,
+        grouping: GroupingStrategy,
+        module_target_minutes: u32,
     ) -> Self {
         Self {
             fetcher,
             course_repo,
-            module_repo,
-            video_repo,
             search_repo,
             sanitizer: TitleSanitizer::new(),
             boundary_batch_size,
+            grouping,
+            module_target_minutes,
             title_generator,
+            progress: None,
+            cancel: CancellationToken::new(),
         }
     }
 
-    /// Executes the playlist ingestion pipeline.
+    /// Reports each step of [`plan`](Self::plan) and [`commit`](Self::commit) to `progress`.
+    pub fn with_progress(mut self, progress: Arc<dyn ProgressSink>) -> Self {
+        self.progress = Some(progress);
+        self
+    }
+
+    /// Stops the import with [`IngestError::Cancelled`] once `cancel` is set. A cancelled
+    /// import writes nothing to the database.
+    pub fn with_cancellation(mut self, cancel: CancellationToken) -> Self {
+        self.cancel = cancel;
+        self
+    }
+
+    /// Executes the playlist ingestion pipeline with the proposed grouping.
     pub async fn execute(
         &self,
         input: IngestPlaylistInput,
     ) -> Result<IngestPlaylistOutput, IngestError> {
+        let draft = self.plan(input).await?;
+        self.commit(&draft)
+    }
+
+    /// Fetches and groups the playlist without persisting anything.
+    pub async fn plan(&self, input: IngestPlaylistInput) -> Result<ImportDraft, IngestError> {
         // 1. Parse and validate URL
         let playlist_url = PlaylistUrl::new(&input.playlist_url)
             .map_err(|e| IngestError::InvalidUrl(e.to_string()))?;
 
         // 2. Check for duplicate
-        let source_hash = media_hash::compute_source_hash(playlist_url.playlist_id());
-        if let Ok(Some(existing)) = self.course_repo.find_by_source_hash(&source_hash) {
-            return Err(IngestError::AlreadyExists(existing.name().to_string()));
-        }
+        self.ensure_new(&playlist_url)?;
 
         // 3. Fetch playlist metadata
-        let raw_videos = self.fetcher.fetch_playlist(&playlist_url).await?;
+        self.report(IngestProgress::Fetching);
+        let fetch = async {
+            if input.split_chapters {
+                self.fetcher.fetch_playlist_with_chapters(&playlist_url).await
+            } else {
+                self.fetcher.fetch_playlist(&playlist_url).await
+            }
+        };
+        let raw_videos = crate::application::until_cancelled(&self.cancel, fetch)
+            .await
+            .ok_or(IngestError::Cancelled)??;
 
         if raw_videos.is_empty() {
             return Err(IngestError::FetchFailed(FetchError::NotFound(
@@ -101,123 +135,123 @@ impl IngestPlaylistUseCase {
             )));
         }
 
-        // 3. Group videos into modules on raw titles (preserves "Module", "Chapter", etc.)
-        let raw_title_refs: Vec<&str> = raw_videos.iter().map(|v| v.title.as_str()).collect();
-        let detector = BoundaryDetector::with_batch_size(self.boundary_batch_size);
-        let module_groups = detector.group_by_titles(&raw_title_refs);
-
-        // 4. Sanitize titles for storage only
-        let sanitized_titles: Vec<String> =
-            raw_videos.iter().map(|v| self.sanitizer.sanitize(&v.title)).collect();
-
-        // 5. Create course
-        // Snapshot the first sanitized title as a fallback for the course name before
-        // constructing the video-title iterator. This keeps the two consumers independent:
-        // course_name derivation never consumes from title_iter, so all N sanitized
-        // titles remain available for the N videos below.
-        let course_name_fallback =
-            sanitized_titles.first().cloned().unwrap_or_else(|| "Untitled Course".to_string());
-        let mut title_iter = sanitized_titles.into_iter();
-        let course_name = input.course_name.unwrap_or(course_name_fallback);
-        let course_id = CourseId::new();
-        let course = Course::new(
-            course_id,
-            course_name.clone(),
-            playlist_url.clone(),
-            playlist_url.playlist_id().to_string(),
-            None,
-            Some(source_hash),
-        );
-        // 6. Pre-compute module/video data (async title generation, outside transaction)
-        struct PendingVideo {
-            youtube_id: String,
-            title: String,
-            description: Option<String>,
-            duration_secs: u32,
-        }
-
-        struct PendingModule {
-            module_id: ModuleId,
-            title: String,
-            videos: Vec<PendingVideo>,
+        // 4. Expand videos into one lesson per chapter when asked
+        let mut raw_titles: Vec<String> = Vec::with_capacity(raw_videos.len());
+        let mut topic_texts: Vec<String> = Vec::with_capacity(raw_videos.len());
+        let mut lessons: Vec<DraftVideo> = Vec::with_capacity(raw_videos.len());
+        for raw in &raw_videos {
+            let chapters =
+                if input.split_chapters { chapter_lessons(&raw.chapters) } else { Vec::new() };
+            if chapters.is_empty() {
+                raw_titles.push(raw.title.clone());
+                topic_texts.push(match &raw.description {
+                    Some(description) => format!("{}\n{}", raw.title, description),
+                    None => raw.title.clone(),
+                });
+                lessons.push(DraftVideo {
+                    source_ref: raw.youtube_id.clone(),
+                    title: self.sanitizer.sanitize(&raw.title),
+                    description: raw.description.clone(),
+                    duration_secs: raw.duration_secs,
+                    transcript: None,
+                    fingerprint: None,
+                    clip: None,
+                });
+                continue;
+            }
+            for (title, clip) in chapters {
+                topic_texts.push(title.clone());
+                lessons.push(DraftVideo {
+                    source_ref: raw.youtube_id.clone(),
+                    title: self.sanitizer.sanitize(&title),
+                    description: None,
+                    duration_secs: clip.duration_secs(),
+                    transcript: None,
+                    fingerprint: None,
+                    clip: Some(clip),
+                });
+                raw_titles.push(title);
+            }
         }
 
-        let mut pending_modules = Vec::with_capacity(module_groups.len());
-        let mut total_videos = 0;
+        // 5. Group lessons into modules on raw titles (preserves "Module", "Chapter", etc.)
+        let durations: Vec<u32> = lessons.iter().map(|l| l.duration_secs).collect();
+        let detector = BoundaryDetector::with_batch_size(self.boundary_batch_size)
+            .with_target_minutes(self.module_target_minutes);
+        let module_groups = detector.group(self.grouping, &raw_titles, &topic_texts, &durations);
 
-        for (module_idx, video_indices) in module_groups.iter().enumerate() {
-            let module_id = ModuleId::new();
+        let course_name =
+            input.course_name.unwrap_or_else(|| self.sanitizer.sanitize(&raw_videos[0].title));
 
+        // 6. Generate module titles
+        let total = module_groups.len();
+        let mut modules = Vec::with_capacity(total);
+        for (module_idx, lesson_indices) in module_groups.iter().enumerate() {
+            self.report(IngestProgress::GeneratingTitles { done: module_idx, total });
             let module_video_titles: Vec<String> =
-                video_indices.iter().map(|&i| raw_videos[i].title.clone()).collect();
-            let module_title = crate::application::generate_module_title(
-                self.title_generator.as_ref(),
-                &module_video_titles,
-                &course_name,
-                module_idx,
+                lesson_indices.iter().map(|&i| raw_titles[i].clone()).collect();
+            let title = crate::application::until_cancelled(
+                &self.cancel,
+                crate::application::generate_module_title(
+                    self.title_generator.as_ref(),
+                    &module_video_titles,
+                    &course_name,
+                    module_idx,
+                ),
             )
-            .await;
-
-            let videos: Vec<PendingVideo> = video_indices
-                .iter()
-                .map(|&i| {
-                    let raw = &raw_videos[i];
-                    PendingVideo {
-                        youtube_id: raw.youtube_id.clone(),
-                        title: title_iter.next().unwrap_or_else(|| "Untitled".to_string()),
-                        description: raw.description.clone(),
-                        duration_secs: raw.duration_secs,
-                    }
-                })
-                .collect();
-
-            total_videos += videos.len();
-            pending_modules.push(PendingModule { module_id, title: module_title, videos });
+            .await
+            .ok_or(IngestError::Cancelled)?;
+
+            let videos = lesson_indices.iter().map(|&i| lessons[i].clone()).collect();
+            modules.push(DraftModule { title, videos });
         }
+        self.report(IngestProgress::GeneratingTitles { done: total, total });
 
-        // 7. Persist using repository batch methods
-        self.course_repo.save(&course).map_err(|e| IngestError::PersistFailed(e.to_string()))?;
-        self.search_repo
-            .index_course(course.id(), course.name(), course.description())
-            .map_err(|e| IngestError::PersistFailed(e.to_string()))?;
+        Ok(ImportDraft {
+            course_name,
+            source: DraftSource::Playlist { url: playlist_url.raw().to_string() },
+            modules,
+        })
+    }
 
-        let mut all_modules = Vec::with_capacity(pending_modules.len());
-        let mut all_videos = Vec::with_capacity(total_videos);
-        let mut video_search_entries = Vec::with_capacity(total_videos);
-
-        for (module_idx, pm) in pending_modules.iter().enumerate() {
-            let module = Module::new(pm.module_id, course_id, pm.title.clone(), module_idx as u32);
-            all_modules.push(module);
-
-            for (sort_order, video_data) in pm.videos.iter().enumerate() {
-                let youtube_id = YouTubeVideoId::new(&video_data.youtube_id)
-                    .map_err(|e| IngestError::PersistFailed(e.to_string()))?;
-                let source = VideoSource::youtube(youtube_id);
-                let video = Video::with_description(
-                    VideoId::new(),
-                    pm.module_id,
-                    source,
-                    video_data.title.clone(),
-                    video_data.description.clone(),
-                    video_data.duration_secs,
-                    sort_order as u32,
-                );
-                video_search_entries.push(SearchEntry {
-                    entity_type: "video".to_string(),
-                    entity_id: video.id().as_uuid().to_string(),
-                    title: video_data.title.clone(),
-                    content: video_data.description.clone().unwrap_or_default(),
-                    course_id: course_id.as_uuid().to_string(),
-                });
-                all_videos.push(video);
-            }
+    /// Persists a (possibly edited) draft as a new course in one transaction.
+    pub fn commit(&self, draft: &ImportDraft) -> Result<IngestPlaylistOutput, IngestError> {
+        let DraftSource::Playlist { url } = &draft.source else {
+            return Err(IngestError::InvalidUrl("Draft is not a playlist import".to_string()));
+        };
+        let playlist_url =
+            PlaylistUrl::new(url).map_err(|e| IngestError::InvalidUrl(e.to_string()))?;
+        let source_hash = self.ensure_new(&playlist_url)?;
+        if draft.video_count() == 0 {
+            return Err(IngestError::FetchFailed(FetchError::NotFound(
+                "Playlist is empty".to_string(),
+            )));
         }
 
-        self.module_repo
-            .save_batch(&all_modules)
+        let course_id = CourseId::new();
+        let course_name = match draft.course_name.trim() {
+            "" => "Untitled Course".to_string(),
+            name => name.to_string(),
+        };
+        let course = Course::new(
+            course_id,
+            course_name,
+            CourseSource::youtube_playlist(playlist_url),
+            None,
+            Some(source_hash),
+        );
+        let (modules, videos, video_search_entries) =
+            draft.build_contents(course_id).map_err(IngestError::PersistFailed)?;
+
+        if self.cancel.is_cancelled() {
+            return Err(IngestError::Cancelled);
+        }
+        self.report(IngestProgress::Persisting);
+        self.course_repo
+            .save_with_contents(&course, &modules, &videos)
             .map_err(|e| IngestError::PersistFailed(e.to_string()))?;
-        self.video_repo
-            .save_batch(&all_videos)
+        self.search_repo
+            .index_course(course.id(), course.name(), course.description())
             .map_err(|e| IngestError::PersistFailed(e.to_string()))?;
         self.search_repo
             .index_batch(&video_search_entries)
@@ -225,8 +259,23 @@ impl IngestPlaylistUseCase {
 
         Ok(IngestPlaylistOutput {
             course_id,
-            modules_count: module_groups.len(),
-            videos_count: total_videos,
+            modules_count: modules.len(),
+            videos_count: videos.len(),
         })
     }
+
+    fn report(&self, progress: IngestProgress) {
+        if let Some(sink) = &self.progress {
+            sink.report(progress);
+        }
+    }
+
+    /// Returns the source hash of a playlist that has not been imported yet.
+    fn ensure_new(&self, playlist_url: &PlaylistUrl) -> Result<String, IngestError> {
+        let source_hash = media_hash::compute_source_hash(playlist_url.playlist_id());
+        if let Ok(Some(existing)) = self.course_repo.find_by_source_hash(&source_hash) {
+            return Err(IngestError::AlreadyExists(existing.name().to_string()));
+        }
+        Ok(source_hash)
+    }
 }
@@ -2,28 +2,55 @@
 
 mod ask_companion;
 mod chat;
+mod course_manifest;
 mod create_module;
 mod dashboard;
 mod delete_module;
+mod export_manifest;
+mod export_schedule;
+mod flashcards;
+mod import_draft;
 mod ingest_local;
+mod ingest_manifest;
 mod ingest_playlist;
 mod move_video_to_module;
 mod notes;
+mod plan_course_schedule;
+mod playback;
 mod preferences;
+mod relink_local;
+mod rescan_local;
+mod reschedule_course;
+mod review;
+mod study_history;
 mod summarize_video;
+mod sync_playlist;
 mod take_exam;
 mod update_module_title;
 mod update_presence;
 
-pub use ask_companion::{AskCompanionInput, AskCompanionUseCase};
+pub use ask_companion::{
+    AskCompanionInput, AskCompanionUseCase, ExplainMomentInput, StreamedAnswer,
+};
 pub use chat::{
     ChatError, ChatMessageView, ChatRole, ChatUseCase, DeleteChatHistoryInput,
     LoadChatHistoryInput, SendChatMessageInput,
 };
+pub use course_manifest::{
+    CourseManifest, LessonSource, ManifestError, ManifestFormat, ManifestLesson, ManifestModule,
+};
 pub use create_module::{CreateModuleError, CreateModuleInput, CreateModuleUseCase};
 pub use dashboard::LoadDashboardUseCase;
 pub use delete_module::{DeleteModuleError, DeleteModuleInput, DeleteModuleUseCase};
-pub use ingest_local::{IngestLocalInput, IngestLocalOutput, IngestLocalUseCase};
+pub use export_manifest::{ExportManifestInput, ExportManifestOutput, ExportManifestUseCase};
+pub use export_schedule::{ExportScheduleInput, ExportScheduleOutput, ExportScheduleUseCase};
+pub use flashcards::{
+    FlashcardError, FlashcardsUseCase, GenerateFlashcardsInput, GenerateFlashcardsUseCase,
+    SaveFlashcardInput,
+};
+pub use import_draft::{DraftEditError, DraftModule, DraftSource, DraftVideo, ImportDraft};
+pub use ingest_local::{IngestLocalError, IngestLocalInput, IngestLocalOutput, IngestLocalUseCase};
+pub use ingest_manifest::{IngestManifestInput, IngestManifestOutput, IngestManifestUseCase};
 pub use ingest_playlist::{
     IngestError, IngestPlaylistInput, IngestPlaylistOutput, IngestPlaylistUseCase,
 };
@@ -31,11 +58,31 @@ pub use move_video_to_module::{MoveVideoError, MoveVideoInput, MoveVideoToModule
 pub use notes::{
     DeleteNoteInput, LoadNoteInput, NoteView, NotesError, NotesUseCase, SaveNoteInput,
 };
+pub use plan_course_schedule::{
+    PlanCourseScheduleInput, PlanCourseScheduleOutput, PlanCourseScheduleUseCase, ScheduleError,
+    ScheduledSessionView,
+};
+pub use playback::{
+    PlaybackError, PlaybackUseCase, ResumePoint, SavePositionInput, SavePositionOutput,
+};
 pub use preferences::{PreferencesUseCase, UpdatePreferencesInput};
+pub use relink_local::{RelinkLocalError, RelinkLocalInput, RelinkLocalReport, RelinkLocalUseCase};
+pub use rescan_local::{RescanLocalError, RescanLocalInput, RescanLocalReport, RescanLocalUseCase};
+pub use reschedule_course::{
+    RescheduleCourseInput, RescheduleCourseOutput, RescheduleCourseUseCase,
+};
+pub use review::{DueCard, RecordReviewInput, ReviewError, ReviewUseCase};
+pub use study_history::{LoadStudyHistoryInput, LoadStudyHistoryUseCase};
 pub use summarize_video::{
     SummarizeVideoError, SummarizeVideoInput, SummarizeVideoOutput, SummarizeVideoUseCase,
 };
-pub use take_exam::{GenerateExamInput, SubmitExamInput, SubmitExamOutput, TakeExamUseCase};
+pub use sync_playlist::{
+    SyncPlaylistError, SyncPlaylistInput, SyncPlaylistReport, SyncPlaylistUseCase,
+};
+pub use take_exam::{
+    ExamError, GenerateExamInput, GenerateExamOutput, GradeExamUseCase, SubmitExamInput,
+    SubmitExamOutput, TakeExamUseCase,
+};
 pub use update_module_title::{
     UpdateModuleTitleError, UpdateModuleTitleInput, UpdateModuleTitleUseCase,
 };
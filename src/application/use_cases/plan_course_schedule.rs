@@ -0,0 +1,164 @@
+//! Plan Course Schedule Use Case
+//!
+//! Orchestrates: Load videos -> SessionPlanner -> Anchor to calendar -> Persist
+
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+
+use crate::domain::{
+    entities::{StudySchedule, Video},
+    ports::{CourseRepository, RepositoryError, StudyScheduleRepository, VideoRepository},
+    services::{SessionPlanner, module_boundaries},
+    value_objects::{CognitiveLimit, CourseId, ReschedulePolicy},
+};
+
+/// Error type for schedule planning.
+#[derive(Debug, thiserror::Error)]
+pub enum ScheduleError {
+    #[error("Course not found")]
+    CourseNotFound,
+    #[error("Nothing left to plan: every video in this course is completed")]
+    NothingToPlan,
+    #[error("This course has no study schedule yet")]
+    NotPlanned,
+    #[error(transparent)]
+    Repository(#[from] RepositoryError),
+}
+
+/// Input for planning a course schedule.
+#[derive(Debug, Clone)]
+pub struct PlanCourseScheduleInput {
+    pub course_id: CourseId,
+    pub start_date: NaiveDate,
+    /// Study days per week (1-7). The first N days of each week starting at `start_date`.
+    pub study_days_per_week: u32,
+    pub cognitive_limit_minutes: u32,
+    /// How the schedule is re-planned when the learner falls behind or gets ahead.
+    pub reschedule_policy: ReschedulePolicy,
+}
+
+/// Output of the plan course schedule use case.
+#[derive(Debug)]
+pub struct PlanCourseScheduleOutput {
+    pub schedule: StudySchedule,
+    pub videos_planned: usize,
+}
+
+/// A scheduled session resolved to its course and videos, for display.
+#[derive(Debug, Clone)]
+pub struct ScheduledSessionView {
+    pub course_id: CourseId,
+    pub course_name: String,
+    pub date: NaiveDate,
+    pub total_duration_secs: u32,
+    pub videos: Vec<Video>,
+}
+
+/// Use case for planning and querying per-course study schedules.
+pub struct PlanCourseScheduleUseCase {
+    course_repo: Arc<dyn CourseRepository>,
+    video_repo: Arc<dyn VideoRepository>,
+    schedule_repo: Arc<dyn StudyScheduleRepository>,
+}
+
+impl PlanCourseScheduleUseCase {
+    pub fn new(
+        course_repo: Arc<dyn CourseRepository>,
+        video_repo: Arc<dyn VideoRepository>,
+        schedule_repo: Arc<dyn StudyScheduleRepository>,
+    ) -> Self {
+        Self { course_repo, video_repo, schedule_repo }
+    }
+
+    /// Plans the remaining (not yet completed) videos of a course and persists the
+    /// schedule, replacing any previous plan. Sessions break at module boundaries
+    /// when the current session is at least half full.
+    pub fn execute(
+        &self,
+        input: PlanCourseScheduleInput,
+    ) -> Result<PlanCourseScheduleOutput, ScheduleError> {
+        self.course_repo.find_by_id(&input.course_id)?.ok_or(ScheduleError::CourseNotFound)?;
+
+        let pending: Vec<Video> = self
+            .video_repo
+            .find_by_course(&input.course_id)?
+            .into_iter()
+            .filter(|v| !v.is_completed())
+            .collect();
+        if pending.is_empty() {
+            return Err(ScheduleError::NothingToPlan);
+        }
+
+        let durations: Vec<u32> = pending.iter().map(|v| v.duration_secs()).collect();
+        let module_ids: Vec<_> = pending.iter().map(|v| *v.module_id()).collect();
+        let boundaries = module_boundaries(&module_ids);
+        let video_ids: Vec<_> = pending.iter().map(|v| *v.id()).collect();
+
+        let study_days_per_week = input.study_days_per_week.clamp(1, 7);
+        let limit = CognitiveLimit::new(input.cognitive_limit_minutes);
+        let plans = SessionPlanner::new(limit).plan_sessions(
+            &durations,
+            Some(&boundaries),
+            study_days_per_week,
+        );
+
+        // Keep the previous plan's high-water mark so calendar exports can cancel dropped sessions.
+        let previous_peak = self
+            .schedule_repo
+            .find_by_course(&input.course_id)?
+            .map_or(0, |previous| previous.peak_session_count());
+        let schedule = StudySchedule::from_plans(
+            input.course_id,
+            input.start_date,
+            study_days_per_week,
+            limit.minutes(),
+            &plans,
+            &video_ids,
+        )
+        .with_reschedule_policy(input.reschedule_policy)
+        .with_peak_session_count(previous_peak);
+        self.schedule_repo.save(&schedule)?;
+
+        Ok(PlanCourseScheduleOutput { schedule, videos_planned: video_ids.len() })
+    }
+
+    /// Loads the stored schedule for a course.
+    pub fn load(&self, course_id: &CourseId) -> Result<Option<StudySchedule>, ScheduleError> {
+        Ok(self.schedule_repo.find_by_course(course_id)?)
+    }
+
+    /// Removes the stored schedule for a course.
+    pub fn clear(&self, course_id: &CourseId) -> Result<(), ScheduleError> {
+        Ok(self.schedule_repo.delete(course_id)?)
+    }
+
+    /// Returns every course's session planned for `date`, with videos resolved.
+    pub fn sessions_on(&self, date: NaiveDate) -> Result<Vec<ScheduledSessionView>, ScheduleError> {
+        let mut views = Vec::new();
+        for schedule in self.schedule_repo.find_all()? {
+            let Some(session) = schedule.session_on(date) else {
+                continue;
+            };
+            let Some(course) = self.course_repo.find_by_id(schedule.course_id())? else {
+                continue;
+            };
+
+            let mut videos = Vec::with_capacity(session.video_ids().len());
+            for video_id in session.video_ids() {
+                if let Some(video) = self.video_repo.find_by_id(video_id)? {
+                    videos.push(video);
+                }
+            }
+
+            views.push(ScheduledSessionView {
+                course_id: *course.id(),
+                course_name: course.name().to_string(),
+                date,
+                total_duration_secs: session.total_duration_secs(),
+                videos,
+            });
+        }
+        Ok(views)
+    }
+}
@@ -0,0 +1,178 @@
+//! Playback position use case.
+//!
+//! Persists where the learner stopped in each video so playback can resume there,
+//! which sections were actually played so videos can complete themselves, and a log
+//! of watch sessions for the study history.
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+
+use crate::domain::{
+    entities::Video,
+    ports::{
+        PlaybackPosition, PlaybackPositionRepository, RepositoryError, VideoRepository,
+        WatchSession, WatchSessionRepository,
+    },
+    value_objects::{CourseId, VideoId, WatchedRanges},
+};
+
+/// Positions closer than this to the start are not worth resuming.
+const MIN_RESUME_MS: u64 = 5_000;
+/// Positions closer than this to the end count as finished and restart from zero.
+const END_MARGIN_MS: u64 = 15_000;
+
+/// Error type for playback position operations.
+#[derive(Debug, thiserror::Error)]
+pub enum PlaybackError {
+    #[error(transparent)]
+    Repository(#[from] RepositoryError),
+}
+
+/// Input for saving playback progress.
+#[derive(Debug, Clone)]
+pub struct SavePositionInput {
+    pub video_id: VideoId,
+    pub position_ms: u64,
+    /// Sections played since the last save; merged into the stored ranges.
+    pub played: WatchedRanges,
+    /// Duration reported by the player. Falls back to the stored video duration.
+    pub duration_ms: Option<u64>,
+    /// Watched share (in percent) at which the video is marked complete.
+    pub auto_complete_percent: u32,
+}
+
+/// Output of saving playback progress.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SavePositionOutput {
+    /// Share of the video watched so far, in `0.0..=1.0`.
+    pub watched_fraction: f64,
+    /// True if this save crossed the threshold and marked the video complete.
+    pub marked_complete: bool,
+}
+
+/// The video to resume in a course and where to resume it.
+#[derive(Debug, Clone)]
+pub struct ResumePoint {
+    pub video: Video,
+    /// `None` when the video should start from the beginning.
+    pub position_ms: Option<u64>,
+}
+
+/// Use case for saving and restoring per-video playback positions.
+pub struct PlaybackUseCase {
+    position_repo: Arc<dyn PlaybackPositionRepository>,
+    session_repo: Arc<dyn WatchSessionRepository>,
+    video_repo: Arc<dyn VideoRepository>,
+}
+
+impl PlaybackUseCase {
+    pub fn new(
+        position_repo: Arc<dyn PlaybackPositionRepository>,
+        session_repo: Arc<dyn WatchSessionRepository>,
+        video_repo: Arc<dyn VideoRepository>,
+    ) -> Self {
+        Self { position_repo, session_repo, video_repo }
+    }
+
+    /// Logs a finished stretch of watching. Sessions with nothing played are dropped.
+    pub fn record_session(
+        &self,
+        video_id: VideoId,
+        started_at: DateTime<Utc>,
+        ended_at: DateTime<Utc>,
+        played_secs: u32,
+    ) -> Result<(), PlaybackError> {
+        if played_secs == 0 {
+            return Ok(());
+        }
+        self.session_repo.record(&WatchSession {
+            video_id,
+            started_at,
+            ended_at: ended_at.max(started_at),
+            played_secs,
+        })?;
+        Ok(())
+    }
+
+    /// Records the current position of a video and the sections played since the last save.
+    ///
+    /// When the watched share first reaches `auto_complete_percent`, the video is marked
+    /// complete. Videos the learner un-marked after crossing the threshold stay un-marked.
+    pub fn save_position(
+        &self,
+        input: SavePositionInput,
+    ) -> Result<SavePositionOutput, PlaybackError> {
+        let previous = self.position_repo.find_by_video(&input.video_id)?;
+        let video = self.video_repo.find_by_id(&input.video_id)?;
+        let duration_ms = input
+            .duration_ms
+            .filter(|ms| *ms > 0)
+            .or_else(|| video.as_ref().map(|v| u64::from(v.duration_secs()) * 1_000))
+            .unwrap_or(0);
+
+        let mut watched = previous.map(|p| p.watched).unwrap_or_default();
+        let before = watched.fraction(duration_ms);
+        watched.merge(&input.played);
+        let after = watched.fraction(duration_ms);
+
+        self.position_repo.save(&PlaybackPosition {
+            video_id: input.video_id,
+            position_ms: input.position_ms,
+            watched,
+            updated_at: Utc::now(),
+        })?;
+
+        let threshold = f64::from(input.auto_complete_percent.clamp(1, 100)) / 100.0;
+        let marked_complete =
+            before < threshold && after >= threshold && video.is_some_and(|v| !v.is_completed());
+        if marked_complete {
+            self.video_repo.update_completion(&input.video_id, true)?;
+        }
+
+        Ok(SavePositionOutput { watched_fraction: after, marked_complete })
+    }
+
+    /// Returns where to resume a video of `duration_secs`, or `None` to start from zero.
+    pub fn resume_position(
+        &self,
+        video_id: &VideoId,
+        duration_secs: u32,
+    ) -> Result<Option<u64>, PlaybackError> {
+        let position = self.position_repo.find_by_video(video_id)?;
+        Ok(position.and_then(|p| resume_point(p.position_ms, duration_secs)))
+    }
+
+    /// Returns the most recently watched video of a course with its resume position.
+    pub fn last_watched(&self, course_id: &CourseId) -> Result<Option<ResumePoint>, PlaybackError> {
+        let Some(position) = self.position_repo.find_latest_by_course(course_id)? else {
+            return Ok(None);
+        };
+        let Some(video) = self.video_repo.find_by_id(&position.video_id)? else {
+            return Ok(None);
+        };
+        let position_ms = resume_point(position.position_ms, video.duration_secs());
+        Ok(Some(ResumePoint { video, position_ms }))
+    }
+}
+
+/// Applies the start/end margins to a stored position.
+fn resume_point(position_ms: u64, duration_secs: u32) -> Option<u64> {
+    let duration_ms = u64::from(duration_secs) * 1_000;
+    let near_end = duration_ms > 0 && position_ms + END_MARGIN_MS >= duration_ms;
+    (position_ms >= MIN_RESUME_MS && !near_end).then_some(position_ms)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resume_point_ignores_start_and_end() {
+        assert_eq!(resume_point(2_000, 600), None);
+        assert_eq!(resume_point(120_000, 600), Some(120_000));
+        assert_eq!(resume_point(590_000, 600), None);
+        // Unknown duration: anything past the start margin resumes.
+        assert_eq!(resume_point(120_000, 0), Some(120_000));
+    }
+}
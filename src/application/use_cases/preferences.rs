@@ -16,6 +16,9 @@ pub struct UpdatePreferencesInput {
     pub onboarding_completed: bool,
     pub preferred_quality: VideoQuality,
     pub boundary_batch_size: u32,
+    pub module_target_minutes: u32,
+    pub auto_complete_percent: u32,
+    pub watch_local_folders: bool,
 }
 
 /// Use case for loading and updating user preferences.
@@ -51,6 +54,9 @@ impl PreferencesUseCase {
         prefs.set_onboarding_completed(input.onboarding_completed);
         prefs.set_preferred_quality(input.preferred_quality);
         prefs.set_boundary_batch_size(input.boundary_batch_size);
+        prefs.set_module_target_minutes(input.module_target_minutes);
+        prefs.set_auto_complete_percent(input.auto_complete_percent);
+        prefs.set_watch_local_folders(input.watch_local_folders);
         self.prefs_repo.save(&prefs)?;
         Ok(prefs)
     }
@@ -95,10 +101,16 @@ mod tests {
             onboarding_completed: true,
             preferred_quality: VideoQuality::P1080,
             boundary_batch_size: 5,
+            module_target_minutes: 40,
+            auto_complete_percent: 85,
+            watch_local_folders: true,
         };
 
         let result = uc.update(input).unwrap();
         assert_eq!(result.preferred_quality(), VideoQuality::P1080);
+        assert_eq!(result.auto_complete_percent(), 85);
+        assert!(result.watch_local_folders());
+        assert_eq!(result.module_target_minutes(), 40);
 
         let loaded = uc.load().unwrap();
         assert_eq!(loaded.preferred_quality(), VideoQuality::P1080);
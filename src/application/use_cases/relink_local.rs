@@ -0,0 +1,262 @@
+//! Relink Local Course Use Case
+//!
+//! Orchestrates: Find missing files -> Match by path suffix under the new root -> Fall back
+//! to fingerprints -> Persist
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
+
+use crate::domain::{
+    ports::{
+        CourseRepository, LocalMediaError, LocalMediaScanner, RepositoryError, VideoRepository,
+    },
+    value_objects::{CourseId, CourseSource, VideoSource},
+};
+use crate::infrastructure::media_hash;
+
+/// Error type for relinking local courses.
+#[derive(Debug, thiserror::Error)]
+pub enum RelinkLocalError {
+    #[error("Course not found")]
+    CourseNotFound,
+    #[error("Course is not backed by a local folder")]
+    NotALocalCourse,
+    #[error("Not a folder: {0}")]
+    InvalidRoot(String),
+    #[error(transparent)]
+    ScanFailed(#[from] LocalMediaError),
+    #[error("Invalid media path: {0}")]
+    InvalidMedia(String),
+    #[error(transparent)]
+    Repository(#[from] RepositoryError),
+}
+
+/// Input for the relink local course use case.
+#[derive(Debug, Clone)]
+pub struct RelinkLocalInput {
+    pub course_id: CourseId,
+    /// Folder the user picked as the course's new location.
+    pub new_root: String,
+}
+
+/// What a relink changed.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RelinkLocalReport {
+    /// Titles of videos found again under the new root.
+    pub relinked: Vec<String>,
+    /// How many of those were only found by their content fingerprint.
+    pub matched_by_content: usize,
+    /// Titles of videos whose file is still missing.
+    pub still_missing: Vec<String>,
+    /// Root folder the course points at after the relink.
+    pub root: String,
+}
+
+/// Use case for pointing a local course at its folder after it was moved or renamed.
+///
+/// Only video paths change: ids, progress, notes, transcripts and summaries stay attached.
+pub struct RelinkLocalUseCase {
+    scanner: Arc<dyn LocalMediaScanner>,
+    course_repo: Arc<dyn CourseRepository>,
+    video_repo: Arc<dyn VideoRepository>,
+}
+
+impl RelinkLocalUseCase {
+    pub fn new(
+        scanner: Arc<dyn LocalMediaScanner>,
+        course_repo: Arc<dyn CourseRepository>,
+        video_repo: Arc<dyn VideoRepository>,
+    ) -> Self {
+        Self { scanner, course_repo, video_repo }
+    }
+
+    /// Counts the videos of a course whose local file no longer exists.
+    pub fn count_missing(&self, course_id: &CourseId) -> Result<usize, RelinkLocalError> {
+        let videos = self.video_repo.find_by_course(course_id)?;
+        Ok(videos
+            .iter()
+            .filter_map(|v| v.local_path())
+            .filter(|path| !Path::new(path).is_file())
+            .count())
+    }
+
+    /// Finds the missing files of a course under `new_root` and moves the course there.
+    pub async fn execute(
+        &self,
+        input: RelinkLocalInput,
+    ) -> Result<RelinkLocalReport, RelinkLocalError> {
+        let mut course = self
+            .course_repo
+            .find_by_id(&input.course_id)?
+            .ok_or(RelinkLocalError::CourseNotFound)?;
+        let old_root = course.source().local_root().ok_or(RelinkLocalError::NotALocalCourse)?;
+        let old_root = PathBuf::from(old_root);
+        let new_root = Path::new(input.new_root.trim());
+        if !new_root.is_dir() {
+            return Err(RelinkLocalError::InvalidRoot(input.new_root));
+        }
+
+        let mut videos = self.video_repo.find_by_course(course.id())?;
+        let mut claimed: HashSet<PathBuf> = videos
+            .iter()
+            .filter_map(|v| v.local_path())
+            .map(PathBuf::from)
+            .filter(|p| p.is_file())
+            .collect();
+        let mut report = RelinkLocalReport::default();
+        let mut changed = Vec::new();
+        let mut unresolved = Vec::new();
+        let mut inferred_root: Option<PathBuf> = None;
+        // Chapter lessons of one file share its path and follow it together.
+        let mut resolved: HashMap<PathBuf, PathBuf> = HashMap::new();
+
+        // 1. Match by the longest path suffix that exists under the new root
+        for (i, video) in videos.iter().enumerate() {
+            let Some(path) = video.local_path().map(Path::new) else {
+                continue;
+            };
+            if path.is_file() {
+                continue;
+            }
+            if let Some(candidate) = resolved.get(path) {
+                changed.push((i, candidate.clone()));
+                continue;
+            }
+            let found = suffix_candidates(path, new_root).into_iter().find(|candidate| {
+                !claimed.contains(candidate)
+                    && candidate.is_file()
+                    // A same-named file from another lecture must not steal the match.
+                    && video.content_fingerprint().is_none_or(|fp| {
+                        media_hash::compute_file_fingerprint(candidate).ok().as_deref() == Some(fp)
+                    })
+            });
+            match found {
+                Some(candidate) => {
+                    if inferred_root.is_none()
+                        && let Ok(rel) = path.strip_prefix(&old_root)
+                    {
+                        inferred_root = strip_suffix(&candidate, rel);
+                    }
+                    claimed.insert(candidate.clone());
+                    resolved.insert(path.to_path_buf(), candidate.clone());
+                    changed.push((i, candidate));
+                },
+                None => unresolved.push(i),
+            }
+        }
+
+        // 2. Fall back to fingerprints for files that were also renamed
+        if unresolved.iter().any(|&i| videos[i].content_fingerprint().is_some()) {
+            let scanned = self.scanner.scan(&new_root.to_string_lossy()).await?;
+            for item in scanned {
+                let path = PathBuf::from(&item.path);
+                if claimed.contains(&path) {
+                    continue;
+                }
+                let Ok(fp) = media_hash::compute_file_fingerprint(&path) else {
+                    continue;
+                };
+                let Some(old_path) = unresolved
+                    .iter()
+                    .find(|&&i| videos[i].content_fingerprint() == Some(fp.as_str()))
+                    .and_then(|&i| videos[i].local_path())
+                    .map(str::to_string)
+                else {
+                    continue;
+                };
+                let (matched, rest): (Vec<usize>, Vec<usize>) = unresolved
+                    .iter()
+                    .partition(|&&i| videos[i].local_path() == Some(old_path.as_str()));
+                unresolved = rest;
+                claimed.insert(path.clone());
+                report.matched_by_content += matched.len();
+                changed.extend(matched.into_iter().map(|i| (i, path.clone())));
+            }
+        }
+
+        // 3. Point the videos, then the course, at their new location
+        let mut to_save = Vec::with_capacity(changed.len());
+        changed.sort_by_key(|(i, _)| *i);
+        for (i, path) in changed {
+            let video = &mut videos[i];
+            let source = VideoSource::local_path(path.to_string_lossy())
+                .map_err(|e| RelinkLocalError::InvalidMedia(e.to_string()))?;
+            video.relocate(source);
+            video.mark_available();
+            if video.content_fingerprint().is_none() {
+                video.set_content_fingerprint(media_hash::compute_file_fingerprint(&path).ok());
+            }
+            report.relinked.push(video.title().to_string());
+            to_save.push(video.clone());
+        }
+        unresolved.sort_unstable();
+        report.still_missing = unresolved.iter().map(|&i| videos[i].title().to_string()).collect();
+
+        // The picked folder may be a parent or child of the course folder; prefer the root
+        // the matched paths imply so later rescans cover exactly the course.
+        let root = inferred_root.unwrap_or_else(|| new_root.to_path_buf());
+        let root = root.to_string_lossy().to_string();
+        if !report.relinked.is_empty() && course.source().local_root() != Some(root.as_str()) {
+            let source = CourseSource::local_folder(&root)
+                .map_err(|e| RelinkLocalError::InvalidRoot(e.to_string()))?;
+            course.relocate(source, Some(media_hash::compute_source_hash(&root)));
+        }
+        report.root = course.source().local_root().unwrap_or_default().to_string();
+
+        // One transaction, so a failed save never leaves the course root ahead of its videos.
+        if !to_save.is_empty() {
+            self.course_repo.save_with_contents(&course, &[], &to_save)?;
+        }
+        Ok(report)
+    }
+}
+
+/// Candidate locations for `path` under `root`, from the longest path suffix to the file name.
+fn suffix_candidates(path: &Path, root: &Path) -> Vec<PathBuf> {
+    let parts: Vec<_> = path.components().filter(|c| matches!(c, Component::Normal(_))).collect();
+    (0..parts.len())
+        .map(|start| parts[start..].iter().fold(root.to_path_buf(), |acc, c| acc.join(c)))
+        .collect()
+}
+
+/// Removes `suffix` from the end of `path`, if `path` ends with it.
+fn strip_suffix(path: &Path, suffix: &Path) -> Option<PathBuf> {
+    if !path.ends_with(suffix) {
+        return None;
+    }
+    let mut path = path.to_path_buf();
+    for _ in suffix.components() {
+        if !path.pop() {
+            return None;
+        }
+    }
+    Some(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn suffix_candidates_run_from_longest_to_file_name() {
+        let candidates =
+            suffix_candidates(Path::new("/old/Course/Basics/a.mp4"), Path::new("/new"));
+        assert_eq!(
+            candidates,
+            vec![
+                PathBuf::from("/new/old/Course/Basics/a.mp4"),
+                PathBuf::from("/new/Course/Basics/a.mp4"),
+                PathBuf::from("/new/Basics/a.mp4"),
+                PathBuf::from("/new/a.mp4"),
+            ]
+        );
+    }
+
+    #[test]
+    fn strip_suffix_requires_a_matching_tail() {
+        let path = Path::new("/new/Course/Basics/a.mp4");
+        assert_eq!(strip_suffix(path, Path::new("Basics/a.mp4")), Some("/new/Course".into()));
+        assert_eq!(strip_suffix(path, Path::new("Other/a.mp4")), None);
+    }
+}
@@ -0,0 +1,295 @@
+//! Rescan Local Course Use Case
+//!
+//! Orchestrates: Scan -> Match by path, then by fingerprint -> Place new files -> Persist
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::sync::Arc;
+
+use super::ingest_local::{group_by_folder, read_subtitle_transcript};
+use crate::domain::{
+    entities::{Module, Video},
+    ports::{
+        CourseRepository, LocalMediaError, LocalMediaScanner, ModuleRepository,
+        ModuleTitleGenerator, RawLocalMediaMetadata, RepositoryError, SearchEntry,
+        SearchRepository, VideoRepository,
+    },
+    services::{SubtitleCleaner, TitleSanitizer},
+    value_objects::{CourseId, ModuleId, VideoId, VideoSource},
+};
+use crate::infrastructure::media_hash;
+
+/// Error type for local rescans.
+#[derive(Debug, thiserror::Error)]
+pub enum RescanLocalError {
+    #[error("Course not found")]
+    CourseNotFound,
+    #[error("Course is not backed by a local folder")]
+    NotALocalCourse,
+    #[error("Course folder is not reachable: {0}")]
+    RootUnavailable(String),
+    #[error(transparent)]
+    ScanFailed(#[from] LocalMediaError),
+    #[error("Invalid media path: {0}")]
+    InvalidMedia(String),
+    #[error(transparent)]
+    Repository(#[from] RepositoryError),
+}
+
+/// Input for the rescan local course use case.
+#[derive(Debug, Clone)]
+pub struct RescanLocalInput {
+    pub course_id: CourseId,
+}
+
+/// What a rescan changed.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RescanLocalReport {
+    /// Titles of files added to the course.
+    pub added: Vec<String>,
+    /// Titles of videos whose file was found under a new path.
+    pub moved: Vec<String>,
+    /// Titles of videos whose file is gone, now flagged unavailable.
+    pub missing: Vec<String>,
+    /// Titles of previously missing videos whose file is back.
+    pub restored: Vec<String>,
+    /// Modules created for new folders.
+    pub modules_created: usize,
+}
+
+impl RescanLocalReport {
+    /// True when the course already matched the folder.
+    pub fn is_unchanged(&self) -> bool {
+        self.added.is_empty()
+            && self.moved.is_empty()
+            && self.missing.is_empty()
+            && self.restored.is_empty()
+    }
+}
+
+/// Use case for picking up files added, moved or deleted in a local course folder.
+///
+/// Existing videos keep their id, module and progress; a moved file is followed by its
+/// content fingerprint rather than re-imported.
+pub struct RescanLocalUseCase {
+    scanner: Arc<dyn LocalMediaScanner>,
+    course_repo: Arc<dyn CourseRepository>,
+    module_repo: Arc<dyn ModuleRepository>,
+    video_repo: Arc<dyn VideoRepository>,
+    search_repo: Arc<dyn SearchRepository>,
+    sanitizer: TitleSanitizer,
+    title_generator: Option<Arc<dyn ModuleTitleGenerator>>,
+}
+
+impl RescanLocalUseCase {
+    pub fn new(
+        scanner: Arc<dyn LocalMediaScanner>,
+        course_repo: Arc<dyn CourseRepository>,
+        module_repo: Arc<dyn ModuleRepository>,
+        video_repo: Arc<dyn VideoRepository>,
+        search_repo: Arc<dyn SearchRepository>,
+        title_generator: Option<Arc<dyn ModuleTitleGenerator>>,
+    ) -> Self {
+        Self {
+            scanner,
+            course_repo,
+            module_repo,
+            video_repo,
+            search_repo,
+            sanitizer: TitleSanitizer::new(),
+            title_generator,
+        }
+    }
+
+    /// Scans the course folder again and merges the result into the course.
+    pub async fn execute(
+        &self,
+        input: RescanLocalInput,
+    ) -> Result<RescanLocalReport, RescanLocalError> {
+        let course = self
+            .course_repo
+            .find_by_id(&input.course_id)?
+            .ok_or(RescanLocalError::CourseNotFound)?;
+        let root = course.source().local_root().ok_or(RescanLocalError::NotALocalCourse)?;
+        // An unmounted drive would otherwise look like every file was deleted.
+        if !Path::new(root).is_dir() {
+            return Err(RescanLocalError::RootUnavailable(root.to_string()));
+        }
+
+        // 1. Scan
+        let scanned = self.scanner.scan(root).await?;
+        let scanned_paths: HashSet<&str> = scanned.iter().map(|m| m.path.as_str()).collect();
+
+        let mut modules = self.module_repo.find_by_course(course.id())?;
+        modules.sort_by_key(|m| m.sort_order());
+        let mut videos = self.video_repo.find_by_course(course.id())?;
+        let mut changed: HashSet<VideoId> = HashSet::new();
+        let mut report = RescanLocalReport::default();
+
+        // 2. Match stored videos by path
+        let stored_paths: HashSet<String> =
+            videos.iter().filter_map(|v| v.local_path()).map(str::to_string).collect();
+        let mut missing: Vec<usize> = Vec::new();
+        for (i, video) in videos.iter_mut().enumerate() {
+            let Some(path) = video.local_path() else {
+                continue;
+            };
+            if !scanned_paths.contains(path) {
+                missing.push(i);
+                continue;
+            }
+            if video.content_fingerprint().is_none() {
+                // Courses imported before fingerprinting pick one up on their first rescan.
+                video.set_content_fingerprint(media_hash::compute_file_fingerprint(path).ok());
+                changed.insert(*video.id());
+            }
+            if !video.is_available() {
+                video.mark_available();
+                report.restored.push(video.title().to_string());
+                changed.insert(*video.id());
+            }
+        }
+
+        // 3. Follow moved files by fingerprint; the rest is new
+        let mut new_media: Vec<(RawLocalMediaMetadata, Option<String>)> = Vec::new();
+        for item in scanned.iter().filter(|m| !stored_paths.contains(&m.path)) {
+            let fingerprint = media_hash::compute_file_fingerprint(&item.path).ok();
+            let moved_from = fingerprint.as_deref().and_then(|fp| {
+                missing
+                    .iter()
+                    .find(|&&i| videos[i].content_fingerprint() == Some(fp))
+                    .and_then(|&i| videos[i].local_path())
+                    .map(str::to_string)
+            });
+            let Some(old_path) = moved_from else {
+                new_media.push((item.clone(), fingerprint));
+                continue;
+            };
+            // Chapter lessons of one file share its path and move together.
+            let (moving, still_missing): (Vec<usize>, Vec<usize>) =
+                missing.iter().partition(|&&i| videos[i].local_path() == Some(old_path.as_str()));
+            missing = still_missing;
+            let source = VideoSource::local_path(&item.path)
+                .map_err(|e| RescanLocalError::InvalidMedia(e.to_string()))?;
+            for i in moving {
+                let video = &mut videos[i];
+                video.relocate(source.clone());
+                video.mark_available();
+                report.moved.push(video.title().to_string());
+                changed.insert(*video.id());
+            }
+        }
+
+        for &i in &missing {
+            let video = &mut videos[i];
+            if video.is_available() {
+                video.mark_unavailable();
+                report.missing.push(video.title().to_string());
+                changed.insert(*video.id());
+            }
+        }
+
+        // 4. Add new files to the module of their folder, or to a new module per folder
+        let module_rank: HashMap<ModuleId, u32> =
+            modules.iter().map(|m| (*m.id(), m.sort_order())).collect();
+        let mut folder_module: HashMap<String, (u32, u32, ModuleId)> = HashMap::new();
+        let mut next_order: HashMap<ModuleId, u32> = HashMap::new();
+        for video in &videos {
+            let next = next_order.entry(*video.module_id()).or_default();
+            *next = (*next).max(video.sort_order() + 1);
+            let (Some(folder), Some(&rank)) =
+                (video.local_path().and_then(parent_folder), module_rank.get(video.module_id()))
+            else {
+                continue;
+            };
+            // A folder split across several modules takes new files into the last one.
+            let key = (rank, video.sort_order(), *video.module_id());
+            folder_module
+                .entry(folder)
+                .and_modify(|current| {
+                    if (key.0, key.1) > (current.0, current.1) {
+                        *current = key;
+                    }
+                })
+                .or_insert(key);
+        }
+
+        let fingerprints: HashMap<String, String> = new_media
+            .iter()
+            .filter_map(|(item, fp)| fp.clone().map(|fp| (item.path.clone(), fp)))
+            .collect();
+        let new_items: Vec<RawLocalMediaMetadata> =
+            new_media.into_iter().map(|(item, _)| item).collect();
+        let cleaner = SubtitleCleaner::new();
+        let mut new_modules = Vec::new();
+        let mut new_videos = Vec::new();
+        let mut module_order = modules.last().map_or(0, |m| m.sort_order() + 1);
+
+        for (folder, items) in group_by_folder(root, &new_items) {
+            let titles: Vec<String> =
+                items.iter().map(|item| self.sanitizer.sanitize(&item.title)).collect();
+            let module_id = match folder_module.get(&folder) {
+                Some(&(_, _, module_id)) => module_id,
+                None => {
+                    let title = crate::application::generate_module_title(
+                        self.title_generator.as_ref(),
+                        &titles,
+                        course.name(),
+                        module_order as usize,
+                    )
+                    .await;
+                    let module = Module::new(ModuleId::new(), *course.id(), title, module_order);
+                    module_order += 1;
+                    let id = *module.id();
+                    new_modules.push(module);
+                    id
+                },
+            };
+
+            for (item, title) in items.iter().zip(titles) {
+                let source = VideoSource::local_path(&item.path)
+                    .map_err(|e| RescanLocalError::InvalidMedia(e.to_string()))?;
+                let order = next_order.entry(module_id).or_default();
+                let mut video = Video::with_description(
+                    VideoId::new(),
+                    module_id,
+                    source,
+                    title,
+                    None,
+                    item.duration_secs,
+                    *order,
+                );
+                *order += 1;
+                video.update_transcript(read_subtitle_transcript(item, &cleaner));
+                video.set_content_fingerprint(fingerprints.get(&item.path).cloned());
+                report.added.push(video.title().to_string());
+                changed.insert(*video.id());
+                new_videos.push(video);
+            }
+        }
+
+        // 5. Persist modules and videos in one transaction, then index
+        report.modules_created = new_modules.len();
+
+        let entries: Vec<SearchEntry> = new_videos
+            .iter()
+            .map(|v| SearchEntry {
+                entity_type: "video".to_string(),
+                entity_id: v.id().as_uuid().to_string(),
+                title: v.title().to_string(),
+                content: String::new(),
+                course_id: course.id().as_uuid().to_string(),
+            })
+            .collect();
+        videos.extend(new_videos);
+        let to_save: Vec<Video> = videos.into_iter().filter(|v| changed.contains(v.id())).collect();
+        self.course_repo.save_with_contents(&course, &new_modules, &to_save)?;
+        self.search_repo.index_batch(&entries)?;
+
+        Ok(report)
+    }
+}
+
+fn parent_folder(path: &str) -> Option<String> {
+    Path::new(path).parent().map(|p| p.to_string_lossy().to_string())
+}
@@ -0,0 +1,94 @@
+//! Reschedule Course Use Case
+//!
+//! Orchestrates: Load schedule + videos -> Rescheduler -> Persist -> Report diff
+
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+
+use crate::application::use_cases::ScheduleError;
+use crate::domain::{
+    entities::{StudySchedule, Video},
+    ports::{StudyScheduleRepository, VideoRepository},
+    services::{Rescheduler, ScheduleDiff},
+    value_objects::{CourseId, ReschedulePolicy},
+};
+
+/// Input for re-planning a course schedule.
+#[derive(Debug, Clone)]
+pub struct RescheduleCourseInput {
+    pub course_id: CourseId,
+    pub today: NaiveDate,
+    /// Overrides (and replaces) the schedule's stored policy when set.
+    pub policy: Option<ReschedulePolicy>,
+}
+
+/// Output of a reschedule: the new plan and what moved.
+#[derive(Debug)]
+pub struct RescheduleCourseOutput {
+    pub course_id: CourseId,
+    pub schedule: StudySchedule,
+    pub diff: ScheduleDiff,
+}
+
+/// Use case for re-planning study schedules that drifted from the calendar.
+pub struct RescheduleCourseUseCase {
+    video_repo: Arc<dyn VideoRepository>,
+    schedule_repo: Arc<dyn StudyScheduleRepository>,
+}
+
+impl RescheduleCourseUseCase {
+    pub fn new(
+        video_repo: Arc<dyn VideoRepository>,
+        schedule_repo: Arc<dyn StudyScheduleRepository>,
+    ) -> Self {
+        Self { video_repo, schedule_repo }
+    }
+
+    /// Re-plans one course's schedule from `today` and persists it.
+    pub fn execute(
+        &self,
+        input: RescheduleCourseInput,
+    ) -> Result<RescheduleCourseOutput, ScheduleError> {
+        let schedule = self
+            .schedule_repo
+            .find_by_course(&input.course_id)?
+            .ok_or(ScheduleError::NotPlanned)?;
+        let videos = self.video_repo.find_by_course(&input.course_id)?;
+        let policy = input.policy.unwrap_or(schedule.reschedule_policy());
+        self.reschedule(&schedule, &videos, input.today, policy)
+    }
+
+    /// Re-plans every schedule that is off track on `today`, each with its stored policy.
+    /// Schedules whose course has nothing left to watch are left untouched.
+    pub fn catch_up_all(
+        &self,
+        today: NaiveDate,
+    ) -> Result<Vec<RescheduleCourseOutput>, ScheduleError> {
+        let mut outputs = Vec::new();
+        for schedule in self.schedule_repo.find_all()? {
+            let videos = self.video_repo.find_by_course(schedule.course_id())?;
+            if videos.iter().all(|v| v.is_completed())
+                || !Rescheduler::is_off_track(&schedule, &videos, today)
+            {
+                continue;
+            }
+            let policy = schedule.reschedule_policy();
+            outputs.push(self.reschedule(&schedule, &videos, today, policy)?);
+        }
+        Ok(outputs)
+    }
+
+    fn reschedule(
+        &self,
+        schedule: &StudySchedule,
+        videos: &[Video],
+        today: NaiveDate,
+        policy: ReschedulePolicy,
+    ) -> Result<RescheduleCourseOutput, ScheduleError> {
+        let (rescheduled, diff) = Rescheduler::reschedule(schedule, videos, today, policy);
+        self.schedule_repo.save(&rescheduled)?;
+
+        Ok(RescheduleCourseOutput { course_id: *schedule.course_id(), schedule: rescheduled, diff })
+    }
+}
@@ -0,0 +1,111 @@
+//! Review use case.
+//!
+//! Orchestrates: Promote exam questions into cards -> Load due cards -> Record graded answers
+
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+
+use crate::domain::entities::{QuizQuestion, ReviewCard};
+use crate::domain::ports::{
+    ExamRepository, RepositoryError, ReviewCardRepository, VideoRepository,
+};
+use crate::domain::value_objects::{ReviewCardId, ReviewGrade};
+
+/// Error type for review operations.
+#[derive(Debug, thiserror::Error)]
+pub enum ReviewError {
+    #[error("Review card not found")]
+    CardNotFound,
+    #[error(transparent)]
+    Repository(#[from] RepositoryError),
+}
+
+/// A card due for review, with the title of the video it came from.
+#[derive(Debug, Clone)]
+pub struct DueCard {
+    pub card: ReviewCard,
+    pub video_title: String,
+}
+
+/// Input for recording a graded review.
+#[derive(Debug, Clone)]
+pub struct RecordReviewInput {
+    pub card_id: ReviewCardId,
+    pub grade: ReviewGrade,
+    pub today: NaiveDate,
+}
+
+/// Use case for the spaced-repetition review queue.
+pub struct ReviewUseCase {
+    exam_repo: Arc<dyn ExamRepository>,
+    card_repo: Arc<dyn ReviewCardRepository>,
+    video_repo: Arc<dyn VideoRepository>,
+}
+
+impl ReviewUseCase {
+    pub fn new(
+        exam_repo: Arc<dyn ExamRepository>,
+        card_repo: Arc<dyn ReviewCardRepository>,
+        video_repo: Arc<dyn VideoRepository>,
+    ) -> Self {
+        Self { exam_repo, card_repo, video_repo }
+    }
+
+    /// Creates review cards, due `today`, for every exam that has none yet.
+    /// Returns the number of cards created.
+    pub fn promote_exams(&self, today: NaiveDate) -> Result<usize, ReviewError> {
+        let mut created = 0;
+        for exam in self.exam_repo.find_all()? {
+            if !self.card_repo.find_by_exam(exam.id())?.is_empty() {
+                continue;
+            }
+            let questions: Vec<QuizQuestion> = match serde_json::from_str(exam.question_json()) {
+                Ok(questions) => questions,
+                Err(e) => {
+                    log::warn!("Skipping exam {} with unreadable questions: {e}", exam.id());
+                    continue;
+                },
+            };
+            for (idx, question) in questions.into_iter().enumerate() {
+                let card = ReviewCard::new(
+                    ReviewCardId::new(),
+                    *exam.id(),
+                    *exam.video_id(),
+                    idx as u32,
+                    question,
+                    today,
+                );
+                self.card_repo.save(&card)?;
+                created += 1;
+            }
+        }
+        Ok(created)
+    }
+
+    /// Returns the cards due on or before `today` across all courses, earliest first.
+    /// Questions from newly generated exams are promoted first.
+    pub fn due_cards(&self, today: NaiveDate) -> Result<Vec<DueCard>, ReviewError> {
+        self.promote_exams(today)?;
+
+        let mut due = Vec::new();
+        for card in self.card_repo.find_due(today)? {
+            let video_title = self
+                .video_repo
+                .find_by_id(card.video_id())?
+                .map(|v| v.title().to_string())
+                .unwrap_or_default();
+            due.push(DueCard { card, video_title });
+        }
+        Ok(due)
+    }
+
+    /// Records a graded answer and reschedules the card.
+    pub fn record_answer(&self, input: RecordReviewInput) -> Result<ReviewCard, ReviewError> {
+        let mut card =
+            self.card_repo.find_by_id(&input.card_id)?.ok_or(ReviewError::CardNotFound)?;
+        card.record_review(input.grade, input.today);
+        self.card_repo.save(&card)?;
+        Ok(card)
+    }
+}
@@ -0,0 +1,88 @@
+//! Study history use case.
+//!
+//! Orchestrates: Load watch sessions -> Bucket by local day -> Streaks + per-course velocity
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::{DateTime, Local, NaiveDate, Utc};
+
+use crate::domain::entities::{CourseVelocity, StudyHistory, history_start};
+use crate::domain::ports::{
+    CourseRepository, RepositoryError, VideoRepository, WatchSessionRepository,
+};
+
+/// Days of watch time averaged into a course's velocity.
+const VELOCITY_WINDOW_DAYS: u32 = 28;
+
+/// Input for loading the study history.
+#[derive(Debug, Clone)]
+pub struct LoadStudyHistoryInput {
+    /// Last day of the history, in local time.
+    pub today: NaiveDate,
+    /// Number of days to cover, including today.
+    pub days: u32,
+}
+
+/// Use case for loading daily watch time, study streaks and per-course velocity.
+pub struct LoadStudyHistoryUseCase {
+    course_repo: Arc<dyn CourseRepository>,
+    video_repo: Arc<dyn VideoRepository>,
+    session_repo: Arc<dyn WatchSessionRepository>,
+}
+
+impl LoadStudyHistoryUseCase {
+    pub fn new(
+        course_repo: Arc<dyn CourseRepository>,
+        video_repo: Arc<dyn VideoRepository>,
+        session_repo: Arc<dyn WatchSessionRepository>,
+    ) -> Self {
+        Self { course_repo, video_repo, session_repo }
+    }
+
+    /// Loads the history. Sessions are attributed to the local day they started on.
+    pub fn execute(&self, input: LoadStudyHistoryInput) -> Result<StudyHistory, RepositoryError> {
+        let from = history_start(input.today, input.days);
+        let mut seconds_by_day: HashMap<NaiveDate, u64> = HashMap::new();
+        for session in self.session_repo.find_since(start_of_day(from))? {
+            let day = session.started_at.with_timezone(&Local).date_naive();
+            *seconds_by_day.entry(day).or_default() += u64::from(session.played_secs);
+        }
+
+        let velocity_since = start_of_day(history_start(input.today, VELOCITY_WINDOW_DAYS));
+        let weeks = f64::from(VELOCITY_WINDOW_DAYS) / 7.0;
+        let mut course_velocity = Vec::new();
+        for course in self.course_repo.find_all()? {
+            let watched_secs: u64 = self
+                .session_repo
+                .find_by_course_since(course.id(), velocity_since)?
+                .iter()
+                .map(|s| u64::from(s.played_secs))
+                .sum();
+            let remaining_secs: u64 = self
+                .video_repo
+                .find_by_course(course.id())?
+                .iter()
+                .filter(|v| !v.is_completed())
+                .map(|v| u64::from(v.duration_secs()))
+                .sum();
+            course_velocity.push(CourseVelocity {
+                course_id: *course.id(),
+                course_name: course.name().to_string(),
+                minutes_per_week: watched_secs as f64 / 60.0 / weeks,
+                remaining_minutes: remaining_secs.div_ceil(60),
+            });
+        }
+
+        Ok(StudyHistory::new(from, input.today, &seconds_by_day, course_velocity))
+    }
+}
+
+/// Local midnight of `date`, as a UTC instant.
+fn start_of_day(date: NaiveDate) -> DateTime<Utc> {
+    date.and_time(chrono::NaiveTime::MIN)
+        .and_local_timezone(Local)
+        .earliest()
+        .map(|at| at.with_timezone(&Utc))
+        .unwrap_or_else(|| date.and_time(chrono::NaiveTime::MIN).and_utc())
+}
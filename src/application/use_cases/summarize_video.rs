@@ -4,17 +4,17 @@
 //! - Loads the video from the repository
 //! - Uses cached summary/transcript when available
 //! - Fetches transcript from a provider when missing or forced
-//! - Generates summary with the LLM and persists it
+//! - Generates summary, key points and key terms with the LLM and persists them
 
 use std::sync::Arc;
 
 use crate::domain::{
     ports::{
         LLMError, RepositoryError, SummarizerAI, TranscriptError, TranscriptProvider,
-        VideoRepository,
+        VideoRepository, VideoSummary,
     },
     services::TranscriptChunker,
-    value_objects::VideoId,
+    value_objects::{KeyTerm, VideoId},
 };
 
 /// Error type for summary generation.
@@ -42,6 +42,8 @@ pub struct SummarizeVideoInput {
 #[derive(Debug, Clone)]
 pub struct SummarizeVideoOutput {
     pub summary: String,
+    pub key_points: Vec<String>,
+    pub key_terms: Vec<KeyTerm>,
     pub transcript_used: String,
     pub cached: bool,
 }
@@ -78,6 +80,8 @@ impl SummarizeVideoUseCase {
             let transcript = video.transcript().unwrap_or_default().to_string();
             return Ok(SummarizeVideoOutput {
                 summary: summary.to_string(),
+                key_points: video.key_points().to_vec(),
+                key_terms: video.key_terms().to_vec(),
                 transcript_used: transcript,
                 cached: true,
             });
@@ -102,16 +106,11 @@ impl SummarizeVideoUseCase {
 
         let chunker = TranscriptChunker::new();
 
-        if chunker.chunk_count(&transcript) <= 1 {
-            let summary = self
-                .llm
+        let result = if chunker.chunk_count(&transcript) <= 1 {
+            self.llm
                 .summarize_transcript(&transcript, video.title())
                 .await
-                .map_err(SummarizeVideoError::from)?;
-
-            self.video_repo.update_summary(&input.video_id, Some(&summary))?;
-
-            Ok(SummarizeVideoOutput { summary, transcript_used: transcript, cached: false })
+                .map_err(SummarizeVideoError::from)?
         } else {
             let chunks = chunker.chunk(&transcript);
             let total = chunks.len();
@@ -128,21 +127,41 @@ impl SummarizeVideoUseCase {
                     "--- Part {} of {} ---\n{}",
                     i + 1,
                     total,
-                    part_summary
+                    part_notes(&part_summary)
                 ));
             }
 
             let merged_transcript = part_summaries.join("\n\n");
 
-            let summary = self
-                .llm
+            self.llm
                 .summarize_transcript(&merged_transcript, video.title())
                 .await
-                .map_err(SummarizeVideoError::from)?;
+                .map_err(SummarizeVideoError::from)?
+        };
 
-            self.video_repo.update_summary(&input.video_id, Some(&summary))?;
+        let VideoSummary { summary, key_points, key_terms } = result;
+        self.video_repo.update_summary(&input.video_id, Some(&summary))?;
+        self.video_repo.update_key_content(&input.video_id, &key_points, &key_terms)?;
+
+        Ok(SummarizeVideoOutput {
+            summary,
+            key_points,
+            key_terms,
+            transcript_used: transcript,
+            cached: false,
+        })
+    }
+}
 
-            Ok(SummarizeVideoOutput { summary, transcript_used: transcript, cached: false })
-        }
+/// Flattens a part summary into plain notes for the final merge pass.
+fn part_notes(part: &VideoSummary) -> String {
+    let mut notes = part.summary.clone();
+    for point in &part.key_points {
+        notes.push_str("\n- ");
+        notes.push_str(point);
+    }
+    for term in &part.key_terms {
+        notes.push_str(&format!("\n- {}: {}", term.term, term.definition));
     }
+    notes
 }
@@ -0,0 +1,380 @@
+//! Sync Playlist Use Case
+//!
+//! Orchestrates: Fetch -> Diff by YouTube ID -> Place new videos -> Reorder -> Persist
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use crate::domain::{
+    entities::{Module, Video},
+    ports::{
+        CourseRepository, FetchError, ModuleRepository, ModuleTitleGenerator, PlaylistFetcher,
+        RawVideoMetadata, RepositoryError, SearchEntry, SearchRepository, VideoRepository,
+    },
+    services::{BoundaryDetector, TitleSanitizer},
+    value_objects::{CourseId, ModuleId, VideoId, VideoSource, YouTubeVideoId},
+};
+
+/// Error type for playlist sync.
+#[derive(Debug, thiserror::Error)]
+pub enum SyncPlaylistError {
+    #[error("Course not found")]
+    CourseNotFound,
+    #[error("Course is not backed by a YouTube playlist")]
+    NotAPlaylist,
+    #[error(transparent)]
+    FetchFailed(#[from] FetchError),
+    #[error("Invalid video from playlist: {0}")]
+    InvalidVideo(String),
+    #[error(transparent)]
+    Repository(#[from] RepositoryError),
+}
+
+/// Input for the sync playlist use case.
+#[derive(Debug, Clone)]
+pub struct SyncPlaylistInput {
+    pub course_id: CourseId,
+}
+
+/// What a sync changed.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SyncPlaylistReport {
+    /// Titles of videos added to the course.
+    pub added: Vec<String>,
+    /// Titles of videos no longer in the playlist, now flagged unavailable.
+    pub removed: Vec<String>,
+    /// Titles of previously unavailable videos that are back in the playlist.
+    pub restored: Vec<String>,
+    /// Existing videos whose module position changed.
+    pub reordered: usize,
+    /// Modules created to hold new videos.
+    pub modules_created: usize,
+    /// Existing modules whose position in the course changed.
+    pub modules_reordered: usize,
+}
+
+impl SyncPlaylistReport {
+    /// True when the course already matched the playlist.
+    pub fn is_unchanged(&self) -> bool {
+        self.added.is_empty()
+            && self.removed.is_empty()
+            && self.restored.is_empty()
+            && self.reordered == 0
+            && self.modules_reordered == 0
+    }
+}
+
+/// Use case for merging upstream playlist changes into an existing course.
+///
+/// Completion, transcripts, summaries, notes and exams stay attached to their videos:
+/// existing videos are never deleted or recreated, only moved, reordered or flagged.
+pub struct SyncPlaylistUseCase {
+    fetcher: Arc<dyn PlaylistFetcher>,
+    course_repo: Arc<dyn CourseRepository>,
+    module_repo: Arc<dyn ModuleRepository>,
+    video_repo: Arc<dyn VideoRepository>,
+    search_repo: Arc<dyn SearchRepository>,
+    sanitizer: TitleSanitizer,
+    boundary_batch_size: usize,
+    module_target_minutes: u32,
+    title_generator: Option<Arc<dyn ModuleTitleGenerator>>,
+}
+
+impl SyncPlaylistUseCase {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        fetcher: Arc<dyn PlaylistFetcher>,
+        course_repo: Arc<dyn CourseRepository>,
+        module_repo: Arc<dyn ModuleRepository>,
+        video_repo: Arc<dyn VideoRepository>,
+        search_repo: Arc<dyn SearchRepository>,
+        title_generator: Option<Arc<dyn ModuleTitleGenerator>>,
+        boundary_batch_size: usize,
+        module_target_minutes: u32,
+    ) -> Self {
+        Self {
+            fetcher,
+            course_repo,
+            module_repo,
+            video_repo,
+            search_repo,
+            sanitizer: TitleSanitizer::new(),
+            boundary_batch_size,
+            module_target_minutes,
+            title_generator,
+        }
+    }
+
+    /// Fetches the playlist again and merges it into the course.
+    pub async fn execute(
+        &self,
+        input: SyncPlaylistInput,
+    ) -> Result<SyncPlaylistReport, SyncPlaylistError> {
+        let course = self
+            .course_repo
+            .find_by_id(&input.course_id)?
+            .ok_or(SyncPlaylistError::CourseNotFound)?;
+        let playlist_url =
+            course.source().playlist_url().ok_or(SyncPlaylistError::NotAPlaylist)?.clone();
+
+        // 1. Fetch upstream, keeping the first occurrence of duplicated entries
+        let mut seen = HashSet::new();
+        let upstream: Vec<RawVideoMetadata> = self
+            .fetcher
+            .fetch_playlist(&playlist_url)
+            .await?
+            .into_iter()
+            .filter(|raw| seen.insert(raw.youtube_id.clone()))
+            .collect();
+        // An empty response is far more likely a fetch problem than a deleted playlist;
+        // flagging every video unavailable would be the wrong call.
+        if upstream.is_empty() {
+            return Err(FetchError::NotFound("Playlist is empty".to_string()).into());
+        }
+
+        let mut modules = self.module_repo.find_by_course(course.id())?;
+        modules.sort_by_key(|m| m.sort_order());
+        let mut videos = self.video_repo.find_by_course(course.id())?;
+        let before: HashMap<VideoId, (ModuleId, u32)> =
+            videos.iter().map(|v| (*v.id(), (*v.module_id(), v.sort_order()))).collect();
+        let mut report = SyncPlaylistReport::default();
+
+        // 2. Diff by YouTube ID
+        let stored: HashMap<String, usize> = videos
+            .iter()
+            .enumerate()
+            .filter_map(|(i, v)| v.youtube_id().map(|id| (id.as_str().to_string(), i)))
+            .collect();
+        let upstream_ids: HashSet<&str> = upstream.iter().map(|r| r.youtube_id.as_str()).collect();
+
+        let mut flagged = HashSet::new();
+        for video in &mut videos {
+            let listed = video.youtube_id().is_some_and(|id| upstream_ids.contains(id.as_str()));
+            if listed && !video.is_available() {
+                video.mark_available();
+                report.restored.push(video.title().to_string());
+            } else if !listed && video.is_available() {
+                video.mark_unavailable();
+                report.removed.push(video.title().to_string());
+            } else {
+                continue;
+            }
+            flagged.insert(*video.id());
+        }
+
+        // 3. Place new videos next to their closest existing neighbour in playlist order
+        let mut upstream_rank: HashMap<VideoId, usize> = HashMap::new();
+        let slots: Vec<Option<usize>> =
+            upstream.iter().map(|raw| stored.get(&raw.youtube_id).copied()).collect();
+        // Chapter lessons split from one upstream video all take its rank.
+        let rank_by_id: HashMap<&str, usize> = upstream
+            .iter()
+            .enumerate()
+            .map(|(rank, raw)| (raw.youtube_id.as_str(), rank))
+            .collect();
+        for video in &videos {
+            if let Some(&rank) = video.youtube_id().and_then(|id| rank_by_id.get(id.as_str())) {
+                upstream_rank.insert(*video.id(), rank);
+            }
+        }
+
+        let last_known = slots.iter().rposition(Option::is_some);
+        let trailing_start = last_known.map_or(0, |last| last + 1);
+        let mut new_videos = Vec::new();
+        let mut new_modules = Vec::new();
+        let mut preceding_module = slots.iter().flatten().next().map(|&i| *videos[i].module_id());
+
+        for (rank, raw) in upstream.iter().enumerate() {
+            if let Some(i) = slots[rank] {
+                preceding_module = Some(*videos[i].module_id());
+                continue;
+            }
+            if rank >= trailing_start {
+                break;
+            }
+            let Some(module_id) = preceding_module else {
+                break;
+            };
+            let video = self.new_video(raw, module_id)?;
+            upstream_rank.insert(*video.id(), rank);
+            new_videos.push(video);
+        }
+
+        // Trailing additions may start new modules: group them together with the
+        // last module's current videos so boundaries line up with the existing structure.
+        if trailing_start < upstream.len() {
+            let last_module =
+                last_known.and_then(|last| slots[last]).map(|i| *videos[i].module_id());
+            let anchor: Vec<&Video> = match last_module {
+                Some(module_id) => {
+                    let mut anchor: Vec<&Video> = videos
+                        .iter()
+                        .filter(|v| {
+                            *v.module_id() == module_id && upstream_rank.contains_key(v.id())
+                        })
+                        .collect();
+                    anchor.sort_by_key(|v| upstream_rank[v.id()]);
+                    anchor
+                },
+                None => Vec::new(),
+            };
+            let trailing = &upstream[trailing_start..];
+            let titles: Vec<&str> = anchor
+                .iter()
+                .map(|v| v.title())
+                .chain(trailing.iter().map(|raw| raw.title.as_str()))
+                .collect();
+            let durations: Vec<u32> = anchor
+                .iter()
+                .map(|v| v.duration_secs())
+                .chain(trailing.iter().map(|raw| raw.duration_secs))
+                .collect();
+            let detector = BoundaryDetector::with_batch_size(self.boundary_batch_size)
+                .with_target_minutes(self.module_target_minutes);
+
+            let mut next_order = modules.last().map_or(0, |m| m.sort_order() + 1);
+            for group in detector.group_by_titles_and_durations(&titles, &durations) {
+                let joins_anchor = group.first().is_some_and(|&i| i < anchor.len());
+                let module_id = match last_module {
+                    Some(id) if joins_anchor => id,
+                    _ => {
+                        let group_titles: Vec<String> = group
+                            .iter()
+                            .filter(|&&i| i >= anchor.len())
+                            .map(|&i| trailing[i - anchor.len()].title.clone())
+                            .collect();
+                        let title = crate::application::generate_module_title(
+                            self.title_generator.as_ref(),
+                            &group_titles,
+                            course.name(),
+                            next_order as usize,
+                        )
+                        .await;
+                        let module = Module::new(ModuleId::new(), *course.id(), title, next_order);
+                        next_order += 1;
+                        let id = *module.id();
+                        new_modules.push(module);
+                        id
+                    },
+                };
+                for &i in group.iter().filter(|&&i| i >= anchor.len()) {
+                    let rank = trailing_start + i - anchor.len();
+                    let video = self.new_video(&upstream[rank], module_id)?;
+                    upstream_rank.insert(*video.id(), rank);
+                    new_videos.push(video);
+                }
+            }
+        }
+
+        // 4. Reorder each module by playlist position; unavailable videos keep their
+        //    relative order at the end of their module.
+        report.added = new_videos.iter().map(|v| v.title().to_string()).collect();
+        let new_ids: HashSet<VideoId> = new_videos.iter().map(|v| *v.id()).collect();
+        videos.extend(new_videos);
+
+        let mut by_module: HashMap<ModuleId, Vec<usize>> = HashMap::new();
+        for (i, video) in videos.iter().enumerate() {
+            by_module.entry(*video.module_id()).or_default().push(i);
+        }
+        for indices in by_module.values_mut() {
+            indices.sort_by_key(|&i| {
+                let video = &videos[i];
+                match upstream_rank.get(video.id()) {
+                    Some(&rank) => (0, rank, video.clip().map_or(0, |clip| clip.start_secs())),
+                    None => (1, 0, video.sort_order()),
+                }
+            });
+            for (order, &i) in indices.iter().enumerate() {
+                videos[i].set_sort_order(order as u32);
+            }
+        }
+
+        // 5. Reorder modules by the earliest playlist position of their videos. Modules
+        //    without a listed video stay right after the module they currently follow.
+        let mut first_rank: HashMap<ModuleId, usize> = HashMap::new();
+        for video in &videos {
+            if let Some(&rank) = upstream_rank.get(video.id()) {
+                first_rank
+                    .entry(*video.module_id())
+                    .and_modify(|first| *first = (*first).min(rank))
+                    .or_insert(rank);
+            }
+        }
+        report.modules_created = new_modules.len();
+        let created: HashSet<ModuleId> = new_modules.iter().map(|m| *m.id()).collect();
+        modules.extend(new_modules);
+        let mut carried = 0;
+        let mut keyed: Vec<(usize, usize, Module)> = modules
+            .into_iter()
+            .enumerate()
+            .map(|(position, module)| {
+                carried = first_rank.get(module.id()).copied().unwrap_or(carried);
+                (carried, position, module)
+            })
+            .collect();
+        keyed.sort_by_key(|&(rank, position, _)| (rank, position));
+        let mut modules_to_save = Vec::new();
+        for (order, (_, _, mut module)) in keyed.into_iter().enumerate() {
+            let is_new = created.contains(module.id());
+            if !is_new && module.sort_order() == order as u32 {
+                continue;
+            }
+            if !is_new {
+                report.modules_reordered += 1;
+            }
+            module.set_sort_order(order as u32);
+            modules_to_save.push(module);
+        }
+
+        let changed: Vec<Video> = videos
+            .into_iter()
+            .filter(|v| match before.get(v.id()) {
+                Some(&(module_id, order)) => {
+                    let moved = module_id != *v.module_id() || order != v.sort_order();
+                    if moved {
+                        report.reordered += 1;
+                    }
+                    moved || flagged.contains(v.id())
+                },
+                None => true,
+            })
+            .collect();
+
+        // 6. Persist modules and videos in one transaction, then index
+        self.course_repo.save_with_contents(&course, &modules_to_save, &changed)?;
+
+        let course_id = course.id().as_uuid().to_string();
+        let entries: Vec<SearchEntry> = changed
+            .iter()
+            .filter(|v| new_ids.contains(v.id()))
+            .map(|v| SearchEntry {
+                entity_type: "video".to_string(),
+                entity_id: v.id().as_uuid().to_string(),
+                title: v.title().to_string(),
+                content: v.description().unwrap_or_default().to_string(),
+                course_id: course_id.clone(),
+            })
+            .collect();
+        self.search_repo.index_batch(&entries)?;
+
+        Ok(report)
+    }
+
+    fn new_video(
+        &self,
+        raw: &RawVideoMetadata,
+        module_id: ModuleId,
+    ) -> Result<Video, SyncPlaylistError> {
+        let youtube_id = YouTubeVideoId::new(&raw.youtube_id)
+            .map_err(|e| SyncPlaylistError::InvalidVideo(e.to_string()))?;
+        Ok(Video::with_description(
+            VideoId::new(),
+            module_id,
+            VideoSource::youtube(youtube_id),
+            self.sanitizer.sanitize(&raw.title),
+            raw.description.clone(),
+            raw.duration_secs,
+            0,
+        ))
+    }
+}
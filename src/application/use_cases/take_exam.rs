@@ -1,6 +1,7 @@
 //! Take Exam Use Case
 //!
-//! Generates MCQ, scores answers, and updates video completion.
+//! Generates MCQ, scores answers, and updates video completion. Scoring needs no AI, so
+//! [`GradeExamUseCase`] offers it on its own.
 
 use std::sync::Arc;
 
@@ -51,6 +52,7 @@ pub struct TakeExamUseCase {
     examiner: Arc<dyn ExaminerAI>,
     video_repo: Arc<dyn VideoRepository>,
     exam_repo: Arc<dyn ExamRepository>,
+    grader: GradeExamUseCase,
 }
 
 impl TakeExamUseCase {
@@ -59,7 +61,8 @@ impl TakeExamUseCase {
         video_repo: Arc<dyn VideoRepository>,
         exam_repo: Arc<dyn ExamRepository>,
     ) -> Self {
-        Self { examiner, video_repo, exam_repo }
+        let grader = GradeExamUseCase::new(video_repo.clone(), exam_repo.clone());
+        Self { examiner, video_repo, exam_repo, grader }
     }
 
     /// Generates an exam for a video.
@@ -96,6 +99,28 @@ impl TakeExamUseCase {
         Ok(GenerateExamOutput { exam_id, questions })
     }
 
+    /// Retrieves an exam and its questions.
+    pub fn get_exam(&self, exam_id: &ExamId) -> Result<(Exam, Vec<MCQuestion>), ExamError> {
+        self.grader.get_exam(exam_id)
+    }
+
+    /// Submits exam answers and calculates score.
+    pub fn submit(&self, input: SubmitExamInput) -> Result<SubmitExamOutput, ExamError> {
+        self.grader.submit(input)
+    }
+}
+
+/// Use case for scoring saved exams, without an AI provider.
+pub struct GradeExamUseCase {
+    video_repo: Arc<dyn VideoRepository>,
+    exam_repo: Arc<dyn ExamRepository>,
+}
+
+impl GradeExamUseCase {
+    pub fn new(video_repo: Arc<dyn VideoRepository>, exam_repo: Arc<dyn ExamRepository>) -> Self {
+        Self { video_repo, exam_repo }
+    }
+
     /// Retrieves an exam and its questions.
     pub fn get_exam(&self, exam_id: &ExamId) -> Result<(Exam, Vec<MCQuestion>), ExamError> {
         let exam = self
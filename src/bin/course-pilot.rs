@@ -0,0 +1,868 @@
+//! Headless Course Pilot CLI
+//!
+//! Drives the application use cases without GTK so imports, summaries and exams
+//! can be scripted from cron jobs or servers. Every command accepts `--json` for
+//! machine-readable output; failures map to stable exit codes (see `exit_codes`).
+
+use std::collections::HashMap;
+use std::io::Read;
+use std::process::ExitCode;
+use std::str::FromStr;
+
+use chrono::NaiveTime;
+use serde_json::json;
+
+use course_pilot::application::use_cases::{
+    CourseManifest, DeleteNoteInput, ExamError, ExportManifestInput, ExportScheduleInput,
+    GenerateExamInput, IngestError, IngestLocalError, IngestLocalInput, IngestManifestInput,
+    IngestPlaylistInput, LoadNoteInput, ManifestError, ManifestFormat, NotesError,
+    RelinkLocalError, RelinkLocalInput, RescanLocalError, RescanLocalInput, SaveNoteInput,
+    ScheduleError, SubmitExamInput, SummarizeVideoError, SummarizeVideoInput, SyncPlaylistError,
+    SyncPlaylistInput,
+};
+use course_pilot::application::{AppConfig, AppContext, ServiceFactory};
+use course_pilot::domain::ports::{FetchError, RepositoryError};
+use course_pilot::domain::value_objects::{CourseId, ExamDifficulty, ExamId, VideoId};
+
+/// Process exit codes. Stable so scripts can branch on them.
+mod exit_codes {
+    pub const FAILURE: u8 = 1;
+    pub const USAGE: u8 = 2;
+    pub const NOT_FOUND: u8 = 3;
+    pub const INVALID_INPUT: u8 = 4;
+    pub const ALREADY_EXISTS: u8 = 5;
+    pub const SOURCE_UNAVAILABLE: u8 = 6;
+    pub const AI_FAILED: u8 = 7;
+    pub const STORAGE: u8 = 8;
+    pub const AI_NOT_CONFIGURED: u8 = 9;
+}
+
+const USAGE: &str = "\
+Usage: course-pilot <command> [options] [--json]
+
+Commands:
+  ingest playlist <url> [--name <course name>] [--split-chapters]
+  ingest local <root folder> [--name <course name>] [--split-chapters]
+  ingest manifest <file.toml|file.json>   (relative lesson paths resolve against its folder)
+  manifest export <course id> [--format toml|json] [--out <file>]   (writes stdout without --out)
+  sync <course id>   (merges upstream playlist changes into the course)
+  rescan <course id>   (picks up files added, moved or deleted in a local course folder)
+  relink <course id> <new folder>   (finds the files of a local course after its folder moved)
+  summarize <video id> [--force]
+  exam generate <video id> [--questions <n>] [--difficulty easy|medium|hard]
+  exam submit <exam id> --answers <i,j,k>
+  notes get <video id>
+  notes save <video id> [--content <text>]   (reads stdin when --content is omitted)
+  notes delete <video id>
+  schedule export <course id> [--at <HH:MM>] [--out <file.ics>]   (writes stdout without --out)
+  dashboard
+
+Environment: DATABASE_URL, GEMINI_API_KEY, LLM_MODEL, LLM_PROVIDER, LLM_BASE_URL, LLM_API_KEY
+(same as the desktop app).";
+
+/// Flags that never take a value.
+const SWITCHES: &[&str] = &["json", "force", "help", "split-chapters"];
+
+/// A failure that terminates the CLI with a specific exit code.
+#[derive(Debug)]
+struct CliError {
+    code: u8,
+    message: String,
+}
+
+impl CliError {
+    fn new(code: u8, message: impl Into<String>) -> Self {
+        Self { code, message: message.into() }
+    }
+
+    fn usage(message: impl Into<String>) -> Self {
+        Self::new(exit_codes::USAGE, message)
+    }
+}
+
+fn repository_exit_code(err: &RepositoryError) -> u8 {
+    match err {
+        RepositoryError::NotFound { .. } => exit_codes::NOT_FOUND,
+        RepositoryError::Conflict { .. } => exit_codes::ALREADY_EXISTS,
+        RepositoryError::Database(_) | RepositoryError::BatchFailed { .. } => exit_codes::STORAGE,
+    }
+}
+
+impl From<IngestError> for CliError {
+    fn from(err: IngestError) -> Self {
+        let code = match &err {
+            IngestError::InvalidUrl(_) => exit_codes::INVALID_INPUT,
+            IngestError::FetchFailed(FetchError::NotFound(_)) => exit_codes::NOT_FOUND,
+            IngestError::FetchFailed(_) => exit_codes::SOURCE_UNAVAILABLE,
+            IngestError::PersistFailed(_) => exit_codes::STORAGE,
+            IngestError::AlreadyExists(_) => exit_codes::ALREADY_EXISTS,
+            IngestError::Cancelled => exit_codes::FAILURE,
+        };
+        Self::new(code, err.to_string())
+    }
+}
+
+impl From<IngestLocalError> for CliError {
+    fn from(err: IngestLocalError) -> Self {
+        let code = match &err {
+            IngestLocalError::InvalidRoot(_) => exit_codes::INVALID_INPUT,
+            IngestLocalError::ScanFailed(_) => exit_codes::SOURCE_UNAVAILABLE,
+            IngestLocalError::PersistFailed(_) => exit_codes::STORAGE,
+            IngestLocalError::AlreadyExists(_) => exit_codes::ALREADY_EXISTS,
+            IngestLocalError::Cancelled => exit_codes::FAILURE,
+        };
+        Self::new(code, err.to_string())
+    }
+}
+
+impl From<ManifestError> for CliError {
+    fn from(err: ManifestError) -> Self {
+        let code = match &err {
+            ManifestError::UnsupportedFormat(_)
+            | ManifestError::Parse(_)
+            | ManifestError::Invalid(_) => exit_codes::INVALID_INPUT,
+            ManifestError::Render(_) => exit_codes::FAILURE,
+            ManifestError::CourseNotFound => exit_codes::NOT_FOUND,
+            ManifestError::AlreadyExists(_) => exit_codes::ALREADY_EXISTS,
+            ManifestError::FetchFailed(FetchError::NotFound(_)) => exit_codes::NOT_FOUND,
+            ManifestError::FetchFailed(_) | ManifestError::ScanFailed(_) => {
+                exit_codes::SOURCE_UNAVAILABLE
+            },
+            ManifestError::Repository(e) => repository_exit_code(e),
+        };
+        Self::new(code, err.to_string())
+    }
+}
+
+impl From<SyncPlaylistError> for CliError {
+    fn from(err: SyncPlaylistError) -> Self {
+        let code = match &err {
+            SyncPlaylistError::CourseNotFound => exit_codes::NOT_FOUND,
+            SyncPlaylistError::NotAPlaylist => exit_codes::INVALID_INPUT,
+            SyncPlaylistError::FetchFailed(FetchError::NotFound(_)) => exit_codes::NOT_FOUND,
+            SyncPlaylistError::FetchFailed(_) | SyncPlaylistError::InvalidVideo(_) => {
+                exit_codes::SOURCE_UNAVAILABLE
+            },
+            SyncPlaylistError::Repository(e) => repository_exit_code(e),
+        };
+        Self::new(code, err.to_string())
+    }
+}
+
+impl From<RelinkLocalError> for CliError {
+    fn from(err: RelinkLocalError) -> Self {
+        let code = match &err {
+            RelinkLocalError::CourseNotFound => exit_codes::NOT_FOUND,
+            RelinkLocalError::NotALocalCourse
+            | RelinkLocalError::InvalidRoot(_)
+            | RelinkLocalError::InvalidMedia(_) => exit_codes::INVALID_INPUT,
+            RelinkLocalError::ScanFailed(_) => exit_codes::SOURCE_UNAVAILABLE,
+            RelinkLocalError::Repository(e) => repository_exit_code(e),
+        };
+        Self::new(code, err.to_string())
+    }
+}
+
+impl From<RescanLocalError> for CliError {
+    fn from(err: RescanLocalError) -> Self {
+        let code = match &err {
+            RescanLocalError::CourseNotFound => exit_codes::NOT_FOUND,
+            RescanLocalError::NotALocalCourse | RescanLocalError::InvalidMedia(_) => {
+                exit_codes::INVALID_INPUT
+            },
+            RescanLocalError::RootUnavailable(_) | RescanLocalError::ScanFailed(_) => {
+                exit_codes::SOURCE_UNAVAILABLE
+            },
+            RescanLocalError::Repository(e) => repository_exit_code(e),
+        };
+        Self::new(code, err.to_string())
+    }
+}
+
+impl From<SummarizeVideoError> for CliError {
+    fn from(err: SummarizeVideoError) -> Self {
+        let code = match &err {
+            SummarizeVideoError::VideoNotFound => exit_codes::NOT_FOUND,
+            SummarizeVideoError::Repository(e) => repository_exit_code(e),
+            SummarizeVideoError::Transcript(_) => exit_codes::SOURCE_UNAVAILABLE,
+            SummarizeVideoError::AI(_) => exit_codes::AI_FAILED,
+        };
+        Self::new(code, err.to_string())
+    }
+}
+
+impl From<ExamError> for CliError {
+    fn from(err: ExamError) -> Self {
+        let code = match &err {
+            ExamError::AI(_) => exit_codes::AI_FAILED,
+            ExamError::Repository(e) => repository_exit_code(e),
+        };
+        Self::new(code, err.to_string())
+    }
+}
+
+impl From<NotesError> for CliError {
+    fn from(err: NotesError) -> Self {
+        let code = match &err {
+            NotesError::Repository(e) => repository_exit_code(e),
+        };
+        Self::new(code, err.to_string())
+    }
+}
+
+impl From<ScheduleError> for CliError {
+    fn from(err: ScheduleError) -> Self {
+        let code = match &err {
+            ScheduleError::CourseNotFound | ScheduleError::NotPlanned => exit_codes::NOT_FOUND,
+            ScheduleError::NothingToPlan => exit_codes::INVALID_INPUT,
+            ScheduleError::Repository(e) => repository_exit_code(e),
+        };
+        Self::new(code, err.to_string())
+    }
+}
+
+impl From<RepositoryError> for CliError {
+    fn from(err: RepositoryError) -> Self {
+        Self::new(repository_exit_code(&err), err.to_string())
+    }
+}
+
+/// Parsed command line: positional words plus `--flag [value]` options.
+#[derive(Debug, Default)]
+struct Args {
+    positionals: Vec<String>,
+    options: HashMap<String, String>,
+    switches: Vec<String>,
+}
+
+impl Args {
+    fn parse(raw: impl IntoIterator<Item = String>) -> Result<Self, CliError> {
+        let mut args = Self::default();
+        let mut iter = raw.into_iter();
+        while let Some(arg) = iter.next() {
+            let Some(flag) = arg.strip_prefix("--") else {
+                args.positionals.push(arg);
+                continue;
+            };
+            if let Some((name, value)) = flag.split_once('=') {
+                args.options.insert(name.to_string(), value.to_string());
+            } else if SWITCHES.contains(&flag) {
+                args.switches.push(flag.to_string());
+            } else {
+                let value = iter
+                    .next()
+                    .ok_or_else(|| CliError::usage(format!("--{flag} needs a value")))?;
+                args.options.insert(flag.to_string(), value);
+            }
+        }
+        Ok(args)
+    }
+
+    fn switch(&self, name: &str) -> bool {
+        self.switches.iter().any(|s| s == name)
+    }
+
+    fn option(&self, name: &str) -> Option<&str> {
+        self.options.get(name).map(String::as_str)
+    }
+
+    fn positional(&self, idx: usize, what: &str) -> Result<&str, CliError> {
+        self.positionals
+            .get(idx)
+            .map(String::as_str)
+            .ok_or_else(|| CliError::usage(format!("missing {what}")))
+    }
+}
+
+fn parse_video_id(raw: &str) -> Result<VideoId, CliError> {
+    VideoId::from_str(raw)
+        .map_err(|e| CliError::new(exit_codes::INVALID_INPUT, format!("Invalid video id: {e}")))
+}
+
+fn parse_course_id(raw: &str) -> Result<CourseId, CliError> {
+    CourseId::from_str(raw)
+        .map_err(|e| CliError::new(exit_codes::INVALID_INPUT, format!("Invalid course id: {e}")))
+}
+
+fn parse_exam_id(raw: &str) -> Result<ExamId, CliError> {
+    ExamId::from_str(raw)
+        .map_err(|e| CliError::new(exit_codes::INVALID_INPUT, format!("Invalid exam id: {e}")))
+}
+
+fn ai_not_configured() -> CliError {
+    CliError::new(
+        exit_codes::AI_NOT_CONFIGURED,
+        "No LLM configured. Set GEMINI_API_KEY (or LLM_PROVIDER=openai with LLM_BASE_URL) \
+         or configure a provider in the desktop app settings.",
+    )
+}
+
+/// Result of a command: the JSON document and its human-readable rendering.
+struct Report {
+    json: serde_json::Value,
+    text: String,
+}
+
+fn run(args: &Args, ctx: &AppContext, rt: &tokio::runtime::Runtime) -> Result<Report, CliError> {
+    let command = args.positional(0, "command")?;
+    match command {
+        "ingest" => match args.positional(1, "ingest source (playlist|local)")? {
+            "playlist" => {
+                let url = args.positional(2, "playlist URL")?;
+                let output = rt.block_on(ServiceFactory::ingest_playlist(ctx).execute(
+                    IngestPlaylistInput {
+                        playlist_url: url.to_string(),
+                        course_name: args.option("name").map(str::to_string),
+                        split_chapters: args.switch("split-chapters"),
+                    },
+                ))?;
+                Ok(Report {
+                    json: json!({
+                        "course_id": output.course_id.to_string(),
+                        "modules": output.modules_count,
+                        "videos": output.videos_count,
+                    }),
+                    text: format!(
+                        "Imported course {} ({} modules, {} videos)",
+                        output.course_id, output.modules_count, output.videos_count
+                    ),
+                })
+            },
+            "local" => {
+                let root = args.positional(2, "root folder")?;
+                let output =
+                    rt.block_on(ServiceFactory::ingest_local(ctx).execute(IngestLocalInput {
+                        root_path: root.to_string(),
+                        course_name: args.option("name").map(str::to_string),
+                        split_chapters: args.switch("split-chapters"),
+                    }))?;
+                Ok(Report {
+                    json: json!({
+                        "course_id": output.course_id.to_string(),
+                        "modules": output.modules_count,
+                        "videos": output.videos_count,
+                    }),
+                    text: format!(
+                        "Imported course {} ({} modules, {} videos)",
+                        output.course_id, output.modules_count, output.videos_count
+                    ),
+                })
+            },
+            "manifest" => {
+                let path = args.positional(2, "manifest file")?;
+                let format = ManifestFormat::from_path(path)?;
+                let text = std::fs::read_to_string(path).map_err(|e| {
+                    CliError::new(exit_codes::INVALID_INPUT, format!("{path}: {e}"))
+                })?;
+                let base_dir = std::path::absolute(path)
+                    .ok()
+                    .and_then(|file| file.parent().map(|dir| dir.to_string_lossy().to_string()))
+                    .ok_or_else(|| {
+                        CliError::new(
+                            exit_codes::INVALID_INPUT,
+                            format!("{path}: no parent folder"),
+                        )
+                    })?;
+                let output = rt.block_on(ServiceFactory::ingest_manifest(ctx).execute(
+                    IngestManifestInput {
+                        manifest: CourseManifest::parse(&text, format)?,
+                        base_dir,
+                    },
+                ))?;
+                Ok(Report {
+                    json: json!({
+                        "course_id": output.course_id.to_string(),
+                        "modules": output.modules_count,
+                        "videos": output.videos_count,
+                    }),
+                    text: format!(
+                        "Imported course {} ({} modules, {} videos)",
+                        output.course_id, output.modules_count, output.videos_count
+                    ),
+                })
+            },
+            other => Err(CliError::usage(format!("unknown ingest source: {other}"))),
+        },
+        "sync" => {
+            let course_id = parse_course_id(args.positional(1, "course id")?)?;
+            let report = rt.block_on(
+                ServiceFactory::sync_playlist(ctx).execute(SyncPlaylistInput { course_id }),
+            )?;
+            let text = if report.is_unchanged() {
+                "Course is already up to date".to_string()
+            } else {
+                format!(
+                    "Synced course {}: {} added, {} unavailable, {} restored, {} moved, \
+                     {} new modules, {} modules moved",
+                    course_id,
+                    report.added.len(),
+                    report.removed.len(),
+                    report.restored.len(),
+                    report.reordered,
+                    report.modules_created,
+                    report.modules_reordered
+                )
+            };
+            Ok(Report {
+                json: json!({
+                    "course_id": course_id.to_string(),
+                    "added": report.added,
+                    "removed": report.removed,
+                    "restored": report.restored,
+                    "reordered": report.reordered,
+                    "modules_created": report.modules_created,
+                    "modules_reordered": report.modules_reordered,
+                }),
+                text,
+            })
+        },
+        "rescan" => {
+            let course_id = parse_course_id(args.positional(1, "course id")?)?;
+            let report = rt.block_on(
+                ServiceFactory::rescan_local(ctx).execute(RescanLocalInput { course_id }),
+            )?;
+            let text = if report.is_unchanged() {
+                "Course is already up to date".to_string()
+            } else {
+                format!(
+                    "Rescanned course {}: {} added, {} moved, {} missing, {} restored, \
+                     {} new modules",
+                    course_id,
+                    report.added.len(),
+                    report.moved.len(),
+                    report.missing.len(),
+                    report.restored.len(),
+                    report.modules_created
+                )
+            };
+            Ok(Report {
+                json: json!({
+                    "course_id": course_id.to_string(),
+                    "added": report.added,
+                    "moved": report.moved,
+                    "missing": report.missing,
+                    "restored": report.restored,
+                    "modules_created": report.modules_created,
+                }),
+                text,
+            })
+        },
+        "relink" => {
+            let course_id = parse_course_id(args.positional(1, "course id")?)?;
+            let new_root = args.positional(2, "new folder")?.to_string();
+            let report = rt.block_on(
+                ServiceFactory::relink_local(ctx).execute(RelinkLocalInput { course_id, new_root }),
+            )?;
+            let text = format!(
+                "Relinked course {} to {}: {} videos found ({} by content), {} still missing",
+                course_id,
+                report.root,
+                report.relinked.len(),
+                report.matched_by_content,
+                report.still_missing.len()
+            );
+            Ok(Report {
+                json: json!({
+                    "course_id": course_id.to_string(),
+                    "root": report.root,
+                    "relinked": report.relinked,
+                    "matched_by_content": report.matched_by_content,
+                    "still_missing": report.still_missing,
+                }),
+                text,
+            })
+        },
+        "summarize" => {
+            let video_id = parse_video_id(args.positional(1, "video id")?)?;
+            let use_case = ServiceFactory::summarize_video(ctx).ok_or_else(ai_not_configured)?;
+            let output =
+                rt.block_on(use_case.execute(SummarizeVideoInput {
+                    video_id,
+                    force_refresh: args.switch("force"),
+                }))?;
+            let mut text = output.summary.clone();
+            if !output.key_points.is_empty() {
+                text.push_str("\n\nKey Points:");
+                for point in &output.key_points {
+                    text.push_str(&format!("\n- {point}"));
+                }
+            }
+            if !output.key_terms.is_empty() {
+                text.push_str("\n\nKey Terms:");
+                for term in &output.key_terms {
+                    text.push_str(&format!("\n- {}: {}", term.term, term.definition));
+                }
+            }
+            Ok(Report {
+                json: json!({
+                    "video_id": video_id.to_string(),
+                    "summary": output.summary,
+                    "key_points": output.key_points,
+                    "key_terms": output.key_terms,
+                    "cached": output.cached,
+                }),
+                text,
+            })
+        },
+        "exam" => match args.positional(1, "exam action (generate|submit)")? {
+            "generate" => {
+                let use_case = ServiceFactory::take_exam(ctx).ok_or_else(ai_not_configured)?;
+                let video_id = parse_video_id(args.positional(2, "video id")?)?;
+                let num_questions = match args.option("questions") {
+                    Some(raw) => raw.parse::<u8>().map_err(|_| {
+                        CliError::usage(format!("--questions expects 1-255, got {raw}"))
+                    })?,
+                    None => 5,
+                };
+                let difficulty = match args.option("difficulty") {
+                    Some(raw) => ExamDifficulty::from_str(raw)
+                        .map_err(|e| CliError::new(exit_codes::INVALID_INPUT, e.to_string()))?,
+                    None => ExamDifficulty::default(),
+                };
+                let output = rt.block_on(use_case.generate(GenerateExamInput {
+                    video_id,
+                    num_questions,
+                    difficulty,
+                }))?;
+
+                let mut text = format!("Exam {}\n", output.exam_id);
+                for (q_idx, q) in output.questions.iter().enumerate() {
+                    text.push_str(&format!("\n{}. {}\n", q_idx + 1, q.question));
+                    for (o_idx, option) in q.options.iter().enumerate() {
+                        text.push_str(&format!("   [{o_idx}] {option}\n"));
+                    }
+                }
+                Ok(Report {
+                    json: json!({
+                        "exam_id": output.exam_id.to_string(),
+                        "questions": output.questions,
+                    }),
+                    text: text.trim_end().to_string(),
+                })
+            },
+            "submit" => {
+                let exam_id = parse_exam_id(args.positional(2, "exam id")?)?;
+                let raw = args
+                    .option("answers")
+                    .ok_or_else(|| CliError::usage("--answers is required"))?;
+                let answers = raw
+                    .split(',')
+                    .map(|a| a.trim().parse::<usize>())
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|_| {
+                        CliError::usage(format!("--answers expects indices like 0,2,1: {raw}"))
+                    })?;
+                // Grading is offline, so no AI provider is needed here.
+                let output =
+                    ServiceFactory::grade_exam(ctx).submit(SubmitExamInput { exam_id, answers })?;
+                Ok(Report {
+                    json: json!({
+                        "exam_id": exam_id.to_string(),
+                        "score": output.score,
+                        "passed": output.passed,
+                        "video_marked_complete": output.video_marked_complete,
+                    }),
+                    text: format!(
+                        "Score {:.0}% - {}{}",
+                        output.score * 100.0,
+                        if output.passed { "passed" } else { "not passed" },
+                        if output.video_marked_complete { " (video marked complete)" } else { "" }
+                    ),
+                })
+            },
+            other => Err(CliError::usage(format!("unknown exam action: {other}"))),
+        },
+        "notes" => {
+            let notes = ServiceFactory::notes(ctx);
+            let action = args.positional(1, "notes action (get|save|delete)")?;
+            let video_id = parse_video_id(args.positional(2, "video id")?)?;
+            match action {
+                "get" => {
+                    let note = notes.load_note(LoadNoteInput { video_id })?;
+                    let content = note.map(|n| n.content);
+                    Ok(Report {
+                        json: json!({ "video_id": video_id.to_string(), "content": content }),
+                        text: content.unwrap_or_else(|| "(no note)".to_string()),
+                    })
+                },
+                "save" => {
+                    let content = match args.option("content") {
+                        Some(c) => c.to_string(),
+                        None => {
+                            let mut buf = String::new();
+                            std::io::stdin().read_to_string(&mut buf).map_err(|e| {
+                                CliError::new(exit_codes::FAILURE, format!("stdin: {e}"))
+                            })?;
+                            buf
+                        },
+                    };
+                    let note = notes.save_note(SaveNoteInput { video_id, content })?;
+                    Ok(Report {
+                        json: json!({
+                            "video_id": video_id.to_string(),
+                            "note_id": note.note_id,
+                            "course_id": note.course_id.to_string(),
+                        }),
+                        text: format!("Saved note {}", note.note_id),
+                    })
+                },
+                "delete" => {
+                    notes.delete_note(DeleteNoteInput { video_id })?;
+                    Ok(Report {
+                        json: json!({ "video_id": video_id.to_string(), "deleted": true }),
+                        text: "Note deleted".to_string(),
+                    })
+                },
+                other => Err(CliError::usage(format!("unknown notes action: {other}"))),
+            }
+        },
+        "manifest" => {
+            match args.positional(1, "manifest action (export)")? {
+                "export" => {
+                    let course_id = parse_course_id(args.positional(2, "course id")?)?;
+                    let out = args.option("out");
+                    let format = match (args.option("format"), out) {
+                        (Some(raw), _) => raw.parse()?,
+                        (None, Some(path)) => ManifestFormat::from_path(path)?,
+                        (None, None) => ManifestFormat::Toml,
+                    };
+                    // Local paths are written relative to the file's folder when it holds them.
+                    let base_dir =
+                        out.and_then(|path| std::path::absolute(path).ok()).and_then(|path| {
+                            path.parent().map(|dir| dir.to_string_lossy().to_string())
+                        });
+                    let output = ServiceFactory::export_manifest(ctx)
+                        .execute(ExportManifestInput { course_id, format, base_dir })?;
+                    match out {
+                        Some(path) => {
+                            std::fs::write(path, &output.content).map_err(|e| {
+                                CliError::new(exit_codes::FAILURE, format!("{path}: {e}"))
+                            })?;
+                            Ok(Report {
+                                json: json!({ "course_id": course_id.to_string(), "path": path }),
+                                text: format!("Wrote manifest to {path}"),
+                            })
+                        },
+                        None => Ok(Report {
+                            json: json!({
+                                "course_id": course_id.to_string(),
+                                "file_name": output.file_name,
+                                "manifest": output.content,
+                            }),
+                            text: output.content.trim_end().to_string(),
+                        }),
+                    }
+                },
+                other => Err(CliError::usage(format!("unknown manifest action: {other}"))),
+            }
+        },
+        "schedule" => match args.positional(1, "schedule action (export)")? {
+            "export" => {
+                let course_id = parse_course_id(args.positional(2, "course id")?)?;
+                let start_time = args
+                    .option("at")
+                    .map(|raw| {
+                        NaiveTime::parse_from_str(raw, "%H:%M").map_err(|_| {
+                            CliError::new(
+                                exit_codes::INVALID_INPUT,
+                                format!("Invalid --at time (expected HH:MM): {raw}"),
+                            )
+                        })
+                    })
+                    .transpose()?;
+                let output = ServiceFactory::export_schedule(ctx)
+                    .execute(ExportScheduleInput { course_id, start_time })?;
+                match args.option("out") {
+                    Some(path) => {
+                        std::fs::write(path, &output.content).map_err(|e| {
+                            CliError::new(exit_codes::FAILURE, format!("{path}: {e}"))
+                        })?;
+                        Ok(Report {
+                            json: json!({
+                                "course_id": course_id.to_string(),
+                                "path": path,
+                                "events": output.events,
+                                "cancelled": output.cancelled,
+                            }),
+                            text: format!("Wrote {} sessions to {path}", output.events),
+                        })
+                    },
+                    None => Ok(Report {
+                        json: json!({
+                            "course_id": course_id.to_string(),
+                            "events": output.events,
+                            "cancelled": output.cancelled,
+                            "ics": output.content,
+                        }),
+                        text: output.content.trim_end().to_string(),
+                    }),
+                }
+            },
+            other => Err(CliError::usage(format!("unknown schedule action: {other}"))),
+        },
+        "dashboard" => {
+            let analytics = ServiceFactory::dashboard(ctx).execute()?;
+            let mut courses = Vec::new();
+            let mut text = format!(
+                "Courses: {}  Modules: {}  Lessons: {}\nCompleted: {} of {} ({:.0}%), {} of {} min\nSummary coverage: {:.0}%",
+                analytics.total_courses(),
+                analytics.total_modules(),
+                analytics.total_videos(),
+                analytics.completed_videos(),
+                analytics.total_videos(),
+                analytics.completion_percent(),
+                analytics.completed_duration_minutes(),
+                analytics.total_duration_minutes(),
+                analytics.summary_coverage_percent(),
+            );
+            for course in ctx.course_repo.find_all()? {
+                let videos = ctx.video_repo.find_by_course(course.id())?;
+                let completed = videos.iter().filter(|v| v.is_completed()).count();
+                text.push_str(&format!(
+                    "\n  {}  {}/{}  {}",
+                    course.id(),
+                    completed,
+                    videos.len(),
+                    course.name()
+                ));
+                courses.push(json!({
+                    "course_id": course.id().to_string(),
+                    "name": course.name(),
+                    "videos": videos.len(),
+                    "completed_videos": completed,
+                    "source_type": course.source().source_type(),
+                    "source": course.source().source_ref(),
+                }));
+            }
+            Ok(Report {
+                json: json!({
+                    "total_courses": analytics.total_courses(),
+                    "total_modules": analytics.total_modules(),
+                    "total_videos": analytics.total_videos(),
+                    "completed_videos": analytics.completed_videos(),
+                    "completion_percent": analytics.completion_percent(),
+                    "total_duration_secs": analytics.total_duration_secs(),
+                    "completed_duration_secs": analytics.completed_duration_secs(),
+                    "summary_coverage_percent": analytics.summary_coverage_percent(),
+                    "courses": courses,
+                }),
+                text,
+            })
+        },
+        other => Err(CliError::usage(format!("unknown command: {other}"))),
+    }
+}
+
+fn main() -> ExitCode {
+    dotenvy::dotenv().ok();
+    env_logger::init();
+
+    let args = match Args::parse(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("error: {}\n\n{USAGE}", e.message);
+            return ExitCode::from(e.code);
+        },
+    };
+    if args.switch("help") {
+        println!("{USAGE}");
+        return ExitCode::SUCCESS;
+    }
+    if args.positionals.is_empty() {
+        eprintln!("{USAGE}");
+        return ExitCode::from(exit_codes::USAGE);
+    }
+    let as_json = args.switch("json");
+
+    if rustls::crypto::ring::default_provider().install_default().is_err() {
+        eprintln!("error: failed to install TLS provider");
+        return ExitCode::from(exit_codes::FAILURE);
+    }
+
+    let result = tokio::runtime::Runtime::new()
+        .map_err(|e| CliError::new(exit_codes::FAILURE, format!("Tokio runtime: {e}")))
+        .and_then(|rt| {
+            let ctx = AppContext::new(AppConfig::from_env())
+                .map_err(|e| CliError::new(exit_codes::STORAGE, e.to_string()))?;
+            run(&args, &ctx, &rt)
+        });
+
+    match result {
+        Ok(report) => {
+            if as_json {
+                println!("{}", report.json);
+            } else {
+                println!("{}", report.text);
+            }
+            ExitCode::SUCCESS
+        },
+        Err(e) => {
+            if as_json {
+                println!("{}", json!({ "error": e.message, "exit_code": e.code }));
+            } else {
+                eprintln!("error: {}", e.message);
+                if e.code == exit_codes::USAGE {
+                    eprintln!("\n{USAGE}");
+                }
+            }
+            ExitCode::from(e.code)
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Args {
+        Args::parse(args.iter().map(|s| s.to_string())).unwrap()
+    }
+
+    #[test]
+    fn args_split_positionals_options_and_switches() {
+        let args = parse(&["exam", "generate", "abc", "--questions", "3", "--json"]);
+        assert_eq!(args.positionals, vec!["exam", "generate", "abc"]);
+        assert_eq!(args.option("questions"), Some("3"));
+        assert!(args.switch("json"));
+        assert!(!args.switch("force"));
+    }
+
+    #[test]
+    fn args_accept_inline_values() {
+        let args = parse(&["ingest", "playlist", "url", "--name=My Course"]);
+        assert_eq!(args.option("name"), Some("My Course"));
+    }
+
+    #[test]
+    fn args_reject_option_without_value() {
+        let err = Args::parse(["notes".to_string(), "--content".to_string()]).unwrap_err();
+        assert_eq!(err.code, exit_codes::USAGE);
+    }
+
+    #[test]
+    fn ingest_errors_map_to_distinct_exit_codes() {
+        let invalid: CliError = IngestError::InvalidUrl("x".into()).into();
+        let exists: CliError = IngestError::AlreadyExists("x".into()).into();
+        let missing: CliError = IngestError::FetchFailed(FetchError::NotFound("x".into())).into();
+        let offline: CliError = IngestError::FetchFailed(FetchError::RateLimited).into();
+        assert_eq!(invalid.code, exit_codes::INVALID_INPUT);
+        assert_eq!(exists.code, exit_codes::ALREADY_EXISTS);
+        assert_eq!(missing.code, exit_codes::NOT_FOUND);
+        assert_eq!(offline.code, exit_codes::SOURCE_UNAVAILABLE);
+    }
+
+    #[test]
+    fn summarize_and_exam_errors_map_to_exit_codes() {
+        let not_found: CliError = SummarizeVideoError::VideoNotFound.into();
+        assert_eq!(not_found.code, exit_codes::NOT_FOUND);
+
+        let ai: CliError =
+            ExamError::AI(course_pilot::domain::ports::LLMError::Api("down".into())).into();
+        assert_eq!(ai.code, exit_codes::AI_FAILED);
+
+        let repo: CliError =
+            ExamError::Repository(RepositoryError::NotFound { entity: "Exam", id: "1".into() })
+                .into();
+        assert_eq!(repo.code, exit_codes::NOT_FOUND);
+    }
+}
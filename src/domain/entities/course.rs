@@ -1,14 +1,13 @@
 //! Course entity - The aggregate root for a learning course.
 
-use crate::domain::value_objects::{CourseId, PlaylistUrl};
+use crate::domain::value_objects::{CourseId, CourseSource};
 
-/// A course represents a structured learning path derived from a YouTube playlist.
+/// A course represents a structured learning path derived from a playlist or a local folder.
 #[derive(Debug, Clone, PartialEq)]
 pub struct Course {
     id: CourseId,
     name: String,
-    source_url: PlaylistUrl,
-    playlist_id: String,
+    source: CourseSource,
     description: Option<String>,
     source_hash: Option<String>,
     created_at: chrono::DateTime<chrono::Utc>,
@@ -19,33 +18,23 @@ impl Course {
     pub fn new(
         id: CourseId,
         name: String,
-        source_url: PlaylistUrl,
-        playlist_id: String,
+        source: CourseSource,
         description: Option<String>,
         source_hash: Option<String>,
     ) -> Self {
-        Self::new_with_created_at(
-            id,
-            name,
-            source_url,
-            playlist_id,
-            description,
-            source_hash,
-            chrono::Utc::now(),
-        )
+        Self::new_with_created_at(id, name, source, description, source_hash, chrono::Utc::now())
     }
 
     /// Creates a new course with an explicit created_at timestamp.
     pub fn new_with_created_at(
         id: CourseId,
         name: String,
-        source_url: PlaylistUrl,
-        playlist_id: String,
+        source: CourseSource,
         description: Option<String>,
         source_hash: Option<String>,
         created_at: chrono::DateTime<chrono::Utc>,
     ) -> Self {
-        Self { id, name, source_url, playlist_id, description, source_hash, created_at }
+        Self { id, name, source, description, source_hash, created_at }
     }
 
     pub fn id(&self) -> &CourseId {
@@ -56,12 +45,8 @@ impl Course {
         &self.name
     }
 
-    pub fn source_url(&self) -> &PlaylistUrl {
-        &self.source_url
-    }
-
-    pub fn playlist_id(&self) -> &str {
-        &self.playlist_id
+    pub fn source(&self) -> &CourseSource {
+        &self.source
     }
 
     pub fn description(&self) -> Option<&str> {
@@ -75,4 +60,10 @@ impl Course {
     pub fn created_at(&self) -> chrono::DateTime<chrono::Utc> {
         self.created_at
     }
+
+    /// Points the course at a new source, e.g. after its folder was moved.
+    pub fn relocate(&mut self, source: CourseSource, source_hash: Option<String>) {
+        self.source = source;
+        self.source_hash = source_hash;
+    }
 }
@@ -6,7 +6,7 @@ use crate::domain::value_objects::{ExamId, VideoId};
 pub const PASS_THRESHOLD: f32 = 0.70;
 
 /// A single MCQ question for an exam.
-#[derive(Debug, Clone, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct QuizQuestion {
     pub question: String,
     pub options: Vec<String>,
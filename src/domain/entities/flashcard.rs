@@ -0,0 +1,176 @@
+//! Flashcard entity - A term/definition or cloze card attached to a video.
+
+use crate::domain::value_objects::{
+    FlashcardId, FlashcardKind, KeyTerm, VideoId, cloze_deletions, replace_cloze,
+};
+
+/// Placeholder shown in place of a cloze deletion on the front of a card.
+const CLOZE_BLANK: &str = "[...]";
+
+/// Why a flashcard's content was rejected.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum FlashcardContentError {
+    #[error("Flashcard front cannot be empty")]
+    EmptyFront,
+    #[error("Basic flashcards need a back")]
+    EmptyBack,
+    #[error("Cloze flashcards need at least one {{{{hidden}}}} span")]
+    MissingCloze,
+}
+
+/// A flashcard for one video.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Flashcard {
+    id: FlashcardId,
+    video_id: VideoId,
+    kind: FlashcardKind,
+    /// Term (basic) or sentence with `{{hidden}}` spans (cloze).
+    front: String,
+    /// Definition (basic) or optional extra context (cloze).
+    back: String,
+    /// Display order within the video's deck.
+    position: u32,
+}
+
+impl Flashcard {
+    /// Creates a flashcard, validating its content for `kind`.
+    pub fn new(
+        id: FlashcardId,
+        video_id: VideoId,
+        kind: FlashcardKind,
+        front: impl Into<String>,
+        back: impl Into<String>,
+        position: u32,
+    ) -> Result<Self, FlashcardContentError> {
+        let (front, back) = validate(kind, front.into(), back.into())?;
+        Ok(Self { id, video_id, kind, front, back, position })
+    }
+
+    /// Creates a basic card from a glossary term.
+    pub fn from_key_term(video_id: VideoId, term: &KeyTerm, position: u32) -> Self {
+        Self {
+            id: FlashcardId::new(),
+            video_id,
+            kind: FlashcardKind::Basic,
+            front: term.term.clone(),
+            back: term.definition.clone(),
+            position,
+        }
+    }
+
+    pub fn id(&self) -> &FlashcardId {
+        &self.id
+    }
+
+    pub fn video_id(&self) -> &VideoId {
+        &self.video_id
+    }
+
+    pub fn kind(&self) -> FlashcardKind {
+        self.kind
+    }
+
+    pub fn front(&self) -> &str {
+        &self.front
+    }
+
+    pub fn back(&self) -> &str {
+        &self.back
+    }
+
+    pub fn position(&self) -> u32 {
+        self.position
+    }
+
+    /// Text to show before the card is flipped.
+    pub fn prompt(&self) -> String {
+        match self.kind {
+            FlashcardKind::Basic => self.front.clone(),
+            FlashcardKind::Cloze => replace_cloze(&self.front, |_| CLOZE_BLANK.to_string()),
+        }
+    }
+
+    /// Text to show once the card is flipped.
+    pub fn answer(&self) -> String {
+        match self.kind {
+            FlashcardKind::Basic => self.back.clone(),
+            FlashcardKind::Cloze => {
+                let full = replace_cloze(&self.front, str::to_string);
+                if self.back.is_empty() { full } else { format!("{full}\n\n{}", self.back) }
+            },
+        }
+    }
+
+    /// Replaces the card's content, validating it for `kind`.
+    pub fn edit(
+        &mut self,
+        kind: FlashcardKind,
+        front: impl Into<String>,
+        back: impl Into<String>,
+    ) -> Result<(), FlashcardContentError> {
+        let (front, back) = validate(kind, front.into(), back.into())?;
+        self.kind = kind;
+        self.front = front;
+        self.back = back;
+        Ok(())
+    }
+
+    pub fn set_position(&mut self, position: u32) {
+        self.position = position;
+    }
+}
+
+fn validate(
+    kind: FlashcardKind,
+    front: String,
+    back: String,
+) -> Result<(String, String), FlashcardContentError> {
+    let front = front.trim().to_string();
+    let back = back.trim().to_string();
+    if front.is_empty() {
+        return Err(FlashcardContentError::EmptyFront);
+    }
+    match kind {
+        FlashcardKind::Basic if back.is_empty() => Err(FlashcardContentError::EmptyBack),
+        FlashcardKind::Cloze if cloze_deletions(&front).is_empty() => {
+            Err(FlashcardContentError::MissingCloze)
+        },
+        _ => Ok((front, back)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn card(
+        kind: FlashcardKind,
+        front: &str,
+        back: &str,
+    ) -> Result<Flashcard, FlashcardContentError> {
+        Flashcard::new(FlashcardId::new(), VideoId::new(), kind, front, back, 0)
+    }
+
+    #[test]
+    fn cloze_card_hides_spans_until_flipped() {
+        let cloze = card(FlashcardKind::Cloze, "Rust frees memory via {{RAII}}.", "").unwrap();
+        assert_eq!(cloze.prompt(), "Rust frees memory via [...].");
+        assert_eq!(cloze.answer(), "Rust frees memory via RAII.");
+    }
+
+    #[test]
+    fn content_is_validated_per_kind() {
+        assert_eq!(card(FlashcardKind::Basic, "  ", "x"), Err(FlashcardContentError::EmptyFront));
+        assert_eq!(card(FlashcardKind::Basic, "Term", " "), Err(FlashcardContentError::EmptyBack));
+        assert_eq!(
+            card(FlashcardKind::Cloze, "No blanks", ""),
+            Err(FlashcardContentError::MissingCloze)
+        );
+
+        let mut basic = card(FlashcardKind::Basic, "Term", "Definition").unwrap();
+        assert!(basic.edit(FlashcardKind::Cloze, "Still no blanks", "").is_err());
+        assert_eq!(basic.kind(), FlashcardKind::Basic);
+        basic.edit(FlashcardKind::Cloze, "A {{term}} here", "").unwrap();
+        assert_eq!(basic.kind(), FlashcardKind::Cloze);
+    }
+}
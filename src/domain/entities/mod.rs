@@ -3,9 +3,13 @@
 mod analytics;
 mod course;
 mod exam;
+mod flashcard;
 mod module;
 mod note;
+mod review_card;
 mod search;
+mod study_history;
+mod study_schedule;
 mod tag;
 mod user_preferences;
 mod video;
@@ -14,9 +18,13 @@ pub use crate::domain::value_objects::UserId;
 pub use analytics::AppAnalytics;
 pub use course::Course;
 pub use exam::{Exam, PASS_THRESHOLD, QuizQuestion};
+pub use flashcard::{Flashcard, FlashcardContentError};
 pub use module::Module;
 pub use note::{Note, NoteId};
+pub use review_card::ReviewCard;
 pub use search::{SearchResult, SearchResultType};
+pub use study_history::{CourseVelocity, DailyStudy, MIN_STREAK_SECS, StudyHistory, history_start};
+pub use study_schedule::{StudySchedule, StudySession, date_for_day};
 pub use tag::{TAG_COLORS, Tag};
 pub use user_preferences::{UserPreferences, UserPreferencesConfig};
 pub use video::Video;
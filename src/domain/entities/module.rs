@@ -32,4 +32,9 @@ impl Module {
     pub fn sort_order(&self) -> u32 {
         self.sort_order
     }
+
+    /// Updates the sort order.
+    pub fn set_sort_order(&mut self, order: u32) {
+        self.sort_order = order;
+    }
 }
@@ -0,0 +1,120 @@
+//! Review card entity - An exam question promoted into spaced repetition.
+
+use chrono::NaiveDate;
+
+use crate::domain::entities::QuizQuestion;
+use crate::domain::value_objects::{ExamId, ReviewCardId, ReviewGrade, ReviewSchedule, VideoId};
+
+/// One exam question with its SM-2 scheduling state.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReviewCard {
+    id: ReviewCardId,
+    exam_id: ExamId,
+    video_id: VideoId,
+    /// Position of the question within its exam.
+    question_index: u32,
+    question: QuizQuestion,
+    schedule: ReviewSchedule,
+    last_reviewed: Option<NaiveDate>,
+}
+
+impl ReviewCard {
+    /// Creates a new, never-reviewed card due on `due`.
+    pub fn new(
+        id: ReviewCardId,
+        exam_id: ExamId,
+        video_id: VideoId,
+        question_index: u32,
+        question: QuizQuestion,
+        due: NaiveDate,
+    ) -> Self {
+        Self {
+            id,
+            exam_id,
+            video_id,
+            question_index,
+            question,
+            schedule: ReviewSchedule::new(due),
+            last_reviewed: None,
+        }
+    }
+
+    /// Restores persisted scheduling state.
+    pub fn with_schedule(
+        mut self,
+        schedule: ReviewSchedule,
+        last_reviewed: Option<NaiveDate>,
+    ) -> Self {
+        self.schedule = schedule;
+        self.last_reviewed = last_reviewed;
+        self
+    }
+
+    pub fn id(&self) -> &ReviewCardId {
+        &self.id
+    }
+
+    pub fn exam_id(&self) -> &ExamId {
+        &self.exam_id
+    }
+
+    pub fn video_id(&self) -> &VideoId {
+        &self.video_id
+    }
+
+    pub fn question_index(&self) -> u32 {
+        self.question_index
+    }
+
+    pub fn question(&self) -> &QuizQuestion {
+        &self.question
+    }
+
+    pub fn schedule(&self) -> &ReviewSchedule {
+        &self.schedule
+    }
+
+    pub fn last_reviewed(&self) -> Option<NaiveDate> {
+        self.last_reviewed
+    }
+
+    pub fn is_due(&self, today: NaiveDate) -> bool {
+        self.schedule.is_due(today)
+    }
+
+    /// Returns true if `selected` is the correct option.
+    pub fn is_correct(&self, selected: usize) -> bool {
+        selected == self.question.correct_index
+    }
+
+    /// Records a graded review on `today` and reschedules the card.
+    pub fn record_review(&mut self, grade: ReviewGrade, today: NaiveDate) {
+        self.schedule = self.schedule.review(grade, today);
+        self.last_reviewed = Some(today);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record_review_moves_due_date() {
+        let today = NaiveDate::from_ymd_opt(2026, 6, 1).unwrap();
+        let question = QuizQuestion {
+            question: "2 + 2?".to_string(),
+            options: vec!["3".to_string(), "4".to_string()],
+            correct_index: 1,
+            explanation: "Arithmetic.".to_string(),
+        };
+        let mut card =
+            ReviewCard::new(ReviewCardId::new(), ExamId::new(), VideoId::new(), 0, question, today);
+        assert!(card.is_due(today));
+        assert!(card.is_correct(1));
+
+        card.record_review(ReviewGrade::Good, today);
+        assert!(!card.is_due(today));
+        assert_eq!(card.last_reviewed(), Some(today));
+        assert_eq!(card.schedule().repetitions(), 1);
+    }
+}
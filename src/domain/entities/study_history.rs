@@ -0,0 +1,203 @@
+//! Study history entity - Daily watch time, streaks and per-course velocity.
+
+use std::collections::HashMap;
+
+use chrono::{Days, NaiveDate};
+
+use crate::domain::value_objects::CourseId;
+
+/// Days with less watch time than this do not keep a streak alive.
+pub const MIN_STREAK_SECS: u64 = 60;
+
+/// Watch time on one calendar day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DailyStudy {
+    pub date: NaiveDate,
+    pub seconds: u64,
+}
+
+impl DailyStudy {
+    pub fn minutes(&self) -> u64 {
+        self.seconds / 60
+    }
+}
+
+/// How fast a course is being worked through.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CourseVelocity {
+    pub course_id: CourseId,
+    pub course_name: String,
+    /// Average watch minutes per week over the velocity window.
+    pub minutes_per_week: f64,
+    /// Duration of the course's incomplete videos, in minutes.
+    pub remaining_minutes: u64,
+}
+
+impl CourseVelocity {
+    /// Weeks left at the current pace, or `None` if the course is not being watched.
+    pub fn weeks_to_finish(&self) -> Option<f64> {
+        (self.minutes_per_week > 0.0).then(|| self.remaining_minutes as f64 / self.minutes_per_week)
+    }
+}
+
+/// Day-by-day watch time over a date range, ending today.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StudyHistory {
+    days: Vec<DailyStudy>,
+    current_streak: u32,
+    longest_streak: u32,
+    course_velocity: Vec<CourseVelocity>,
+}
+
+impl StudyHistory {
+    /// Builds the history for `from..=today` from per-day watch seconds.
+    /// Days without an entry count as zero.
+    pub fn new(
+        from: NaiveDate,
+        today: NaiveDate,
+        seconds_by_day: &HashMap<NaiveDate, u64>,
+        course_velocity: Vec<CourseVelocity>,
+    ) -> Self {
+        let days: Vec<DailyStudy> = from
+            .iter_days()
+            .take_while(|date| *date <= today)
+            .map(|date| DailyStudy {
+                date,
+                seconds: seconds_by_day.get(&date).copied().unwrap_or(0),
+            })
+            .collect();
+
+        let studied = |day: &DailyStudy| day.seconds >= MIN_STREAK_SECS;
+
+        let mut longest_streak = 0;
+        let mut run = 0;
+        for day in &days {
+            run = if studied(day) { run + 1 } else { 0 };
+            longest_streak = longest_streak.max(run);
+        }
+
+        // Today still counts as "in progress": a streak through yesterday is not broken yet.
+        let mut recent = days.iter().rev().peekable();
+        if recent.peek().is_some_and(|day| day.date == today && !studied(day)) {
+            recent.next();
+        }
+        let current_streak = recent.take_while(|day| studied(day)).count() as u32;
+
+        Self { days, current_streak, longest_streak, course_velocity }
+    }
+
+    /// One entry per calendar day, oldest first.
+    pub fn days(&self) -> &[DailyStudy] {
+        &self.days
+    }
+
+    /// Consecutive study days ending today (or yesterday, if nothing was watched yet today).
+    pub fn current_streak(&self) -> u32 {
+        self.current_streak
+    }
+
+    /// Longest run of consecutive study days within the range.
+    pub fn longest_streak(&self) -> u32 {
+        self.longest_streak
+    }
+
+    pub fn course_velocity(&self) -> &[CourseVelocity] {
+        &self.course_velocity
+    }
+
+    /// Total watch minutes over the last `days` days, including today.
+    pub fn recent_minutes(&self, days: usize) -> u64 {
+        self.days.iter().rev().take(days).map(|d| d.seconds).sum::<u64>() / 60
+    }
+
+    /// Trailing average of daily minutes over `window` days, one value per day.
+    /// Early days average over the days available so far.
+    pub fn rolling_average_minutes(&self, window: usize) -> Vec<f64> {
+        let window = window.max(1);
+        (0..self.days.len())
+            .map(|idx| {
+                let start = (idx + 1).saturating_sub(window);
+                let slice = &self.days[start..=idx];
+                slice.iter().map(|d| d.seconds as f64 / 60.0).sum::<f64>() / slice.len() as f64
+            })
+            .collect()
+    }
+
+    /// First day of the range.
+    pub fn start_date(&self) -> Option<NaiveDate> {
+        self.days.first().map(|d| d.date)
+    }
+}
+
+/// First day of a `days`-long range ending on `today`.
+pub fn history_start(today: NaiveDate, days: u32) -> NaiveDate {
+    today.checked_sub_days(Days::new(u64::from(days.max(1) - 1))).unwrap_or(today)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 6, d).unwrap()
+    }
+
+    fn build(today: u32, studied: &[(u32, u64)]) -> StudyHistory {
+        let seconds: HashMap<NaiveDate, u64> =
+            studied.iter().map(|&(d, secs)| (date(d), secs)).collect();
+        StudyHistory::new(date(1), date(today), &seconds, vec![])
+    }
+
+    #[test]
+    fn fills_missing_days_with_zero() {
+        let history = build(5, &[(2, 600)]);
+        assert_eq!(history.days().len(), 5);
+        assert_eq!(history.days()[0].seconds, 0);
+        assert_eq!(history.days()[1].minutes(), 10);
+        assert_eq!(history.recent_minutes(7), 10);
+    }
+
+    #[test]
+    fn streaks_count_consecutive_study_days() {
+        let history = build(10, &[(1, 600), (2, 600), (3, 600), (6, 600), (8, 600), (9, 600)]);
+        assert_eq!(history.longest_streak(), 3);
+        // Nothing yet on the 10th: the streak through the 9th is still alive.
+        assert_eq!(history.current_streak(), 2);
+
+        let broken = build(10, &[(7, 600), (8, 600)]);
+        assert_eq!(broken.current_streak(), 0);
+        assert_eq!(broken.longest_streak(), 2);
+    }
+
+    #[test]
+    fn short_days_do_not_count_towards_streaks() {
+        let history = build(3, &[(1, 600), (2, 30), (3, 600)]);
+        assert_eq!(history.current_streak(), 1);
+        assert_eq!(history.longest_streak(), 1);
+    }
+
+    #[test]
+    fn rolling_average_uses_available_days() {
+        let history = build(3, &[(1, 600), (2, 0), (3, 1200)]);
+        assert_eq!(history.rolling_average_minutes(2), vec![10.0, 5.0, 10.0]);
+    }
+
+    #[test]
+    fn weeks_to_finish_needs_a_pace() {
+        let mut velocity = CourseVelocity {
+            course_id: CourseId::new(),
+            course_name: "Rust".to_string(),
+            minutes_per_week: 60.0,
+            remaining_minutes: 180,
+        };
+        assert_eq!(velocity.weeks_to_finish(), Some(3.0));
+        velocity.minutes_per_week = 0.0;
+        assert_eq!(velocity.weeks_to_finish(), None);
+    }
+
+    #[test]
+    fn history_start_covers_requested_days() {
+        assert_eq!(history_start(date(10), 10), date(1));
+        assert_eq!(history_start(date(10), 0), date(10));
+    }
+}
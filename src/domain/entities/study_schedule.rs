@@ -0,0 +1,238 @@
+//! Study schedule entity - A calendar-anchored session plan for a course.
+
+use chrono::{DateTime, Days, NaiveDate, Utc};
+
+use crate::domain::value_objects::{CourseId, ReschedulePolicy, SessionPlan, VideoId};
+
+/// A single planned study session on a concrete calendar date.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StudySession {
+    day: u32,
+    date: NaiveDate,
+    video_ids: Vec<VideoId>,
+    total_duration_secs: u32,
+}
+
+impl StudySession {
+    /// Creates a new study session.
+    pub fn new(
+        day: u32,
+        date: NaiveDate,
+        video_ids: Vec<VideoId>,
+        total_duration_secs: u32,
+    ) -> Self {
+        Self { day, date, video_ids, total_duration_secs }
+    }
+
+    /// Calendar day relative to the schedule start (1-indexed).
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    pub fn date(&self) -> NaiveDate {
+        self.date
+    }
+
+    pub fn video_ids(&self) -> &[VideoId] {
+        &self.video_ids
+    }
+
+    pub fn total_duration_secs(&self) -> u32 {
+        self.total_duration_secs
+    }
+}
+
+/// A persisted study schedule: the planner output anchored to a start date.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StudySchedule {
+    course_id: CourseId,
+    start_date: NaiveDate,
+    study_days_per_week: u32,
+    cognitive_limit_minutes: u32,
+    reschedule_policy: ReschedulePolicy,
+    sessions: Vec<StudySession>,
+    updated_at: DateTime<Utc>,
+    peak_session_count: u32,
+}
+
+impl StudySchedule {
+    /// Creates a schedule from already-dated sessions.
+    pub fn new(
+        course_id: CourseId,
+        start_date: NaiveDate,
+        study_days_per_week: u32,
+        cognitive_limit_minutes: u32,
+        sessions: Vec<StudySession>,
+    ) -> Self {
+        Self {
+            course_id,
+            start_date,
+            study_days_per_week,
+            cognitive_limit_minutes,
+            reschedule_policy: ReschedulePolicy::default(),
+            peak_session_count: sessions.len() as u32,
+            sessions,
+            updated_at: Utc::now(),
+        }
+    }
+
+    /// Sets the policy used when this schedule drifts from the calendar.
+    pub fn with_reschedule_policy(mut self, policy: ReschedulePolicy) -> Self {
+        self.reschedule_policy = policy;
+        self
+    }
+
+    /// Sets when this schedule was last planned, e.g. when loading it from storage.
+    pub fn with_updated_at(mut self, updated_at: DateTime<Utc>) -> Self {
+        self.updated_at = updated_at;
+        self
+    }
+
+    /// Carries over the largest session count of an earlier version of this schedule,
+    /// so sessions dropped by a re-plan can still be cancelled in exported calendars.
+    pub fn with_peak_session_count(mut self, count: u32) -> Self {
+        self.peak_session_count = self.peak_session_count.max(count);
+        self
+    }
+
+    /// Anchors `SessionPlanner` output to `start_date`.
+    /// `video_ids` must be the same ordered list the plan indices refer to.
+    pub fn from_plans(
+        course_id: CourseId,
+        start_date: NaiveDate,
+        study_days_per_week: u32,
+        cognitive_limit_minutes: u32,
+        plans: &[SessionPlan],
+        video_ids: &[VideoId],
+    ) -> Self {
+        let sessions = plans
+            .iter()
+            .map(|plan| {
+                let ids =
+                    plan.video_indices.iter().filter_map(|&i| video_ids.get(i).copied()).collect();
+                StudySession::new(
+                    plan.day,
+                    date_for_day(start_date, plan.day),
+                    ids,
+                    plan.total_duration_secs,
+                )
+            })
+            .collect();
+        Self::new(course_id, start_date, study_days_per_week, cognitive_limit_minutes, sessions)
+    }
+
+    pub fn course_id(&self) -> &CourseId {
+        &self.course_id
+    }
+
+    pub fn start_date(&self) -> NaiveDate {
+        self.start_date
+    }
+
+    pub fn study_days_per_week(&self) -> u32 {
+        self.study_days_per_week
+    }
+
+    pub fn cognitive_limit_minutes(&self) -> u32 {
+        self.cognitive_limit_minutes
+    }
+
+    pub fn reschedule_policy(&self) -> ReschedulePolicy {
+        self.reschedule_policy
+    }
+
+    pub fn sessions(&self) -> &[StudySession] {
+        &self.sessions
+    }
+
+    /// When the sessions were last planned or re-planned.
+    pub fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+
+    /// Most sessions this schedule has ever had; at least `sessions().len()`.
+    pub fn peak_session_count(&self) -> u32 {
+        self.peak_session_count
+    }
+
+    /// Returns the session planned for `date`, if any.
+    pub fn session_on(&self, date: NaiveDate) -> Option<&StudySession> {
+        self.sessions.iter().find(|s| s.date == date)
+    }
+
+    /// Returns the first session on or after `date`.
+    pub fn next_session_from(&self, date: NaiveDate) -> Option<&StudySession> {
+        self.sessions.iter().find(|s| s.date >= date)
+    }
+
+    /// Returns the date a video is planned for, if it is part of this schedule.
+    pub fn date_of(&self, video_id: &VideoId) -> Option<NaiveDate> {
+        self.sessions.iter().find(|s| s.video_ids.contains(video_id)).map(|s| s.date)
+    }
+
+    /// Whether `date` is a study day under this schedule's weekly pattern.
+    /// The first `study_days_per_week` days of each week starting at `start_date` are study days.
+    pub fn is_study_day(&self, date: NaiveDate) -> bool {
+        let offset = (date - self.start_date).num_days();
+        offset >= 0 && offset % 7 < i64::from(self.study_days_per_week.clamp(1, 7))
+    }
+
+    /// Date of the last planned session.
+    pub fn end_date(&self) -> Option<NaiveDate> {
+        self.sessions.last().map(|s| s.date)
+    }
+}
+
+/// Converts a 1-indexed planner day into a calendar date.
+pub fn date_for_day(start_date: NaiveDate, day: u32) -> NaiveDate {
+    start_date.checked_add_days(Days::new(u64::from(day.saturating_sub(1)))).unwrap_or(start_date)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn from_plans_maps_days_to_dates_and_indices_to_ids() {
+        let ids: Vec<VideoId> = (0..3).map(|_| VideoId::new()).collect();
+        let plans = vec![SessionPlan::new(1, vec![0, 1], 1200), SessionPlan::new(8, vec![2], 600)];
+
+        let schedule =
+            StudySchedule::from_plans(CourseId::new(), date(2026, 6, 1), 5, 45, &plans, &ids);
+
+        assert_eq!(schedule.sessions().len(), 2);
+        assert_eq!(schedule.sessions()[0].date(), date(2026, 6, 1));
+        assert_eq!(schedule.sessions()[0].video_ids(), &ids[0..2]);
+        assert_eq!(schedule.sessions()[1].date(), date(2026, 6, 8));
+        assert_eq!(schedule.end_date(), Some(date(2026, 6, 8)));
+    }
+
+    #[test]
+    fn session_lookup_by_date() {
+        let ids = vec![VideoId::new(), VideoId::new()];
+        let plans = vec![SessionPlan::new(1, vec![0], 600), SessionPlan::new(3, vec![1], 600)];
+        let schedule =
+            StudySchedule::from_plans(CourseId::new(), date(2026, 6, 1), 7, 45, &plans, &ids);
+
+        assert!(schedule.session_on(date(2026, 6, 2)).is_none());
+        assert_eq!(schedule.session_on(date(2026, 6, 3)).map(|s| s.day()), Some(3));
+        assert_eq!(schedule.next_session_from(date(2026, 6, 2)).map(|s| s.day()), Some(3));
+        assert!(schedule.next_session_from(date(2026, 6, 4)).is_none());
+    }
+
+    #[test]
+    fn peak_session_count_never_drops_below_current_sessions() {
+        let ids = vec![VideoId::new(), VideoId::new()];
+        let plans = vec![SessionPlan::new(1, vec![0], 600), SessionPlan::new(2, vec![1], 600)];
+        let schedule =
+            StudySchedule::from_plans(CourseId::new(), date(2026, 6, 1), 7, 45, &plans, &ids);
+
+        assert_eq!(schedule.peak_session_count(), 2);
+        assert_eq!(schedule.clone().with_peak_session_count(5).peak_session_count(), 5);
+        assert_eq!(schedule.with_peak_session_count(1).peak_session_count(), 2);
+    }
+}
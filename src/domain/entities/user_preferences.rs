@@ -11,6 +11,9 @@ pub struct UserPreferencesConfig {
     pub onboarding_completed: bool,
     pub preferred_quality: VideoQuality,
     pub boundary_batch_size: u32,
+    pub module_target_minutes: u32,
+    pub auto_complete_percent: u32,
+    pub watch_local_folders: bool,
 }
 
 /// User preferences stored in the database.
@@ -24,6 +27,12 @@ pub struct UserPreferences {
     onboarding_completed: bool,
     preferred_quality: VideoQuality,
     boundary_batch_size: u32,
+    /// Total video minutes to aim for per module; `0` groups by `boundary_batch_size`.
+    module_target_minutes: u32,
+    /// Share of a video that must be watched before it is marked complete.
+    auto_complete_percent: u32,
+    /// Rescan folder-based courses automatically when their files change.
+    watch_local_folders: bool,
 }
 
 impl UserPreferences {
@@ -38,6 +47,9 @@ impl UserPreferences {
             onboarding_completed: config.onboarding_completed,
             preferred_quality: config.preferred_quality,
             boundary_batch_size: config.boundary_batch_size,
+            module_target_minutes: config.module_target_minutes,
+            auto_complete_percent: config.auto_complete_percent.clamp(1, 100),
+            watch_local_folders: config.watch_local_folders,
         }
     }
 
@@ -52,6 +64,9 @@ impl UserPreferences {
             onboarding_completed: false,
             preferred_quality: VideoQuality::P720,
             boundary_batch_size: 5,
+            module_target_minutes: 0,
+            auto_complete_percent: 90,
+            watch_local_folders: false,
         }
     }
 
@@ -92,6 +107,18 @@ impl UserPreferences {
         self.boundary_batch_size
     }
 
+    pub fn module_target_minutes(&self) -> u32 {
+        self.module_target_minutes
+    }
+
+    pub fn auto_complete_percent(&self) -> u32 {
+        self.auto_complete_percent
+    }
+
+    pub fn watch_local_folders(&self) -> bool {
+        self.watch_local_folders
+    }
+
     pub fn set_ml_boundary_enabled(&mut self, enabled: bool) {
         self.ml_boundary_enabled = enabled;
     }
@@ -119,4 +146,16 @@ impl UserPreferences {
     pub fn set_boundary_batch_size(&mut self, size: u32) {
         self.boundary_batch_size = size;
     }
+
+    pub fn set_module_target_minutes(&mut self, minutes: u32) {
+        self.module_target_minutes = minutes;
+    }
+
+    pub fn set_auto_complete_percent(&mut self, percent: u32) {
+        self.auto_complete_percent = percent.clamp(1, 100);
+    }
+
+    pub fn set_watch_local_folders(&mut self, enabled: bool) {
+        self.watch_local_folders = enabled;
+    }
 }
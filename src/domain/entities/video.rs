@@ -1,6 +1,8 @@
 //! Video entity - A single video within a module.
 
-use crate::domain::value_objects::{ModuleId, VideoId, VideoSource, YouTubeVideoId};
+use crate::domain::value_objects::{
+    ClipRange, KeyTerm, ModuleId, VideoId, VideoSource, YouTubeVideoId,
+};
 
 /// A video represents a single learning unit within a module.
 #[derive(Debug, Clone, PartialEq)]
@@ -12,9 +14,19 @@ pub struct Video {
     description: Option<String>,
     transcript: Option<String>,
     summary: Option<String>,
+    /// Main takeaways extracted alongside the summary.
+    key_points: Vec<String>,
+    /// Glossary extracted alongside the summary.
+    key_terms: Vec<KeyTerm>,
     duration_secs: u32,
     is_completed: bool,
+    /// False once the video has disappeared from its source playlist or folder.
+    is_available: bool,
     sort_order: u32,
+    /// Fingerprint of a local file's content, used to follow it across renames.
+    content_fingerprint: Option<String>,
+    /// Chapter of the source this video covers; `None` plays the whole source.
+    clip: Option<ClipRange>,
 }
 
 impl Video {
@@ -35,9 +47,14 @@ impl Video {
             description: None,
             transcript: None,
             summary: None,
+            key_points: Vec::new(),
+            key_terms: Vec::new(),
             duration_secs,
             is_completed: false,
+            is_available: true,
             sort_order,
+            content_fingerprint: None,
+            clip: None,
         }
     }
 
@@ -59,9 +76,14 @@ impl Video {
             description,
             transcript: None,
             summary: None,
+            key_points: Vec::new(),
+            key_terms: Vec::new(),
             duration_secs,
             is_completed: false,
+            is_available: true,
             sort_order,
+            content_fingerprint: None,
+            clip: None,
         }
     }
 
@@ -101,6 +123,14 @@ impl Video {
         self.summary.as_deref()
     }
 
+    pub fn key_points(&self) -> &[String] {
+        &self.key_points
+    }
+
+    pub fn key_terms(&self) -> &[KeyTerm] {
+        &self.key_terms
+    }
+
     pub fn duration_secs(&self) -> u32 {
         self.duration_secs
     }
@@ -109,10 +139,22 @@ impl Video {
         self.is_completed
     }
 
+    pub fn is_available(&self) -> bool {
+        self.is_available
+    }
+
     pub fn sort_order(&self) -> u32 {
         self.sort_order
     }
 
+    pub fn content_fingerprint(&self) -> Option<&str> {
+        self.content_fingerprint.as_deref()
+    }
+
+    pub fn clip(&self) -> Option<ClipRange> {
+        self.clip
+    }
+
     /// Updates the transcript content.
     pub fn update_transcript(&mut self, transcript: Option<String>) {
         self.transcript = transcript;
@@ -123,6 +165,12 @@ impl Video {
         self.summary = summary;
     }
 
+    /// Replaces the key points and glossary terms.
+    pub fn update_key_content(&mut self, key_points: Vec<String>, key_terms: Vec<KeyTerm>) {
+        self.key_points = key_points;
+        self.key_terms = key_terms;
+    }
+
     /// Marks the video as completed.
     pub fn mark_completed(&mut self) {
         self.is_completed = true;
@@ -133,6 +181,31 @@ impl Video {
         self.is_completed = false;
     }
 
+    /// Flags the video as gone from its source, keeping all its data.
+    pub fn mark_unavailable(&mut self) {
+        self.is_available = false;
+    }
+
+    /// Clears the unavailable flag.
+    pub fn mark_available(&mut self) {
+        self.is_available = true;
+    }
+
+    /// Updates the local file fingerprint.
+    pub fn set_content_fingerprint(&mut self, fingerprint: Option<String>) {
+        self.content_fingerprint = fingerprint;
+    }
+
+    /// Limits the video to a chapter of its source.
+    pub fn set_clip(&mut self, clip: Option<ClipRange>) {
+        self.clip = clip;
+    }
+
+    /// Points the video at a new source, e.g. after its file was moved.
+    pub fn relocate(&mut self, source: VideoSource) {
+        self.source = source;
+    }
+
     /// Updates the sort order.
     pub fn set_sort_order(&mut self, order: u32) {
         self.sort_order = order;
@@ -192,6 +265,16 @@ mod tests {
         assert_eq!(video.summary(), Some("A summary"));
     }
 
+    #[test]
+    fn update_key_content() {
+        let mut video = sample_video();
+        assert!(video.key_points().is_empty() && video.key_terms().is_empty());
+        let term = KeyTerm::new("Borrow", "A reference to a value").unwrap();
+        video.update_key_content(vec!["Values have one owner".to_string()], vec![term.clone()]);
+        assert_eq!(video.key_points(), ["Values have one owner".to_string()]);
+        assert_eq!(video.key_terms(), [term]);
+    }
+
     #[test]
     fn youtube_source_delegates() {
         let yt_id = YouTubeVideoId::new("9bZkp7q19f0").unwrap();
@@ -0,0 +1,26 @@
+//! Calendar Port - Interface for exporting study sessions to calendar formats.
+
+use chrono::{NaiveDate, NaiveTime};
+
+/// A single calendar entry for a planned study session.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalendarEvent {
+    /// Globally unique, stable identifier. Re-exporting with the same UID updates the event.
+    pub uid: String,
+    pub date: NaiveDate,
+    /// Local (floating) start time. `None` exports an all-day event.
+    pub start_time: Option<NaiveTime>,
+    pub duration_secs: u32,
+    pub summary: String,
+    pub description: String,
+    /// Revision number; must grow whenever the event changes so clients apply the update.
+    pub sequence: u32,
+    /// Marks a session that a re-plan dropped, so clients remove it on re-import.
+    pub cancelled: bool,
+}
+
+/// Port for serializing study sessions into a calendar document.
+pub trait CalendarExporter: Send + Sync {
+    /// Renders `events` as a single calendar named `calendar_name`.
+    fn export(&self, calendar_name: &str, events: &[CalendarEvent]) -> String;
+}
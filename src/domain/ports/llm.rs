@@ -3,7 +3,8 @@
 use std::future::Future;
 use std::pin::Pin;
 
-use crate::domain::value_objects::ExamDifficulty;
+use crate::domain::ports::{ChatRole, TranscriptChunk};
+use crate::domain::value_objects::{ExamDifficulty, FlashcardKind, KeyTerm};
 
 /// Error type for LLM operations.
 #[derive(Debug, thiserror::Error)]
@@ -25,13 +26,80 @@ pub struct CompanionContext {
     pub notes: Option<String>,
     /// Extra user-provided context for local videos without transcripts.
     pub local_context: Option<String>,
+    /// Transcript passages retrieved for the question, best match first. The companion cites
+    /// them by their position in this list, starting at 1.
+    pub transcript_excerpts: Vec<TranscriptChunk>,
+    /// What was said just before the playback position the question is about.
+    pub moment: Option<PlaybackMoment>,
+}
+
+/// The transcript of a stretch of a video, ending at the playback position.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlaybackMoment {
+    /// Start of the stretch, in seconds into the video.
+    pub from_secs: u32,
+    /// The playback position, in seconds into the video.
+    pub to_secs: u32,
+    pub text: String,
+    /// The transcript has no timings, so the stretch was estimated from the video's length.
+    pub estimated: bool,
+}
+
+impl PlaybackMoment {
+    /// A position as `m:ss`, or `h:mm:ss` once past the first hour.
+    pub fn clock(secs: u32) -> String {
+        let (h, m, s) = (secs / 3600, secs / 60 % 60, secs % 60);
+        if h > 0 { format!("{h}:{m:02}:{s:02}") } else { format!("{m}:{s:02}") }
+    }
+
+    /// The stretch as `m:ss–m:ss`.
+    pub fn span(&self) -> String {
+        format!("{}\u{2013}{}", Self::clock(self.from_secs), Self::clock(self.to_secs))
+    }
+}
+
+/// A message from earlier in a companion conversation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConversationTurn {
+    pub role: ChatRole,
+    pub content: String,
+}
+
+/// The earlier part of a conversation sent along with a new question.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConversationHistory {
+    /// Condensed account of turns too old to be sent verbatim.
+    pub summary: Option<String>,
+    /// Most recent turns, oldest first.
+    pub turns: Vec<ConversationTurn>,
 }
 
 /// Port for the Sidecar Companion (AI-B).
 #[async_trait::async_trait]
 pub trait CompanionAI: Send + Sync {
-    /// Answers a question in the context of the current video.
-    async fn ask(&self, question: &str, context: &CompanionContext) -> Result<String, LLMError>;
+    /// Answers a question in the context of the current video, following on from `history`.
+    async fn ask(
+        &self,
+        question: &str,
+        context: &CompanionContext,
+        history: &ConversationHistory,
+    ) -> Result<String, LLMError>;
+
+    /// Like [`ask`](Self::ask), but hands each piece of text to `on_delta` as it is generated
+    /// and returns the full answer at the end.
+    ///
+    /// Dropping the future stops generation. The default delivers the whole answer as one delta.
+    async fn ask_streaming(
+        &self,
+        question: &str,
+        context: &CompanionContext,
+        history: &ConversationHistory,
+        on_delta: &(dyn Fn(&str) + Send + Sync),
+    ) -> Result<String, LLMError> {
+        let answer = self.ask(question, context, history).await?;
+        on_delta(&answer);
+        Ok(answer)
+    }
 }
 
 /// MCQ question structure.
@@ -57,15 +125,50 @@ pub trait ExaminerAI: Send + Sync {
     ) -> Result<Vec<MCQuestion>, LLMError>;
 }
 
+/// Flashcard as returned by the generator, before it is attached to a video.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct GeneratedFlashcard {
+    pub kind: FlashcardKind,
+    pub front: String,
+    #[serde(default)]
+    pub back: String,
+}
+
+/// Port for generating term/definition and cloze flashcards.
+#[async_trait::async_trait]
+pub trait FlashcardGeneratorAI: Send + Sync {
+    /// Generates up to `max_cards` flashcards for a video.
+    /// `key_terms` are glossary entries already known for the video and should be covered first.
+    async fn generate_flashcards(
+        &self,
+        video_title: &str,
+        video_summary: Option<&str>,
+        transcript: Option<&str>,
+        key_terms: &[KeyTerm],
+        max_cards: u8,
+    ) -> Result<Vec<GeneratedFlashcard>, LLMError>;
+}
+
+/// Structured summary of a video transcript.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct VideoSummary {
+    /// Short prose overview of the video's main topic.
+    pub summary: String,
+    #[serde(default)]
+    pub key_points: Vec<String>,
+    #[serde(default)]
+    pub key_terms: Vec<KeyTerm>,
+}
+
 /// Port for video transcript summarization.
 #[async_trait::async_trait]
 pub trait SummarizerAI: Send + Sync {
-    /// Summarizes a video transcript into key points.
+    /// Summarizes a video transcript into an overview, key points and glossary terms.
     async fn summarize_transcript(
         &self,
         transcript: &str,
         video_title: &str,
-    ) -> Result<String, LLMError>;
+    ) -> Result<VideoSummary, LLMError>;
 }
 
 /// Port for generating descriptive module titles from grouped video titles.
@@ -1,5 +1,7 @@
 //! Local media scanner port.
 
+use super::{CancellationToken, ProgressSink, RawChapter};
+
 /// Raw subtitle metadata from filesystem scan.
 #[derive(Debug, Clone)]
 pub struct RawSubtitleMetadata {
@@ -14,6 +16,8 @@ pub struct RawLocalMediaMetadata {
     pub duration_secs: u32,
     /// Candidate subtitle files matching the video.
     pub subtitles: Vec<RawSubtitleMetadata>,
+    /// Chapters from the container's table of contents, in order.
+    pub chapters: Vec<RawChapter>,
 }
 
 /// Error type for local media scanning.
@@ -27,6 +31,8 @@ pub enum LocalMediaError {
     Metadata(String),
     #[error("GStreamer discovery init failed: {0}")]
     DiscoveryFailed(String),
+    #[error("Scan cancelled")]
+    Cancelled,
 }
 
 /// Port for scanning local media libraries.
@@ -34,4 +40,22 @@ pub enum LocalMediaError {
 pub trait LocalMediaScanner: Send + Sync {
     /// Recursively scans a root directory for supported media.
     async fn scan(&self, root: &str) -> Result<Vec<RawLocalMediaMetadata>, LocalMediaError>;
+
+    /// Like [`scan`](Self::scan), reporting `Discovering` / `ExtractingSubtitles` progress
+    /// and stopping with [`LocalMediaError::Cancelled`] once `cancel` is set.
+    ///
+    /// The default scans in one go and only honours a cancellation requested meanwhile.
+    async fn scan_with_progress(
+        &self,
+        root: &str,
+        progress: &dyn ProgressSink,
+        cancel: &CancellationToken,
+    ) -> Result<Vec<RawLocalMediaMetadata>, LocalMediaError> {
+        let _ = progress;
+        let media = self.scan(root).await?;
+        if cancel.is_cancelled() {
+            return Err(LocalMediaError::Cancelled);
+        }
+        Ok(media)
+    }
 }
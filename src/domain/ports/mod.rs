@@ -1,30 +1,41 @@
 //! Ports - Trait definitions for external dependencies.
 //! These define the contracts that infrastructure adapters must implement.
 
+mod calendar;
 mod chat_repository;
 mod keystore;
 mod llm;
 mod local_media;
+mod playback;
 mod presence;
+mod progress;
 mod repository;
 mod stream;
 mod transcript;
 mod youtube;
 
+pub use calendar::{CalendarEvent, CalendarExporter};
 pub use chat_repository::{ChatMessage, ChatMessageRepository, ChatRole};
 pub use keystore::{KeystoreError, SecretStore};
 pub use llm::{
-    CompanionAI, CompanionContext, ExaminerAI, LLMError, MCQuestion, ModuleTitleGenerator,
-    SummarizerAI,
+    CompanionAI, CompanionContext, ConversationHistory, ConversationTurn, ExaminerAI,
+    FlashcardGeneratorAI, GeneratedFlashcard, LLMError, MCQuestion, ModuleTitleGenerator,
+    PlaybackMoment, SummarizerAI, VideoSummary,
 };
 pub use local_media::{
     LocalMediaError, LocalMediaScanner, RawLocalMediaMetadata, RawSubtitleMetadata,
 };
+pub use playback::{
+    PlaybackPosition, PlaybackPositionRepository, WatchSession, WatchSessionRepository,
+};
 pub use presence::{Activity, PresenceProvider};
+pub use progress::{CancellationToken, IngestProgress, ProgressSink};
 pub use repository::{
-    CourseRepository, ExamRepository, ModuleRepository, NoteRepository, RepositoryError,
-    SearchEntry, SearchRepository, TagRepository, UserPreferencesRepository, VideoRepository,
+    CourseRepository, ExamRepository, FlashcardRepository, ModuleRepository, NoteRepository,
+    RepositoryError, ReviewCardRepository, SearchEntry, SearchRepository, StudyScheduleRepository,
+    TagRepository, TranscriptChunk, TranscriptChunkRepository, TranscriptCueRepository,
+    UserPreferencesRepository, VideoRepository,
 };
 pub use stream::StreamResolver;
-pub use transcript::{TranscriptError, TranscriptProvider};
-pub use youtube::{FetchError, PlaylistFetcher, RawVideoMetadata};
+pub use transcript::{TranscriptCue, TranscriptError, TranscriptProvider};
+pub use youtube::{FetchError, PlaylistFetcher, RawChapter, RawVideoMetadata};
@@ -0,0 +1,53 @@
+//! Playback Port - Persistence of per-video resume positions and watch sessions.
+
+use chrono::{DateTime, Utc};
+
+use crate::domain::ports::RepositoryError;
+use crate::domain::value_objects::{CourseId, VideoId, WatchedRanges};
+
+/// Where the learner stopped watching a video.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PlaybackPosition {
+    pub video_id: VideoId,
+    pub position_ms: u64,
+    /// Sections of the video that were actually played.
+    pub watched: WatchedRanges,
+    pub updated_at: DateTime<Utc>,
+}
+
+pub trait PlaybackPositionRepository: Send + Sync {
+    /// Inserts or replaces the position for the video.
+    fn save(&self, position: &PlaybackPosition) -> Result<(), RepositoryError>;
+    fn find_by_video(
+        &self,
+        video_id: &VideoId,
+    ) -> Result<Option<PlaybackPosition>, RepositoryError>;
+    /// The most recently updated position among the course's videos.
+    fn find_latest_by_course(
+        &self,
+        course_id: &CourseId,
+    ) -> Result<Option<PlaybackPosition>, RepositoryError>;
+    fn delete(&self, video_id: &VideoId) -> Result<(), RepositoryError>;
+}
+
+/// One continuous stretch of watching a video, from play until pause, stop or switch.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WatchSession {
+    pub video_id: VideoId,
+    pub started_at: DateTime<Utc>,
+    pub ended_at: DateTime<Utc>,
+    /// Seconds of video actually played; seeks and pauses are not counted.
+    pub played_secs: u32,
+}
+
+pub trait WatchSessionRepository: Send + Sync {
+    fn record(&self, session: &WatchSession) -> Result<(), RepositoryError>;
+    /// Sessions started at or after `since`, oldest first.
+    fn find_since(&self, since: DateTime<Utc>) -> Result<Vec<WatchSession>, RepositoryError>;
+    /// Sessions of the course's videos started at or after `since`, oldest first.
+    fn find_by_course_since(
+        &self,
+        course_id: &CourseId,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<WatchSession>, RepositoryError>;
+}
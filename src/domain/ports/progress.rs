@@ -0,0 +1,85 @@
+//! Progress reporting and cancellation for long-running imports.
+
+use std::fmt;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// A step of a course import, with counts where the step works item by item.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IngestProgress {
+    /// Fetching the playlist listing.
+    Fetching,
+    /// Reading duration and chapters of the media files found.
+    Discovering { done: usize, total: usize },
+    /// Extracting subtitles embedded in media files.
+    ExtractingSubtitles { done: usize, total: usize },
+    /// Naming the proposed modules.
+    GeneratingTitles { done: usize, total: usize },
+    /// Saving the course.
+    Persisting,
+}
+
+impl IngestProgress {
+    /// Completed share of the current step, when it is counted.
+    pub fn fraction(&self) -> Option<f64> {
+        match *self {
+            Self::Discovering { done, total }
+            | Self::ExtractingSubtitles { done, total }
+            | Self::GeneratingTitles { done, total }
+                if total > 0 =>
+            {
+                Some(done.min(total) as f64 / total as f64)
+            },
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for IngestProgress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Fetching => write!(f, "Fetching playlist..."),
+            Self::Discovering { done, total } => write!(f, "Reading media files ({done}/{total})"),
+            Self::ExtractingSubtitles { done, total } => {
+                write!(f, "Extracting subtitles ({done}/{total})")
+            },
+            Self::GeneratingTitles { done, total } => {
+                write!(f, "Generating module titles ({done}/{total})")
+            },
+            Self::Persisting => write!(f, "Saving course..."),
+        }
+    }
+}
+
+/// Receives import progress. Called from the task doing the work.
+pub trait ProgressSink: Send + Sync {
+    fn report(&self, progress: IngestProgress);
+}
+
+impl<F: Fn(IngestProgress) + Send + Sync> ProgressSink for F {
+    fn report(&self, progress: IngestProgress) {
+        self(progress)
+    }
+}
+
+/// Cooperative cancellation flag shared between a running import and whoever may stop it.
+///
+/// Work checks the token between steps, so a cancelled import stops at the next item
+/// rather than immediately.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Asks the work holding this token (or a clone of it) to stop.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+}
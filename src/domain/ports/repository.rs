@@ -1,7 +1,14 @@
 //! Repository ports for persistence.
 
-use crate::domain::entities::{Course, Exam, Module, Note, Tag, Video};
-use crate::domain::value_objects::{CourseId, ExamId, ModuleId, TagId, UserId, VideoId};
+use chrono::NaiveDate;
+
+use crate::domain::entities::{
+    Course, Exam, Flashcard, Module, Note, ReviewCard, StudySchedule, Tag, Video,
+};
+use crate::domain::ports::TranscriptCue;
+use crate::domain::value_objects::{
+    CourseId, ExamId, FlashcardId, KeyTerm, ModuleId, ReviewCardId, TagId, UserId, VideoId,
+};
 
 /// Error type for repository operations.
 #[derive(Debug, thiserror::Error)]
@@ -25,6 +32,13 @@ pub enum RepositoryError {
 pub trait CourseRepository: Send + Sync {
     fn save(&self, course: &Course) -> Result<(), RepositoryError>;
     fn save_batch(&self, courses: &[Course]) -> Result<(), RepositoryError>;
+    /// Saves a course together with its modules and videos in one transaction.
+    fn save_with_contents(
+        &self,
+        course: &Course,
+        modules: &[Module],
+        videos: &[Video],
+    ) -> Result<(), RepositoryError>;
     fn find_by_id(&self, id: &CourseId) -> Result<Option<Course>, RepositoryError>;
     fn find_by_source_hash(&self, hash: &str) -> Result<Option<Course>, RepositoryError>;
     fn find_all(&self) -> Result<Vec<Course>, RepositoryError>;
@@ -61,6 +75,13 @@ pub trait VideoRepository: Send + Sync {
         transcript: Option<&str>,
     ) -> Result<(), RepositoryError>;
     fn update_summary(&self, id: &VideoId, summary: Option<&str>) -> Result<(), RepositoryError>;
+    /// Replaces the video's key points and glossary terms.
+    fn update_key_content(
+        &self,
+        id: &VideoId,
+        key_points: &[String],
+        key_terms: &[KeyTerm],
+    ) -> Result<(), RepositoryError>;
     fn update_module(
         &self,
         id: &VideoId,
@@ -123,6 +144,51 @@ pub trait TagRepository: Send + Sync {
     fn delete(&self, tag_id: &TagId) -> Result<(), RepositoryError>;
 }
 
+/// Repository for per-course study schedules.
+pub trait StudyScheduleRepository: Send + Sync {
+    /// Saves a schedule, replacing any existing plan for the same course.
+    fn save(&self, schedule: &StudySchedule) -> Result<(), RepositoryError>;
+
+    /// Finds the schedule for a course.
+    fn find_by_course(
+        &self,
+        course_id: &CourseId,
+    ) -> Result<Option<StudySchedule>, RepositoryError>;
+
+    /// Finds all stored schedules.
+    fn find_all(&self) -> Result<Vec<StudySchedule>, RepositoryError>;
+
+    /// Deletes the schedule for a course.
+    fn delete(&self, course_id: &CourseId) -> Result<(), RepositoryError>;
+}
+
+/// Repository for spaced-repetition review cards.
+pub trait ReviewCardRepository: Send + Sync {
+    /// Saves a card, replacing any existing card with the same ID.
+    fn save(&self, card: &ReviewCard) -> Result<(), RepositoryError>;
+
+    fn find_by_id(&self, id: &ReviewCardId) -> Result<Option<ReviewCard>, RepositoryError>;
+
+    /// Finds the cards promoted from an exam, in question order.
+    fn find_by_exam(&self, exam_id: &ExamId) -> Result<Vec<ReviewCard>, RepositoryError>;
+
+    /// Finds cards due on or before `date`, earliest due first.
+    fn find_due(&self, date: NaiveDate) -> Result<Vec<ReviewCard>, RepositoryError>;
+}
+
+/// Repository for per-video flashcards.
+pub trait FlashcardRepository: Send + Sync {
+    /// Saves a card, replacing any existing card with the same ID.
+    fn save(&self, card: &Flashcard) -> Result<(), RepositoryError>;
+
+    fn find_by_id(&self, id: &FlashcardId) -> Result<Option<Flashcard>, RepositoryError>;
+
+    /// Finds a video's cards in display order.
+    fn find_by_video(&self, video_id: &VideoId) -> Result<Vec<Flashcard>, RepositoryError>;
+
+    fn delete(&self, id: &FlashcardId) -> Result<(), RepositoryError>;
+}
+
 /// Repository for user preferences.
 pub trait UserPreferencesRepository: Send + Sync {
     fn load(
@@ -184,3 +250,53 @@ pub trait SearchRepository: Send + Sync {
     /// Removes an entity from the search index.
     fn remove_from_index(&self, entity_id: &str) -> Result<(), RepositoryError>;
 }
+
+/// A passage of a video's transcript, as indexed for companion retrieval.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TranscriptChunk {
+    /// Position of the chunk in the transcript, from 0.
+    pub index: usize,
+    pub content: String,
+}
+
+/// Repository for the per-video index of transcript chunks.
+pub trait TranscriptChunkRepository: Send + Sync {
+    /// Hash of the transcript the video's chunks were cut from, if it has been indexed.
+    fn indexed_hash(&self, video_id: &VideoId) -> Result<Option<String>, RepositoryError>;
+
+    /// Replaces the video's chunks in a single transaction.
+    fn replace_chunks(
+        &self,
+        video_id: &VideoId,
+        transcript_hash: &str,
+        chunks: &[String],
+    ) -> Result<(), RepositoryError>;
+
+    /// Finds up to `limit` of the video's chunks relevant to `query`, best match first.
+    fn search(
+        &self,
+        video_id: &VideoId,
+        query: &str,
+        limit: usize,
+    ) -> Result<Vec<TranscriptChunk>, RepositoryError>;
+
+    /// Removes the video's chunks from the index.
+    fn remove_video(&self, video_id: &VideoId) -> Result<(), RepositoryError>;
+}
+
+/// Repository for the timed caption cues of video transcripts.
+pub trait TranscriptCueRepository: Send + Sync {
+    /// Replaces the video's cues in a single transaction, recording the hash of their source.
+    fn replace_for_video(
+        &self,
+        video_id: &VideoId,
+        source_hash: &str,
+        cues: &[TranscriptCue],
+    ) -> Result<(), RepositoryError>;
+
+    /// Hash of the caption source the video's stored cues came from, if it has any.
+    fn source_hash(&self, video_id: &VideoId) -> Result<Option<String>, RepositoryError>;
+
+    /// Finds the video's cues in playback order.
+    fn find_by_video(&self, video_id: &VideoId) -> Result<Vec<TranscriptCue>, RepositoryError>;
+}
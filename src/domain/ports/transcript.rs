@@ -11,9 +11,24 @@ pub enum TranscriptError {
     Provider(String),
 }
 
+/// A caption cue: text shown between two points of the video.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TranscriptCue {
+    pub start_ms: u64,
+    pub end_ms: u64,
+    pub text: String,
+}
+
 /// Port for fetching transcripts for videos.
 #[async_trait::async_trait]
 pub trait TranscriptProvider: Send + Sync {
     /// Fetch the transcript for a given video ID.
     async fn fetch_transcript(&self, video_id: &str) -> Result<String, TranscriptError>;
+
+    /// Fetch the transcript as timed cues, in playback order.
+    ///
+    /// Providers without timings keep the default, which reports none available.
+    async fn fetch_cues(&self, _video_id: &str) -> Result<Vec<TranscriptCue>, TranscriptError> {
+        Err(TranscriptError::NotAvailable)
+    }
 }
@@ -10,6 +10,16 @@ pub struct RawVideoMetadata {
     pub description: Option<String>,
     pub duration_secs: u32,
     pub position: u32,
+    /// Chapters listed for the video, in order. Empty when it has none.
+    pub chapters: Vec<RawChapter>,
+}
+
+/// A chapter of a video, from YouTube chapter markers or a container table of contents.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RawChapter {
+    pub title: String,
+    pub start_secs: u32,
+    pub end_secs: u32,
 }
 
 /// Error type for playlist fetching.
@@ -32,4 +42,13 @@ pub enum FetchError {
 pub trait PlaylistFetcher: Send + Sync {
     /// Fetches all videos from a playlist.
     async fn fetch_playlist(&self, url: &PlaylistUrl) -> Result<Vec<RawVideoMetadata>, FetchError>;
+
+    /// Like `fetch_playlist`, but with each video's full metadata, including chapters.
+    /// Slower for playlists, whose quick listing leaves chapters out.
+    async fn fetch_playlist_with_chapters(
+        &self,
+        url: &PlaylistUrl,
+    ) -> Result<Vec<RawVideoMetadata>, FetchError> {
+        self.fetch_playlist(url).await
+    }
 }
@@ -2,24 +2,45 @@
 
 use std::collections::BTreeSet;
 
+use super::topic_segmenter::TopicSegmenter;
+
+/// How videos are grouped into modules on import.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GroupingStrategy {
+    /// Numbering and labels in the titles (`1.2`, `Module 3`), then fixed-size batches.
+    #[default]
+    TitlePatterns,
+    /// Topic shifts in titles and descriptions, then title patterns on weak signal.
+    TopicShift,
+}
+
 /// Groups videos into modules using title-aware patterns with a batch-size fallback.
 /// - Detects hierarchical numbering: `1.5`, `1.5.1`
 /// - Detects labeled patterns: `Module 2`, `Chapter 3.1`, `Week 4`
 /// - Handles hybrid mixes (labels + dotted numbers + plain leading numbers)
+/// - With a target duration, weak-signal batches are balanced by minutes instead of count
 #[derive(Debug)]
 pub struct BoundaryDetector {
     batch_size: usize,
+    target_secs: Option<u32>,
 }
 
 impl BoundaryDetector {
     /// Creates a boundary detector with default batch size (5 videos per module).
     pub fn new() -> Self {
-        Self { batch_size: 5 }
+        Self { batch_size: 5, target_secs: None }
     }
 
     /// Creates a boundary detector with a custom batch size.
     pub fn with_batch_size(batch_size: usize) -> Self {
-        Self { batch_size: batch_size.max(1) }
+        Self { batch_size: batch_size.max(1), target_secs: None }
+    }
+
+    /// Aims batches at `minutes` of total video duration instead of a fixed video count.
+    /// `0` keeps count-based batches.
+    pub fn with_target_minutes(mut self, minutes: u32) -> Self {
+        self.target_secs = (minutes > 0).then(|| minutes.saturating_mul(60));
+        self
     }
 
     /// Groups video indices into modules (each module has up to `batch_size` videos).
@@ -43,9 +64,91 @@ impl BoundaryDetector {
         groups
     }
 
+    /// Groups consecutive videos into modules of roughly the target duration.
+    ///
+    /// A module is closed once it reaches the target, or before a video that would overshoot
+    /// it by more than the module currently falls short. Unknown durations (`0`) count as the
+    /// average known duration. Falls back to `group_into_modules` without a target or when
+    /// most durations are unknown.
+    pub fn group_by_duration(&self, durations_secs: &[u32]) -> Vec<Vec<usize>> {
+        let Some(average) = self.average_known_duration(durations_secs) else {
+            return self.group_into_modules(durations_secs.len());
+        };
+        self.pack_by_duration(0..durations_secs.len(), durations_secs, average)
+    }
+
+    /// Packs `indices` into consecutive modules of roughly the target duration, counting
+    /// unknown durations as `average`.
+    fn pack_by_duration(
+        &self,
+        indices: impl IntoIterator<Item = usize>,
+        durations_secs: &[u32],
+        average: u64,
+    ) -> Vec<Vec<usize>> {
+        let target = u64::from(self.target_secs.unwrap_or_default());
+
+        let mut groups = Vec::new();
+        let mut current = Vec::new();
+        let mut current_secs = 0u64;
+        for i in indices {
+            let secs = match durations_secs[i] {
+                0 => average,
+                secs => u64::from(secs),
+            };
+            let overshoot = (current_secs + secs).saturating_sub(target);
+            let shortfall = target.saturating_sub(current_secs);
+            if !current.is_empty() && overshoot > shortfall {
+                groups.push(std::mem::take(&mut current));
+                current_secs = 0;
+            }
+            current.push(i);
+            current_secs += secs;
+            if current_secs >= target {
+                groups.push(std::mem::take(&mut current));
+                current_secs = 0;
+            }
+        }
+        if !current.is_empty() {
+            groups.push(current);
+        }
+        groups
+    }
+
+    /// Groups videos into modules with the given strategy.
+    ///
+    /// `texts` holds the text compared for [`GroupingStrategy::TopicShift`] (typically title
+    /// plus description) and `durations_secs` the video lengths used with a target duration.
+    /// Both must line up with `titles`; pass an empty slice to leave either out.
+    pub fn group<T: AsRef<str>, U: AsRef<str>>(
+        &self,
+        strategy: GroupingStrategy,
+        titles: &[T],
+        texts: &[U],
+        durations_secs: &[u32],
+    ) -> Vec<Vec<usize>> {
+        if strategy == GroupingStrategy::TopicShift && texts.len() == titles.len() {
+            let batch_size = self.duration_batch_size(durations_secs).unwrap_or(self.batch_size);
+            if let Some(groups) = TopicSegmenter::with_batch_size(batch_size).segment(texts) {
+                return groups;
+            }
+        }
+        self.group_by_titles_and_durations(titles, durations_secs)
+    }
+
     /// Groups videos into modules using title-aware boundary detection.
     /// Falls back to `group_into_modules` if signal is weak or ambiguous.
     pub fn group_by_titles<T: AsRef<str>>(&self, titles: &[T]) -> Vec<Vec<usize>> {
+        self.group_by_titles_and_durations(titles, &[])
+    }
+
+    /// Like `group_by_titles`, but balances modules by duration when `durations_secs` lines up
+    /// with `titles`: weak signals fall back to `group_by_duration`, and title modules longer
+    /// than the target are split by duration without crossing their title boundaries.
+    pub fn group_by_titles_and_durations<T: AsRef<str>>(
+        &self,
+        titles: &[T],
+        durations_secs: &[u32],
+    ) -> Vec<Vec<usize>> {
         if titles.is_empty() {
             return vec![];
         }
@@ -60,14 +163,13 @@ impl BoundaryDetector {
         let distinct_majors: BTreeSet<u32> =
             keys.iter().filter_map(|k| k.as_ref().map(|key| key.major)).collect();
 
-        // Weak signal: fallback to batch grouping.
-        if matched < 2 || matched_ratio < 0.5 {
-            return self.group_into_modules(total);
-        }
-
-        // Only one major detected: fallback to batch grouping.
-        if distinct_majors.len() <= 1 {
-            return self.group_into_modules(total);
+        // Weak signal, or only one major detected: fallback to batch grouping.
+        if matched < 2 || matched_ratio < 0.5 || distinct_majors.len() <= 1 {
+            return if durations_secs.len() == total {
+                self.group_by_duration(durations_secs)
+            } else {
+                self.group_into_modules(total)
+            };
         }
 
         // Split on major changes in observed order.
@@ -94,8 +196,36 @@ impl BoundaryDetector {
             groups.push(current_group);
         }
 
+        // Split title modules that run past the target, keeping their title boundaries.
+        if durations_secs.len() == total
+            && let Some(average) = self.average_known_duration(durations_secs)
+        {
+            return groups
+                .into_iter()
+                .flat_map(|group| self.pack_by_duration(group, durations_secs, average))
+                .collect();
+        }
+
         groups
     }
+
+    /// Average of the known durations, if a target is set and most durations are known.
+    fn average_known_duration(&self, durations_secs: &[u32]) -> Option<u64> {
+        self.target_secs?;
+        let known: Vec<u64> =
+            durations_secs.iter().filter(|&&d| d > 0).map(|&d| u64::from(d)).collect();
+        if known.is_empty() || known.len() * 2 < durations_secs.len() {
+            return None;
+        }
+        Some(known.iter().sum::<u64>() / known.len() as u64)
+    }
+
+    /// Video count per module that matches the target duration on average.
+    fn duration_batch_size(&self, durations_secs: &[u32]) -> Option<usize> {
+        let average = self.average_known_duration(durations_secs)?;
+        let target = u64::from(self.target_secs?);
+        Some(((target + average / 2) / average.max(1)).max(1) as usize)
+    }
 }
 
 impl Default for BoundaryDetector {
@@ -436,6 +566,52 @@ mod tests {
         assert_eq!(groups[1], vec![2, 3]);
     }
 
+    #[test]
+    fn topic_shift_falls_back_to_title_patterns() {
+        let titles = vec!["1.1 Welcome", "1.2 Graphs", "2.1 Sorting", "2.2 Wrap up"];
+        let detector = BoundaryDetector::with_batch_size(5);
+        let groups = detector.group(GroupingStrategy::TopicShift, &titles, &titles, &[]);
+
+        assert_eq!(groups, vec![vec![0, 1], vec![2, 3]]);
+    }
+
+    #[test]
+    fn duration_target_balances_minutes_instead_of_count() {
+        let detector = BoundaryDetector::with_batch_size(5).with_target_minutes(30);
+        let durations = [600, 900, 600, 3000, 300, 300, 600, 1200];
+        let groups = detector.group_by_duration(&durations);
+
+        assert_eq!(groups, vec![vec![0, 1, 2], vec![3], vec![4, 5, 6, 7]]);
+    }
+
+    #[test]
+    fn duration_target_falls_back_to_count_when_durations_unknown() {
+        let detector = BoundaryDetector::with_batch_size(2).with_target_minutes(30);
+        let groups = detector.group_by_duration(&[0, 0, 600, 0, 0]);
+
+        assert_eq!(groups, vec![vec![0, 1], vec![2, 3], vec![4]]);
+    }
+
+    #[test]
+    fn duration_target_keeps_title_boundaries() {
+        let titles = vec!["1.1 Intro", "1.2 Setup", "2.1 Basics", "2.2 Advanced", "3.1 Wrap up"];
+        let detector = BoundaryDetector::with_batch_size(5).with_target_minutes(30);
+        let groups = detector.group_by_titles_and_durations(&titles, &[300, 300, 300, 300, 300]);
+
+        assert_eq!(groups, vec![vec![0, 1], vec![2, 3], vec![4]]);
+    }
+
+    #[test]
+    fn duration_target_splits_oversized_title_modules() {
+        let titles =
+            vec!["1.1 Intro", "1.2 Setup", "1.3 Tools", "1.4 Testing", "2.1 Basics", "2.2 Wrap up"];
+        let detector = BoundaryDetector::with_batch_size(5).with_target_minutes(40);
+        let durations = [1200, 1200, 0, 1200, 300, 300];
+        let groups = detector.group_by_titles_and_durations(&titles, &durations);
+
+        assert_eq!(groups, vec![vec![0, 1], vec![2, 3], vec![4, 5]]);
+    }
+
     #[test]
     fn test_title_number_sequence_various_formats() {
         assert_eq!(title_number_sequence("Module 1 - 2 - 3"), Some(vec![1, 2, 3]));
@@ -0,0 +1,193 @@
+//! Conversation Window - Fits companion chat history into a token budget.
+
+use crate::domain::ports::{ChatRole, ConversationHistory, ConversationTurn};
+
+/// Longest question kept in a summary line, in characters.
+const SUMMARY_QUESTION_CHARS: usize = 100;
+/// Longest answer excerpt kept in a summary line, in characters.
+const SUMMARY_ANSWER_CHARS: usize = 160;
+
+/// Chooses which earlier turns of a conversation go along with a new question.
+///
+/// The most recent turns are sent verbatim while they fit in three quarters of the budget.
+/// Older turns are condensed into one line per exchange (the question and the first
+/// sentence of its answer), newest first, in whatever budget is left. The summary is
+/// extractive, so building the window needs no extra LLM call.
+#[derive(Debug, Clone)]
+pub struct ConversationWindow {
+    token_budget: usize,
+}
+
+impl ConversationWindow {
+    /// Default budget in tokens for the history sent with a question.
+    pub const DEFAULT_TOKEN_BUDGET: usize = 1500;
+
+    /// Creates a window with the default budget.
+    pub fn new() -> Self {
+        Self { token_budget: Self::DEFAULT_TOKEN_BUDGET }
+    }
+
+    /// Creates a window with a budget of `token_budget` tokens.
+    pub fn with_budget(token_budget: usize) -> Self {
+        Self { token_budget }
+    }
+
+    /// Rough token count of `text`: one token per four characters.
+    pub fn estimate_tokens(text: &str) -> usize {
+        text.chars().count().div_ceil(4)
+    }
+
+    /// Builds the history for `turns` (oldest first).
+    pub fn build(&self, mut turns: Vec<ConversationTurn>) -> ConversationHistory {
+        let total: usize = turns.iter().map(|t| Self::estimate_tokens(&t.content)).sum();
+        if total <= self.token_budget {
+            return ConversationHistory { summary: None, turns };
+        }
+
+        // Newest turns first, while they fit in the verbatim share.
+        let verbatim_budget = self.token_budget * 3 / 4;
+        let mut used = 0;
+        let mut split = turns.len();
+        for (i, turn) in turns.iter().enumerate().rev() {
+            let tokens = Self::estimate_tokens(&turn.content);
+            if used + tokens > verbatim_budget {
+                break;
+            }
+            used += tokens;
+            split = i;
+        }
+        // The window opens on a question, so an answer is never shown without it.
+        while split < turns.len() && turns[split].role == ChatRole::Assistant {
+            used -= Self::estimate_tokens(&turns[split].content);
+            split += 1;
+        }
+        let recent = turns.split_off(split);
+
+        ConversationHistory {
+            summary: summarize(&turns, self.token_budget.saturating_sub(used)),
+            turns: recent,
+        }
+    }
+}
+
+impl Default for ConversationWindow {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// One line per exchange, newest kept first, within `token_budget`.
+fn summarize(turns: &[ConversationTurn], token_budget: usize) -> Option<String> {
+    let mut exchanges: Vec<(Option<&str>, Option<&str>)> = Vec::new();
+    for turn in turns {
+        match turn.role {
+            ChatRole::User => exchanges.push((Some(turn.content.as_str()), None)),
+            ChatRole::Assistant => match exchanges.last_mut() {
+                Some((_, answer @ None)) => *answer = Some(turn.content.as_str()),
+                _ => exchanges.push((None, Some(turn.content.as_str()))),
+            },
+        }
+    }
+    if exchanges.is_empty() {
+        return None;
+    }
+
+    let mut lines = Vec::new();
+    let mut used = 0;
+    for (question, answer) in exchanges.iter().rev() {
+        let question = truncate(question.unwrap_or("(no question)"), SUMMARY_QUESTION_CHARS);
+        let line = match answer {
+            Some(answer) => {
+                format!("- Asked \"{question}\"; answered: {}", first_sentence(answer))
+            },
+            None => format!("- Asked \"{question}\" (no answer)"),
+        };
+        let tokens = ConversationWindow::estimate_tokens(&line);
+        if used + tokens > token_budget {
+            break;
+        }
+        used += tokens;
+        lines.push(line);
+    }
+
+    let omitted = exchanges.len() - lines.len();
+    lines.reverse();
+    if omitted > 0 {
+        lines.insert(0, format!("({omitted} earlier exchanges not shown)"));
+    }
+    Some(lines.join("\n"))
+}
+
+/// The first sentence of `text`, shortened to the summary length.
+fn first_sentence(text: &str) -> String {
+    let text = text.trim();
+    let end = text.find(". ").or_else(|| text.find('\n')).map_or(text.len(), |i| i + 1);
+    truncate(&text[..end], SUMMARY_ANSWER_CHARS)
+}
+
+fn truncate(text: &str, limit: usize) -> String {
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if text.chars().count() <= limit {
+        text
+    } else {
+        let mut out: String = text.chars().take(limit).collect();
+        out.push('…');
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn turn(role: ChatRole, content: &str) -> ConversationTurn {
+        ConversationTurn { role, content: content.to_string() }
+    }
+
+    fn conversation(exchanges: usize) -> Vec<ConversationTurn> {
+        (1..=exchanges)
+            .flat_map(|i| {
+                [
+                    turn(ChatRole::User, &format!("Question {i}?")),
+                    turn(
+                        ChatRole::Assistant,
+                        &format!("Answer {i}. {}", "More detail. ".repeat(20)),
+                    ),
+                ]
+            })
+            .collect()
+    }
+
+    #[test]
+    fn short_conversations_are_sent_whole() {
+        let turns = conversation(2);
+        let history = ConversationWindow::new().build(turns.clone());
+        assert_eq!(history, ConversationHistory { summary: None, turns });
+    }
+
+    #[test]
+    fn older_turns_are_summarised_once_over_budget() {
+        let window = ConversationWindow::with_budget(300);
+        let history = window.build(conversation(6));
+
+        // Recent turns are verbatim, open on a question and fit their share of the budget
+        assert_eq!(history.turns.first().map(|t| t.role), Some(ChatRole::User));
+        assert_eq!(history.turns.last().unwrap().content, conversation(6)[11].content);
+        let verbatim: usize =
+            history.turns.iter().map(|t| ConversationWindow::estimate_tokens(&t.content)).sum();
+        assert!(verbatim <= 225);
+
+        let summary = history.summary.unwrap();
+        assert!(summary.contains("- Asked \"Question 1?\"; answered: Answer 1."), "{summary}");
+        assert!(!summary.contains("More detail"));
+    }
+
+    #[test]
+    fn summary_drops_the_oldest_exchanges_when_even_it_is_too_long() {
+        let history = ConversationWindow::with_budget(120).build(conversation(12));
+        let summary = history.summary.unwrap();
+        assert!(summary.starts_with('('), "{summary}");
+        assert!(!summary.contains("\"Question 1?\""));
+        assert!(summary.contains("\"Question 11?\"") || summary.contains("\"Question 10?\""));
+    }
+}
@@ -1,13 +1,21 @@
 //! Domain Services - Pure business logic.
 
 mod boundary_detector;
+mod conversation_window;
+mod rescheduler;
 mod sanitizer;
 mod session_planner;
 mod subtitle_cleaner;
+mod topic_segmenter;
 mod transcript_chunker;
+mod transcript_window;
 
-pub use boundary_detector::{BoundaryDetector, title_number_sequence};
+pub use boundary_detector::{BoundaryDetector, GroupingStrategy, title_number_sequence};
+pub use conversation_window::ConversationWindow;
+pub use rescheduler::{Rescheduler, ScheduleDiff, VideoMove, module_boundaries};
 pub use sanitizer::TitleSanitizer;
 pub use session_planner::SessionPlanner;
 pub use subtitle_cleaner::SubtitleCleaner;
+pub use topic_segmenter::TopicSegmenter;
 pub use transcript_chunker::TranscriptChunker;
+pub use transcript_window::TranscriptWindow;
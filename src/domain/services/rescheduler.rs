@@ -0,0 +1,310 @@
+//! Rescheduler - Re-plans a study schedule that has drifted from the calendar.
+
+use std::collections::{HashMap, HashSet};
+
+use chrono::{Days, NaiveDate};
+
+use crate::domain::entities::{StudySchedule, StudySession, Video};
+use crate::domain::services::SessionPlanner;
+use crate::domain::value_objects::{CognitiveLimit, ReschedulePolicy, VideoId};
+
+/// Step used when raising the session load to hold a deadline.
+const LOAD_STEP_MINUTES: u32 = 5;
+
+/// A video whose planned date changed during rescheduling.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VideoMove {
+    pub video_id: VideoId,
+    /// Previously planned date, or `None` if the video was not in the old plan.
+    pub from: Option<NaiveDate>,
+    pub to: NaiveDate,
+}
+
+/// What changed between an old schedule and its re-planned version.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScheduleDiff {
+    pub moved: Vec<VideoMove>,
+    /// Videos completed ahead of their planned date, now dropped from the plan.
+    pub completed_early: Vec<VideoId>,
+    pub previous_end_date: Option<NaiveDate>,
+    pub new_end_date: Option<NaiveDate>,
+    /// Per-session load actually used for the remaining sessions.
+    pub effective_limit_minutes: u32,
+}
+
+impl ScheduleDiff {
+    /// Returns true if nothing moved and nothing was dropped.
+    pub fn is_empty(&self) -> bool {
+        self.moved.is_empty() && self.completed_early.is_empty()
+    }
+
+    /// Days the end date moved by (positive = later).
+    pub fn end_date_shift_days(&self) -> i64 {
+        match (self.previous_end_date, self.new_end_date) {
+            (Some(old), Some(new)) => (new - old).num_days(),
+            _ => 0,
+        }
+    }
+}
+
+/// Re-plans the remaining videos of a schedule against today's date.
+pub struct Rescheduler;
+
+impl Rescheduler {
+    /// Returns true if the learner is behind or ahead of `schedule` on `today`.
+    ///
+    /// Behind: an incomplete video is planned before today, or not planned at all.
+    /// Ahead: a completed video is planned after today.
+    pub fn is_off_track(schedule: &StudySchedule, videos: &[Video], today: NaiveDate) -> bool {
+        videos.iter().any(|video| match (schedule.date_of(video.id()), video.is_completed()) {
+            (Some(date), false) => date < today,
+            (Some(date), true) => date > today,
+            (None, completed) => !completed,
+        })
+    }
+
+    /// Re-plans the incomplete `videos` (in course order) of `schedule` from `today`.
+    ///
+    /// Past sessions keep only what was completed. A session on `today` that has
+    /// already been started is kept as is. Everything else is re-planned with
+    /// `SessionPlanner` onto the schedule's weekly study days.
+    pub fn reschedule(
+        schedule: &StudySchedule,
+        videos: &[Video],
+        today: NaiveDate,
+        policy: ReschedulePolicy,
+    ) -> (StudySchedule, ScheduleDiff) {
+        let start = schedule.start_date();
+        let completed: HashSet<VideoId> =
+            videos.iter().filter(|v| v.is_completed()).map(|v| *v.id()).collect();
+        let durations: HashMap<VideoId, u32> =
+            videos.iter().map(|v| (*v.id(), v.duration_secs())).collect();
+
+        let today_started = schedule
+            .session_on(today)
+            .is_some_and(|s| s.video_ids().iter().any(|id| completed.contains(id)));
+        let mut anchor = today.max(start);
+        if today_started && anchor == today {
+            anchor = today.succ_opt().unwrap_or(today);
+        }
+
+        let mut sessions: Vec<StudySession> = Vec::new();
+        let mut kept: HashSet<VideoId> = HashSet::new();
+        for session in schedule.sessions().iter().filter(|s| s.date() < anchor) {
+            let ids: Vec<VideoId> = if session.date() == today {
+                session.video_ids().to_vec()
+            } else {
+                session.video_ids().iter().filter(|id| completed.contains(id)).copied().collect()
+            };
+            if ids.is_empty() {
+                continue;
+            }
+            let total = ids.iter().filter_map(|id| durations.get(id)).sum();
+            kept.extend(ids.iter().copied());
+            sessions.push(StudySession::new(session.day(), session.date(), ids, total));
+        }
+
+        let remaining: Vec<&Video> =
+            videos.iter().filter(|v| !v.is_completed() && !kept.contains(v.id())).collect();
+        let remaining_durations: Vec<u32> = remaining.iter().map(|v| v.duration_secs()).collect();
+        let boundaries =
+            module_boundaries(&remaining.iter().map(|v| *v.module_id()).collect::<Vec<_>>());
+
+        let mut limit = CognitiveLimit::new(schedule.cognitive_limit_minutes());
+        let plan_with = |limit| {
+            SessionPlanner::new(limit).plan_sessions(&remaining_durations, Some(&boundaries), 7)
+        };
+        let mut plans = plan_with(limit);
+
+        if policy == ReschedulePolicy::KeepDeadline
+            && let Some(deadline) = schedule.end_date()
+        {
+            let available =
+                study_dates(schedule, anchor).take_while(|date| *date <= deadline).count();
+            let ceiling = remaining_durations.iter().sum::<u32>().div_ceil(60) * 2;
+            while available > 0 && plans.len() > available && limit.minutes() < ceiling {
+                limit = CognitiveLimit::new(limit.minutes() + LOAD_STEP_MINUTES);
+                plans = plan_with(limit);
+            }
+        }
+
+        for (plan, date) in plans.iter().zip(study_dates(schedule, anchor)) {
+            let ids = plan.video_indices.iter().map(|&i| *remaining[i].id()).collect();
+            let day = (date - start).num_days() as u32 + 1;
+            sessions.push(StudySession::new(day, date, ids, plan.total_duration_secs));
+        }
+
+        let rescheduled = StudySchedule::new(
+            *schedule.course_id(),
+            start,
+            schedule.study_days_per_week(),
+            schedule.cognitive_limit_minutes(),
+            sessions,
+        )
+        .with_reschedule_policy(policy)
+        .with_peak_session_count(schedule.peak_session_count());
+
+        let moved = remaining
+            .iter()
+            .filter_map(|video| {
+                let to = rescheduled.date_of(video.id())?;
+                let from = schedule.date_of(video.id());
+                (from != Some(to)).then_some(VideoMove { video_id: *video.id(), from, to })
+            })
+            .collect();
+        let completed_early = videos
+            .iter()
+            .filter(|v| v.is_completed() && !kept.contains(v.id()))
+            .filter(|v| schedule.date_of(v.id()).is_some_and(|date| date >= anchor))
+            .map(|v| *v.id())
+            .collect();
+
+        let diff = ScheduleDiff {
+            moved,
+            completed_early,
+            previous_end_date: schedule.end_date(),
+            new_end_date: rescheduled.end_date(),
+            effective_limit_minutes: limit.minutes(),
+        };
+        (rescheduled, diff)
+    }
+}
+
+/// Indices where a new group starts within an ordered list of keys,
+/// e.g. the module ids of a course's videos.
+pub fn module_boundaries<T: PartialEq>(keys: &[T]) -> Vec<usize> {
+    keys.windows(2)
+        .enumerate()
+        .filter(|(_, pair)| pair[0] != pair[1])
+        .map(|(idx, _)| idx + 1)
+        .collect()
+}
+
+/// Study dates of `schedule` on or after `from`, in order.
+fn study_dates(schedule: &StudySchedule, from: NaiveDate) -> impl Iterator<Item = NaiveDate> + '_ {
+    (0u64..)
+        .map_while(move |offset| from.checked_add_days(Days::new(offset)))
+        .filter(|date| schedule.is_study_day(*date))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::{CourseId, ModuleId, SessionPlan, VideoSource};
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn videos(count: usize, duration: u32) -> Vec<Video> {
+        let module_id = ModuleId::new();
+        (0..count)
+            .map(|idx| {
+                let source = VideoSource::local_path(format!("/videos/{idx}.mp4")).unwrap();
+                Video::new(
+                    VideoId::new(),
+                    module_id,
+                    source,
+                    format!("V{idx}"),
+                    duration,
+                    idx as u32,
+                )
+            })
+            .collect()
+    }
+
+    /// One 30-minute video per day, every day, starting 2026-06-01.
+    fn daily_schedule(videos: &[Video]) -> StudySchedule {
+        let ids: Vec<VideoId> = videos.iter().map(|v| *v.id()).collect();
+        let plans: Vec<SessionPlan> = (0..videos.len())
+            .map(|i| SessionPlan::new(i as u32 + 1, vec![i], videos[i].duration_secs()))
+            .collect();
+        StudySchedule::from_plans(CourseId::new(), date(2026, 6, 1), 7, 30, &plans, &ids)
+    }
+
+    #[test]
+    fn on_track_schedule_is_not_off_track() {
+        let mut videos = videos(4, 1800);
+        videos[0].mark_completed();
+        let schedule = daily_schedule(&videos);
+
+        assert!(!Rescheduler::is_off_track(&schedule, &videos, date(2026, 6, 2)));
+        assert!(Rescheduler::is_off_track(&schedule, &videos, date(2026, 6, 3)));
+    }
+
+    #[test]
+    fn keep_daily_load_shifts_missed_sessions_forward() {
+        let mut videos = videos(4, 1800);
+        videos[0].mark_completed();
+        let schedule = daily_schedule(&videos);
+
+        // Missed June 2 and 3.
+        let (rescheduled, diff) = Rescheduler::reschedule(
+            &schedule,
+            &videos,
+            date(2026, 6, 4),
+            ReschedulePolicy::KeepDailyLoad,
+        );
+
+        let dates: Vec<NaiveDate> = rescheduled.sessions().iter().map(|s| s.date()).collect();
+        assert_eq!(
+            dates,
+            vec![date(2026, 6, 1), date(2026, 6, 4), date(2026, 6, 5), date(2026, 6, 6)]
+        );
+        assert_eq!(diff.moved.len(), 3);
+        assert_eq!(diff.end_date_shift_days(), 2);
+        assert_eq!(diff.effective_limit_minutes, 30);
+        assert_eq!(rescheduled.sessions()[1].day(), 4);
+    }
+
+    #[test]
+    fn keep_deadline_raises_load_to_fit_remaining_days() {
+        let videos = videos(4, 1800);
+        let schedule = daily_schedule(&videos);
+
+        // Nothing watched yet, two days left before the June 4 deadline.
+        let (rescheduled, diff) = Rescheduler::reschedule(
+            &schedule,
+            &videos,
+            date(2026, 6, 3),
+            ReschedulePolicy::KeepDeadline,
+        );
+
+        assert_eq!(rescheduled.end_date(), Some(date(2026, 6, 4)));
+        assert_eq!(rescheduled.sessions().len(), 2);
+        assert_eq!(diff.effective_limit_minutes, 60);
+        assert_eq!(diff.end_date_shift_days(), 0);
+        assert_eq!(rescheduled.reschedule_policy(), ReschedulePolicy::KeepDeadline);
+    }
+
+    #[test]
+    fn getting_ahead_pulls_sessions_in_and_keeps_todays_session() {
+        let mut videos = videos(4, 1800);
+        videos[0].mark_completed();
+        videos[1].mark_completed();
+        let schedule = daily_schedule(&videos);
+
+        // Finished June 2's video already on June 1.
+        assert!(Rescheduler::is_off_track(&schedule, &videos, date(2026, 6, 1)));
+        let (rescheduled, diff) = Rescheduler::reschedule(
+            &schedule,
+            &videos,
+            date(2026, 6, 1),
+            ReschedulePolicy::KeepDailyLoad,
+        );
+
+        assert_eq!(diff.completed_early, vec![*videos[1].id()]);
+        assert_eq!(
+            rescheduled.session_on(date(2026, 6, 1)).unwrap().video_ids(),
+            &[*videos[0].id()]
+        );
+        assert_eq!(rescheduled.date_of(videos[2].id()), Some(date(2026, 6, 2)));
+        assert_eq!(rescheduled.end_date(), Some(date(2026, 6, 3)));
+    }
+
+    #[test]
+    fn module_boundaries_mark_key_changes() {
+        assert_eq!(module_boundaries(&[1, 1, 2, 2, 3]), vec![2, 4]);
+        assert!(module_boundaries::<u8>(&[]).is_empty());
+    }
+}
@@ -6,6 +6,8 @@
 
 use std::borrow::Cow;
 
+use crate::domain::ports::TranscriptCue;
+
 /// Cleans subtitle text into a compact, readable transcript.
 /// # Supported formats
 /// - SRT
@@ -43,15 +45,7 @@ impl SubtitleCleaner {
                 continue;
             }
 
-            // Strip inline tags like <i>...</i>
-            let cleaned = strip_inline_tags(line);
-
-            // Strip speaker indicators like "[Speaker]:" or ">>"
-            let cleaned = strip_speaker_labels(&cleaned);
-
-            // Normalize whitespace (internal and surrounding)
-            let cleaned = normalize_whitespace(&cleaned);
-
+            let cleaned = clean_line(line);
             if cleaned.is_empty() {
                 continue;
             }
@@ -69,6 +63,65 @@ impl SubtitleCleaner {
 
         out.join(" ")
     }
+
+    /// Cleans the provided subtitle content like [`clean`](Self::clean), but keeps each cue's
+    /// timing.
+    ///
+    /// Lines repeated from the previous cue (as in rolling auto-generated captions) are dropped,
+    /// and cues left without text are skipped.
+    pub fn cues(&self, raw: &str) -> Vec<TranscriptCue> {
+        let normalized = strip_bom(raw);
+        let mut cues: Vec<TranscriptCue> = Vec::new();
+        let mut current: Option<TranscriptCue> = None;
+        let mut prev_line: Option<String> = None;
+
+        for line in normalized.lines() {
+            let line = line.trim();
+            if line.is_empty() || is_vtt_header(line) || is_cue_index(line) {
+                continue;
+            }
+
+            if is_timestamp_line(line) {
+                cues.extend(current.take().filter(|cue| !cue.text.is_empty()));
+                let mut parts = line.split("-->");
+                let start = parts.next().and_then(parse_timecode);
+                let end = parts.next().and_then(parse_timecode);
+                if let (Some(start_ms), Some(end_ms)) = (start, end) {
+                    current = Some(TranscriptCue { start_ms, end_ms, text: String::new() });
+                }
+                continue;
+            }
+
+            // Text outside any cue (e.g. after a malformed timestamp) has no timing to keep
+            let Some(cue) = current.as_mut() else {
+                continue;
+            };
+            let cleaned = clean_line(line);
+            if cleaned.is_empty() || prev_line.as_ref() == Some(&cleaned) {
+                continue;
+            }
+            if !cue.text.is_empty() {
+                cue.text.push(' ');
+            }
+            cue.text.push_str(&cleaned);
+            prev_line = Some(cleaned);
+        }
+        cues.extend(current.filter(|cue| !cue.text.is_empty()));
+
+        cues
+    }
+}
+
+/// Strips tags and speaker labels from one line of cue text and normalizes its whitespace.
+fn clean_line(line: &str) -> String {
+    // Strip inline tags like <i>...</i>
+    let cleaned = strip_inline_tags(line);
+
+    // Strip speaker indicators like "[Speaker]:" or ">>"
+    let cleaned = strip_speaker_labels(&cleaned);
+
+    // Normalize whitespace (internal and surrounding)
+    normalize_whitespace(&cleaned)
 }
 
 fn strip_bom(input: &str) -> &str {
@@ -123,6 +176,23 @@ fn is_timecode(value: &str) -> bool {
     parts.iter().all(|p| p.chars().any(|c| c.is_ascii_digit()))
 }
 
+/// Parses `hh:mm:ss.mmm`, `mm:ss.mmm` or the SRT form with a comma into milliseconds.
+/// Cue settings after the timecode are ignored.
+fn parse_timecode(value: &str) -> Option<u64> {
+    let v = value.split_whitespace().next()?.replace(',', ".");
+    let mut parts = v.rsplit(':');
+    let seconds: f64 = parts.next()?.parse().ok()?;
+    let minutes: u64 = parts.next()?.parse().ok()?;
+    let hours: u64 = match parts.next() {
+        Some(h) => h.parse().ok()?,
+        None => 0,
+    };
+    if parts.next().is_some() || !seconds.is_finite() || seconds < 0.0 {
+        return None;
+    }
+    Some((hours * 3600 + minutes * 60) * 1000 + (seconds * 1000.0).round() as u64)
+}
+
 fn strip_inline_tags(line: &str) -> Cow<'_, str> {
     if !line.contains('<') {
         return Cow::Borrowed(line);
@@ -235,4 +305,27 @@ mod tests {
         let cleaned = SubtitleCleaner::new().clean(input);
         assert_eq!(cleaned, "BOM Test");
     }
+
+    #[test]
+    fn cues_keep_timings() {
+        let input = "1\n00:00:01,000 --> 00:00:02,500\nHello\nthere\n\n2\n01:02:03,040 --> 01:02:05,000\n<i>World</i>\n";
+        let cues = SubtitleCleaner::new().cues(input);
+        assert_eq!(
+            cues,
+            [
+                TranscriptCue { start_ms: 1_000, end_ms: 2_500, text: "Hello there".to_string() },
+                TranscriptCue { start_ms: 3_723_040, end_ms: 3_725_000, text: "World".to_string() },
+            ]
+        );
+    }
+
+    #[test]
+    fn cues_drop_rolling_caption_repeats() {
+        // yt-dlp auto-subs repeat the previous line at the top of each cue
+        let input = "WEBVTT\nKind: captions\n\n00:00.000 --> 00:03.500 align:start position:0%\n\nHello<00:00:00.480><c> and</c><00:00:00.720><c> welcome</c>\n\n00:03.500 --> 00:03.510\nHello and welcome\n\n00:03.510 --> 00:07.000\nHello and welcome\n<00:00:03.700><c> to</c><00:00:03.900><c> this</c><c> course.</c>\n";
+        let cues = SubtitleCleaner::new().cues(input);
+        let texts: Vec<&str> = cues.iter().map(|c| c.text.as_str()).collect();
+        assert_eq!(texts, ["Hello and welcome", "to this course."]);
+        assert_eq!((cues[1].start_ms, cues[1].end_ms), (3_510, 7_000));
+    }
 }
@@ -0,0 +1,216 @@
+//! Topic Segmenter - Places module boundaries where the vocabulary shifts.
+
+use std::collections::{BTreeMap, HashMap};
+
+/// Words that carry no topic signal in course titles and descriptions.
+const STOPWORDS: &[&str] = &[
+    "about", "after", "all", "and", "are", "but", "can", "for", "from", "get", "how", "into",
+    "its", "let", "more", "not", "now", "our", "out", "part", "than", "that", "the", "their",
+    "then", "there", "these", "this", "use", "using", "video", "was", "way", "what", "when", "why",
+    "will", "with", "you", "your",
+];
+
+/// Gaps shallower than this are not treated as topic shifts.
+const MIN_DEPTH: f32 = 0.1;
+
+/// Groups videos into modules from the similarity of their titles and descriptions.
+///
+/// Each video becomes a TF-IDF vector. For every gap between two videos the cosine
+/// similarity of the surrounding windows is computed, and the gaps sitting deepest in a
+/// similarity valley become module boundaries (TextTiling). The number of boundaries is
+/// aimed at `batch_size` videos per module.
+#[derive(Debug, Clone)]
+pub struct TopicSegmenter {
+    batch_size: usize,
+    window: usize,
+}
+
+impl TopicSegmenter {
+    /// Creates a segmenter aiming for `batch_size` videos per module.
+    pub fn with_batch_size(batch_size: usize) -> Self {
+        Self { batch_size: batch_size.max(1), window: 2 }
+    }
+
+    /// Splits `texts` (one per video, in order) into modules at topic shifts.
+    ///
+    /// Returns `None` when the signal is too weak to segment on: too few videos, too little
+    /// shared vocabulary, or no gap that stands out.
+    pub fn segment<T: AsRef<str>>(&self, texts: &[T]) -> Option<Vec<Vec<usize>>> {
+        let n = texts.len();
+        if n < 4 {
+            return None;
+        }
+
+        let docs: Vec<Vec<String>> = texts.iter().map(|t| tokenize(t.as_ref())).collect();
+        let mut doc_freq: HashMap<&str, usize> = HashMap::new();
+        for doc in &docs {
+            let mut seen: Vec<&str> = doc.iter().map(String::as_str).collect();
+            seen.sort_unstable();
+            seen.dedup();
+            for term in seen {
+                *doc_freq.entry(term).or_default() += 1;
+            }
+        }
+
+        // Most videos need a term in common with some other video.
+        let connected = docs
+            .iter()
+            .filter(|doc| doc.iter().any(|t| doc_freq.get(t.as_str()).is_some_and(|&df| df > 1)))
+            .count();
+        if connected * 2 < n {
+            return None;
+        }
+
+        let vectors: Vec<BTreeMap<&str, f32>> = docs
+            .iter()
+            .map(|doc| {
+                let mut vector = BTreeMap::new();
+                for term in doc {
+                    let df = doc_freq[term.as_str()] as f32;
+                    *vector.entry(term.as_str()).or_insert(0.0) += (n as f32 / df).ln();
+                }
+                vector
+            })
+            .collect();
+
+        // similarities[g] compares the videos before gap g with the ones after it (gap g sits
+        // between videos g and g + 1).
+        let similarities: Vec<f32> = (1..n)
+            .map(|g| {
+                let left = block(&vectors[g.saturating_sub(self.window)..g]);
+                let right = block(&vectors[g..(g + self.window).min(n)]);
+                cosine(&left, &right)
+            })
+            .collect();
+        let depths = depth_scores(&similarities);
+
+        let wanted = (n as f32 / self.batch_size as f32).round().max(2.0) as usize - 1;
+        let mean = depths.iter().sum::<f32>() / depths.len() as f32;
+        let mut candidates: Vec<usize> =
+            (0..depths.len()).filter(|&g| depths[g] >= MIN_DEPTH && depths[g] > mean).collect();
+        candidates.sort_by(|&a, &b| depths[b].total_cmp(&depths[a]).then(a.cmp(&b)));
+
+        // Deepest gaps first, keeping every module at least two videos long.
+        let mut cuts: Vec<usize> = Vec::with_capacity(wanted);
+        for gap in candidates {
+            if cuts.len() == wanted {
+                break;
+            }
+            let cut = gap + 1;
+            let fits =
+                cut >= 2 && n - cut >= 2 && cuts.iter().all(|&other| cut.abs_diff(other) >= 2);
+            if fits {
+                cuts.push(cut);
+            }
+        }
+        if cuts.is_empty() {
+            return None;
+        }
+        cuts.sort_unstable();
+
+        let mut groups = Vec::with_capacity(cuts.len() + 1);
+        let mut start = 0;
+        for cut in cuts.into_iter().chain(std::iter::once(n)) {
+            groups.push((start..cut).collect());
+            start = cut;
+        }
+        Some(groups)
+    }
+}
+
+/// Lowercased word stems, without stopwords, numbers and very short words.
+fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| w.chars().count() >= 3 && !w.chars().all(|c| c.is_ascii_digit()))
+        .map(str::to_lowercase)
+        .filter(|w| !STOPWORDS.contains(&w.as_str()))
+        .map(|w| stem(&w))
+        .collect()
+}
+
+/// Folds simple plurals so "closures" and "closure" count as one term.
+fn stem(word: &str) -> String {
+    match word.strip_suffix('s') {
+        Some(base) if base.chars().count() >= 4 && !base.ends_with('s') => base.to_string(),
+        _ => word.to_string(),
+    }
+}
+
+fn block<'a>(vectors: &[BTreeMap<&'a str, f32>]) -> BTreeMap<&'a str, f32> {
+    let mut sum = BTreeMap::new();
+    for vector in vectors {
+        for (&term, &weight) in vector {
+            *sum.entry(term).or_insert(0.0) += weight;
+        }
+    }
+    sum
+}
+
+fn cosine(a: &BTreeMap<&str, f32>, b: &BTreeMap<&str, f32>) -> f32 {
+    let dot: f32 = a.iter().filter_map(|(term, x)| b.get(term).map(|y| x * y)).sum();
+    let norm_a = a.values().map(|x| x * x).sum::<f32>().sqrt();
+    let norm_b = b.values().map(|x| x * x).sum::<f32>().sqrt();
+    if norm_a == 0.0 || norm_b == 0.0 { 0.0 } else { dot / (norm_a * norm_b) }
+}
+
+/// How far each gap's similarity sits below the peaks on either side of it.
+fn depth_scores(similarities: &[f32]) -> Vec<f32> {
+    (0..similarities.len())
+        .map(|g| {
+            let s = similarities[g];
+            let mut left = s;
+            for &prev in similarities[..g].iter().rev() {
+                if prev < left {
+                    break;
+                }
+                left = prev;
+            }
+            let mut right = s;
+            for &next in &similarities[g + 1..] {
+                if next < right {
+                    break;
+                }
+                right = next;
+            }
+            (left - s) + (right - s)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_where_the_topic_changes() {
+        let texts = [
+            "Installing the Rust toolchain with rustup",
+            "Rustup toolchain components and cargo",
+            "Cargo projects and the toolchain",
+            "Ownership rules and moves",
+            "Borrowing and ownership in functions",
+            "Ownership, borrowing and slices",
+            "Async runtimes and futures",
+            "Futures, tasks and the async executor",
+            "Async streams built on futures",
+        ];
+        let groups = TopicSegmenter::with_batch_size(3).segment(&texts).unwrap();
+        assert_eq!(groups, vec![vec![0, 1, 2], vec![3, 4, 5], vec![6, 7, 8]]);
+    }
+
+    #[test]
+    fn gives_up_without_shared_vocabulary() {
+        let texts = ["Welcome", "Graphs", "Sorting algorithms", "Dynamic programming", "Wrap up"];
+        assert_eq!(TopicSegmenter::with_batch_size(2).segment(&texts), None);
+    }
+
+    #[test]
+    fn gives_up_on_too_few_videos() {
+        assert_eq!(TopicSegmenter::with_batch_size(1).segment(&["Rust", "Rust", "Go"]), None);
+    }
+
+    #[test]
+    fn tokenize_drops_noise_and_folds_plurals() {
+        assert_eq!(tokenize("The 3 Closures of 2024: using closure"), vec!["closure", "closure"]);
+    }
+}
@@ -0,0 +1,135 @@
+//! Transcript Window - Picks what was said just before a playback position.
+
+use crate::domain::ports::{PlaybackMoment, TranscriptCue};
+
+/// Cuts the stretch of transcript that leads up to a playback position.
+///
+/// With timed cues the stretch is exact: every cue shown during the lookback, including the one
+/// on screen at the position. A transcript without timings is assumed to be spoken at an even
+/// pace over the video, which is close enough to find the right paragraph.
+#[derive(Debug, Clone)]
+pub struct TranscriptWindow {
+    lookback_secs: u32,
+}
+
+impl TranscriptWindow {
+    /// Default length of the stretch, in seconds.
+    pub const DEFAULT_LOOKBACK_SECS: u32 = 90;
+
+    /// Creates a window with the default lookback.
+    pub fn new() -> Self {
+        Self { lookback_secs: Self::DEFAULT_LOOKBACK_SECS }
+    }
+
+    /// Creates a window reaching `lookback_secs` back from the position.
+    pub fn with_lookback(lookback_secs: u32) -> Self {
+        Self { lookback_secs }
+    }
+
+    /// What `cues` say in the lookback before `position_secs`, or `None` if nothing is said.
+    pub fn from_cues(&self, cues: &[TranscriptCue], position_secs: u32) -> Option<PlaybackMoment> {
+        let from_secs = position_secs.saturating_sub(self.lookback_secs);
+        let (from_ms, to_ms) = (u64::from(from_secs) * 1000, u64::from(position_secs) * 1000);
+
+        let text = cues
+            .iter()
+            .filter(|cue| cue.end_ms > from_ms && cue.start_ms <= to_ms)
+            .map(|cue| cue.text.as_str())
+            .collect::<Vec<_>>()
+            .join(" ");
+        (!text.is_empty()).then_some(PlaybackMoment {
+            from_secs,
+            to_secs: position_secs,
+            text,
+            estimated: false,
+        })
+    }
+
+    /// Estimates the lookback before `position_secs` in an untimed `transcript` of a video
+    /// `duration_secs` long. The cut is widened to whole words.
+    pub fn from_text(
+        &self,
+        transcript: &str,
+        duration_secs: u32,
+        position_secs: u32,
+    ) -> Option<PlaybackMoment> {
+        if duration_secs == 0 {
+            return None;
+        }
+        let position_secs = position_secs.min(duration_secs);
+        let from_secs = position_secs.saturating_sub(self.lookback_secs);
+
+        let chars: Vec<(usize, char)> = transcript.char_indices().collect();
+        let at =
+            |secs: u32| (chars.len() as u64 * u64::from(secs) / u64::from(duration_secs)) as usize;
+        let mut start = at(from_secs);
+        let mut end = at(position_secs);
+        while start > 0 && !chars[start - 1].1.is_whitespace() {
+            start -= 1;
+        }
+        while end < chars.len() && !chars[end].1.is_whitespace() {
+            end += 1;
+        }
+
+        let byte = |i: usize| chars.get(i).map_or(transcript.len(), |&(b, _)| b);
+        let text = transcript[byte(start)..byte(end)].trim();
+        (!text.is_empty()).then(|| PlaybackMoment {
+            from_secs,
+            to_secs: position_secs,
+            text: text.to_string(),
+            estimated: true,
+        })
+    }
+}
+
+impl Default for TranscriptWindow {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cue(start_secs: u64, end_secs: u64, text: &str) -> TranscriptCue {
+        TranscriptCue { start_ms: start_secs * 1000, end_ms: end_secs * 1000, text: text.into() }
+    }
+
+    #[test]
+    fn cues_in_the_lookback_are_joined() {
+        let cues = [
+            cue(0, 20, "Welcome."),
+            cue(20, 95, "Ownership is a set of rules."),
+            cue(95, 120, "Each value has one owner."),
+            cue(120, 130, "When the owner goes out of scope,"),
+            cue(130, 140, "the value is dropped."),
+        ];
+        let moment = TranscriptWindow::with_lookback(30).from_cues(&cues, 120).unwrap();
+        assert_eq!(
+            moment.text,
+            "Ownership is a set of rules. Each value has one owner. When the owner goes out of scope,"
+        );
+        assert_eq!((moment.from_secs, moment.to_secs, moment.estimated), (90, 120, false));
+        assert_eq!(moment.span(), "1:30\u{2013}2:00");
+
+        // Near the start the window is cut short; past the last cue nothing is said
+        let moment = TranscriptWindow::new().from_cues(&cues, 10).unwrap();
+        assert_eq!((moment.from_secs, moment.text.as_str()), (0, "Welcome."));
+        assert!(TranscriptWindow::with_lookback(30).from_cues(&cues, 200).is_none());
+    }
+
+    #[test]
+    fn untimed_transcripts_are_cut_in_proportion() {
+        // Ten words of ten characters each, spoken over 100 seconds
+        let transcript: String = (0..10).map(|i| format!("word{i:05} ")).collect();
+        let moment = TranscriptWindow::with_lookback(30).from_text(&transcript, 100, 65).unwrap();
+        assert_eq!(moment.text, "word00003 word00004 word00005 word00006");
+        assert_eq!((moment.from_secs, moment.to_secs, moment.estimated), (35, 65, true));
+
+        let moment = TranscriptWindow::new().from_text(&transcript, 100, 500).unwrap();
+        assert_eq!(moment.to_secs, 100);
+        assert!(moment.text.ends_with("word00009"));
+        assert!(TranscriptWindow::new().from_text(&transcript, 0, 10).is_none());
+    }
+}
@@ -0,0 +1,43 @@
+//! Clip range value object.
+
+/// The part of a source video a lesson covers, in seconds from the start of the source.
+///
+/// Set on lessons split out of one long video by its chapters; the player plays only this
+/// window and reports positions relative to its start.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct ClipRange {
+    start_secs: u32,
+    end_secs: u32,
+}
+
+impl ClipRange {
+    /// Creates a range. Returns `None` unless it ends after it starts.
+    pub fn new(start_secs: u32, end_secs: u32) -> Option<Self> {
+        (end_secs > start_secs).then_some(Self { start_secs, end_secs })
+    }
+
+    pub fn start_secs(&self) -> u32 {
+        self.start_secs
+    }
+
+    pub fn end_secs(&self) -> u32 {
+        self.end_secs
+    }
+
+    /// Length of the clip.
+    pub fn duration_secs(&self) -> u32 {
+        self.end_secs - self.start_secs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_empty_and_reversed_ranges() {
+        assert!(ClipRange::new(60, 60).is_none());
+        assert!(ClipRange::new(90, 60).is_none());
+        assert_eq!(ClipRange::new(60, 150).map(|c| c.duration_secs()), Some(90));
+    }
+}
@@ -0,0 +1,131 @@
+//! Course source value object.
+//!
+//! Records where a course was imported from (YouTube playlist, local folder, or nowhere).
+
+use std::path::Path;
+
+use crate::domain::value_objects::PlaylistUrl;
+
+/// Error when constructing a course source.
+#[derive(Debug, thiserror::Error)]
+pub enum CourseSourceError {
+    #[error("Invalid local folder: {0}")]
+    InvalidLocalFolder(String),
+    #[error("Invalid playlist URL: {0}")]
+    InvalidPlaylistUrl(String),
+    #[error("Unknown course source type: {0}")]
+    UnknownType(String),
+}
+
+/// Origin of a course.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CourseSource {
+    /// Imported from a YouTube playlist.
+    YouTubePlaylist(PlaylistUrl),
+    /// Imported from a folder on disk (absolute path).
+    LocalFolder { root: String },
+    /// Assembled by hand, with no external source to sync from.
+    Manual,
+}
+
+impl CourseSource {
+    /// Creates a YouTube playlist source.
+    pub fn youtube_playlist(url: PlaylistUrl) -> Self {
+        Self::YouTubePlaylist(url)
+    }
+
+    /// Creates a local folder source.
+    pub fn local_folder(root: impl AsRef<str>) -> Result<Self, CourseSourceError> {
+        let raw = root.as_ref().trim();
+        if raw.is_empty() {
+            return Err(CourseSourceError::InvalidLocalFolder("path is empty".to_string()));
+        }
+        if !Path::new(raw).is_absolute() {
+            return Err(CourseSourceError::InvalidLocalFolder("path must be absolute".to_string()));
+        }
+        Ok(Self::LocalFolder { root: raw.to_string() })
+    }
+
+    /// Rebuilds a source from its persisted type label and reference.
+    pub fn from_parts(source_type: &str, source_ref: &str) -> Result<Self, CourseSourceError> {
+        match source_type {
+            "youtube" => PlaylistUrl::new(source_ref)
+                .map(Self::YouTubePlaylist)
+                .map_err(|e| CourseSourceError::InvalidPlaylistUrl(e.to_string())),
+            "local" => Self::local_folder(source_ref),
+            "manual" => Ok(Self::Manual),
+            other => Err(CourseSourceError::UnknownType(other.to_string())),
+        }
+    }
+
+    /// Returns the playlist URL if the course came from YouTube.
+    pub fn playlist_url(&self) -> Option<&PlaylistUrl> {
+        match self {
+            Self::YouTubePlaylist(url) => Some(url),
+            _ => None,
+        }
+    }
+
+    /// Returns the root folder if the course came from disk.
+    pub fn local_root(&self) -> Option<&str> {
+        match self {
+            Self::LocalFolder { root } => Some(root.as_str()),
+            _ => None,
+        }
+    }
+
+    /// Returns the source type label for persistence.
+    pub fn source_type(&self) -> &'static str {
+        match self {
+            Self::YouTubePlaylist(_) => "youtube",
+            Self::LocalFolder { .. } => "local",
+            Self::Manual => "manual",
+        }
+    }
+
+    /// Returns the source reference for persistence: the playlist URL, the root folder, or "".
+    pub fn source_ref(&self) -> &str {
+        match self {
+            Self::YouTubePlaylist(url) => url.raw(),
+            Self::LocalFolder { root } => root.as_str(),
+            Self::Manual => "",
+        }
+    }
+
+    /// Human-readable label for the source kind.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::YouTubePlaylist(_) => "YouTube Playlist",
+            Self::LocalFolder { .. } => "Local Folder",
+            Self::Manual => "Manual",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn local_folder_must_be_absolute() {
+        assert!(CourseSource::local_folder("").is_err());
+        assert!(CourseSource::local_folder("videos/course").is_err());
+        let src = CourseSource::local_folder(" /home/user/course ").unwrap();
+        assert_eq!(src.local_root(), Some("/home/user/course"));
+        assert!(src.playlist_url().is_none());
+    }
+
+    #[test]
+    fn round_trips_through_parts() {
+        let url = PlaylistUrl::new("https://www.youtube.com/playlist?list=PLtest123").unwrap();
+        for src in [
+            CourseSource::youtube_playlist(url),
+            CourseSource::local_folder("/tmp/course").unwrap(),
+            CourseSource::Manual,
+        ] {
+            let parsed = CourseSource::from_parts(src.source_type(), src.source_ref()).unwrap();
+            assert_eq!(parsed, src);
+        }
+        assert!(CourseSource::from_parts("ftp", "x").is_err());
+    }
+}
@@ -0,0 +1,118 @@
+//! Flashcard kind value object.
+
+use std::fmt;
+use std::str::FromStr;
+
+/// Opening marker of a cloze deletion, e.g. `The {{borrow checker}} enforces ownership.`
+pub const CLOZE_OPEN: &str = "{{";
+/// Closing marker of a cloze deletion.
+pub const CLOZE_CLOSE: &str = "}}";
+
+/// Shape of a flashcard.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize, Default,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum FlashcardKind {
+    /// Term on the front, definition on the back.
+    #[default]
+    Basic,
+    /// A sentence with one or more `{{hidden}}` spans to recall.
+    Cloze,
+}
+
+impl FlashcardKind {
+    /// Returns the canonical string representation.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Basic => "basic",
+            Self::Cloze => "cloze",
+        }
+    }
+}
+
+impl fmt::Display for FlashcardKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Error returned when parsing an invalid flashcard kind.
+#[derive(Debug, thiserror::Error)]
+pub enum FlashcardKindParseError {
+    #[error("Invalid flashcard kind: {0}")]
+    Invalid(String),
+}
+
+impl FromStr for FlashcardKind {
+    type Err = FlashcardKindParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "basic" => Ok(Self::Basic),
+            "cloze" => Ok(Self::Cloze),
+            _ => Err(FlashcardKindParseError::Invalid(s.trim().to_string())),
+        }
+    }
+}
+
+/// Returns the hidden spans of a cloze text, in order.
+/// Unclosed or empty markers are ignored.
+pub fn cloze_deletions(text: &str) -> Vec<&str> {
+    let mut spans = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find(CLOZE_OPEN) {
+        let after = &rest[start + CLOZE_OPEN.len()..];
+        let Some(end) = after.find(CLOZE_CLOSE) else {
+            break;
+        };
+        let span = after[..end].trim();
+        if !span.is_empty() {
+            spans.push(span);
+        }
+        rest = &after[end + CLOZE_CLOSE.len()..];
+    }
+    spans
+}
+
+/// Rewrites every cloze deletion in `text` with `replace(span)`.
+pub fn replace_cloze(text: &str, replace: impl Fn(&str) -> String) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find(CLOZE_OPEN) {
+        let after = &rest[start + CLOZE_OPEN.len()..];
+        let Some(end) = after.find(CLOZE_CLOSE) else {
+            break;
+        };
+        out.push_str(&rest[..start]);
+        out.push_str(&replace(after[..end].trim()));
+        rest = &after[end + CLOZE_CLOSE.len()..];
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kind_round_trips_through_str_and_json() {
+        for kind in [FlashcardKind::Basic, FlashcardKind::Cloze] {
+            assert_eq!(kind.as_str().parse::<FlashcardKind>().unwrap(), kind);
+            let json = serde_json::to_string(&kind).unwrap();
+            assert_eq!(json, format!("\"{}\"", kind.as_str()));
+        }
+        assert!("reverse".parse::<FlashcardKind>().is_err());
+    }
+
+    #[test]
+    fn cloze_deletions_are_extracted_and_replaced() {
+        let text = "A {{mutex}} guards {{ shared state }} across {{}} threads {{unclosed";
+        assert_eq!(cloze_deletions(text), vec!["mutex", "shared state"]);
+        assert_eq!(
+            replace_cloze(text, |_| "[...]".to_string()),
+            "A [...] guards [...] across [...] threads {{unclosed"
+        );
+    }
+}
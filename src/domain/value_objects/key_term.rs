@@ -0,0 +1,76 @@
+//! Glossary term value object.
+
+/// A term and its short definition, as extracted from a video.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct KeyTerm {
+    pub term: String,
+    pub definition: String,
+}
+
+impl KeyTerm {
+    /// Creates a term, trimming both parts. Returns `None` if either is empty.
+    pub fn new(term: impl AsRef<str>, definition: impl AsRef<str>) -> Option<Self> {
+        let term = term.as_ref().trim();
+        let definition = definition.as_ref().trim();
+        (!term.is_empty() && !definition.is_empty())
+            .then(|| Self { term: term.to_string(), definition: definition.to_string() })
+    }
+}
+
+/// Extracts `term: definition` bullets from the "Key Terms" section of a summary.
+///
+/// The section starts at a line containing "Key Terms" and ends at the next line that is
+/// neither a bullet nor blank. Bullets without a colon are skipped.
+pub fn parse_glossary(text: &str) -> Vec<KeyTerm> {
+    let mut terms = Vec::new();
+    let mut in_section = false;
+    for line in text.lines() {
+        let trimmed = line.trim();
+        if !in_section {
+            in_section = trimmed.to_ascii_lowercase().contains("key terms");
+            continue;
+        }
+        if trimmed.is_empty() {
+            continue;
+        }
+        let Some(bullet) = trimmed.strip_prefix(['-', '*', '•']) else {
+            break;
+        };
+        let Some((term, definition)) = bullet.split_once(':') else {
+            continue;
+        };
+        // Markdown bold may wrap the term or the term and its colon: `**Term**:` / `**Term:**`.
+        let term = term.trim().trim_matches('*');
+        let definition = definition.trim().trim_start_matches('*');
+        terms.extend(KeyTerm::new(term, definition));
+    }
+    terms
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_key_terms_section_of_summary() {
+        let summary = "1. Main Topic: Ownership\n2. Key Points:\n- Values have one owner\n\
+                       3. Key Terms:\n- Borrow: a reference to a value\n\
+                       - **Move**: transfer of ownership\n- **Drop:** end of scope\n\
+                       - no colon here\n\nTrailing text";
+        assert_eq!(
+            parse_glossary(summary),
+            vec![
+                KeyTerm::new("Borrow", "a reference to a value").unwrap(),
+                KeyTerm::new("Move", "transfer of ownership").unwrap(),
+                KeyTerm::new("Drop", "end of scope").unwrap(),
+            ]
+        );
+    }
+
+    #[test]
+    fn none_section_yields_no_terms() {
+        assert!(parse_glossary("3. Key Terms:\nNone").is_empty());
+        assert!(parse_glossary("No glossary at all").is_empty());
+        assert!(KeyTerm::new(" ", "empty term").is_none());
+    }
+}
@@ -1,17 +1,31 @@
 //! Value Objects - Immutable domain primitives.
 
+mod clip_range;
+mod course_source;
 mod exam_difficulty;
+mod flashcard_kind;
 mod ids;
+mod key_term;
+mod review;
 mod session;
 mod tag_id;
 mod video_quality;
 mod video_source;
+mod watched_ranges;
 mod youtube;
 
+pub use clip_range::ClipRange;
+pub use course_source::{CourseSource, CourseSourceError};
 pub use exam_difficulty::ExamDifficulty;
-pub use ids::{CourseId, ExamId, ModuleId, UserId, VideoId};
-pub use session::{CognitiveLimit, SessionPlan};
+pub use flashcard_kind::{
+    CLOZE_CLOSE, CLOZE_OPEN, FlashcardKind, FlashcardKindParseError, cloze_deletions, replace_cloze,
+};
+pub use ids::{CourseId, ExamId, FlashcardId, ModuleId, ReviewCardId, UserId, VideoId};
+pub use key_term::{KeyTerm, parse_glossary};
+pub use review::{ReviewGrade, ReviewGradeParseError, ReviewSchedule};
+pub use session::{CognitiveLimit, ReschedulePolicy, ReschedulePolicyParseError, SessionPlan};
 pub use tag_id::TagId;
 pub use video_quality::VideoQuality;
 pub use video_source::{VideoSource, VideoSourceError};
+pub use watched_ranges::{WatchedRanges, WatchedRangesParseError};
 pub use youtube::{PlaylistUrl, YouTubeVideoId};
@@ -0,0 +1,198 @@
+//! Spaced-repetition value objects (SM-2).
+
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{Days, NaiveDate};
+
+/// How well a review card was recalled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReviewGrade {
+    /// Forgotten or answered wrong; the card starts over.
+    Again,
+    Hard,
+    Good,
+    Easy,
+}
+
+impl ReviewGrade {
+    /// Returns the canonical string representation.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Again => "again",
+            Self::Hard => "hard",
+            Self::Good => "good",
+            Self::Easy => "easy",
+        }
+    }
+
+    /// SM-2 response quality (0-5).
+    pub fn quality(&self) -> u8 {
+        match self {
+            Self::Again => 0,
+            Self::Hard => 3,
+            Self::Good => 4,
+            Self::Easy => 5,
+        }
+    }
+}
+
+impl fmt::Display for ReviewGrade {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Error returned when parsing an invalid review grade.
+#[derive(Debug, thiserror::Error)]
+pub enum ReviewGradeParseError {
+    #[error("Invalid review grade: {0}")]
+    Invalid(String),
+}
+
+impl FromStr for ReviewGrade {
+    type Err = ReviewGradeParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "again" => Ok(Self::Again),
+            "hard" => Ok(Self::Hard),
+            "good" => Ok(Self::Good),
+            "easy" => Ok(Self::Easy),
+            _ => Err(ReviewGradeParseError::Invalid(s.trim().to_string())),
+        }
+    }
+}
+
+/// SM-2 scheduling state of a review card.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReviewSchedule {
+    ease: f64,
+    interval_days: u32,
+    repetitions: u32,
+    lapses: u32,
+    due: NaiveDate,
+}
+
+impl ReviewSchedule {
+    /// Ease factor of a card that has never been reviewed.
+    pub const INITIAL_EASE: f64 = 2.5;
+    /// SM-2 never lets the ease factor drop below this.
+    pub const MIN_EASE: f64 = 1.3;
+
+    /// A new card, due on `due`.
+    pub fn new(due: NaiveDate) -> Self {
+        Self { ease: Self::INITIAL_EASE, interval_days: 0, repetitions: 0, lapses: 0, due }
+    }
+
+    /// Restores a persisted schedule.
+    pub fn restore(
+        ease: f64,
+        interval_days: u32,
+        repetitions: u32,
+        lapses: u32,
+        due: NaiveDate,
+    ) -> Self {
+        Self { ease: ease.max(Self::MIN_EASE), interval_days, repetitions, lapses, due }
+    }
+
+    pub fn ease(&self) -> f64 {
+        self.ease
+    }
+
+    /// Days between the last review and the due date.
+    pub fn interval_days(&self) -> u32 {
+        self.interval_days
+    }
+
+    /// Successful reviews in a row.
+    pub fn repetitions(&self) -> u32 {
+        self.repetitions
+    }
+
+    /// Times the card was forgotten after having been learned.
+    pub fn lapses(&self) -> u32 {
+        self.lapses
+    }
+
+    pub fn due(&self) -> NaiveDate {
+        self.due
+    }
+
+    pub fn is_due(&self, today: NaiveDate) -> bool {
+        self.due <= today
+    }
+
+    /// Applies one SM-2 review on `today` and returns the next state.
+    pub fn review(&self, grade: ReviewGrade, today: NaiveDate) -> Self {
+        let q = f64::from(grade.quality());
+        let ease = (self.ease + (0.1 - (5.0 - q) * (0.08 + (5.0 - q) * 0.02))).max(Self::MIN_EASE);
+
+        let (repetitions, interval_days, lapses) = if grade == ReviewGrade::Again {
+            let lapses = if self.repetitions > 0 { self.lapses + 1 } else { self.lapses };
+            (0, 1, lapses)
+        } else {
+            let interval = match self.repetitions {
+                0 => 1,
+                1 => 6,
+                _ => (f64::from(self.interval_days) * ease).round() as u32,
+            };
+            (self.repetitions + 1, interval.max(1), self.lapses)
+        };
+
+        let due = today.checked_add_days(Days::new(u64::from(interval_days))).unwrap_or(today);
+        Self { ease, interval_days, repetitions, lapses, due }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 6, d).unwrap()
+    }
+
+    #[test]
+    fn grade_round_trips_through_str() {
+        for grade in [ReviewGrade::Again, ReviewGrade::Hard, ReviewGrade::Good, ReviewGrade::Easy] {
+            assert_eq!(grade.as_str().parse::<ReviewGrade>().unwrap(), grade);
+        }
+        assert!("perfect".parse::<ReviewGrade>().is_err());
+    }
+
+    #[test]
+    fn good_answers_follow_sm2_intervals() {
+        let first = ReviewSchedule::new(date(1)).review(ReviewGrade::Good, date(1));
+        assert_eq!((first.interval_days(), first.due()), (1, date(2)));
+        assert_eq!(first.ease(), ReviewSchedule::INITIAL_EASE);
+
+        let second = first.review(ReviewGrade::Good, date(2));
+        assert_eq!((second.interval_days(), second.due()), (6, date(8)));
+
+        let third = second.review(ReviewGrade::Good, date(8));
+        assert_eq!(third.interval_days(), 15);
+        assert_eq!(third.repetitions(), 3);
+    }
+
+    #[test]
+    fn again_resets_and_lowers_ease() {
+        let learned = ReviewSchedule::restore(2.5, 15, 3, 0, date(1));
+        let forgotten = learned.review(ReviewGrade::Again, date(1));
+        assert_eq!(forgotten.repetitions(), 0);
+        assert_eq!(forgotten.interval_days(), 1);
+        assert_eq!(forgotten.lapses(), 1);
+        assert!((forgotten.ease() - 1.7).abs() < 1e-9);
+        assert!(forgotten.is_due(date(2)));
+        assert!(!forgotten.is_due(date(1)));
+    }
+
+    #[test]
+    fn ease_never_drops_below_minimum() {
+        let mut schedule = ReviewSchedule::new(date(1));
+        for _ in 0..10 {
+            schedule = schedule.review(ReviewGrade::Hard, date(1));
+        }
+        assert_eq!(schedule.ease(), ReviewSchedule::MIN_EASE);
+    }
+}
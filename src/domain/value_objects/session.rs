@@ -1,5 +1,7 @@
 //! Session planning value objects.
 
+use std::fmt;
+use std::str::FromStr;
 use std::time::Duration;
 
 /// User-defined cognitive limit for session planning.
@@ -55,6 +57,53 @@ impl SessionPlan {
     }
 }
 
+/// How the rescheduler treats a schedule that has drifted from the calendar.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize, Default,
+)]
+pub enum ReschedulePolicy {
+    /// Keep the original end date, raising the per-session load if needed.
+    KeepDeadline,
+    /// Keep the per-session load, pushing the end date out if needed.
+    #[default]
+    KeepDailyLoad,
+}
+
+impl ReschedulePolicy {
+    /// Returns the canonical string representation.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::KeepDeadline => "keep_deadline",
+            Self::KeepDailyLoad => "keep_daily_load",
+        }
+    }
+}
+
+impl fmt::Display for ReschedulePolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Error returned when parsing an invalid reschedule policy.
+#[derive(Debug, thiserror::Error)]
+pub enum ReschedulePolicyParseError {
+    #[error("Invalid reschedule policy: {0}")]
+    Invalid(String),
+}
+
+impl FromStr for ReschedulePolicy {
+    type Err = ReschedulePolicyParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().replace('-', "_").as_str() {
+            "keep_deadline" | "deadline" => Ok(Self::KeepDeadline),
+            "keep_daily_load" | "daily_load" | "load" => Ok(Self::KeepDailyLoad),
+            _ => Err(ReschedulePolicyParseError::Invalid(s.trim().to_string())),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -71,4 +120,16 @@ mod tests {
         assert_eq!(limit.minutes(), 60);
         assert_eq!(limit.seconds(), 3600);
     }
+
+    #[test]
+    fn reschedule_policy_round_trips_through_str() {
+        for policy in [ReschedulePolicy::KeepDeadline, ReschedulePolicy::KeepDailyLoad] {
+            assert_eq!(policy.as_str().parse::<ReschedulePolicy>().unwrap(), policy);
+        }
+        assert_eq!(
+            "keep-deadline".parse::<ReschedulePolicy>().unwrap(),
+            ReschedulePolicy::KeepDeadline
+        );
+        assert!("sometimes".parse::<ReschedulePolicy>().is_err());
+    }
 }
@@ -0,0 +1,158 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// The parts of a video that were actually played, as merged millisecond intervals.
+///
+/// Seeking past a section leaves a gap, so the watched fraction reflects what the
+/// learner saw rather than where the seek bar ended up.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WatchedRanges {
+    /// Sorted, non-overlapping, non-adjacent `[start, end)` intervals.
+    ranges: Vec<(u64, u64)>,
+}
+
+impl WatchedRanges {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a played interval, merging it with any it overlaps or touches.
+    pub fn add(&mut self, start_ms: u64, end_ms: u64) {
+        if end_ms <= start_ms {
+            return;
+        }
+        let (mut start, mut end) = (start_ms, end_ms);
+        let mut merged = Vec::with_capacity(self.ranges.len() + 1);
+        let mut inserted = false;
+        for &(s, e) in &self.ranges {
+            if e < start {
+                merged.push((s, e));
+            } else if end < s {
+                if !inserted {
+                    merged.push((start, end));
+                    inserted = true;
+                }
+                merged.push((s, e));
+            } else {
+                start = start.min(s);
+                end = end.max(e);
+            }
+        }
+        if !inserted {
+            merged.push((start, end));
+        }
+        self.ranges = merged;
+    }
+
+    /// Adds every interval of `other`.
+    pub fn merge(&mut self, other: &WatchedRanges) {
+        for &(start, end) in &other.ranges {
+            self.add(start, end);
+        }
+    }
+
+    pub fn ranges(&self) -> &[(u64, u64)] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Total milliseconds watched.
+    pub fn watched_ms(&self) -> u64 {
+        self.ranges.iter().map(|(s, e)| e - s).sum()
+    }
+
+    /// Share of a video of `duration_ms` that was watched, in `0.0..=1.0`.
+    /// Returns 0 when the duration is unknown.
+    pub fn fraction(&self, duration_ms: u64) -> f64 {
+        if duration_ms == 0 {
+            return 0.0;
+        }
+        let watched: u64 =
+            self.ranges.iter().map(|&(s, e)| e.min(duration_ms).saturating_sub(s)).sum();
+        watched as f64 / duration_ms as f64
+    }
+}
+
+/// Compact storage form: `start-end` pairs separated by commas, e.g. `0-12000,30000-45000`.
+impl fmt::Display for WatchedRanges {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, (start, end)) in self.ranges.iter().enumerate() {
+            if idx > 0 {
+                f.write_str(",")?;
+            }
+            write!(f, "{start}-{end}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Invalid watched range: {0}")]
+pub struct WatchedRangesParseError(String);
+
+impl FromStr for WatchedRanges {
+    type Err = WatchedRangesParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut ranges = Self::new();
+        for part in s.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+            let parsed = part
+                .split_once('-')
+                .and_then(|(start, end)| Some((start.parse().ok()?, end.parse().ok()?)));
+            let Some((start, end)) = parsed else {
+                return Err(WatchedRangesParseError(part.to_string()));
+            };
+            ranges.add(start, end);
+        }
+        Ok(ranges)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_merges_overlapping_and_adjacent_ranges() {
+        let mut ranges = WatchedRanges::new();
+        ranges.add(10_000, 20_000);
+        ranges.add(40_000, 50_000);
+        ranges.add(0, 5_000);
+        assert_eq!(ranges.ranges(), &[(0, 5_000), (10_000, 20_000), (40_000, 50_000)]);
+
+        ranges.add(5_000, 12_000);
+        ranges.add(15_000, 45_000);
+        assert_eq!(ranges.ranges(), &[(0, 50_000)]);
+        assert_eq!(ranges.watched_ms(), 50_000);
+
+        ranges.add(30_000, 30_000);
+        assert_eq!(ranges.ranges(), &[(0, 50_000)]);
+    }
+
+    #[test]
+    fn test_fraction_ignores_skipped_sections() {
+        let mut ranges = WatchedRanges::new();
+        ranges.add(0, 30_000);
+        ranges.add(90_000, 100_000);
+        assert!((ranges.fraction(100_000) - 0.4).abs() < f64::EPSILON);
+        // Ranges past the end are clamped.
+        ranges.add(100_000, 120_000);
+        assert!((ranges.fraction(100_000) - 0.4).abs() < f64::EPSILON);
+        assert_eq!(ranges.fraction(0), 0.0);
+    }
+
+    #[test]
+    fn test_round_trip_storage_form() {
+        let mut ranges = WatchedRanges::new();
+        ranges.add(0, 12_000);
+        ranges.add(30_000, 45_000);
+        let stored = ranges.to_string();
+        assert_eq!(stored, "0-12000,30000-45000");
+        assert_eq!(stored.parse::<WatchedRanges>().unwrap(), ranges);
+        assert!("".parse::<WatchedRanges>().unwrap().is_empty());
+        assert!("12-x".parse::<WatchedRanges>().is_err());
+    }
+}
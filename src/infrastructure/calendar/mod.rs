@@ -0,0 +1,202 @@
+//! iCalendar (RFC 5545) exporter for planned study sessions.
+
+use std::fmt::Write;
+
+use chrono::{DateTime, Days, Utc};
+
+use crate::domain::ports::{CalendarEvent, CalendarExporter};
+
+const PRODID: &str = "-//Course Pilot//Study Schedule//EN";
+/// Maximum content line length in octets, excluding the CRLF (RFC 5545 §3.1).
+const MAX_LINE_OCTETS: usize = 75;
+
+/// Writes `VCALENDAR` documents with one `VEVENT` per study session.
+#[derive(Debug, Default)]
+pub struct IcsCalendarExporter {
+    /// Fixed `DTSTAMP` for reproducible output; the current time when unset.
+    timestamp: Option<DateTime<Utc>>,
+}
+
+impl IcsCalendarExporter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Uses a fixed `DTSTAMP` instead of the current time.
+    pub fn with_timestamp(timestamp: DateTime<Utc>) -> Self {
+        Self { timestamp: Some(timestamp) }
+    }
+}
+
+impl CalendarExporter for IcsCalendarExporter {
+    fn export(&self, calendar_name: &str, events: &[CalendarEvent]) -> String {
+        let dtstamp = self.timestamp.unwrap_or_else(Utc::now).format("%Y%m%dT%H%M%SZ").to_string();
+
+        let mut out = String::new();
+        push_line(&mut out, "BEGIN:VCALENDAR");
+        push_line(&mut out, "VERSION:2.0");
+        push_line(&mut out, &format!("PRODID:{PRODID}"));
+        push_line(&mut out, "CALSCALE:GREGORIAN");
+        push_line(&mut out, "METHOD:PUBLISH");
+        push_line(&mut out, &format!("X-WR-CALNAME:{}", escape_text(calendar_name)));
+
+        for event in events {
+            push_line(&mut out, "BEGIN:VEVENT");
+            push_line(&mut out, &format!("UID:{}", event.uid));
+            push_line(&mut out, &format!("DTSTAMP:{dtstamp}"));
+            push_line(&mut out, &format!("SEQUENCE:{}", event.sequence));
+            push_line(
+                &mut out,
+                if event.cancelled { "STATUS:CANCELLED" } else { "STATUS:CONFIRMED" },
+            );
+            match event.start_time {
+                Some(time) => {
+                    let start = event.date.and_time(time);
+                    push_line(&mut out, &format!("DTSTART:{}", start.format("%Y%m%dT%H%M%S")));
+                    push_line(
+                        &mut out,
+                        &format!("DURATION:{}", format_duration(event.duration_secs)),
+                    );
+                },
+                None => {
+                    let end = event.date.checked_add_days(Days::new(1)).unwrap_or(event.date);
+                    push_line(
+                        &mut out,
+                        &format!("DTSTART;VALUE=DATE:{}", event.date.format("%Y%m%d")),
+                    );
+                    push_line(&mut out, &format!("DTEND;VALUE=DATE:{}", end.format("%Y%m%d")));
+                    push_line(&mut out, "TRANSP:TRANSPARENT");
+                },
+            }
+            push_line(&mut out, &format!("SUMMARY:{}", escape_text(&event.summary)));
+            push_line(&mut out, &format!("DESCRIPTION:{}", escape_text(&event.description)));
+            push_line(&mut out, "END:VEVENT");
+        }
+
+        push_line(&mut out, "END:VCALENDAR");
+        out
+    }
+}
+
+/// Appends a content line, folding it at 75 octets without splitting UTF-8 characters.
+fn push_line(out: &mut String, line: &str) {
+    let mut octets = 0;
+    for ch in line.chars() {
+        let len = ch.len_utf8();
+        if octets + len > MAX_LINE_OCTETS {
+            out.push_str("\r\n ");
+            // The leading space of a continuation line counts towards its length.
+            octets = 1;
+        }
+        out.push(ch);
+        octets += len;
+    }
+    out.push_str("\r\n");
+}
+
+/// Escapes a TEXT property value (RFC 5545 §3.3.11).
+fn escape_text(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for ch in value.chars() {
+        match ch {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {},
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+/// Formats seconds as an RFC 5545 DURATION value, e.g. `PT1H5M`.
+fn format_duration(secs: u32) -> String {
+    if secs == 0 {
+        return "PT0S".to_string();
+    }
+    let (hours, minutes, seconds) = (secs / 3600, secs % 3600 / 60, secs % 60);
+    let mut out = String::from("PT");
+    for (value, unit) in [(hours, 'H'), (minutes, 'M'), (seconds, 'S')] {
+        if value > 0 {
+            let _ = write!(out, "{value}{unit}");
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{NaiveDate, NaiveTime, TimeZone};
+
+    fn event(start_time: Option<NaiveTime>) -> CalendarEvent {
+        CalendarEvent {
+            uid: "session-1.abc@course-pilot".to_string(),
+            date: NaiveDate::from_ymd_opt(2026, 6, 1).unwrap(),
+            start_time,
+            duration_secs: 3900,
+            summary: "Rust, Part 1; Basics".to_string(),
+            description: "Intro\nOwnership".to_string(),
+            sequence: 7,
+            cancelled: false,
+        }
+    }
+
+    fn exporter() -> IcsCalendarExporter {
+        IcsCalendarExporter::with_timestamp(Utc.with_ymd_and_hms(2026, 5, 30, 8, 0, 0).unwrap())
+    }
+
+    #[test]
+    fn all_day_event_uses_date_values() {
+        let ics = exporter().export("Study", &[event(None)]);
+
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+        assert!(ics.contains("\r\nUID:session-1.abc@course-pilot\r\n"));
+        assert!(ics.contains("\r\nDTSTAMP:20260530T080000Z\r\n"));
+        assert!(ics.contains("\r\nSEQUENCE:7\r\n"));
+        assert!(ics.contains("\r\nSTATUS:CONFIRMED\r\n"));
+        assert!(ics.contains("\r\nDTSTART;VALUE=DATE:20260601\r\n"));
+        assert!(ics.contains("\r\nDTEND;VALUE=DATE:20260602\r\n"));
+        assert!(ics.contains("\r\nSUMMARY:Rust\\, Part 1\\; Basics\r\n"));
+        assert!(ics.contains("\r\nDESCRIPTION:Intro\\nOwnership\r\n"));
+    }
+
+    #[test]
+    fn timed_event_uses_floating_start_and_duration() {
+        let ics = exporter().export("Study", &[event(NaiveTime::from_hms_opt(19, 30, 0))]);
+
+        assert!(ics.contains("\r\nDTSTART:20260601T193000\r\n"));
+        assert!(ics.contains("\r\nDURATION:PT1H5M\r\n"));
+        assert!(!ics.contains("DTEND"));
+    }
+
+    #[test]
+    fn cancelled_event_is_marked_cancelled() {
+        let mut dropped = event(None);
+        dropped.cancelled = true;
+        let ics = exporter().export("Study", &[dropped]);
+
+        assert!(ics.contains("\r\nSTATUS:CANCELLED\r\n"));
+        assert!(!ics.contains("CONFIRMED"));
+    }
+
+    #[test]
+    fn long_lines_are_folded_at_75_octets() {
+        let mut long = event(None);
+        long.description = "é".repeat(100);
+        let ics = exporter().export("Study", &[long]);
+
+        assert!(ics.split("\r\n").all(|line| line.len() <= MAX_LINE_OCTETS));
+        let unfolded = ics.replace("\r\n ", "");
+        assert!(unfolded.contains(&format!("DESCRIPTION:{}", "é".repeat(100))));
+    }
+
+    #[test]
+    fn duration_formatting() {
+        assert_eq!(format_duration(0), "PT0S");
+        assert_eq!(format_duration(2700), "PT45M");
+        assert_eq!(format_duration(3661), "PT1H1M1S");
+    }
+}
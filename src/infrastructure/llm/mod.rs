@@ -1,28 +1,35 @@
-//! LLM adapter using genai for multi-provider AI.
+//! LLM adapter using genai for multi-provider AI: Gemini, or any server speaking the
+//! OpenAI chat completions API (Ollama, llama.cpp server, vLLM) for fully offline use.
 
 use std::future::Future;
 use std::pin::Pin;
 use std::time::Duration;
 
-use genai::Client;
-use genai::chat::{ChatMessage, ChatOptions, ChatRequest};
-use genai::resolver::AuthData;
+use futures::StreamExt;
+use genai::adapter::AdapterKind;
+use genai::chat::{ChatMessage, ChatOptions, ChatRequest, ChatStreamEvent};
+use genai::resolver::{AuthData, Endpoint};
+use genai::{Client, ModelIden, ServiceTarget};
 
 use crate::domain::ports::{
-    CompanionAI, CompanionContext, ExaminerAI, LLMError, MCQuestion, ModuleTitleGenerator,
-    SummarizerAI,
+    ChatRole, CompanionAI, CompanionContext, ConversationHistory, ExaminerAI, FlashcardGeneratorAI,
+    GeneratedFlashcard, LLMError, MCQuestion, ModuleTitleGenerator, SummarizerAI, VideoSummary,
 };
-use crate::domain::value_objects::ExamDifficulty;
+use crate::domain::value_objects::{ExamDifficulty, FlashcardKind, KeyTerm, cloze_deletions};
 
-/// Multi-provider AI adapter (Gemini by default).
-pub struct GeminiAdapter {
+/// Bearer token sent to OpenAI-compatible servers configured without a key. genai always
+/// sends one; local servers such as Ollama ignore it.
+const UNUSED_API_KEY: &str = "unused";
+
+/// Multi-provider AI adapter.
+pub struct LlmAdapter {
     client: Client,
     model: String,
 }
 
-impl GeminiAdapter {
-    /// Creates a new adapter with the given API key and model name.
-    pub fn new(api_key: String, model: String) -> Self {
+impl LlmAdapter {
+    /// Creates a Gemini adapter with the given API key and model name.
+    pub fn gemini(api_key: String, model: String) -> Self {
         let model = model.strip_prefix("gemini/").unwrap_or(&model).to_string();
         let client = Client::builder()
             .with_auth_resolver_fn(move |_: genai::ModelIden| {
@@ -32,6 +39,26 @@ impl GeminiAdapter {
         Self { client, model }
     }
 
+    /// Creates an adapter for an OpenAI-compatible server, e.g. `http://localhost:11434/v1`
+    /// for Ollama. Requests go to `{base_url}/chat/completions`.
+    pub fn openai_compatible(base_url: &str, api_key: Option<String>, model: String) -> Self {
+        // Url::join replaces the last path segment unless the base ends with a slash.
+        let endpoint = Endpoint::from_owned(format!("{}/", base_url.trim().trim_end_matches('/')));
+        let auth = AuthData::from_single(
+            api_key.filter(|k| !k.trim().is_empty()).unwrap_or_else(|| UNUSED_API_KEY.to_string()),
+        );
+        let client = Client::builder()
+            .with_service_target_resolver_fn(move |target: ServiceTarget| {
+                Ok(ServiceTarget {
+                    endpoint: endpoint.clone(),
+                    auth: auth.clone(),
+                    model: ModelIden::new(AdapterKind::OpenAI, target.model.model_name),
+                })
+            })
+            .build();
+        Self { client, model: model.trim().to_string() }
+    }
+
     /// Executes a chat request with automatic retry on transient errors.
     async fn execute_with_retry(
         &self,
@@ -39,10 +66,6 @@ impl GeminiAdapter {
         user_prompt: &str,
         temperature: Option<f64>,
     ) -> Result<String, LLMError> {
-        const MAX_RETRIES: u32 = 3;
-        let delays =
-            [Duration::from_millis(500), Duration::from_millis(1000), Duration::from_millis(2000)];
-
         let messages: Vec<ChatMessage> = {
             let mut v = Vec::with_capacity(2);
             if let Some(sys) = system_prompt {
@@ -51,6 +74,18 @@ impl GeminiAdapter {
             v.push(ChatMessage::user(user_prompt));
             v
         };
+        self.execute_messages_with_retry(messages, temperature).await
+    }
+
+    /// Executes a chat request over a whole conversation, retrying transient errors.
+    async fn execute_messages_with_retry(
+        &self,
+        messages: Vec<ChatMessage>,
+        temperature: Option<f64>,
+    ) -> Result<String, LLMError> {
+        const MAX_RETRIES: u32 = 3;
+        let delays =
+            [Duration::from_millis(500), Duration::from_millis(1000), Duration::from_millis(2000)];
 
         for attempt in 0..MAX_RETRIES {
             let req = ChatRequest::new(messages.clone());
@@ -81,13 +116,46 @@ impl GeminiAdapter {
 
         Err(LLMError::Api("Max retries exceeded".to_string()))
     }
+
+    /// Executes a chat request as a stream, passing each text chunk to `on_delta`.
+    ///
+    /// Not retried: part of the answer may already have been shown.
+    async fn execute_streaming(
+        &self,
+        messages: Vec<ChatMessage>,
+        temperature: Option<f64>,
+        on_delta: &(dyn Fn(&str) + Send + Sync),
+    ) -> Result<String, LLMError> {
+        let req = ChatRequest::new(messages);
+        let options = temperature.map(|t| ChatOptions::default().with_temperature(t));
+        let mut response = self
+            .client
+            .exec_chat_stream(&self.model, req, options.as_ref())
+            .await
+            .map_err(|e| LLMError::Api(e.to_string()))?;
+
+        let mut answer = String::new();
+        while let Some(event) = response.stream.next().await {
+            if let ChatStreamEvent::Chunk(chunk) =
+                event.map_err(|e| LLMError::Api(e.to_string()))?
+                && !chunk.content.is_empty()
+            {
+                on_delta(&chunk.content);
+                answer.push_str(&chunk.content);
+            }
+        }
+        if answer.trim().is_empty() {
+            return Err(LLMError::InvalidResponse("Empty response".into()));
+        }
+        Ok(answer)
+    }
 }
 
 /// Extracts a JSON array or object from an LLM response string, skipping
 /// any surrounding markdown fences or explanatory text.
 fn extract_json_from_response(text: &str) -> Result<&str, LLMError> {
     let text = text.trim();
-    let start = text.find('[').or_else(|| text.find('{')).ok_or_else(|| {
+    let start = text.find(['[', '{']).ok_or_else(|| {
         LLMError::InvalidResponse("No JSON array or object found in response".into())
     })?;
     let end = if text.as_bytes()[start] == b'[' {
@@ -98,29 +166,79 @@ fn extract_json_from_response(text: &str) -> Result<&str, LLMError> {
     Ok(&text[start..end])
 }
 
-#[async_trait::async_trait]
-impl CompanionAI for GeminiAdapter {
-    async fn ask(&self, question: &str, context: &CompanionContext) -> Result<String, LLMError> {
-        use std::borrow::Cow;
-        fn truncate_cow(value: &str, limit: usize) -> Cow<'_, str> {
-            if value.chars().count() <= limit {
-                Cow::Borrowed(value)
-            } else {
-                let mut out: String = value.chars().take(limit).collect();
-                out.push_str("… [truncated]");
-                Cow::Owned(out)
-            }
+/// Builds the companion conversation: the earlier turns, then the new question with the
+/// video context.
+fn companion_messages(
+    question: &str,
+    context: &CompanionContext,
+    history: &ConversationHistory,
+) -> Vec<ChatMessage> {
+    let mut messages: Vec<ChatMessage> = history
+        .turns
+        .iter()
+        .map(|turn| match turn.role {
+            ChatRole::User => ChatMessage::user(turn.content.as_str()),
+            ChatRole::Assistant => ChatMessage::assistant(turn.content.as_str()),
+        })
+        .collect();
+    messages.push(ChatMessage::user(companion_prompt(question, context, history)));
+    messages
+}
+
+/// Builds the companion prompt for a question about the current video.
+fn companion_prompt(
+    question: &str,
+    context: &CompanionContext,
+    history: &ConversationHistory,
+) -> String {
+    use std::borrow::Cow;
+    fn truncate_cow(value: &str, limit: usize) -> Cow<'_, str> {
+        if value.chars().count() <= limit {
+            Cow::Borrowed(value)
+        } else {
+            let mut out: String = value.chars().take(limit).collect();
+            out.push_str("… [truncated]");
+            Cow::Owned(out)
         }
+    }
 
-        let description =
-            truncate_cow(context.video_description.as_deref().unwrap_or("Not available"), 1200);
-        let summary = truncate_cow(context.summary.as_deref().unwrap_or("Not available"), 2500);
-        let notes = truncate_cow(context.notes.as_deref().unwrap_or("Not available"), 1200);
-        let local_context =
-            truncate_cow(context.local_context.as_deref().unwrap_or("Not provided"), 1200);
+    let description =
+        truncate_cow(context.video_description.as_deref().unwrap_or("Not available"), 1200);
+    let summary = truncate_cow(context.summary.as_deref().unwrap_or("Not available"), 2500);
+    let notes = truncate_cow(context.notes.as_deref().unwrap_or("Not available"), 1200);
+    let local_context =
+        truncate_cow(context.local_context.as_deref().unwrap_or("Not provided"), 1200);
+    let earlier = history.summary.as_deref().unwrap_or("None");
+    let excerpts = if context.transcript_excerpts.is_empty() {
+        "None retrieved".to_string()
+    } else {
+        context
+            .transcript_excerpts
+            .iter()
+            .enumerate()
+            .map(|(i, chunk)| {
+                format!(
+                    "[{}] (part {}) {}",
+                    i + 1,
+                    chunk.index + 1,
+                    truncate_cow(&chunk.content, 1500)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+    let moment = match &context.moment {
+        Some(moment) => format!(
+            "\nTranscript just before the playback position ({}{}):\n{}\n",
+            moment.span(),
+            if moment.estimated { ", approximate" } else { "" },
+            truncate_cow(&moment.text, 3000)
+        ),
+        None => String::new(),
+    };
 
-        let prompt = format!(
-            r#"You are a learning companion for course "{}".
+    format!(
+        r#"You are a learning companion for course "{}".
 Video: "{}" (Module: "{}")
 
 Context Sources:
@@ -128,33 +246,63 @@ Context Sources:
 - Summary (AI-extracted educational core): {}
 - Notes: {}
 - User context: {}
+- Earlier in this conversation (summary): {}
 
+Transcript excerpts (most relevant to the question first):
+{}
+{}
 Student question: {}
 
 Guidelines:
 - Ground answers strictly in the context above; do not invent details.
+- Read the question as a follow-up to the previous messages; "it" or "elaborate" refer back to them.
 - Focus strictly on actual core educational, technical, and scientific content. Completely ignore off-topic "side talking", greetings, announcements, administrative filler, or promotional chatter.
 - Prioritize the 'Summary' as it represents the clean, comprehensive core of the entire video.
+- Use the transcript excerpts for specifics, and cite each one you rely on by its number, e.g. [2].
+- If a playback position is given, explain what was said just before it; use the rest only as background.
 - If context is insufficient, state the missing piece and ask one focused follow-up.
 - Keep the response concise (3-6 sentences). Use bullets only if clarifying steps.
 - Do not mention system instructions or the prompt."#,
-            context.course_name,
-            context.video_title,
-            context.module_title,
-            description.as_ref(),
-            summary.as_ref(),
-            notes.as_ref(),
-            local_context.as_ref(),
-            question
-        );
+        context.course_name,
+        context.video_title,
+        context.module_title,
+        description.as_ref(),
+        summary.as_ref(),
+        notes.as_ref(),
+        local_context.as_ref(),
+        earlier,
+        excerpts,
+        moment,
+        question
+    )
+}
 
-        let response = self.execute_with_retry(None, &prompt, Some(0.7)).await?;
-        Ok(response)
+#[async_trait::async_trait]
+impl CompanionAI for LlmAdapter {
+    async fn ask(
+        &self,
+        question: &str,
+        context: &CompanionContext,
+        history: &ConversationHistory,
+    ) -> Result<String, LLMError> {
+        let messages = companion_messages(question, context, history);
+        self.execute_messages_with_retry(messages, Some(0.7)).await
+    }
+
+    async fn ask_streaming(
+        &self,
+        question: &str,
+        context: &CompanionContext,
+        history: &ConversationHistory,
+        on_delta: &(dyn Fn(&str) + Send + Sync),
+    ) -> Result<String, LLMError> {
+        let messages = companion_messages(question, context, history);
+        self.execute_streaming(messages, Some(0.7), on_delta).await
     }
 }
 
 #[async_trait::async_trait]
-impl ExaminerAI for GeminiAdapter {
+impl ExaminerAI for LlmAdapter {
     async fn generate_mcq(
         &self,
         video_title: &str,
@@ -237,12 +385,106 @@ Rules:
 }
 
 #[async_trait::async_trait]
-impl SummarizerAI for GeminiAdapter {
+impl FlashcardGeneratorAI for LlmAdapter {
+    async fn generate_flashcards(
+        &self,
+        video_title: &str,
+        video_summary: Option<&str>,
+        transcript: Option<&str>,
+        key_terms: &[KeyTerm],
+        max_cards: u8,
+    ) -> Result<Vec<GeneratedFlashcard>, LLMError> {
+        // Transcripts only fill gaps left by the summary; keep them well inside the context window.
+        let max_transcript_chars = 30_000;
+        let summary = video_summary.unwrap_or("Not available");
+        let transcript: String = match transcript {
+            Some(t) => t.chars().take(max_transcript_chars).collect(),
+            None => "Not available".to_string(),
+        };
+        let glossary = if key_terms.is_empty() {
+            "None".to_string()
+        } else {
+            key_terms
+                .iter()
+                .map(|t| format!("- {}: {}", t.term, t.definition))
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+        let prompt = format!(
+            r#"You are an expert tutor writing flashcards for spaced-repetition study of a video.
+Focus strictly on core educational, technical, and scientific content. Ignore greetings, side talk, announcements, and promotional filler.
+
+Context Sources:
+- Video Title: "{video_title}"
+- Summary (AI-extracted educational core): {summary}
+- Known key terms:
+{glossary}
+- Transcript excerpt: {transcript}
+
+Instructions:
+1. Generate at most {max_cards} flashcards. Cover every known key term first, then the most important remaining concepts.
+2. Use two kinds of cards:
+   - "basic": "front" is a term or short question, "back" is a concise definition or answer (1-2 sentences).
+   - "cloze": "front" is one self-contained sentence in which the key words to recall are wrapped in double curly braces, e.g. "Rust frees memory automatically through {{{{RAII}}}}." "back" may hold one sentence of extra context, or be empty.
+3. Each card tests exactly one idea. Do not repeat the same fact across cards.
+4. Use only information from the context above. Never ask about timestamps, speakers, or visual details.
+
+Output Format:
+Return ONLY a valid, parseable JSON array. Do not wrap in markdown or write conversational filler. The schema MUST be:
+[
+  {{ "kind": "basic", "front": "Term", "back": "Definition" }},
+  {{ "kind": "cloze", "front": "A sentence with a {{{{hidden}}}} part.", "back": "" }}
+]"#,
+        );
+
+        let text = self.execute_with_retry(None, &prompt, Some(0.2)).await?;
+        let json_text = extract_json_from_response(&text)?;
+
+        let cards: Vec<GeneratedFlashcard> = serde_json::from_str(json_text)
+            .map_err(|e| LLMError::InvalidResponse(format!("JSON parse error: {}", e)))?;
+        validate_flashcards(&cards, max_cards)?;
+        Ok(cards)
+    }
+}
+
+/// Rejects generated flashcard sets that are empty, oversized, or contain malformed cards.
+fn validate_flashcards(cards: &[GeneratedFlashcard], max_cards: u8) -> Result<(), LLMError> {
+    if cards.is_empty() {
+        return Err(LLMError::InvalidResponse("No flashcards generated".into()));
+    }
+    if cards.len() > usize::from(max_cards.max(1)) {
+        return Err(LLMError::InvalidResponse("Too many flashcards generated".into()));
+    }
+    for (i, card) in cards.iter().enumerate() {
+        if card.front.trim().is_empty() {
+            return Err(LLMError::InvalidResponse(format!("Flashcard {} has an empty front", i)));
+        }
+        match card.kind {
+            FlashcardKind::Basic if card.back.trim().is_empty() => {
+                return Err(LLMError::InvalidResponse(format!(
+                    "Basic flashcard {} has an empty back",
+                    i
+                )));
+            },
+            FlashcardKind::Cloze if cloze_deletions(&card.front).is_empty() => {
+                return Err(LLMError::InvalidResponse(format!(
+                    "Cloze flashcard {} has no hidden span",
+                    i
+                )));
+            },
+            _ => {},
+        }
+    }
+    Ok(())
+}
+
+#[async_trait::async_trait]
+impl SummarizerAI for LlmAdapter {
     async fn summarize_transcript(
         &self,
         transcript: &str,
         video_title: &str,
-    ) -> Result<String, LLMError> {
+    ) -> Result<VideoSummary, LLMError> {
         // Truncate long transcripts to stay within token limits
         let max_chars = 100_000;
         let truncated: &str = if transcript.chars().count() > max_chars {
@@ -263,15 +505,14 @@ Video: ""#;
             let template_mid = "\"\nTranscript:\n";
             let template_end = r#"
 
-Output format (plain text only):
-1. Main Topic: <one sentence summarizing the primary scientific/educational topic>
-2. Key Points:
-- ... (strictly core conceptual or technical learnings, ignore filler)
-- ...
-- ...
-3. Key Terms:
-- term: short definition
-(or "None")
+Output Format:
+Return ONLY a valid, parseable JSON object. Do not wrap in markdown or write conversational filler. The schema MUST be:
+{
+  "summary": "2-4 sentences on the primary scientific/educational topic",
+  "key_points": ["strictly core conceptual or technical learning", "..."],
+  "key_terms": [{ "term": "Term", "definition": "short definition" }]
+}
+Use an empty array for "key_terms" when the video introduces no terminology.
 
 Rules:
 - Focus solely on concrete, learnable concepts and scientific content from the transcript.
@@ -295,11 +536,33 @@ Rules:
         };
 
         let text = self.execute_with_retry(None, &prompt, Some(0.3)).await?;
-        Ok(text)
+        parse_video_summary(&text)
+    }
+}
+
+/// Parses a structured summary, dropping blank key points and incomplete glossary entries.
+fn parse_video_summary(text: &str) -> Result<VideoSummary, LLMError> {
+    let json_text = extract_json_from_response(text)?;
+    let parsed: VideoSummary = serde_json::from_str(json_text)
+        .map_err(|e| LLMError::InvalidResponse(format!("JSON parse error: {}", e)))?;
+
+    let summary = parsed.summary.trim().to_string();
+    if summary.is_empty() {
+        return Err(LLMError::InvalidResponse("Summary is empty".into()));
     }
+    let key_points = parsed
+        .key_points
+        .iter()
+        .map(|p| p.trim())
+        .filter(|p| !p.is_empty())
+        .map(str::to_string)
+        .collect();
+    let key_terms =
+        parsed.key_terms.iter().filter_map(|t| KeyTerm::new(&t.term, &t.definition)).collect();
+    Ok(VideoSummary { summary, key_points, key_terms })
 }
 
-impl ModuleTitleGenerator for GeminiAdapter {
+impl ModuleTitleGenerator for LlmAdapter {
     fn generate_module_title(
         &self,
         video_titles: &[String],
@@ -373,4 +636,189 @@ mod tests {
         let result = extract_json_from_response(input).unwrap();
         assert_eq!(result, input.trim());
     }
+
+    #[test]
+    fn extract_json_prefers_outer_object_over_inner_array() {
+        let input = "```json\n{\"key_points\": [\"a\"]}\n```";
+        let result = extract_json_from_response(input).unwrap();
+        assert_eq!(result, "{\"key_points\": [\"a\"]}");
+    }
+
+    #[test]
+    fn parses_structured_summary_and_drops_blank_entries() {
+        let input = r#"Sure! {"summary": " Ownership in Rust. ",
+            "key_points": ["Values have one owner", "  "],
+            "key_terms": [{"term": "Borrow", "definition": "A reference"},
+                          {"term": "Move", "definition": ""}]}"#;
+        let summary = parse_video_summary(input).unwrap();
+        assert_eq!(summary.summary, "Ownership in Rust.");
+        assert_eq!(summary.key_points, vec!["Values have one owner".to_string()]);
+        assert_eq!(summary.key_terms, vec![KeyTerm::new("Borrow", "A reference").unwrap()]);
+
+        let no_terms = parse_video_summary(r#"{"summary": "Intro"}"#).unwrap();
+        assert!(no_terms.key_points.is_empty() && no_terms.key_terms.is_empty());
+        assert!(parse_video_summary(r#"{"summary": " ", "key_points": []}"#).is_err());
+    }
+
+    #[test]
+    fn validate_flashcards_rejects_malformed_cards() {
+        let card = |kind, front: &str, back: &str| GeneratedFlashcard {
+            kind,
+            front: front.to_string(),
+            back: back.to_string(),
+        };
+        let valid = vec![
+            card(FlashcardKind::Basic, "Borrow", "A reference"),
+            card(FlashcardKind::Cloze, "Values have {{one owner}}.", ""),
+        ];
+        assert!(validate_flashcards(&valid, 5).is_ok());
+        assert!(validate_flashcards(&valid, 1).is_err());
+        assert!(validate_flashcards(&[], 5).is_err());
+        assert!(validate_flashcards(&[card(FlashcardKind::Basic, "Borrow", "")], 5).is_err());
+        assert!(validate_flashcards(&[card(FlashcardKind::Cloze, "No blank", "")], 5).is_err());
+    }
+
+    #[test]
+    fn generated_flashcard_back_defaults_to_empty() {
+        let json = r#"[{"kind": "cloze", "front": "A {{gap}}."}]"#;
+        let cards: Vec<GeneratedFlashcard> = serde_json::from_str(json).unwrap();
+        assert_eq!(cards[0].back, "");
+        assert_eq!(cards[0].kind, FlashcardKind::Cloze);
+    }
+
+    /// Serves one canned response on a local port and hands back the raw request.
+    fn serve_once(
+        content_type: &'static str,
+        body: String,
+    ) -> (String, std::thread::JoinHandle<String>) {
+        use std::io::{BufRead, BufReader, Read, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}/v1/", listener.local_addr().unwrap());
+        let server = std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request = String::new();
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(len) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                    content_length = len.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" {
+                    break;
+                }
+            }
+            let mut request_body = vec![0; content_length];
+            reader.read_exact(&mut request_body).unwrap();
+            request.push_str(&String::from_utf8(request_body).unwrap());
+
+            let mut stream = reader.into_inner();
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\n\
+                 Connection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+            request
+        });
+        (base_url, server)
+    }
+
+    #[tokio::test]
+    async fn openai_compatible_adapter_talks_to_a_local_server() {
+        let body = serde_json::json!({
+            "model": "llama3.2",
+            "choices": [{
+                "index": 0,
+                "message": { "role": "assistant", "content": "Ownership and Borrowing" },
+            }],
+        });
+        let (base_url, server) = serve_once("application/json", body.to_string());
+        let adapter = LlmAdapter::openai_compatible(&base_url, None, "llama3.2".to_string());
+
+        let title = adapter.generate_module_title(&["Moves".to_string()], "Rust", 0).await.unwrap();
+        assert_eq!(title, "Ownership and Borrowing");
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /v1/chat/completions "), "{request}");
+        assert!(request.contains(r#""model":"llama3.2""#), "{request}");
+        assert!(request.to_ascii_lowercase().contains("authorization: bearer unused"));
+    }
+
+    #[tokio::test]
+    async fn companion_answer_streams_in_deltas() {
+        use crate::domain::ports::{ConversationTurn, PlaybackMoment, TranscriptChunk};
+
+        let body: String = ["Values have ", "one owner."]
+            .iter()
+            .map(|delta| {
+                let chunk = serde_json::json!({
+                    "choices": [{ "index": 0, "delta": { "content": delta } }],
+                });
+                format!("data: {chunk}\n\n")
+            })
+            .chain(["data: [DONE]\n\n".to_string()])
+            .collect();
+        let (base_url, server) = serve_once("text/event-stream", body);
+        let adapter = LlmAdapter::openai_compatible(&base_url, None, "llama3.2".to_string());
+        let context = CompanionContext {
+            video_title: "Moves".to_string(),
+            video_description: None,
+            module_title: "Ownership".to_string(),
+            course_name: "Rust".to_string(),
+            summary: None,
+            notes: None,
+            local_context: None,
+            transcript_excerpts: vec![TranscriptChunk {
+                index: 3,
+                content: "Assigning a value moves it.".to_string(),
+            }],
+            moment: Some(PlaybackMoment {
+                from_secs: 90,
+                to_secs: 180,
+                text: "Then the old binding is invalid.".to_string(),
+                estimated: false,
+            }),
+        };
+
+        let history = ConversationHistory {
+            summary: None,
+            turns: vec![
+                ConversationTurn { role: ChatRole::User, content: "What is a move?".to_string() },
+                ConversationTurn {
+                    role: ChatRole::Assistant,
+                    content: "Ownership passes on.".to_string(),
+                },
+            ],
+        };
+
+        let deltas = std::sync::Mutex::new(Vec::new());
+        let on_delta = |delta: &str| deltas.lock().unwrap().push(delta.to_string());
+        let answer = adapter
+            .ask_streaming("Who owns a value?", &context, &history, &on_delta)
+            .await
+            .unwrap();
+
+        assert_eq!(answer, "Values have one owner.");
+        assert_eq!(*deltas.lock().unwrap(), ["Values have ", "one owner."]);
+        let request = server.join().unwrap();
+        assert!(request.contains(r#""stream":true"#));
+        // Earlier turns go first, as their own messages
+        assert!(
+            request.contains(
+                r#"{"content":"What is a move?","role":"user"},{"content":"Ownership passes on.","role":"assistant"}"#
+            ),
+            "{request}"
+        );
+        // Retrieved passages are numbered for citing
+        assert!(request.contains("[1] (part 4) Assigning a value moves it."), "{request}");
+        assert!(
+            request.contains("Transcript just before the playback position (1:30\u{2013}3:00):"),
+            "{request}"
+        );
+    }
 }
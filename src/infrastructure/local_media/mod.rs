@@ -1,7 +1,7 @@
 //! Local media scanner adapter.
 //!
 //! Scans a local folder recursively and extracts lightweight metadata for video files.
-//! Duration extraction uses GStreamer Discoverer for universal container support.
+//! Duration and chapter extraction use GStreamer Discoverer for universal container support.
 
 use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
@@ -13,7 +13,8 @@ use gst_pbutils::Discoverer;
 use walkdir::WalkDir;
 
 use crate::domain::ports::{
-    LocalMediaError, LocalMediaScanner, RawLocalMediaMetadata, RawSubtitleMetadata,
+    CancellationToken, IngestProgress, LocalMediaError, LocalMediaScanner, ProgressSink,
+    RawChapter, RawLocalMediaMetadata, RawSubtitleMetadata,
 };
 
 /// Local media scanner implementation backed by GStreamer Discoverer.
@@ -31,7 +32,9 @@ impl LocalMediaScannerAdapter {
         Ok(Self { discoverer })
     }
 
-    async fn scan_video_file(
+    /// Reads a video's title, duration, chapters and side-car subtitle. Embedded subtitles
+    /// are extracted in a second pass, once every file has been probed.
+    fn scan_video_file(
         &self,
         path: &Path,
         subtitle: Option<&PathBuf>,
@@ -47,27 +50,20 @@ impl LocalMediaScannerAdapter {
             .filter(|s| !s.trim().is_empty())
             .unwrap_or_else(|| "Untitled".to_string());
 
-        let duration_secs = self.get_video_duration(path).unwrap_or(0);
+        let (duration_secs, chapters) = self.probe(path).unwrap_or_default();
 
-        let mut subtitles: Vec<RawSubtitleMetadata> = subtitle
+        let subtitles: Vec<RawSubtitleMetadata> = subtitle
             .map(|p| RawSubtitleMetadata { path: p.to_string_lossy().to_string() })
             .into_iter()
             .collect();
 
-        if subtitles.is_empty() {
-            let embedded = extract_embedded_subtitles_ffmpeg(path).await;
-            subtitles = embedded
-                .into_iter()
-                .map(|p| RawSubtitleMetadata { path: p.to_string_lossy().to_string() })
-                .collect();
-        }
-
         let absolute = path.to_string_lossy().to_string();
 
-        Some(RawLocalMediaMetadata { path: absolute, title, duration_secs, subtitles })
+        Some(RawLocalMediaMetadata { path: absolute, title, duration_secs, subtitles, chapters })
     }
 
-    fn get_video_duration(&self, path: &Path) -> Result<u32, LocalMediaError> {
+    /// Reads the duration and the chapter table of contents, if the container has one.
+    fn probe(&self, path: &Path) -> Result<(u32, Vec<RawChapter>), LocalMediaError> {
         let url = url::Url::from_file_path(path)
             .map(|u| u.to_string())
             .unwrap_or_else(|_| format!("file://{}", path.to_string_lossy()));
@@ -77,13 +73,24 @@ impl LocalMediaScannerAdapter {
             .map_err(|e| LocalMediaError::Metadata(e.to_string()))?;
         let duration =
             info.duration().ok_or_else(|| LocalMediaError::Metadata("no duration".into()))?;
-        Ok(duration.seconds() as u32)
+        let duration_secs = duration.seconds() as u32;
+        let chapters = info.toc().map(|toc| toc_chapters(&toc, duration_secs)).unwrap_or_default();
+        Ok((duration_secs, chapters))
     }
 }
 
 #[async_trait::async_trait]
 impl LocalMediaScanner for LocalMediaScannerAdapter {
     async fn scan(&self, root: &str) -> Result<Vec<RawLocalMediaMetadata>, LocalMediaError> {
+        self.scan_with_progress(root, &|_: IngestProgress| {}, &CancellationToken::new()).await
+    }
+
+    async fn scan_with_progress(
+        &self,
+        root: &str,
+        progress: &dyn ProgressSink,
+        cancel: &CancellationToken,
+    ) -> Result<Vec<RawLocalMediaMetadata>, LocalMediaError> {
         let root_path = Path::new(root);
         if !root_path.exists() {
             return Err(LocalMediaError::Io(format!("path does not exist: {root}")));
@@ -107,6 +114,9 @@ impl LocalMediaScanner for LocalMediaScannerAdapter {
                 .filter_map(|e| e.ok())
                 .filter(|e| e.file_type().is_file())
             {
+                if cancel.is_cancelled() {
+                    return Err(LocalMediaError::Cancelled);
+                }
                 let path = entry.path();
                 if is_video_file(path) {
                     video_paths.push(path.to_path_buf());
@@ -118,17 +128,77 @@ impl LocalMediaScanner for LocalMediaScannerAdapter {
 
         let assignments = match_subtitles_greedy(&video_paths, &subtitle_paths);
 
-        let mut results = Vec::new();
-        for path in video_paths {
-            if let Some(item) = self.scan_video_file(&path, assignments.get(&path)).await {
+        let total = video_paths.len();
+        let mut results = Vec::with_capacity(total);
+        for (done, path) in video_paths.iter().enumerate() {
+            if cancel.is_cancelled() {
+                return Err(LocalMediaError::Cancelled);
+            }
+            progress.report(IngestProgress::Discovering { done, total });
+            if let Some(item) = self.scan_video_file(path, assignments.get(path)) {
                 results.push(item);
             }
         }
+        progress.report(IngestProgress::Discovering { done: total, total });
+
+        // Only files without a side-car subtitle are searched for embedded ones.
+        let pending: Vec<usize> =
+            (0..results.len()).filter(|&i| results[i].subtitles.is_empty()).collect();
+        for (done, &i) in pending.iter().enumerate() {
+            if cancel.is_cancelled() {
+                return Err(LocalMediaError::Cancelled);
+            }
+            progress.report(IngestProgress::ExtractingSubtitles { done, total: pending.len() });
+            let embedded = extract_embedded_subtitles_ffmpeg(Path::new(&results[i].path)).await;
+            results[i].subtitles = embedded
+                .into_iter()
+                .map(|p| RawSubtitleMetadata { path: p.to_string_lossy().to_string() })
+                .collect();
+        }
 
         Ok(results)
     }
 }
 
+/// Chapters of a TOC in order. Chapters without an end time run until the next chapter,
+/// or to the end of the file for the last one.
+fn toc_chapters(toc: &gst::TocRef, duration_secs: u32) -> Vec<RawChapter> {
+    let mut chapters = Vec::new();
+    collect_chapters(&toc.entries(), &mut chapters);
+    for i in 0..chapters.len() {
+        let next_start = chapters.get(i + 1).map_or(duration_secs, |next| next.start_secs);
+        let chapter = &mut chapters[i];
+        if chapter.end_secs <= chapter.start_secs {
+            chapter.end_secs = next_start;
+        }
+    }
+    chapters
+}
+
+/// Flattens the chapter entries of a TOC, descending into editions and nested chapters.
+fn collect_chapters(entries: &[gst::TocEntry], out: &mut Vec<RawChapter>) {
+    for entry in entries {
+        let sub_entries = entry.sub_entries();
+        if entry.entry_type() != gst::TocEntryType::Chapter || !sub_entries.is_empty() {
+            collect_chapters(&sub_entries, out);
+            continue;
+        }
+        let Some((start_ns, stop_ns)) = entry.start_stop_times() else {
+            continue;
+        };
+        let title = entry
+            .tags()
+            .and_then(|tags| tags.get::<gst::tags::Title>().map(|t| t.get().to_string()))
+            .filter(|t| !t.trim().is_empty())
+            .unwrap_or_else(|| format!("Chapter {}", out.len() + 1));
+        out.push(RawChapter {
+            title,
+            start_secs: (start_ns.max(0) / 1_000_000_000) as u32,
+            end_secs: (stop_ns.max(0) / 1_000_000_000) as u32,
+        });
+    }
+}
+
 async fn extract_embedded_subtitles_ffmpeg(path: &Path) -> Vec<PathBuf> {
     let mut ffmpeg_check_cmd = Command::new("ffmpeg");
     ffmpeg_check_cmd.arg("-version");
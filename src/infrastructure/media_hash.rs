@@ -1,11 +1,43 @@
+use std::fs::File;
+use std::io::{self, Read, Seek, SeekFrom};
+use std::path::Path;
+
 use sha2::{Digest, Sha256};
 
+/// Bytes hashed from each end of a file when fingerprinting it.
+const FINGERPRINT_CHUNK: u64 = 64 * 1024;
+
 pub fn compute_source_hash(source: &str) -> String {
     let mut hasher = Sha256::new();
     hasher.update(source.as_bytes());
     format!("{:x}", hasher.finalize())
 }
 
+/// Fingerprints a file by its size and the bytes at its start and end.
+///
+/// Cheap enough to run over a whole video library, and stable across renames and moves.
+pub fn compute_file_fingerprint(path: impl AsRef<Path>) -> io::Result<String> {
+    let mut file = File::open(path)?;
+    let len = file.metadata()?.len();
+
+    let mut hasher = Sha256::new();
+    hasher.update(len.to_le_bytes());
+    let mut buf = Vec::with_capacity(FINGERPRINT_CHUNK as usize);
+    file.by_ref().take(FINGERPRINT_CHUNK).read_to_end(&mut buf)?;
+    hasher.update(&buf);
+    if len > FINGERPRINT_CHUNK * 2 {
+        buf.clear();
+        file.seek(SeekFrom::End(-(FINGERPRINT_CHUNK as i64)))?;
+        file.read_to_end(&mut buf)?;
+        hasher.update(&buf);
+    } else if len > FINGERPRINT_CHUNK {
+        buf.clear();
+        file.read_to_end(&mut buf)?;
+        hasher.update(&buf);
+    }
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -29,4 +61,26 @@ mod tests {
         let h = compute_source_hash("/videos/test");
         assert!(!h.is_empty());
     }
+
+    #[test]
+    fn file_fingerprint_follows_content_not_path() {
+        let dir = std::env::temp_dir().join(format!("cp-fingerprint-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let big: Vec<u8> = (0..200_000u32).map(|i| (i % 251) as u8).collect();
+        let mut changed_tail = big.clone();
+        *changed_tail.last_mut().unwrap() ^= 0xff;
+
+        let write = |name: &str, data: &[u8]| {
+            let path = dir.join(name);
+            std::fs::write(&path, data).unwrap();
+            compute_file_fingerprint(&path).unwrap()
+        };
+        let original = write("a.mp4", &big);
+        assert_eq!(write("renamed.mp4", &big), original);
+        assert_ne!(write("tail.mp4", &changed_tail), original);
+        assert_ne!(write("short.mp4", &big[..1000]), original);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert!(compute_file_fingerprint(dir.join("a.mp4")).is_err());
+    }
 }
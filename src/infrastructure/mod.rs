@@ -1,5 +1,6 @@
 //! Infrastructure Layer - Adapters implementing domain ports.
 
+pub mod calendar;
 pub mod discord;
 pub mod keystore;
 pub mod llm;
@@ -95,7 +95,7 @@ mod tests {
     use super::*;
     use crate::domain::entities::{Course, Module, Video};
     use crate::domain::ports::{CourseRepository, ModuleRepository, VideoRepository};
-    use crate::domain::value_objects::{CourseId, ModuleId, PlaylistUrl};
+    use crate::domain::value_objects::{CourseId, CourseSource, ModuleId, PlaylistUrl};
     use crate::infrastructure::persistence::repositories::{
         SqliteCourseRepository, SqliteModuleRepository, SqliteVideoRepository,
     };
@@ -117,8 +117,7 @@ mod tests {
         let course = Course::new(
             course_id,
             "Test Course".to_string(),
-            playlist_url,
-            "PL38E37F4BE52E385D".to_string(),
+            CourseSource::youtube_playlist(playlist_url),
             Some("Description".to_string()),
             None,
         );
@@ -5,6 +5,7 @@ use diesel::r2d2::{ConnectionManager, Pool};
 use diesel_migrations::{EmbeddedMigrations, MigrationHarness, embed_migrations};
 
 use crate::application::context::AppContextError;
+use crate::infrastructure::persistence::data_migrations::backfill_local_course_roots;
 
 pub type DbPool = Pool<ConnectionManager<SqliteConnection>>;
 
@@ -40,6 +41,9 @@ pub fn establish_connection(database_url: &str) -> Result<DbPool, AppContextErro
 
     conn.run_pending_migrations(MIGRATIONS)
         .map_err(|e| AppContextError::Database(format!("Failed to run migrations: {}", e)))?;
+    backfill_local_course_roots(&mut conn).map_err(|e| {
+        AppContextError::Database(format!("Failed to recover local course folders: {}", e))
+    })?;
 
     Ok(pool)
 }
@@ -0,0 +1,141 @@
+//! Data fixes that need more than SQL, run at startup after the schema migrations.
+
+use std::path::{Path, PathBuf};
+
+use diesel::prelude::*;
+
+use crate::infrastructure::media_hash;
+use crate::schema::{courses, modules, videos};
+
+/// Recovers the folder of local courses that the `add_course_source_type` migration could only
+/// mark as local.
+///
+/// The root is the longest common parent of the course's local video paths. When a course only
+/// has videos in subfolders, that parent sits below the imported folder, so the ancestor whose
+/// hash matches `courses.source_hash` wins. Courses with no local video left become manual.
+pub(crate) fn backfill_local_course_roots(conn: &mut SqliteConnection) -> QueryResult<()> {
+    let pending: Vec<(String, Option<String>)> = courses::table
+        .filter(courses::source_type.eq("local"))
+        .filter(courses::source_url.eq(""))
+        .select((courses::id, courses::source_hash))
+        .load(conn)?;
+
+    for (course_id, source_hash) in pending {
+        let paths: Vec<String> = videos::table
+            .inner_join(modules::table)
+            .filter(modules::course_id.eq(&course_id))
+            .filter(videos::source_type.eq("local"))
+            .select(videos::source_ref)
+            .load(conn)?;
+
+        let course = courses::table.find(&course_id);
+        match local_root(&paths, source_hash.as_deref()) {
+            Some(root) => {
+                diesel::update(course).set(courses::source_url.eq(root)).execute(conn)?;
+            },
+            None => {
+                diesel::update(course).set(courses::source_type.eq("manual")).execute(conn)?;
+            },
+        }
+    }
+    Ok(())
+}
+
+/// The folder a local course was imported from, given its video paths and source hash.
+fn local_root(paths: &[String], source_hash: Option<&str>) -> Option<String> {
+    let common = common_parent(paths.iter().map(Path::new))?;
+    if !common.is_absolute() {
+        return None;
+    }
+
+    let root = common
+        .ancestors()
+        .map(|dir| dir.to_string_lossy().to_string())
+        .find(|dir| source_hash == Some(media_hash::compute_source_hash(dir).as_str()));
+    if root.is_none() && source_hash.is_some() {
+        log::warn!(
+            "No parent of {} matches the course's source hash; using it as the course folder",
+            common.display()
+        );
+    }
+    Some(root.unwrap_or_else(|| common.to_string_lossy().to_string()))
+}
+
+/// Longest directory that contains every path, or `None` without paths.
+fn common_parent<'a>(mut paths: impl Iterator<Item = &'a Path>) -> Option<PathBuf> {
+    let mut common = paths.next()?.parent()?.to_path_buf();
+    for path in paths {
+        while !path.starts_with(&common) {
+            if !common.pop() {
+                return None;
+            }
+        }
+    }
+    Some(common)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use diesel::connection::SimpleConnection;
+
+    use crate::infrastructure::persistence::establish_connection;
+
+    fn paths(raw: &[&str]) -> Vec<String> {
+        raw.iter().map(|p| p.to_string()).collect()
+    }
+
+    #[test]
+    fn root_is_the_longest_common_parent() {
+        let videos = paths(&["/courses/rust/a/1.mp4", "/courses/rust/b/deep/2.mp4"]);
+        assert_eq!(local_root(&videos, None).as_deref(), Some("/courses/rust"));
+
+        let flat = paths(&["/courses/rust/1.mp4", "/courses/rust/2.mp4"]);
+        assert_eq!(local_root(&flat, None).as_deref(), Some("/courses/rust"));
+
+        assert_eq!(local_root(&[], None), None);
+        assert_eq!(local_root(&paths(&["relative/1.mp4"]), None), None);
+    }
+
+    #[test]
+    fn root_moves_up_to_the_folder_matching_the_source_hash() {
+        let videos = paths(&["/courses/go/basics/1.mp4", "/courses/go/basics/2.mp4"]);
+        let hash = media_hash::compute_source_hash("/courses/go");
+
+        assert_eq!(local_root(&videos, Some(&hash)).as_deref(), Some("/courses/go"));
+    }
+
+    #[test]
+    fn backfills_a_course_made_only_of_subfolder_videos() {
+        let pool = establish_connection(":memory:").unwrap();
+        let mut conn = pool.get().unwrap();
+        let hash = media_hash::compute_source_hash("/courses/rust");
+        conn.batch_execute(&format!(
+            "INSERT INTO courses (id, name, source_url, playlist_id, source_hash, source_type)
+             VALUES ('c1', 'Rust', '', '', '{hash}', 'local'),
+                    ('c2', 'Empty', '', '', NULL, 'local');
+             INSERT INTO modules (id, course_id, title, sort_order)
+             VALUES ('m1', 'c1', 'A', 0), ('m2', 'c1', 'B', 1);
+             INSERT INTO videos (id, module_id, title, duration_secs, is_completed, sort_order,
+                                 source_type, source_ref)
+             VALUES ('v1', 'm1', 'One', 60, 0, 0, 'local', '/courses/rust/A/1.mp4'),
+                    ('v2', 'm2', 'Two', 60, 0, 0, 'local', '/courses/rust/B/2.mp4');"
+        ))
+        .unwrap();
+
+        backfill_local_course_roots(&mut conn).unwrap();
+
+        let rows: Vec<(String, String, String)> = courses::table
+            .order(courses::id.asc())
+            .select((courses::id, courses::source_type, courses::source_url))
+            .load(&mut conn)
+            .unwrap();
+        assert_eq!(
+            rows,
+            vec![
+                ("c1".to_string(), "local".to_string(), "/courses/rust".to_string()),
+                ("c2".to_string(), "manual".to_string(), String::new()),
+            ]
+        );
+    }
+}
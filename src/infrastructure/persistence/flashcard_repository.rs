@@ -0,0 +1,166 @@
+use std::str::FromStr;
+use std::sync::Arc;
+
+use diesel::prelude::*;
+
+use crate::domain::entities::Flashcard;
+use crate::domain::ports::{FlashcardRepository, RepositoryError};
+use crate::domain::value_objects::{FlashcardId, FlashcardKind, VideoId};
+use crate::infrastructure::persistence::connection::DbPool;
+use crate::infrastructure::persistence::models::FlashcardRow;
+use crate::infrastructure::persistence::repositories::i32_to_u32;
+use crate::schema::flashcards;
+
+/// SQLite-backed flashcard repository.
+pub struct SqliteFlashcardRepository {
+    pool: Arc<DbPool>,
+}
+
+impl SqliteFlashcardRepository {
+    pub fn new(pool: Arc<DbPool>) -> Self {
+        Self { pool }
+    }
+
+    fn row_to_entity(row: FlashcardRow) -> Result<Flashcard, RepositoryError> {
+        let invalid = |what: &str, e: &dyn std::fmt::Display| {
+            RepositoryError::Database(format!("Invalid {what} in flashcard {}: {e}", row.id))
+        };
+        let id = FlashcardId::from_str(&row.id).map_err(|e| invalid("ID", &e))?;
+        let video_id = VideoId::from_str(&row.video_id).map_err(|e| invalid("video ID", &e))?;
+        let kind = FlashcardKind::from_str(&row.kind).map_err(|e| invalid("kind", &e))?;
+        let position = i32_to_u32(row.position, "position")?;
+
+        Flashcard::new(id, video_id, kind, row.front.as_str(), row.back.as_str(), position)
+            .map_err(|e| invalid("content", &e))
+    }
+}
+
+impl FlashcardRepository for SqliteFlashcardRepository {
+    fn save(&self, card: &Flashcard) -> Result<(), RepositoryError> {
+        let mut conn = self.pool.get().map_err(|e| RepositoryError::Database(e.to_string()))?;
+        let row = FlashcardRow {
+            id: card.id().as_uuid().to_string(),
+            video_id: card.video_id().as_uuid().to_string(),
+            kind: card.kind().as_str().to_string(),
+            front: card.front().to_string(),
+            back: card.back().to_string(),
+            position: card.position() as i32,
+        };
+
+        diesel::insert_into(flashcards::table)
+            .values(&row)
+            .on_conflict(flashcards::id)
+            .do_update()
+            .set((
+                flashcards::kind.eq(&row.kind),
+                flashcards::front.eq(&row.front),
+                flashcards::back.eq(&row.back),
+                flashcards::position.eq(row.position),
+            ))
+            .execute(&mut conn)?;
+        Ok(())
+    }
+
+    fn find_by_id(&self, id: &FlashcardId) -> Result<Option<Flashcard>, RepositoryError> {
+        let mut conn = self.pool.get().map_err(|e| RepositoryError::Database(e.to_string()))?;
+        let row: Option<FlashcardRow> = flashcards::table
+            .filter(flashcards::id.eq(id.as_uuid().to_string()))
+            .select(FlashcardRow::as_select())
+            .first(&mut conn)
+            .optional()?;
+
+        row.map(Self::row_to_entity).transpose()
+    }
+
+    fn find_by_video(&self, video_id: &VideoId) -> Result<Vec<Flashcard>, RepositoryError> {
+        let mut conn = self.pool.get().map_err(|e| RepositoryError::Database(e.to_string()))?;
+        let rows: Vec<FlashcardRow> = flashcards::table
+            .filter(flashcards::video_id.eq(video_id.as_uuid().to_string()))
+            .order(flashcards::position.asc())
+            .select(FlashcardRow::as_select())
+            .load(&mut conn)?;
+
+        rows.into_iter().map(Self::row_to_entity).collect()
+    }
+
+    fn delete(&self, id: &FlashcardId) -> Result<(), RepositoryError> {
+        let mut conn = self.pool.get().map_err(|e| RepositoryError::Database(e.to_string()))?;
+        diesel::delete(flashcards::table.filter(flashcards::id.eq(id.as_uuid().to_string())))
+            .execute(&mut conn)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::{Course, Module, Video};
+    use crate::domain::ports::{CourseRepository, ModuleRepository, VideoRepository};
+    use crate::domain::value_objects::{
+        CourseId, CourseSource, ModuleId, PlaylistUrl, VideoSource,
+    };
+    use crate::infrastructure::persistence::repositories::{
+        SqliteCourseRepository, SqliteModuleRepository, SqliteVideoRepository,
+    };
+
+    #[test]
+    fn test_flashcards_save_edit_order_and_delete() {
+        let pool =
+            Arc::new(crate::infrastructure::persistence::establish_connection(":memory:").unwrap());
+        let course_repo = SqliteCourseRepository::new(pool.clone());
+        let module_repo = SqliteModuleRepository::new(pool.clone());
+        let video_repo = SqliteVideoRepository::new(pool.clone());
+        let repo = SqliteFlashcardRepository::new(pool.clone());
+
+        let course_id = CourseId::new();
+        let playlist_url =
+            PlaylistUrl::new("https://www.youtube.com/playlist?list=PL38E37F4BE52E385D").unwrap();
+        course_repo
+            .save(&Course::new(
+                course_id,
+                "Flashcards".to_string(),
+                CourseSource::youtube_playlist(playlist_url),
+                None,
+                None,
+            ))
+            .unwrap();
+        let module_id = ModuleId::new();
+        module_repo.save(&Module::new(module_id, course_id, "Module".to_string(), 0)).unwrap();
+        let video_id = VideoId::new();
+        let source = VideoSource::local_path("/videos/0.mp4").unwrap();
+        video_repo
+            .save(&Video::new(video_id, module_id, source, "V0".to_string(), 600, 0))
+            .unwrap();
+
+        let cloze = Flashcard::new(
+            FlashcardId::new(),
+            video_id,
+            FlashcardKind::Cloze,
+            "Values have exactly {{one owner}}.",
+            "",
+            1,
+        )
+        .unwrap();
+        let mut basic = Flashcard::new(
+            FlashcardId::new(),
+            video_id,
+            FlashcardKind::Basic,
+            "Borrow",
+            "A reference",
+            0,
+        )
+        .unwrap();
+        repo.save(&cloze).unwrap();
+        repo.save(&basic).unwrap();
+        assert_eq!(repo.find_by_video(&video_id).unwrap(), vec![basic.clone(), cloze.clone()]);
+
+        basic.edit(FlashcardKind::Basic, "Borrow", "A non-owning reference").unwrap();
+        basic.set_position(2);
+        repo.save(&basic).unwrap();
+        assert_eq!(repo.find_by_id(basic.id()).unwrap(), Some(basic.clone()));
+        assert_eq!(repo.find_by_video(&video_id).unwrap(), vec![cloze.clone(), basic.clone()]);
+
+        repo.delete(cloze.id()).unwrap();
+        assert_eq!(repo.find_by_video(&video_id).unwrap(), vec![basic]);
+    }
+}
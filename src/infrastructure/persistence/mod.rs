@@ -2,22 +2,37 @@
 
 mod chat_message_repository;
 mod connection;
+mod data_migrations;
+mod flashcard_repository;
 pub mod models;
+mod playback_repository;
 mod preferences_repository;
 mod repositories;
+mod review_repository;
+mod schedule_repository;
 mod search_repository;
 mod tag_repository;
+mod transcript_chunk_repository;
+mod transcript_cue_repository;
+mod watch_session_repository;
 
 pub use chat_message_repository::SqliteChatMessageRepository;
 pub use connection::{DbPool, establish_connection};
+pub use flashcard_repository::SqliteFlashcardRepository;
 pub use models::{
     ChatMessageRow, CourseTagRow, NewChatMessage, NewTag, TagRow, UpdatePreferences,
     UserPreferencesRow,
 };
+pub use playback_repository::SqlitePlaybackPositionRepository;
 pub use preferences_repository::SqliteUserPreferencesRepository;
 pub use repositories::{
     SqliteCourseRepository, SqliteExamRepository, SqliteModuleRepository, SqliteNoteRepository,
     SqliteVideoRepository,
 };
+pub use review_repository::SqliteReviewCardRepository;
+pub use schedule_repository::SqliteStudyScheduleRepository;
 pub use search_repository::SqliteSearchRepository;
 pub use tag_repository::SqliteTagRepository;
+pub use transcript_chunk_repository::SqliteTranscriptChunkRepository;
+pub use transcript_cue_repository::SqliteTranscriptCueRepository;
+pub use watch_session_repository::SqliteWatchSessionRepository;
@@ -3,7 +3,11 @@
 use diesel::prelude::*;
 use diesel::sqlite::Sqlite;
 
-use crate::schema::{chat_messages, courses, exams, modules, notes, user_preferences, videos};
+use crate::schema::{
+    chat_messages, courses, exams, flashcards, modules, notes, playback_positions, review_cards,
+    study_schedules, study_session_videos, study_sessions, transcript_cues, user_preferences,
+    videos, watch_sessions,
+};
 
 /// Diesel model for the courses table.
 #[derive(Queryable, Selectable, Identifiable, Debug)]
@@ -17,6 +21,7 @@ pub struct CourseRow {
     pub description: Option<String>,
     pub created_at: String, // SQLite stores TIMESTAMP as TEXT
     pub source_hash: Option<String>,
+    pub source_type: String,
 }
 
 /// Insertable model for courses.
@@ -29,6 +34,7 @@ pub struct NewCourse<'a> {
     pub playlist_id: &'a str,
     pub description: Option<&'a str>,
     pub source_hash: Option<&'a str>,
+    pub source_type: &'a str,
 }
 
 /// Diesel model for the modules table.
@@ -73,6 +79,10 @@ pub struct VideoRow {
     pub source_ref: String,
     pub key_points: Option<String>,
     pub key_terms: Option<String>,
+    pub is_available: bool,
+    pub content_fingerprint: Option<String>,
+    pub clip_start_secs: Option<i32>,
+    pub clip_end_secs: Option<i32>,
 }
 
 /// Insertable model for videos.
@@ -93,6 +103,10 @@ pub struct NewVideo<'a> {
     pub source_ref: &'a str,
     pub key_points: Option<&'a str>,
     pub key_terms: Option<&'a str>,
+    pub is_available: bool,
+    pub content_fingerprint: Option<&'a str>,
+    pub clip_start_secs: Option<i32>,
+    pub clip_end_secs: Option<i32>,
 }
 
 /// Diesel model for the exams table.
@@ -153,6 +167,9 @@ pub struct UserPreferencesRow {
     pub right_panel_width: i32,
     pub preferred_quality: String,
     pub boundary_batch_size: i32,
+    pub auto_complete_percent: i32,
+    pub watch_local_folders: i32,
+    pub module_target_minutes: i32,
 }
 
 /// Insertable model for user preferences.
@@ -167,6 +184,9 @@ pub struct NewUserPreferences<'a> {
     pub onboarding_completed: i32,
     pub preferred_quality: &'a str,
     pub boundary_batch_size: i32,
+    pub auto_complete_percent: i32,
+    pub watch_local_folders: i32,
+    pub module_target_minutes: i32,
 }
 
 /// Changeset for updating user preferences.
@@ -180,6 +200,9 @@ pub struct UpdatePreferences {
     pub onboarding_completed: Option<i32>,
     pub preferred_quality: Option<String>,
     pub boundary_batch_size: Option<i32>,
+    pub auto_complete_percent: Option<i32>,
+    pub watch_local_folders: Option<i32>,
+    pub module_target_minutes: Option<i32>,
 }
 
 /// Diesel model for the tags table.
@@ -233,3 +256,130 @@ pub struct NewChatMessage<'a> {
     pub content: &'a str,
     pub created_at: &'a str,
 }
+
+/// Diesel model for the study_schedules table.
+#[derive(Queryable, Selectable, Debug)]
+#[diesel(table_name = study_schedules)]
+#[diesel(check_for_backend(Sqlite))]
+pub struct StudyScheduleRow {
+    pub course_id: String,
+    pub start_date: String,
+    pub study_days_per_week: i32,
+    pub cognitive_limit_minutes: i32,
+    pub reschedule_policy: String,
+    pub updated_at: String,
+    pub peak_session_count: i32,
+}
+
+/// Insertable model for study schedules.
+#[derive(Insertable)]
+#[diesel(table_name = study_schedules)]
+pub struct NewStudySchedule<'a> {
+    pub course_id: &'a str,
+    pub start_date: &'a str,
+    pub study_days_per_week: i32,
+    pub cognitive_limit_minutes: i32,
+    pub reschedule_policy: &'a str,
+    pub updated_at: &'a str,
+    pub peak_session_count: i32,
+}
+
+/// Diesel model for the study_sessions table.
+#[derive(Queryable, Selectable, Debug)]
+#[diesel(table_name = study_sessions)]
+#[diesel(check_for_backend(Sqlite))]
+pub struct StudySessionRow {
+    pub id: String,
+    pub course_id: String,
+    pub day: i32,
+    pub scheduled_date: String,
+    pub total_duration_secs: i32,
+}
+
+/// Insertable model for study sessions.
+#[derive(Insertable)]
+#[diesel(table_name = study_sessions)]
+pub struct NewStudySession<'a> {
+    pub id: &'a str,
+    pub course_id: &'a str,
+    pub day: i32,
+    pub scheduled_date: &'a str,
+    pub total_duration_secs: i32,
+}
+
+/// Diesel model for the study_session_videos junction table.
+#[derive(Queryable, Selectable, Insertable, Debug)]
+#[diesel(table_name = study_session_videos)]
+#[diesel(check_for_backend(Sqlite))]
+pub struct StudySessionVideoRow {
+    pub session_id: String,
+    pub video_id: String,
+    pub position: i32,
+}
+
+/// Diesel model for the playback_positions table.
+#[derive(Queryable, Selectable, Insertable, Debug)]
+#[diesel(table_name = playback_positions)]
+#[diesel(check_for_backend(Sqlite))]
+pub struct PlaybackPositionRow {
+    pub video_id: String,
+    pub position_ms: i64,
+    pub updated_at: String,
+    pub watched_ranges: String,
+}
+
+/// Diesel model for the transcript_cues table.
+#[derive(Queryable, Selectable, Insertable, Debug)]
+#[diesel(table_name = transcript_cues)]
+#[diesel(check_for_backend(Sqlite))]
+pub struct TranscriptCueRow {
+    pub video_id: String,
+    pub position: i32,
+    pub start_ms: i64,
+    pub end_ms: i64,
+    pub text: String,
+    pub source_hash: String,
+}
+
+/// Diesel model for the watch_sessions table.
+#[derive(Queryable, Selectable, Insertable, Debug)]
+#[diesel(table_name = watch_sessions)]
+#[diesel(check_for_backend(Sqlite))]
+pub struct WatchSessionRow {
+    pub id: String,
+    pub video_id: String,
+    pub started_at: String,
+    pub ended_at: String,
+    pub played_secs: i32,
+}
+
+/// Diesel model for the review_cards table.
+#[derive(Queryable, Selectable, Insertable, Debug)]
+#[diesel(table_name = review_cards)]
+#[diesel(check_for_backend(Sqlite))]
+pub struct ReviewCardRow {
+    pub id: String,
+    pub exam_id: String,
+    pub video_id: String,
+    pub question_index: i32,
+    pub question_json: String,
+    pub ease: f64,
+    pub interval_days: i32,
+    pub repetitions: i32,
+    pub lapses: i32,
+    pub due_date: String,
+    pub last_reviewed: Option<String>,
+}
+
+/// Diesel model for the flashcards table.
+#[derive(Queryable, Selectable, Insertable, Debug)]
+#[diesel(table_name = flashcards)]
+#[diesel(check_for_backend(Sqlite))]
+pub struct FlashcardRow {
+    pub id: String,
+    pub video_id: String,
+    pub kind: String,
+    pub front: String,
+    pub back: String,
+    pub position: i32,
+}
@@ -0,0 +1,186 @@
+use std::str::FromStr;
+use std::sync::Arc;
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use diesel::prelude::*;
+
+use crate::domain::ports::{PlaybackPosition, PlaybackPositionRepository, RepositoryError};
+use crate::domain::value_objects::{CourseId, VideoId, WatchedRanges};
+use crate::infrastructure::persistence::connection::DbPool;
+use crate::infrastructure::persistence::models::PlaybackPositionRow;
+use crate::schema::{modules, playback_positions, videos};
+
+/// SQLite-backed playback position repository.
+pub struct SqlitePlaybackPositionRepository {
+    pool: Arc<DbPool>,
+}
+
+impl SqlitePlaybackPositionRepository {
+    pub fn new(pool: Arc<DbPool>) -> Self {
+        Self { pool }
+    }
+
+    fn row_to_entity(row: PlaybackPositionRow) -> Result<PlaybackPosition, RepositoryError> {
+        let video_id = VideoId::from_str(&row.video_id).map_err(|e| {
+            RepositoryError::Database(format!("Invalid video ID in playback position: {e}"))
+        })?;
+        let updated_at = DateTime::parse_from_rfc3339(&row.updated_at)
+            .map_err(|e| {
+                RepositoryError::Database(format!("Invalid timestamp {}: {e}", row.updated_at))
+            })?
+            .with_timezone(&Utc);
+        let watched = WatchedRanges::from_str(&row.watched_ranges)
+            .map_err(|e| RepositoryError::Database(e.to_string()))?;
+        Ok(PlaybackPosition {
+            video_id,
+            position_ms: u64::try_from(row.position_ms).unwrap_or(0),
+            watched,
+            updated_at,
+        })
+    }
+}
+
+impl PlaybackPositionRepository for SqlitePlaybackPositionRepository {
+    fn save(&self, position: &PlaybackPosition) -> Result<(), RepositoryError> {
+        let mut conn = self.pool.get().map_err(|e| RepositoryError::Database(e.to_string()))?;
+        let row = PlaybackPositionRow {
+            video_id: position.video_id.as_uuid().to_string(),
+            position_ms: i64::try_from(position.position_ms).unwrap_or(i64::MAX),
+            // Fixed-width UTC timestamps so lexical order matches chronological order.
+            updated_at: position.updated_at.to_rfc3339_opts(SecondsFormat::Millis, true),
+            watched_ranges: position.watched.to_string(),
+        };
+
+        diesel::insert_into(playback_positions::table)
+            .values(&row)
+            .on_conflict(playback_positions::video_id)
+            .do_update()
+            .set((
+                playback_positions::position_ms.eq(row.position_ms),
+                playback_positions::updated_at.eq(&row.updated_at),
+                playback_positions::watched_ranges.eq(&row.watched_ranges),
+            ))
+            .execute(&mut conn)?;
+        Ok(())
+    }
+
+    fn find_by_video(
+        &self,
+        video_id: &VideoId,
+    ) -> Result<Option<PlaybackPosition>, RepositoryError> {
+        let mut conn = self.pool.get().map_err(|e| RepositoryError::Database(e.to_string()))?;
+        let row: Option<PlaybackPositionRow> = playback_positions::table
+            .filter(playback_positions::video_id.eq(video_id.as_uuid().to_string()))
+            .select(PlaybackPositionRow::as_select())
+            .first(&mut conn)
+            .optional()?;
+
+        row.map(Self::row_to_entity).transpose()
+    }
+
+    fn find_latest_by_course(
+        &self,
+        course_id: &CourseId,
+    ) -> Result<Option<PlaybackPosition>, RepositoryError> {
+        let mut conn = self.pool.get().map_err(|e| RepositoryError::Database(e.to_string()))?;
+        let row: Option<PlaybackPositionRow> = playback_positions::table
+            .inner_join(videos::table.inner_join(modules::table))
+            .filter(modules::course_id.eq(course_id.as_uuid().to_string()))
+            .order(playback_positions::updated_at.desc())
+            .select(PlaybackPositionRow::as_select())
+            .first(&mut conn)
+            .optional()?;
+
+        row.map(Self::row_to_entity).transpose()
+    }
+
+    fn delete(&self, video_id: &VideoId) -> Result<(), RepositoryError> {
+        let mut conn = self.pool.get().map_err(|e| RepositoryError::Database(e.to_string()))?;
+        diesel::delete(
+            playback_positions::table
+                .filter(playback_positions::video_id.eq(video_id.as_uuid().to_string())),
+        )
+        .execute(&mut conn)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::{Course, Module, Video};
+    use crate::domain::ports::{CourseRepository, ModuleRepository, VideoRepository};
+    use crate::domain::value_objects::{CourseSource, ModuleId, PlaylistUrl, VideoSource};
+    use crate::infrastructure::persistence::repositories::{
+        SqliteCourseRepository, SqliteModuleRepository, SqliteVideoRepository,
+    };
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_playback_position_upsert_and_latest_by_course() {
+        let pool =
+            Arc::new(crate::infrastructure::persistence::establish_connection(":memory:").unwrap());
+        let course_repo = SqliteCourseRepository::new(pool.clone());
+        let module_repo = SqliteModuleRepository::new(pool.clone());
+        let video_repo = SqliteVideoRepository::new(pool.clone());
+        let repo = SqlitePlaybackPositionRepository::new(pool.clone());
+
+        let course_id = CourseId::new();
+        let playlist_url =
+            PlaylistUrl::new("https://www.youtube.com/playlist?list=PL38E37F4BE52E385D").unwrap();
+        course_repo
+            .save(&Course::new(
+                course_id,
+                "Resumable".to_string(),
+                CourseSource::youtube_playlist(playlist_url),
+                None,
+                None,
+            ))
+            .unwrap();
+        let module_id = ModuleId::new();
+        module_repo.save(&Module::new(module_id, course_id, "Module".to_string(), 0)).unwrap();
+
+        let video_ids: Vec<VideoId> = (0..2).map(|_| VideoId::new()).collect();
+        for (idx, id) in video_ids.iter().enumerate() {
+            let source = VideoSource::local_path(format!("/videos/{idx}.mp4")).unwrap();
+            video_repo
+                .save(&Video::new(*id, module_id, source, format!("V{idx}"), 600, idx as u32))
+                .unwrap();
+        }
+
+        let at = |secs| Utc.with_ymd_and_hms(2026, 6, 1, 12, 0, secs).unwrap();
+        repo.save(&PlaybackPosition {
+            video_id: video_ids[0],
+            position_ms: 1_000,
+            watched: WatchedRanges::new(),
+            updated_at: at(0),
+        })
+        .unwrap();
+        repo.save(&PlaybackPosition {
+            video_id: video_ids[1],
+            position_ms: 2_000,
+            watched: WatchedRanges::new(),
+            updated_at: at(1),
+        })
+        .unwrap();
+        assert_eq!(repo.find_latest_by_course(&course_id).unwrap().unwrap().video_id, video_ids[1]);
+
+        // Saving again replaces the row and bumps it to the most recent.
+        let mut watched = WatchedRanges::new();
+        watched.add(0, 12_000);
+        watched.add(30_000, 42_500);
+        let updated = PlaybackPosition {
+            video_id: video_ids[0],
+            position_ms: 42_500,
+            watched,
+            updated_at: at(2),
+        };
+        repo.save(&updated).unwrap();
+        assert_eq!(repo.find_by_video(&video_ids[0]).unwrap(), Some(updated));
+        assert_eq!(repo.find_latest_by_course(&course_id).unwrap().unwrap().video_id, video_ids[0]);
+        assert!(repo.find_latest_by_course(&CourseId::new()).unwrap().is_none());
+
+        repo.delete(&video_ids[0]).unwrap();
+        assert!(repo.find_by_video(&video_ids[0]).unwrap().is_none());
+    }
+}
@@ -48,6 +48,9 @@ impl UserPreferencesRepository for SqliteUserPreferencesRepository {
             onboarding_completed: bool_to_i32(prefs.onboarding_completed()),
             preferred_quality: &quality_str,
             boundary_batch_size: prefs.boundary_batch_size() as i32,
+            auto_complete_percent: prefs.auto_complete_percent() as i32,
+            watch_local_folders: bool_to_i32(prefs.watch_local_folders()),
+            module_target_minutes: prefs.module_target_minutes() as i32,
         };
 
         diesel::replace_into(user_preferences::table)
@@ -72,6 +75,9 @@ fn row_to_preferences(row: UserPreferencesRow) -> UserPreferences {
             onboarding_completed: row.onboarding_completed != 0,
             preferred_quality: str_to_quality(&row.preferred_quality),
             boundary_batch_size: row.boundary_batch_size as u32,
+            auto_complete_percent: row.auto_complete_percent as u32,
+            watch_local_folders: row.watch_local_folders != 0,
+            module_target_minutes: row.module_target_minutes.max(0) as u32,
         },
     )
 }
@@ -161,8 +167,12 @@ mod tests {
             right_panel_width: 320,
             preferred_quality: "p1080".to_string(),
             boundary_batch_size: 5,
+            auto_complete_percent: 90,
+            watch_local_folders: 0,
+            module_target_minutes: 45,
         };
         let prefs = row_to_preferences(row);
         assert_eq!(prefs.preferred_quality(), VideoQuality::P1080);
+        assert_eq!(prefs.module_target_minutes(), 45);
     }
 }
@@ -13,7 +13,8 @@ use crate::domain::{
         VideoRepository,
     },
     value_objects::{
-        CourseId, ExamId, ModuleId, PlaylistUrl, VideoId, VideoSource, YouTubeVideoId,
+        ClipRange, CourseId, CourseSource, ExamId, KeyTerm, ModuleId, VideoId, VideoSource,
+        YouTubeVideoId,
     },
 };
 use crate::infrastructure::persistence::connection::DbPool;
@@ -55,29 +56,7 @@ impl SqliteCourseRepository {
 impl CourseRepository for SqliteCourseRepository {
     fn save(&self, course: &Course) -> Result<(), RepositoryError> {
         let mut conn = self.pool.get().map_err(|e| RepositoryError::Database(e.to_string()))?;
-
-        let id_str = course.id().as_uuid().to_string();
-        let new_course = NewCourse {
-            id: &id_str,
-            name: course.name(),
-            source_url: course.source_url().raw(),
-            playlist_id: course.playlist_id(),
-            description: course.description(),
-            source_hash: course.source_hash(),
-        };
-
-        diesel::insert_into(courses::table)
-            .values(&new_course)
-            .on_conflict(courses::id)
-            .do_update()
-            .set((
-                courses::name.eq(new_course.name),
-                courses::description.eq(new_course.description),
-            ))
-            .execute(&mut conn)
-            .map_err(|e| map_diesel_save_err(e, "Course", &id_str))?;
-
-        Ok(())
+        upsert_course(&mut conn, course)
     }
 
     fn save_batch(&self, courses: &[Course]) -> Result<(), RepositoryError> {
@@ -87,29 +66,26 @@ impl CourseRepository for SqliteCourseRepository {
         let mut conn = self.pool.get().map_err(|e| RepositoryError::Database(e.to_string()))?;
         conn.transaction::<_, RepositoryError, _>(|tx| {
             for (index, course) in courses.iter().enumerate() {
-                let id_str = course.id().as_uuid().to_string();
-                let new_course = NewCourse {
-                    id: &id_str,
-                    name: course.name(),
-                    source_url: course.source_url().raw(),
-                    playlist_id: course.playlist_id(),
-                    description: course.description(),
-                    source_hash: course.source_hash(),
-                };
-                diesel::insert_into(courses::table)
-                    .values(&new_course)
-                    .on_conflict(courses::id)
-                    .do_update()
-                    .set((
-                        courses::name.eq(new_course.name),
-                        courses::description.eq(new_course.description),
-                    ))
-                    .execute(tx)
-                    .map_err(|e| RepositoryError::BatchFailed {
-                        entity: "Course",
-                        index,
-                        source: Box::new(map_diesel_save_err(e, "Course", &id_str)),
-                    })?;
+                upsert_course(tx, course).map_err(|e| batch_failed("Course", index, e))?;
+            }
+            Ok(())
+        })
+    }
+
+    fn save_with_contents(
+        &self,
+        course: &Course,
+        modules: &[Module],
+        videos: &[Video],
+    ) -> Result<(), RepositoryError> {
+        let mut conn = self.pool.get().map_err(|e| RepositoryError::Database(e.to_string()))?;
+        conn.transaction::<_, RepositoryError, _>(|tx| {
+            upsert_course(tx, course)?;
+            for (index, module) in modules.iter().enumerate() {
+                upsert_module(tx, module).map_err(|e| batch_failed("Module", index, e))?;
+            }
+            for (index, video) in videos.iter().enumerate() {
+                upsert_video(tx, video).map_err(|e| batch_failed("Video", index, e))?;
             }
             Ok(())
         })
@@ -198,28 +174,7 @@ impl SqliteModuleRepository {
 impl ModuleRepository for SqliteModuleRepository {
     fn save(&self, module: &Module) -> Result<(), RepositoryError> {
         let mut conn = self.pool.get().map_err(|e| RepositoryError::Database(e.to_string()))?;
-
-        let id_str = module.id().as_uuid().to_string();
-        let course_id_str = module.course_id().as_uuid().to_string();
-        let new_module = NewModule {
-            id: &id_str,
-            course_id: &course_id_str,
-            title: module.title(),
-            sort_order: module.sort_order() as i32,
-        };
-
-        diesel::insert_into(modules::table)
-            .values(&new_module)
-            .on_conflict(modules::id)
-            .do_update()
-            .set((
-                modules::title.eq(new_module.title),
-                modules::sort_order.eq(new_module.sort_order),
-            ))
-            .execute(&mut conn)
-            .map_err(|e| map_diesel_save_err(e, "Module", &id_str))?;
-
-        Ok(())
+        upsert_module(&mut conn, module)
     }
 
     fn save_batch(&self, modules: &[Module]) -> Result<(), RepositoryError> {
@@ -229,28 +184,7 @@ impl ModuleRepository for SqliteModuleRepository {
         let mut conn = self.pool.get().map_err(|e| RepositoryError::Database(e.to_string()))?;
         conn.transaction::<_, RepositoryError, _>(|tx| {
             for (index, module) in modules.iter().enumerate() {
-                let id_str = module.id().as_uuid().to_string();
-                let course_id_str = module.course_id().as_uuid().to_string();
-                let new_module = NewModule {
-                    id: &id_str,
-                    course_id: &course_id_str,
-                    title: module.title(),
-                    sort_order: module.sort_order() as i32,
-                };
-                diesel::insert_into(modules::table)
-                    .values(&new_module)
-                    .on_conflict(modules::id)
-                    .do_update()
-                    .set((
-                        modules::title.eq(new_module.title),
-                        modules::sort_order.eq(new_module.sort_order),
-                    ))
-                    .execute(tx)
-                    .map_err(|e| RepositoryError::BatchFailed {
-                        entity: "Module",
-                        index,
-                        source: Box::new(map_diesel_save_err(e, "Module", &id_str)),
-                    })?;
+                upsert_module(tx, module).map_err(|e| batch_failed("Module", index, e))?;
             }
             Ok(())
         })
@@ -323,49 +257,7 @@ impl SqliteVideoRepository {
 impl VideoRepository for SqliteVideoRepository {
     fn save(&self, video: &Video) -> Result<(), RepositoryError> {
         let mut conn = self.pool.get().map_err(|e| RepositoryError::Database(e.to_string()))?;
-
-        let (source_type, source_ref, youtube_id) = match video.source() {
-            VideoSource::YouTube(id) => ("youtube", id.as_str().to_string(), Some(id.as_str())),
-            VideoSource::LocalPath(path) => ("local", path.clone(), None),
-        };
-
-        let video_id_str = video.id().as_uuid().to_string();
-        let module_id_str = video.module_id().as_uuid().to_string();
-        let new_video = NewVideo {
-            id: &video_id_str,
-            module_id: &module_id_str,
-            youtube_id,
-            title: video.title(),
-            duration_secs: video.duration_secs() as i32,
-            is_completed: video.is_completed(),
-            sort_order: video.sort_order() as i32,
-            description: video.description(),
-            transcript: video.transcript(),
-            summary: video.summary(),
-            source_type,
-            source_ref: &source_ref,
-            key_points: None,
-            key_terms: None,
-        };
-
-        diesel::insert_into(videos::table)
-            .values(&new_video)
-            .on_conflict(videos::id)
-            .do_update()
-            .set((
-                videos::title.eq(new_video.title),
-                videos::duration_secs.eq(new_video.duration_secs),
-                videos::is_completed.eq(new_video.is_completed),
-                videos::sort_order.eq(new_video.sort_order),
-                videos::description.eq(new_video.description),
-                videos::transcript.eq(new_video.transcript),
-                videos::summary.eq(new_video.summary),
-                videos::module_id.eq(&module_id_str),
-            ))
-            .execute(&mut conn)
-            .map_err(|e| map_diesel_save_err(e, "Video", &video_id_str))?;
-
-        Ok(())
+        upsert_video(&mut conn, video)
     }
 
     fn save_batch(&self, videos: &[Video]) -> Result<(), RepositoryError> {
@@ -375,52 +267,7 @@ impl VideoRepository for SqliteVideoRepository {
         let mut conn = self.pool.get().map_err(|e| RepositoryError::Database(e.to_string()))?;
         conn.transaction::<_, RepositoryError, _>(|tx| {
             for (index, video) in videos.iter().enumerate() {
-                let (source_type, source_ref, youtube_id) = match video.source() {
-                    VideoSource::YouTube(id) => {
-                        ("youtube", id.as_str().to_string(), Some(id.as_str()))
-                    },
-                    VideoSource::LocalPath(path) => ("local", path.clone(), None),
-                };
-
-                let video_id_str = video.id().as_uuid().to_string();
-                let module_id_str = video.module_id().as_uuid().to_string();
-                let new_video = NewVideo {
-                    id: &video_id_str,
-                    module_id: &module_id_str,
-                    youtube_id,
-                    title: video.title(),
-                    duration_secs: video.duration_secs() as i32,
-                    is_completed: video.is_completed(),
-                    sort_order: video.sort_order() as i32,
-                    description: video.description(),
-                    transcript: video.transcript(),
-                    summary: video.summary(),
-                    source_type,
-                    source_ref: &source_ref,
-                    key_points: None,
-                    key_terms: None,
-                };
-
-                diesel::insert_into(videos::table)
-                    .values(&new_video)
-                    .on_conflict(videos::id)
-                    .do_update()
-                    .set((
-                        videos::title.eq(new_video.title),
-                        videos::duration_secs.eq(new_video.duration_secs),
-                        videos::is_completed.eq(new_video.is_completed),
-                        videos::sort_order.eq(new_video.sort_order),
-                        videos::description.eq(new_video.description),
-                        videos::transcript.eq(new_video.transcript),
-                        videos::summary.eq(new_video.summary),
-                        videos::module_id.eq(&module_id_str),
-                    ))
-                    .execute(tx)
-                    .map_err(|e| RepositoryError::BatchFailed {
-                        entity: "Video",
-                        index,
-                        source: Box::new(map_diesel_save_err(e, "Video", &video_id_str)),
-                    })?;
+                upsert_video(tx, video).map_err(|e| batch_failed("Video", index, e))?;
             }
             Ok(())
         })
@@ -510,6 +357,26 @@ impl VideoRepository for SqliteVideoRepository {
         Ok(())
     }
 
+    fn update_key_content(
+        &self,
+        id: &VideoId,
+        key_points: &[String],
+        key_terms: &[KeyTerm],
+    ) -> Result<(), RepositoryError> {
+        let mut conn = self.pool.get().map_err(|e| RepositoryError::Database(e.to_string()))?;
+
+        let id_str = id.as_uuid().to_string();
+        diesel::update(videos::table.find(&id_str))
+            .set((
+                videos::key_points.eq(list_to_json(key_points)?),
+                videos::key_terms.eq(list_to_json(key_terms)?),
+            ))
+            .execute(&mut conn)
+            .map_err(|e| RepositoryError::Database(e.to_string()))?;
+
+        Ok(())
+    }
+
     fn update_module(
         &self,
         id: &VideoId,
@@ -734,16 +601,15 @@ fn row_to_course(row: CourseRow) -> Result<Course, RepositoryError> {
     let course_id = CourseId::from_uuid(
         uuid::Uuid::parse_str(&row.id).map_err(|e| RepositoryError::Database(e.to_string()))?,
     );
-    let playlist_url =
-        PlaylistUrl::new(&row.source_url).map_err(|e| RepositoryError::Database(e.to_string()))?;
+    let source = CourseSource::from_parts(&row.source_type, &row.source_url)
+        .map_err(|e| RepositoryError::Database(e.to_string()))?;
 
     let created_at = parse_sqlite_timestamp(&row.created_at)?;
 
     Ok(Course::new_with_created_at(
         course_id,
         row.name,
-        playlist_url,
-        row.playlist_id,
+        source,
         row.description,
         row.source_hash,
         created_at,
@@ -798,12 +664,158 @@ fn row_to_video(row: VideoRow) -> Result<Video, RepositoryError> {
     );
     video.update_transcript(row.transcript);
     video.update_summary(row.summary);
+    video.update_key_content(
+        list_from_json(row.key_points.as_deref(), "key_points")?,
+        list_from_json(row.key_terms.as_deref(), "key_terms")?,
+    );
     if row.is_completed {
         video.mark_completed();
     }
+    if !row.is_available {
+        video.mark_unavailable();
+    }
+    video.set_content_fingerprint(row.content_fingerprint);
+    if let (Some(start), Some(end)) = (row.clip_start_secs, row.clip_end_secs) {
+        let start = i32_to_u32(start, "clip_start_secs")?;
+        let end = i32_to_u32(end, "clip_end_secs")?;
+        video.set_clip(Some(ClipRange::new(start, end).ok_or_else(|| {
+            RepositoryError::Database(format!("Invalid clip range: {start}..{end}"))
+        })?));
+    }
     Ok(video)
 }
 
+/// Wraps the error of one item in a batch save.
+fn batch_failed(entity: &'static str, index: usize, source: RepositoryError) -> RepositoryError {
+    RepositoryError::BatchFailed { entity, index, source: Box::new(source) }
+}
+
+fn upsert_course(conn: &mut SqliteConnection, course: &Course) -> Result<(), RepositoryError> {
+    let id_str = course.id().as_uuid().to_string();
+    let new_course = NewCourse {
+        id: &id_str,
+        name: course.name(),
+        source_url: course.source().source_ref(),
+        playlist_id: course.source().playlist_url().map_or("", |url| url.playlist_id()),
+        description: course.description(),
+        source_hash: course.source_hash(),
+        source_type: course.source().source_type(),
+    };
+
+    diesel::insert_into(courses::table)
+        .values(&new_course)
+        .on_conflict(courses::id)
+        .do_update()
+        .set((
+            courses::name.eq(new_course.name),
+            courses::description.eq(new_course.description),
+            courses::source_url.eq(new_course.source_url),
+            courses::source_type.eq(new_course.source_type),
+            courses::source_hash.eq(new_course.source_hash),
+        ))
+        .execute(conn)
+        .map_err(|e| map_diesel_save_err(e, "Course", &id_str))?;
+    Ok(())
+}
+
+fn upsert_module(conn: &mut SqliteConnection, module: &Module) -> Result<(), RepositoryError> {
+    let id_str = module.id().as_uuid().to_string();
+    let course_id_str = module.course_id().as_uuid().to_string();
+    let new_module = NewModule {
+        id: &id_str,
+        course_id: &course_id_str,
+        title: module.title(),
+        sort_order: module.sort_order() as i32,
+    };
+
+    diesel::insert_into(modules::table)
+        .values(&new_module)
+        .on_conflict(modules::id)
+        .do_update()
+        .set((modules::title.eq(new_module.title), modules::sort_order.eq(new_module.sort_order)))
+        .execute(conn)
+        .map_err(|e| map_diesel_save_err(e, "Module", &id_str))?;
+    Ok(())
+}
+
+fn upsert_video(conn: &mut SqliteConnection, video: &Video) -> Result<(), RepositoryError> {
+    let (source_type, source_ref, youtube_id) = match video.source() {
+        VideoSource::YouTube(id) => ("youtube", id.as_str().to_string(), Some(id.as_str())),
+        VideoSource::LocalPath(path) => ("local", path.clone(), None),
+    };
+
+    let video_id_str = video.id().as_uuid().to_string();
+    let module_id_str = video.module_id().as_uuid().to_string();
+    let key_points = list_to_json(video.key_points())?;
+    let key_terms = list_to_json(video.key_terms())?;
+    let new_video = NewVideo {
+        id: &video_id_str,
+        module_id: &module_id_str,
+        youtube_id,
+        title: video.title(),
+        duration_secs: video.duration_secs() as i32,
+        is_completed: video.is_completed(),
+        sort_order: video.sort_order() as i32,
+        description: video.description(),
+        transcript: video.transcript(),
+        summary: video.summary(),
+        source_type,
+        source_ref: &source_ref,
+        key_points: key_points.as_deref(),
+        key_terms: key_terms.as_deref(),
+        is_available: video.is_available(),
+        content_fingerprint: video.content_fingerprint(),
+        clip_start_secs: video.clip().map(|clip| clip.start_secs() as i32),
+        clip_end_secs: video.clip().map(|clip| clip.end_secs() as i32),
+    };
+
+    diesel::insert_into(videos::table)
+        .values(&new_video)
+        .on_conflict(videos::id)
+        .do_update()
+        .set((
+            videos::title.eq(new_video.title),
+            videos::duration_secs.eq(new_video.duration_secs),
+            videos::is_completed.eq(new_video.is_completed),
+            videos::sort_order.eq(new_video.sort_order),
+            videos::description.eq(new_video.description),
+            videos::transcript.eq(new_video.transcript),
+            videos::summary.eq(new_video.summary),
+            videos::key_points.eq(new_video.key_points),
+            videos::key_terms.eq(new_video.key_terms),
+            videos::is_available.eq(new_video.is_available),
+            videos::content_fingerprint.eq(new_video.content_fingerprint),
+            videos::clip_start_secs.eq(new_video.clip_start_secs),
+            videos::clip_end_secs.eq(new_video.clip_end_secs),
+            videos::source_type.eq(new_video.source_type),
+            videos::source_ref.eq(new_video.source_ref),
+            videos::youtube_id.eq(new_video.youtube_id),
+            videos::module_id.eq(&module_id_str),
+        ))
+        .execute(conn)
+        .map_err(|e| map_diesel_save_err(e, "Video", &video_id_str))?;
+    Ok(())
+}
+
+/// Encodes a list column as a JSON array; empty lists are stored as NULL.
+fn list_to_json<T: serde::Serialize>(items: &[T]) -> Result<Option<String>, RepositoryError> {
+    if items.is_empty() {
+        return Ok(None);
+    }
+    serde_json::to_string(items).map(Some).map_err(|e| RepositoryError::Database(e.to_string()))
+}
+
+fn list_from_json<T: serde::de::DeserializeOwned>(
+    json: Option<&str>,
+    field: &str,
+) -> Result<Vec<T>, RepositoryError> {
+    match json {
+        Some(json) => serde_json::from_str(json)
+            .map_err(|e| RepositoryError::Database(format!("Invalid {field}: {e}"))),
+        None => Ok(Vec::new()),
+    }
+}
+
 fn row_to_exam(row: ExamRow) -> Result<Exam, RepositoryError> {
     let exam_id = ExamId::from_uuid(
         uuid::Uuid::parse_str(&row.id).map_err(|e| RepositoryError::Database(e.to_string()))?,
@@ -833,7 +845,7 @@ fn row_to_note(row: NoteRow) -> Result<Note, RepositoryError> {
 
 // --- Internal Helpers ---
 
-fn i32_to_u32(value: i32, field: &str) -> Result<u32, RepositoryError> {
+pub(super) fn i32_to_u32(value: i32, field: &str) -> Result<u32, RepositoryError> {
     u32::try_from(value)
         .map_err(|_| RepositoryError::Database(format!("Invalid value for {field}: {value}")))
 }
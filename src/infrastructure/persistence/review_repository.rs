@@ -0,0 +1,212 @@
+use std::str::FromStr;
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use diesel::prelude::*;
+
+use crate::domain::entities::{QuizQuestion, ReviewCard};
+use crate::domain::ports::{RepositoryError, ReviewCardRepository};
+use crate::domain::value_objects::{ExamId, ReviewCardId, ReviewSchedule, VideoId};
+use crate::infrastructure::persistence::connection::DbPool;
+use crate::infrastructure::persistence::models::ReviewCardRow;
+use crate::infrastructure::persistence::repositories::i32_to_u32;
+use crate::schema::review_cards;
+
+const DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// SQLite-backed review card repository.
+pub struct SqliteReviewCardRepository {
+    pool: Arc<DbPool>,
+}
+
+impl SqliteReviewCardRepository {
+    pub fn new(pool: Arc<DbPool>) -> Self {
+        Self { pool }
+    }
+
+    fn row_to_entity(row: ReviewCardRow) -> Result<ReviewCard, RepositoryError> {
+        let invalid = |what: &str, e: &dyn std::fmt::Display| {
+            RepositoryError::Database(format!("Invalid {what} in review card {}: {e}", row.id))
+        };
+        let id = ReviewCardId::from_str(&row.id).map_err(|e| invalid("ID", &e))?;
+        let exam_id = ExamId::from_str(&row.exam_id).map_err(|e| invalid("exam ID", &e))?;
+        let video_id = VideoId::from_str(&row.video_id).map_err(|e| invalid("video ID", &e))?;
+        let question: QuizQuestion =
+            serde_json::from_str(&row.question_json).map_err(|e| invalid("question", &e))?;
+        let schedule = ReviewSchedule::restore(
+            row.ease,
+            i32_to_u32(row.interval_days, "interval_days")?,
+            i32_to_u32(row.repetitions, "repetitions")?,
+            i32_to_u32(row.lapses, "lapses")?,
+            parse_date(&row.due_date)?,
+        );
+        let last_reviewed = row.last_reviewed.as_deref().map(parse_date).transpose()?;
+
+        Ok(ReviewCard::new(
+            id,
+            exam_id,
+            video_id,
+            i32_to_u32(row.question_index, "question_index")?,
+            question,
+            schedule.due(),
+        )
+        .with_schedule(schedule, last_reviewed))
+    }
+}
+
+impl ReviewCardRepository for SqliteReviewCardRepository {
+    fn save(&self, card: &ReviewCard) -> Result<(), RepositoryError> {
+        let mut conn = self.pool.get().map_err(|e| RepositoryError::Database(e.to_string()))?;
+        let question_json = serde_json::to_string(card.question())
+            .map_err(|e| RepositoryError::Database(format!("Failed to encode question: {e}")))?;
+        let schedule = card.schedule();
+        let row = ReviewCardRow {
+            id: card.id().as_uuid().to_string(),
+            exam_id: card.exam_id().as_uuid().to_string(),
+            video_id: card.video_id().as_uuid().to_string(),
+            question_index: card.question_index() as i32,
+            question_json,
+            ease: schedule.ease(),
+            interval_days: schedule.interval_days() as i32,
+            repetitions: schedule.repetitions() as i32,
+            lapses: schedule.lapses() as i32,
+            due_date: schedule.due().format(DATE_FORMAT).to_string(),
+            last_reviewed: card.last_reviewed().map(|d| d.format(DATE_FORMAT).to_string()),
+        };
+
+        diesel::insert_into(review_cards::table)
+            .values(&row)
+            .on_conflict(review_cards::id)
+            .do_update()
+            .set((
+                review_cards::question_json.eq(&row.question_json),
+                review_cards::ease.eq(row.ease),
+                review_cards::interval_days.eq(row.interval_days),
+                review_cards::repetitions.eq(row.repetitions),
+                review_cards::lapses.eq(row.lapses),
+                review_cards::due_date.eq(&row.due_date),
+                review_cards::last_reviewed.eq(&row.last_reviewed),
+            ))
+            .execute(&mut conn)?;
+        Ok(())
+    }
+
+    fn find_by_id(&self, id: &ReviewCardId) -> Result<Option<ReviewCard>, RepositoryError> {
+        let mut conn = self.pool.get().map_err(|e| RepositoryError::Database(e.to_string()))?;
+        let row: Option<ReviewCardRow> = review_cards::table
+            .filter(review_cards::id.eq(id.as_uuid().to_string()))
+            .select(ReviewCardRow::as_select())
+            .first(&mut conn)
+            .optional()?;
+
+        row.map(Self::row_to_entity).transpose()
+    }
+
+    fn find_by_exam(&self, exam_id: &ExamId) -> Result<Vec<ReviewCard>, RepositoryError> {
+        let mut conn = self.pool.get().map_err(|e| RepositoryError::Database(e.to_string()))?;
+        let rows: Vec<ReviewCardRow> = review_cards::table
+            .filter(review_cards::exam_id.eq(exam_id.as_uuid().to_string()))
+            .order(review_cards::question_index.asc())
+            .select(ReviewCardRow::as_select())
+            .load(&mut conn)?;
+
+        rows.into_iter().map(Self::row_to_entity).collect()
+    }
+
+    fn find_due(&self, date: NaiveDate) -> Result<Vec<ReviewCard>, RepositoryError> {
+        let mut conn = self.pool.get().map_err(|e| RepositoryError::Database(e.to_string()))?;
+        let rows: Vec<ReviewCardRow> = review_cards::table
+            .filter(review_cards::due_date.le(date.format(DATE_FORMAT).to_string()))
+            .order((review_cards::due_date.asc(), review_cards::question_index.asc()))
+            .select(ReviewCardRow::as_select())
+            .load(&mut conn)?;
+
+        rows.into_iter().map(Self::row_to_entity).collect()
+    }
+}
+
+fn parse_date(raw: &str) -> Result<NaiveDate, RepositoryError> {
+    NaiveDate::parse_from_str(raw, DATE_FORMAT)
+        .map_err(|e| RepositoryError::Database(format!("Failed to parse date {raw}: {e}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::{Course, Exam, Module, Video};
+    use crate::domain::ports::{
+        CourseRepository, ExamRepository, ModuleRepository, VideoRepository,
+    };
+    use crate::domain::value_objects::{
+        CourseId, CourseSource, ModuleId, PlaylistUrl, ReviewGrade, VideoSource,
+    };
+    use crate::infrastructure::persistence::repositories::{
+        SqliteCourseRepository, SqliteExamRepository, SqliteModuleRepository, SqliteVideoRepository,
+    };
+
+    #[test]
+    fn test_review_card_round_trip_and_due_query() {
+        let pool =
+            Arc::new(crate::infrastructure::persistence::establish_connection(":memory:").unwrap());
+        let course_repo = SqliteCourseRepository::new(pool.clone());
+        let module_repo = SqliteModuleRepository::new(pool.clone());
+        let video_repo = SqliteVideoRepository::new(pool.clone());
+        let exam_repo = SqliteExamRepository::new(pool.clone());
+        let repo = SqliteReviewCardRepository::new(pool.clone());
+
+        let course_id = CourseId::new();
+        let playlist_url =
+            PlaylistUrl::new("https://www.youtube.com/playlist?list=PL38E37F4BE52E385D").unwrap();
+        course_repo
+            .save(&Course::new(
+                course_id,
+                "Reviewable".to_string(),
+                CourseSource::youtube_playlist(playlist_url),
+                None,
+                None,
+            ))
+            .unwrap();
+        let module_id = ModuleId::new();
+        module_repo.save(&Module::new(module_id, course_id, "Module".to_string(), 0)).unwrap();
+        let video_id = VideoId::new();
+        let source = VideoSource::local_path("/videos/0.mp4").unwrap();
+        video_repo
+            .save(&Video::new(video_id, module_id, source, "V0".to_string(), 600, 0))
+            .unwrap();
+        let exam_id = ExamId::new();
+        exam_repo.save(&Exam::new(exam_id, video_id, "[]".to_string())).unwrap();
+
+        let day = |d| NaiveDate::from_ymd_opt(2026, 6, d).unwrap();
+        let cards: Vec<ReviewCard> = (0..2)
+            .map(|idx| {
+                let question = QuizQuestion {
+                    question: format!("Question {idx}?"),
+                    options: vec!["A".to_string(), "B".to_string()],
+                    correct_index: idx,
+                    explanation: "Because.".to_string(),
+                };
+                ReviewCard::new(
+                    ReviewCardId::new(),
+                    exam_id,
+                    video_id,
+                    idx as u32,
+                    question,
+                    day(1),
+                )
+            })
+            .collect();
+        for card in &cards {
+            repo.save(card).unwrap();
+        }
+        assert_eq!(repo.find_due(day(1)).unwrap(), cards);
+
+        let mut reviewed = cards[0].clone();
+        reviewed.record_review(ReviewGrade::Good, day(1));
+        repo.save(&reviewed).unwrap();
+
+        assert_eq!(repo.find_by_id(reviewed.id()).unwrap(), Some(reviewed.clone()));
+        assert_eq!(repo.find_due(day(1)).unwrap(), vec![cards[1].clone()]);
+        assert_eq!(repo.find_due(day(2)).unwrap().len(), 2);
+        assert_eq!(repo.find_by_exam(&exam_id).unwrap()[0], reviewed);
+    }
+}
@@ -0,0 +1,264 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use chrono::{DateTime, NaiveDate, SecondsFormat, Utc};
+use diesel::prelude::*;
+
+use crate::domain::entities::{StudySchedule, StudySession};
+use crate::domain::ports::{RepositoryError, StudyScheduleRepository};
+use crate::domain::value_objects::{CourseId, ReschedulePolicy, VideoId};
+use crate::infrastructure::persistence::connection::DbPool;
+use crate::infrastructure::persistence::models::{
+    NewStudySchedule, NewStudySession, StudyScheduleRow, StudySessionRow, StudySessionVideoRow,
+};
+use crate::infrastructure::persistence::repositories::i32_to_u32;
+use crate::schema::{study_schedules, study_session_videos, study_sessions};
+
+const DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// SQLite-backed study schedule repository.
+pub struct SqliteStudyScheduleRepository {
+    pool: Arc<DbPool>,
+}
+
+impl SqliteStudyScheduleRepository {
+    pub fn new(pool: Arc<DbPool>) -> Self {
+        Self { pool }
+    }
+
+    fn load_schedule(
+        conn: &mut SqliteConnection,
+        row: StudyScheduleRow,
+    ) -> Result<StudySchedule, RepositoryError> {
+        let session_rows: Vec<StudySessionRow> = study_sessions::table
+            .filter(study_sessions::course_id.eq(&row.course_id))
+            .order(study_sessions::day.asc())
+            .load(conn)?;
+
+        let session_ids: Vec<&str> = session_rows.iter().map(|s| s.id.as_str()).collect();
+        let video_rows: Vec<StudySessionVideoRow> = study_session_videos::table
+            .filter(study_session_videos::session_id.eq_any(&session_ids))
+            .order(study_session_videos::position.asc())
+            .load(conn)?;
+
+        let mut videos_by_session: HashMap<String, Vec<VideoId>> = HashMap::new();
+        for video_row in video_rows {
+            let video_id = VideoId::from_str(&video_row.video_id).map_err(|e| {
+                RepositoryError::Database(format!("Invalid video ID in study session: {e}"))
+            })?;
+            videos_by_session.entry(video_row.session_id).or_default().push(video_id);
+        }
+
+        let mut sessions = Vec::with_capacity(session_rows.len());
+        for session_row in session_rows {
+            sessions.push(StudySession::new(
+                i32_to_u32(session_row.day, "day")?,
+                parse_date(&session_row.scheduled_date)?,
+                videos_by_session.remove(&session_row.id).unwrap_or_default(),
+                i32_to_u32(session_row.total_duration_secs, "total_duration_secs")?,
+            ));
+        }
+
+        let course_id = CourseId::from_str(&row.course_id)
+            .map_err(|e| RepositoryError::Database(format!("Invalid course ID: {e}")))?;
+        let updated_at = DateTime::parse_from_rfc3339(&row.updated_at)
+            .map(|ts| ts.with_timezone(&Utc))
+            .map_err(|e| {
+                RepositoryError::Database(format!("Invalid timestamp {}: {e}", row.updated_at))
+            })?;
+        let reschedule_policy = row.reschedule_policy.parse::<ReschedulePolicy>().map_err(|e| {
+            RepositoryError::Database(format!(
+                "Invalid reschedule policy {}: {e}",
+                row.reschedule_policy
+            ))
+        })?;
+        Ok(StudySchedule::new(
+            course_id,
+            parse_date(&row.start_date)?,
+            i32_to_u32(row.study_days_per_week, "study_days_per_week")?,
+            i32_to_u32(row.cognitive_limit_minutes, "cognitive_limit_minutes")?,
+            sessions,
+        )
+        .with_reschedule_policy(reschedule_policy)
+        .with_updated_at(updated_at)
+        .with_peak_session_count(i32_to_u32(row.peak_session_count, "peak_session_count")?))
+    }
+}
+
+impl StudyScheduleRepository for SqliteStudyScheduleRepository {
+    fn save(&self, schedule: &StudySchedule) -> Result<(), RepositoryError> {
+        let mut conn = self.pool.get().map_err(|e| RepositoryError::Database(e.to_string()))?;
+        let course_id = schedule.course_id().as_uuid().to_string();
+        let start_date = schedule.start_date().format(DATE_FORMAT).to_string();
+        let updated_at = schedule.updated_at().to_rfc3339_opts(SecondsFormat::AutoSi, true);
+
+        conn.transaction::<_, RepositoryError, _>(|tx| {
+            delete_schedule(tx, &course_id)?;
+
+            diesel::insert_into(study_schedules::table)
+                .values(&NewStudySchedule {
+                    course_id: &course_id,
+                    start_date: &start_date,
+                    study_days_per_week: schedule.study_days_per_week() as i32,
+                    cognitive_limit_minutes: schedule.cognitive_limit_minutes() as i32,
+                    reschedule_policy: schedule.reschedule_policy().as_str(),
+                    updated_at: &updated_at,
+                    peak_session_count: schedule.peak_session_count() as i32,
+                })
+                .execute(tx)?;
+
+            for session in schedule.sessions() {
+                let session_id = uuid::Uuid::new_v4().to_string();
+                let scheduled_date = session.date().format(DATE_FORMAT).to_string();
+                diesel::insert_into(study_sessions::table)
+                    .values(&NewStudySession {
+                        id: &session_id,
+                        course_id: &course_id,
+                        day: session.day() as i32,
+                        scheduled_date: &scheduled_date,
+                        total_duration_secs: session.total_duration_secs() as i32,
+                    })
+                    .execute(tx)?;
+
+                let video_rows: Vec<StudySessionVideoRow> = session
+                    .video_ids()
+                    .iter()
+                    .enumerate()
+                    .map(|(position, video_id)| StudySessionVideoRow {
+                        session_id: session_id.clone(),
+                        video_id: video_id.as_uuid().to_string(),
+                        position: position as i32,
+                    })
+                    .collect();
+                diesel::insert_into(study_session_videos::table).values(&video_rows).execute(tx)?;
+            }
+            Ok(())
+        })
+    }
+
+    fn find_by_course(
+        &self,
+        course_id: &CourseId,
+    ) -> Result<Option<StudySchedule>, RepositoryError> {
+        let mut conn = self.pool.get().map_err(|e| RepositoryError::Database(e.to_string()))?;
+        let row: Option<StudyScheduleRow> = study_schedules::table
+            .filter(study_schedules::course_id.eq(course_id.as_uuid().to_string()))
+            .first(&mut conn)
+            .optional()?;
+
+        row.map(|r| Self::load_schedule(&mut conn, r)).transpose()
+    }
+
+    fn find_all(&self) -> Result<Vec<StudySchedule>, RepositoryError> {
+        let mut conn = self.pool.get().map_err(|e| RepositoryError::Database(e.to_string()))?;
+        let rows: Vec<StudyScheduleRow> = study_schedules::table.load(&mut conn)?;
+
+        rows.into_iter().map(|r| Self::load_schedule(&mut conn, r)).collect()
+    }
+
+    fn delete(&self, course_id: &CourseId) -> Result<(), RepositoryError> {
+        let mut conn = self.pool.get().map_err(|e| RepositoryError::Database(e.to_string()))?;
+        let course_id = course_id.as_uuid().to_string();
+        conn.transaction::<_, RepositoryError, _>(|tx| delete_schedule(tx, &course_id))
+    }
+}
+
+fn delete_schedule(conn: &mut SqliteConnection, course_id: &str) -> Result<(), RepositoryError> {
+    let session_ids = study_sessions::table
+        .filter(study_sessions::course_id.eq(course_id))
+        .select(study_sessions::id);
+    diesel::delete(
+        study_session_videos::table.filter(study_session_videos::session_id.eq_any(session_ids)),
+    )
+    .execute(conn)?;
+    diesel::delete(study_sessions::table.filter(study_sessions::course_id.eq(course_id)))
+        .execute(conn)?;
+    diesel::delete(study_schedules::table.filter(study_schedules::course_id.eq(course_id)))
+        .execute(conn)?;
+    Ok(())
+}
+
+fn parse_date(raw: &str) -> Result<NaiveDate, RepositoryError> {
+    NaiveDate::parse_from_str(raw, DATE_FORMAT)
+        .map_err(|e| RepositoryError::Database(format!("Failed to parse date {raw}: {e}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::{Course, Module, Video};
+    use crate::domain::ports::{CourseRepository, ModuleRepository, VideoRepository};
+    use crate::domain::value_objects::{
+        CourseSource, ModuleId, PlaylistUrl, SessionPlan, VideoSource,
+    };
+    use crate::infrastructure::persistence::repositories::{
+        SqliteCourseRepository, SqliteModuleRepository, SqliteVideoRepository,
+    };
+
+    #[test]
+    fn test_schedule_round_trip_and_replace() {
+        let pool =
+            Arc::new(crate::infrastructure::persistence::establish_connection(":memory:").unwrap());
+        let course_repo = SqliteCourseRepository::new(pool.clone());
+        let module_repo = SqliteModuleRepository::new(pool.clone());
+        let video_repo = SqliteVideoRepository::new(pool.clone());
+        let schedule_repo = SqliteStudyScheduleRepository::new(pool.clone());
+
+        let course_id = CourseId::new();
+        let playlist_url =
+            PlaylistUrl::new("https://www.youtube.com/playlist?list=PL38E37F4BE52E385D").unwrap();
+        let course = Course::new(
+            course_id,
+            "Scheduled Course".to_string(),
+            CourseSource::youtube_playlist(playlist_url),
+            None,
+            None,
+        );
+        course_repo.save(&course).unwrap();
+
+        let module_id = ModuleId::new();
+        module_repo.save(&Module::new(module_id, course_id, "Module".to_string(), 0)).unwrap();
+
+        let video_ids: Vec<VideoId> = (0..3).map(|_| VideoId::new()).collect();
+        for (idx, id) in video_ids.iter().enumerate() {
+            let source = VideoSource::local_path(format!("/videos/{idx}.mp4")).unwrap();
+            let video = Video::new(*id, module_id, source, format!("V{idx}"), 600, idx as u32);
+            video_repo.save(&video).unwrap();
+        }
+
+        let start = NaiveDate::from_ymd_opt(2026, 6, 1).unwrap();
+        let plans = vec![SessionPlan::new(1, vec![0, 1], 1200), SessionPlan::new(2, vec![2], 600)];
+        let schedule = StudySchedule::from_plans(course_id, start, 5, 30, &plans, &video_ids);
+        schedule_repo.save(&schedule).unwrap();
+
+        let loaded = schedule_repo.find_by_course(&course_id).unwrap().unwrap();
+        assert_eq!(loaded, schedule);
+
+        // Saving again replaces rather than appends.
+        let replan = vec![SessionPlan::new(1, vec![0, 1, 2], 1800)];
+        let replacement = StudySchedule::from_plans(course_id, start, 7, 60, &replan, &video_ids)
+            .with_reschedule_policy(ReschedulePolicy::KeepDeadline);
+        schedule_repo.save(&replacement).unwrap();
+        let all = schedule_repo.find_all().unwrap();
+        assert_eq!(all.len(), 1);
+        assert_eq!(all[0].sessions().len(), 1);
+        assert_eq!(all[0].reschedule_policy(), ReschedulePolicy::KeepDeadline);
+        assert_eq!(all[0].sessions()[0].video_ids(), video_ids.as_slice());
+        assert_eq!(all[0].peak_session_count(), 1);
+        assert_eq!(all[0].updated_at(), replacement.updated_at());
+
+        // A policy the app does not know is an error rather than a silent default.
+        diesel::update(study_schedules::table)
+            .set(study_schedules::reschedule_policy.eq("keep_everything"))
+            .execute(&mut pool.get().unwrap())
+            .unwrap();
+        assert!(matches!(
+            schedule_repo.find_by_course(&course_id),
+            Err(RepositoryError::Database(_))
+        ));
+
+        schedule_repo.delete(&course_id).unwrap();
+        assert!(schedule_repo.find_by_course(&course_id).unwrap().is_none());
+    }
+}
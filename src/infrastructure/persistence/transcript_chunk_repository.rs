@@ -0,0 +1,253 @@
+//! SQLite transcript chunk index using FTS5 with BM25 ranking.
+
+use std::sync::Arc;
+
+use diesel::prelude::*;
+use diesel::sql_query;
+use diesel::sql_types::{Integer, Text};
+
+use crate::domain::ports::{RepositoryError, TranscriptChunk, TranscriptChunkRepository};
+use crate::domain::value_objects::VideoId;
+use crate::infrastructure::persistence::DbPool;
+
+/// Words too common to say anything about which passage a question is about.
+const STOP_WORDS: &[&str] = &[
+    "about", "and", "are", "but", "can", "could", "did", "does", "for", "from", "has", "have",
+    "how", "into", "its", "not", "says", "should", "that", "the", "their", "them", "then", "there",
+    "these", "this", "was", "were", "what", "when", "where", "which", "who", "why", "will", "with",
+    "would", "you", "your",
+];
+
+/// SQLite FTS5 implementation of TranscriptChunkRepository.
+pub struct SqliteTranscriptChunkRepository {
+    pool: Arc<DbPool>,
+}
+
+impl SqliteTranscriptChunkRepository {
+    pub fn new(pool: Arc<DbPool>) -> Self {
+        Self { pool }
+    }
+}
+
+#[derive(QueryableByName, Debug)]
+struct HashRow {
+    #[diesel(sql_type = Text)]
+    transcript_hash: String,
+}
+
+#[derive(QueryableByName, Debug)]
+struct ChunkRow {
+    #[diesel(sql_type = Integer)]
+    chunk_index: i32,
+    #[diesel(sql_type = Text)]
+    content: String,
+}
+
+/// Turns a free-text question into an FTS5 query matching any of its significant words.
+///
+/// Returns `None` when nothing in the question is worth searching for.
+fn match_query(question: &str) -> Option<String> {
+    let mut terms: Vec<String> = Vec::new();
+    for word in question.split(|c: char| !c.is_alphanumeric()) {
+        let word = word.to_lowercase();
+        if word.chars().count() < 3 || STOP_WORDS.contains(&word.as_str()) {
+            continue;
+        }
+        if !terms.contains(&word) {
+            terms.push(word);
+        }
+    }
+    if terms.is_empty() {
+        return None;
+    }
+    Some(terms.iter().map(|t| format!("\"{t}\"")).collect::<Vec<_>>().join(" OR "))
+}
+
+impl TranscriptChunkRepository for SqliteTranscriptChunkRepository {
+    fn indexed_hash(&self, video_id: &VideoId) -> Result<Option<String>, RepositoryError> {
+        let mut conn = self.pool.get().map_err(|e| RepositoryError::Database(e.to_string()))?;
+
+        let row: Option<HashRow> =
+            sql_query("SELECT transcript_hash FROM transcript_chunks WHERE video_id = ? LIMIT 1")
+                .bind::<Text, _>(video_id.as_uuid().to_string())
+                .get_result(&mut conn)
+                .optional()
+                .map_err(|e| RepositoryError::Database(e.to_string()))?;
+
+        Ok(row.map(|r| r.transcript_hash))
+    }
+
+    fn replace_chunks(
+        &self,
+        video_id: &VideoId,
+        transcript_hash: &str,
+        chunks: &[String],
+    ) -> Result<(), RepositoryError> {
+        let mut conn = self.pool.get().map_err(|e| RepositoryError::Database(e.to_string()))?;
+        let id_str = video_id.as_uuid().to_string();
+
+        conn.transaction::<_, RepositoryError, _>(|tx| {
+            sql_query("DELETE FROM transcript_chunks WHERE video_id = ?")
+                .bind::<Text, _>(&id_str)
+                .execute(tx)
+                .map_err(|e| RepositoryError::Database(e.to_string()))?;
+
+            for (index, chunk) in chunks.iter().enumerate() {
+                sql_query(
+                    "INSERT INTO transcript_chunks (video_id, chunk_index, transcript_hash, content) VALUES (?, ?, ?, ?)",
+                )
+                .bind::<Text, _>(&id_str)
+                .bind::<Integer, _>(index as i32)
+                .bind::<Text, _>(transcript_hash)
+                .bind::<Text, _>(chunk)
+                .execute(tx)
+                .map_err(|e| RepositoryError::BatchFailed {
+                    entity: "TranscriptChunk",
+                    index,
+                    source: Box::new(RepositoryError::Database(e.to_string())),
+                })?;
+            }
+            Ok(())
+        })
+    }
+
+    fn search(
+        &self,
+        video_id: &VideoId,
+        query: &str,
+        limit: usize,
+    ) -> Result<Vec<TranscriptChunk>, RepositoryError> {
+        let Some(match_query) = match_query(query) else {
+            return Ok(vec![]);
+        };
+        let mut conn = self.pool.get().map_err(|e| RepositoryError::Database(e.to_string()))?;
+
+        let rows: Vec<ChunkRow> = sql_query(
+            r#"
+            SELECT chunk_index, content
+            FROM transcript_chunks
+            WHERE transcript_chunks MATCH ? AND video_id = ?
+            ORDER BY bm25(transcript_chunks)
+            LIMIT ?
+            "#,
+        )
+        .bind::<Text, _>(&match_query)
+        .bind::<Text, _>(video_id.as_uuid().to_string())
+        .bind::<Integer, _>(limit as i32)
+        .load(&mut conn)
+        .map_err(|e| RepositoryError::Database(e.to_string()))?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| TranscriptChunk { index: row.chunk_index as usize, content: row.content })
+            .collect())
+    }
+
+    fn remove_video(&self, video_id: &VideoId) -> Result<(), RepositoryError> {
+        let mut conn = self.pool.get().map_err(|e| RepositoryError::Database(e.to_string()))?;
+
+        sql_query("DELETE FROM transcript_chunks WHERE video_id = ?")
+            .bind::<Text, _>(video_id.as_uuid().to_string())
+            .execute(&mut conn)
+            .map_err(|e| RepositoryError::Database(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chunks(texts: &[&str]) -> Vec<String> {
+        texts.iter().map(|t| t.to_string()).collect()
+    }
+
+    #[test]
+    fn questions_become_or_queries_over_significant_words() {
+        assert_eq!(
+            match_query("What does \"borrowing\" mean for the Borrow checker?").as_deref(),
+            Some("\"borrowing\" OR \"mean\" OR \"borrow\" OR \"checker\"")
+        );
+        assert_eq!(match_query("Why is it so?"), None);
+    }
+
+    #[test]
+    fn search_ranks_the_video_chunks_by_relevance() {
+        let pool =
+            Arc::new(crate::infrastructure::persistence::establish_connection(":memory:").unwrap());
+        let repo = SqliteTranscriptChunkRepository::new(pool);
+        let video_id = VideoId::new();
+        let other_id = VideoId::new();
+
+        assert_eq!(repo.indexed_hash(&video_id).unwrap(), None);
+        repo.replace_chunks(
+            &video_id,
+            "old",
+            &chunks(&["Stale text about lifetimes and lifetimes again"]),
+        )
+        .unwrap();
+        repo.replace_chunks(
+            &video_id,
+            "h1",
+            &chunks(&[
+                "Every value in Rust has a single owner.",
+                "Borrowing lets a function use a value without taking ownership.",
+                "Lifetimes describe how long a borrow is valid.",
+            ]),
+        )
+        .unwrap();
+        repo.replace_chunks(&other_id, "h2", &chunks(&["Borrowing borrowing borrowing."])).unwrap();
+        assert_eq!(repo.indexed_hash(&video_id).unwrap().as_deref(), Some("h1"));
+
+        // Only the video's own chunks, best match first, at most `limit`
+        let found = repo.search(&video_id, "How does a function borrow a value?", 2).unwrap();
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[0].index, 1);
+        let found = repo.search(&video_id, "lifetimes", 3).unwrap();
+        assert_eq!(
+            found,
+            [TranscriptChunk {
+                index: 2,
+                content: "Lifetimes describe how long a borrow is valid.".to_string()
+            }]
+        );
+        assert!(repo.search(&video_id, "Why is it so?", 3).unwrap().is_empty());
+
+        repo.remove_video(&video_id).unwrap();
+        assert_eq!(repo.indexed_hash(&video_id).unwrap(), None);
+        assert_eq!(repo.search(&other_id, "borrowing", 3).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn deleting_a_course_clears_its_videos_chunks() {
+        use crate::domain::entities::{Course, Module, Video};
+        use crate::domain::ports::{CourseRepository, ModuleRepository, VideoRepository};
+        use crate::domain::value_objects::{CourseId, CourseSource, ModuleId, VideoSource};
+        use crate::infrastructure::persistence::repositories::{
+            SqliteCourseRepository, SqliteModuleRepository, SqliteVideoRepository,
+        };
+
+        let pool =
+            Arc::new(crate::infrastructure::persistence::establish_connection(":memory:").unwrap());
+        let course_repo = SqliteCourseRepository::new(pool.clone());
+        let course = Course::new(CourseId::new(), "Rust".into(), CourseSource::Manual, None, None);
+        course_repo.save(&course).unwrap();
+        let module = Module::new(ModuleId::new(), *course.id(), "Ownership".into(), 0);
+        SqliteModuleRepository::new(pool.clone()).save(&module).unwrap();
+        let video_id = VideoId::new();
+        let source = VideoSource::local_path("/videos/moves.mp4").unwrap();
+        let video = Video::new(video_id, *module.id(), source, "Moves".into(), 600, 0);
+        SqliteVideoRepository::new(pool.clone()).save(&video).unwrap();
+
+        let repo = SqliteTranscriptChunkRepository::new(pool);
+        let other_id = VideoId::new();
+        repo.replace_chunks(&video_id, "h1", &chunks(&["Values move on assignment."])).unwrap();
+        repo.replace_chunks(&other_id, "h2", &chunks(&["Borrowing avoids a move."])).unwrap();
+
+        course_repo.delete(course.id()).unwrap();
+        assert_eq!(repo.indexed_hash(&video_id).unwrap(), None);
+        assert!(repo.search(&video_id, "move", 3).unwrap().is_empty());
+        assert_eq!(repo.indexed_hash(&other_id).unwrap().as_deref(), Some("h2"));
+    }
+}
@@ -0,0 +1,115 @@
+use std::sync::Arc;
+
+use diesel::prelude::*;
+
+use crate::domain::ports::{RepositoryError, TranscriptCue, TranscriptCueRepository};
+use crate::domain::value_objects::VideoId;
+use crate::infrastructure::persistence::connection::DbPool;
+use crate::infrastructure::persistence::models::TranscriptCueRow;
+use crate::schema::transcript_cues;
+
+/// SQLite-backed store of timed transcript cues.
+pub struct SqliteTranscriptCueRepository {
+    pool: Arc<DbPool>,
+}
+
+impl SqliteTranscriptCueRepository {
+    pub fn new(pool: Arc<DbPool>) -> Self {
+        Self { pool }
+    }
+}
+
+impl TranscriptCueRepository for SqliteTranscriptCueRepository {
+    fn replace_for_video(
+        &self,
+        video_id: &VideoId,
+        source_hash: &str,
+        cues: &[TranscriptCue],
+    ) -> Result<(), RepositoryError> {
+        let mut conn = self.pool.get().map_err(|e| RepositoryError::Database(e.to_string()))?;
+        let id_str = video_id.as_uuid().to_string();
+        let rows: Vec<TranscriptCueRow> = cues
+            .iter()
+            .enumerate()
+            .map(|(position, cue)| TranscriptCueRow {
+                video_id: id_str.clone(),
+                position: position as i32,
+                start_ms: i64::try_from(cue.start_ms).unwrap_or(i64::MAX),
+                end_ms: i64::try_from(cue.end_ms).unwrap_or(i64::MAX),
+                text: cue.text.clone(),
+                source_hash: source_hash.to_string(),
+            })
+            .collect();
+
+        conn.transaction::<_, RepositoryError, _>(|tx| {
+            diesel::delete(transcript_cues::table.filter(transcript_cues::video_id.eq(&id_str)))
+                .execute(tx)?;
+            diesel::insert_into(transcript_cues::table).values(&rows).execute(tx)?;
+            Ok(())
+        })
+    }
+
+    fn source_hash(&self, video_id: &VideoId) -> Result<Option<String>, RepositoryError> {
+        let mut conn = self.pool.get().map_err(|e| RepositoryError::Database(e.to_string()))?;
+        Ok(transcript_cues::table
+            .filter(transcript_cues::video_id.eq(video_id.as_uuid().to_string()))
+            .select(transcript_cues::source_hash)
+            .first(&mut conn)
+            .optional()?)
+    }
+
+    fn find_by_video(&self, video_id: &VideoId) -> Result<Vec<TranscriptCue>, RepositoryError> {
+        let mut conn = self.pool.get().map_err(|e| RepositoryError::Database(e.to_string()))?;
+        let rows: Vec<TranscriptCueRow> = transcript_cues::table
+            .filter(transcript_cues::video_id.eq(video_id.as_uuid().to_string()))
+            .order(transcript_cues::position.asc())
+            .select(TranscriptCueRow::as_select())
+            .load(&mut conn)?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| TranscriptCue {
+                start_ms: u64::try_from(row.start_ms).unwrap_or(0),
+                end_ms: u64::try_from(row.end_ms).unwrap_or(0),
+                text: row.text,
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::{Course, Module, Video};
+    use crate::domain::ports::{CourseRepository, ModuleRepository, VideoRepository};
+    use crate::domain::value_objects::{CourseId, CourseSource, ModuleId, VideoSource};
+    use crate::infrastructure::persistence::repositories::{
+        SqliteCourseRepository, SqliteModuleRepository, SqliteVideoRepository,
+    };
+
+    #[test]
+    fn cues_are_replaced_and_read_back_in_order() {
+        let pool =
+            Arc::new(crate::infrastructure::persistence::establish_connection(":memory:").unwrap());
+        let course = Course::new(CourseId::new(), "Rust".into(), CourseSource::Manual, None, None);
+        SqliteCourseRepository::new(pool.clone()).save(&course).unwrap();
+        let module = Module::new(ModuleId::new(), *course.id(), "Ownership".into(), 0);
+        SqliteModuleRepository::new(pool.clone()).save(&module).unwrap();
+        let video_id = VideoId::new();
+        let source = VideoSource::local_path("/videos/moves.mp4").unwrap();
+        let video = Video::new(video_id, *module.id(), source, "Moves".into(), 600, 0);
+        SqliteVideoRepository::new(pool.clone()).save(&video).unwrap();
+
+        let repo = SqliteTranscriptCueRepository::new(pool);
+        assert!(repo.find_by_video(&video_id).unwrap().is_empty());
+
+        let cue =
+            |start_ms, end_ms, text: &str| TranscriptCue { start_ms, end_ms, text: text.into() };
+        assert_eq!(repo.source_hash(&video_id).unwrap(), None);
+        repo.replace_for_video(&video_id, "old", &[cue(0, 1_000, "Old")]).unwrap();
+        let cues = vec![cue(0, 2_500, "Values move"), cue(2_500, 4_000, "on assignment.")];
+        repo.replace_for_video(&video_id, "new", &cues).unwrap();
+        assert_eq!(repo.find_by_video(&video_id).unwrap(), cues);
+        assert_eq!(repo.source_hash(&video_id).unwrap().as_deref(), Some("new"));
+    }
+}
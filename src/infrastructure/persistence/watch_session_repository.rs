@@ -0,0 +1,170 @@
+use std::str::FromStr;
+use std::sync::Arc;
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use diesel::prelude::*;
+
+use crate::domain::ports::{RepositoryError, WatchSession, WatchSessionRepository};
+use crate::domain::value_objects::{CourseId, VideoId};
+use crate::infrastructure::persistence::connection::DbPool;
+use crate::infrastructure::persistence::models::WatchSessionRow;
+use crate::schema::{modules, videos, watch_sessions};
+
+/// SQLite-backed watch session log.
+pub struct SqliteWatchSessionRepository {
+    pool: Arc<DbPool>,
+}
+
+impl SqliteWatchSessionRepository {
+    pub fn new(pool: Arc<DbPool>) -> Self {
+        Self { pool }
+    }
+
+    fn row_to_entity(row: WatchSessionRow) -> Result<WatchSession, RepositoryError> {
+        let video_id = VideoId::from_str(&row.video_id).map_err(|e| {
+            RepositoryError::Database(format!("Invalid video ID in watch session: {e}"))
+        })?;
+        Ok(WatchSession {
+            video_id,
+            started_at: parse_timestamp(&row.started_at)?,
+            ended_at: parse_timestamp(&row.ended_at)?,
+            played_secs: u32::try_from(row.played_secs).unwrap_or(0),
+        })
+    }
+}
+
+/// Fixed-width UTC timestamps so lexical order matches chronological order.
+fn format_timestamp(at: DateTime<Utc>) -> String {
+    at.to_rfc3339_opts(SecondsFormat::Millis, true)
+}
+
+fn parse_timestamp(value: &str) -> Result<DateTime<Utc>, RepositoryError> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|at| at.with_timezone(&Utc))
+        .map_err(|e| RepositoryError::Database(format!("Invalid timestamp {value}: {e}")))
+}
+
+impl WatchSessionRepository for SqliteWatchSessionRepository {
+    fn record(&self, session: &WatchSession) -> Result<(), RepositoryError> {
+        let mut conn = self.pool.get().map_err(|e| RepositoryError::Database(e.to_string()))?;
+        let row = WatchSessionRow {
+            id: uuid::Uuid::new_v4().to_string(),
+            video_id: session.video_id.as_uuid().to_string(),
+            started_at: format_timestamp(session.started_at),
+            ended_at: format_timestamp(session.ended_at),
+            played_secs: i32::try_from(session.played_secs).unwrap_or(i32::MAX),
+        };
+
+        diesel::insert_into(watch_sessions::table).values(&row).execute(&mut conn)?;
+        Ok(())
+    }
+
+    fn find_since(&self, since: DateTime<Utc>) -> Result<Vec<WatchSession>, RepositoryError> {
+        let mut conn = self.pool.get().map_err(|e| RepositoryError::Database(e.to_string()))?;
+        let rows: Vec<WatchSessionRow> = watch_sessions::table
+            .filter(watch_sessions::started_at.ge(format_timestamp(since)))
+            .order(watch_sessions::started_at.asc())
+            .select(WatchSessionRow::as_select())
+            .load(&mut conn)?;
+
+        rows.into_iter().map(Self::row_to_entity).collect()
+    }
+
+    fn find_by_course_since(
+        &self,
+        course_id: &CourseId,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<WatchSession>, RepositoryError> {
+        let mut conn = self.pool.get().map_err(|e| RepositoryError::Database(e.to_string()))?;
+        let rows: Vec<WatchSessionRow> = watch_sessions::table
+            .inner_join(videos::table.inner_join(modules::table))
+            .filter(modules::course_id.eq(course_id.as_uuid().to_string()))
+            .filter(watch_sessions::started_at.ge(format_timestamp(since)))
+            .order(watch_sessions::started_at.asc())
+            .select(WatchSessionRow::as_select())
+            .load(&mut conn)?;
+
+        rows.into_iter().map(Self::row_to_entity).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::{Course, Module, Video};
+    use crate::domain::ports::{CourseRepository, ModuleRepository, VideoRepository};
+    use crate::domain::value_objects::{CourseSource, ModuleId, PlaylistUrl, VideoSource};
+    use crate::infrastructure::persistence::repositories::{
+        SqliteCourseRepository, SqliteModuleRepository, SqliteVideoRepository,
+    };
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_watch_sessions_filter_by_time_and_course() {
+        let pool =
+            Arc::new(crate::infrastructure::persistence::establish_connection(":memory:").unwrap());
+        let course_repo = SqliteCourseRepository::new(pool.clone());
+        let module_repo = SqliteModuleRepository::new(pool.clone());
+        let video_repo = SqliteVideoRepository::new(pool.clone());
+        let repo = SqliteWatchSessionRepository::new(pool.clone());
+
+        let mut video_ids = Vec::new();
+        let mut course_ids = Vec::new();
+        for idx in 0..2 {
+            let course_id = CourseId::new();
+            let playlist_url =
+                PlaylistUrl::new("https://www.youtube.com/playlist?list=PL38E37F4BE52E385D")
+                    .unwrap();
+            course_repo
+                .save(&Course::new(
+                    course_id,
+                    format!("Course {idx}"),
+                    CourseSource::youtube_playlist(playlist_url),
+                    None,
+                    None,
+                ))
+                .unwrap();
+            let module_id = ModuleId::new();
+            module_repo.save(&Module::new(module_id, course_id, "Module".to_string(), 0)).unwrap();
+            let video_id = VideoId::new();
+            let source = VideoSource::local_path(format!("/videos/{idx}.mp4")).unwrap();
+            video_repo
+                .save(&Video::new(video_id, module_id, source, format!("V{idx}"), 600, 0))
+                .unwrap();
+            course_ids.push(course_id);
+            video_ids.push(video_id);
+        }
+
+        let at = |day, hour| Utc.with_ymd_and_hms(2026, 6, day, hour, 0, 0).unwrap();
+        let sessions = [
+            WatchSession {
+                video_id: video_ids[0],
+                started_at: at(1, 9),
+                ended_at: at(1, 10),
+                played_secs: 3_000,
+            },
+            WatchSession {
+                video_id: video_ids[1],
+                started_at: at(2, 9),
+                ended_at: at(2, 9),
+                played_secs: 0,
+            },
+            WatchSession {
+                video_id: video_ids[0],
+                started_at: at(3, 9),
+                ended_at: at(3, 10),
+                played_secs: 1_200,
+            },
+        ];
+        for session in &sessions {
+            repo.record(session).unwrap();
+        }
+
+        assert_eq!(repo.find_since(at(2, 0)).unwrap(), sessions[1..].to_vec());
+        assert_eq!(
+            repo.find_by_course_since(&course_ids[0], at(1, 0)).unwrap(),
+            vec![sessions[0].clone(), sessions[2].clone()]
+        );
+        assert!(repo.find_by_course_since(&course_ids[1], at(3, 0)).unwrap().is_empty());
+    }
+}
@@ -1,6 +1,9 @@
 //! YouTube transcript fetcher using yt-dlp directly.
 
-use crate::domain::ports::{TranscriptError as PortTranscriptError, TranscriptProvider};
+use crate::domain::ports::{
+    TranscriptCue, TranscriptError as PortTranscriptError, TranscriptProvider,
+};
+use crate::domain::services::SubtitleCleaner;
 
 /// Error type for transcript operations.
 #[derive(Debug, thiserror::Error)]
@@ -21,6 +24,22 @@ impl TranscriptAdapter {
 
     /// Fetches the transcript for a YouTube video using yt-dlp.
     pub async fn fetch_transcript(&self, video_id: &str) -> Result<String, TranscriptError> {
+        let raw = self.download_vtt(video_id).await?;
+
+        let text = parse_vtt(&raw);
+        if text.is_empty() { Err(TranscriptError::NoCaptions) } else { Ok(text) }
+    }
+
+    /// Fetches the captions of a YouTube video as timed cues.
+    pub async fn fetch_cues(&self, video_id: &str) -> Result<Vec<TranscriptCue>, TranscriptError> {
+        let raw = self.download_vtt(video_id).await?;
+
+        let cues = SubtitleCleaner::new().cues(&raw);
+        if cues.is_empty() { Err(TranscriptError::NoCaptions) } else { Ok(cues) }
+    }
+
+    /// Downloads the English captions of a video as raw WebVTT.
+    async fn download_vtt(&self, video_id: &str) -> Result<String, TranscriptError> {
         let url = format!("https://www.youtube.com/watch?v={video_id}");
         let output_template = format!("/tmp/cpilot_{video_id}");
 
@@ -78,10 +97,7 @@ impl TranscriptAdapter {
             }
         }
 
-        let raw = vtt_content.ok_or(TranscriptError::NoCaptions)?;
-
-        let text = parse_vtt(&raw);
-        if text.is_empty() { Err(TranscriptError::NoCaptions) } else { Ok(text) }
+        vtt_content.ok_or(TranscriptError::NoCaptions)
     }
 }
 
@@ -159,13 +175,23 @@ fn strip_vtt_tags(input: &str) -> String {
     out
 }
 
+impl From<TranscriptError> for PortTranscriptError {
+    fn from(e: TranscriptError) -> Self {
+        match e {
+            TranscriptError::NoCaptions => PortTranscriptError::NotAvailable,
+            TranscriptError::FetchError(msg) => PortTranscriptError::Provider(msg),
+        }
+    }
+}
+
 #[async_trait::async_trait]
 impl TranscriptProvider for TranscriptAdapter {
     async fn fetch_transcript(&self, video_id: &str) -> Result<String, PortTranscriptError> {
-        self.fetch_transcript(video_id).await.map_err(|e| match e {
-            TranscriptError::NoCaptions => PortTranscriptError::NotAvailable,
-            TranscriptError::FetchError(msg) => PortTranscriptError::Provider(msg),
-        })
+        self.fetch_transcript(video_id).await.map_err(Into::into)
+    }
+
+    async fn fetch_cues(&self, video_id: &str) -> Result<Vec<TranscriptCue>, PortTranscriptError> {
+        self.fetch_cues(video_id).await.map_err(Into::into)
     }
 }
 
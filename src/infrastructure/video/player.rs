@@ -1,6 +1,7 @@
 use gst::glib::prelude::Cast;
 use gst::prelude::{ElementExt, ElementExtManual, GstBinExt, GstObjectExt, ObjectExt};
 use gtk::prelude::WidgetExt;
+use std::cell::Cell;
 use std::sync::mpsc;
 
 struct FrameData {
@@ -14,6 +15,9 @@ pub struct VideoPlayer {
     pipeline: gst::Pipeline,
     playbin: gst::Element,
     picture: gtk::Picture,
+    // Window of the source played for chapter lessons, as (start, end) in nanoseconds.
+    // Positions, durations and seeks are all relative to its start.
+    clip: Cell<Option<(u64, u64)>>,
     _bus_guard: gst::bus::BusWatchGuard,
     _frame_tx: mpsc::Sender<FrameData>,
     // Held so Drop can cancel the frame-poll timer and prevent ghost loops.
@@ -125,7 +129,15 @@ impl VideoPlayer {
             gst::glib::ControlFlow::Continue
         })?;
 
-        Ok(Self { pipeline, playbin, picture, _bus_guard, _frame_tx: frame_tx, _frame_source })
+        Ok(Self {
+            pipeline,
+            playbin,
+            picture,
+            clip: Cell::new(None),
+            _bus_guard,
+            _frame_tx: frame_tx,
+            _frame_source,
+        })
     }
 
     pub fn widget(&self) -> &gtk::Picture {
@@ -167,28 +179,59 @@ impl VideoPlayer {
         }
     }
 
+    /// Restricts playback to part of the source; `None` plays the whole source.
+    pub fn set_clip(&self, clip: Option<(u64, u64)>) {
+        self.clip.set(clip.filter(|&(start, end)| end > start));
+    }
+
     pub fn seek(&self, pos_ns: u64) {
         if pos_ns == u64::MAX {
             return;
         }
+        let target = match self.clip.get() {
+            Some((start, end)) => start + pos_ns.min(end - start),
+            None => pos_ns,
+        };
         if let Err(e) =
-            self.pipeline.seek_simple(gst::SeekFlags::FLUSH, gst::ClockTime::from_nseconds(pos_ns))
+            self.pipeline.seek_simple(gst::SeekFlags::FLUSH, gst::ClockTime::from_nseconds(target))
         {
             log::warn!("GStreamer seek failed: {:?}", e);
         }
     }
 
     pub fn position(&self) -> Option<u64> {
-        self.pipeline
-            .query_position::<gst::ClockTime>()
-            .map(|t| t.nseconds())
-            .filter(|&ns| ns != u64::MAX)
+        let pos = self.source_position()?;
+        Some(match self.clip.get() {
+            Some((start, end)) => pos.clamp(start, end) - start,
+            None => pos,
+        })
     }
 
+    /// Length of the source, or of the clip once the source is prerolled.
     pub fn duration(&self) -> Option<u64> {
-        self.pipeline
+        let source = self
+            .pipeline
             .query_duration::<gst::ClockTime>()
             .map(|t| t.nseconds())
+            .filter(|&ns| ns != u64::MAX)?;
+        Some(match self.clip.get() {
+            Some((start, end)) => end.min(source).saturating_sub(start),
+            None => source,
+        })
+    }
+
+    /// True once playback has run past the end of the clip.
+    pub fn is_past_clip_end(&self) -> bool {
+        match (self.clip.get(), self.source_position()) {
+            (Some((_, end)), Some(pos)) => pos >= end,
+            _ => false,
+        }
+    }
+
+    fn source_position(&self) -> Option<u64> {
+        self.pipeline
+            .query_position::<gst::ClockTime>()
+            .map(|t| t.nseconds())
             .filter(|&ns| ns != u64::MAX)
     }
 
@@ -6,10 +6,15 @@ use std::time::Duration;
 use serde::Deserialize;
 use tokio::process::Command;
 
-use crate::domain::ports::{FetchError, PlaylistFetcher, RawVideoMetadata, StreamResolver};
+use crate::domain::ports::{
+    FetchError, PlaylistFetcher, RawChapter, RawVideoMetadata, StreamResolver,
+};
 use crate::domain::value_objects::{PlaylistUrl, VideoQuality};
 
 const RETRY_DELAYS_MS: [u64; 3] = [500, 1000, 2000];
+const FETCH_TIMEOUT_SECS: u64 = 60;
+/// A full playlist listing extracts every video in turn, so it gets far longer.
+const FULL_PLAYLIST_TIMEOUT_SECS: u64 = 900;
 
 #[derive(Debug, Deserialize)]
 struct YtDlpEntry {
@@ -18,6 +23,27 @@ struct YtDlpEntry {
     description: Option<String>,
     duration: Option<f64>,
     playlist_index: Option<u32>,
+    /// Only present in full dumps; flat playlist entries carry no chapters.
+    #[serde(default)]
+    chapters: Option<Vec<YtDlpChapter>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct YtDlpChapter {
+    title: Option<String>,
+    start_time: f64,
+    end_time: f64,
+}
+
+/// How much yt-dlp extracts for a URL.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Listing {
+    /// One video, with full metadata.
+    Single,
+    /// Playlist entries as listed, without per-video details such as chapters.
+    FlatPlaylist,
+    /// Every playlist entry with full metadata.
+    FullPlaylist,
 }
 
 /// YouTube adapter using yt-dlp CLI.
@@ -50,11 +76,29 @@ impl Default for RustyYtdlAdapter {
 #[async_trait::async_trait]
 impl PlaylistFetcher for RustyYtdlAdapter {
     async fn fetch_playlist(&self, url: &PlaylistUrl) -> Result<Vec<RawVideoMetadata>, FetchError> {
+        self.fetch(url, Listing::FlatPlaylist).await
+    }
+
+    async fn fetch_playlist_with_chapters(
+        &self,
+        url: &PlaylistUrl,
+    ) -> Result<Vec<RawVideoMetadata>, FetchError> {
+        self.fetch(url, Listing::FullPlaylist).await
+    }
+}
+
+impl RustyYtdlAdapter {
+    async fn fetch(
+        &self,
+        url: &PlaylistUrl,
+        playlist_listing: Listing,
+    ) -> Result<Vec<RawVideoMetadata>, FetchError> {
         let url_str = url.raw().to_string();
         let cookies = self.cookies.clone();
         let is_playlist = url_str.contains("list=");
+        let listing = if is_playlist { playlist_listing } else { Listing::Single };
 
-        let result = fetch_with_retry(url_str.clone(), cookies.clone(), is_playlist).await;
+        let result = fetch_with_retry(url_str.clone(), cookies.clone(), listing).await;
 
         match result {
             Ok(videos) if !videos.is_empty() => Ok(videos),
@@ -62,7 +106,7 @@ impl PlaylistFetcher for RustyYtdlAdapter {
                 // Playlist empty or fetch failed; fall back to single video
                 let single_url =
                     format!("https://www.youtube.com/watch?v={}", url.video_id().unwrap());
-                fetch_with_retry(single_url, cookies, false).await
+                fetch_with_retry(single_url, cookies, Listing::Single).await
             },
             Ok(_) => Err(FetchError::NotFound(url.playlist_id().to_string())),
             Err(e) => Err(e),
@@ -73,12 +117,12 @@ impl PlaylistFetcher for RustyYtdlAdapter {
 async fn fetch_with_retry(
     url: String,
     cookies: Option<String>,
-    is_playlist: bool,
+    listing: Listing,
 ) -> Result<Vec<RawVideoMetadata>, FetchError> {
     let mut last_err = None;
 
     for (i, delay_ms) in RETRY_DELAYS_MS.iter().enumerate() {
-        match run_yt_dlp(&url, cookies.as_deref(), is_playlist).await {
+        match run_yt_dlp(&url, cookies.as_deref(), listing).await {
             Ok(videos) => return Ok(videos),
             Err(FetchError::Network(msg)) if i < RETRY_DELAYS_MS.len() - 1 => {
                 last_err = Some(FetchError::Network(msg));
@@ -91,31 +135,41 @@ async fn fetch_with_retry(
     Err(last_err.unwrap_or_else(|| FetchError::Api("unknown error".to_string())))
 }
 
-async fn run_yt_dlp(
-    url: &str,
-    cookies: Option<&str>,
-    is_playlist: bool,
-) -> Result<Vec<RawVideoMetadata>, FetchError> {
-    let mut cmd = Command::new("yt-dlp");
-
-    cmd.arg("--dump-json");
-    cmd.arg("--no-warnings");
+/// Command-line arguments for a `--dump-json` run over `url`.
+fn yt_dlp_args<'a>(url: &'a str, cookies: Option<&'a str>, listing: Listing) -> Vec<&'a str> {
+    let mut args = vec!["--dump-json", "--no-warnings"];
 
-    if is_playlist {
-        cmd.arg("--flat-playlist");
-        cmd.arg("--no-download");
-        cmd.arg("--ignore-errors");
+    match listing {
+        Listing::Single => {},
+        Listing::FlatPlaylist => {
+            args.extend(["--flat-playlist", "--no-download", "--ignore-errors"])
+        },
+        // Full extraction of each entry; unavailable videos are skipped rather than fatal.
+        Listing::FullPlaylist => args.extend(["--no-download", "--ignore-errors"]),
     }
 
     if let Some(cookie_path) = cookies {
-        cmd.arg("--cookies");
-        cmd.arg(cookie_path);
+        args.extend(["--cookies", cookie_path]);
     }
 
-    cmd.arg(url);
+    args.push(url);
+    args
+}
+
+async fn run_yt_dlp(
+    url: &str,
+    cookies: Option<&str>,
+    listing: Listing,
+) -> Result<Vec<RawVideoMetadata>, FetchError> {
+    let mut cmd = Command::new("yt-dlp");
+    cmd.args(yt_dlp_args(url, cookies, listing));
     cmd.kill_on_drop(true);
 
-    let output_res = tokio::time::timeout(Duration::from_secs(60), cmd.output()).await;
+    let timeout_secs = match listing {
+        Listing::FullPlaylist => FULL_PLAYLIST_TIMEOUT_SECS,
+        Listing::Single | Listing::FlatPlaylist => FETCH_TIMEOUT_SECS,
+    };
+    let output_res = tokio::time::timeout(Duration::from_secs(timeout_secs), cmd.output()).await;
     let output = match output_res {
         Ok(res) => res.map_err(|e| {
             if e.kind() == std::io::ErrorKind::NotFound {
@@ -127,9 +181,9 @@ async fn run_yt_dlp(
             }
         })?,
         Err(_) => {
-            return Err(FetchError::Network(
-                "yt-dlp playlist fetch timed out after 60 seconds".to_string(),
-            ));
+            return Err(FetchError::Network(format!(
+                "yt-dlp playlist fetch timed out after {timeout_secs} seconds"
+            )));
         },
     };
 
@@ -190,12 +244,27 @@ fn parse_output(stdout: &str) -> Result<Vec<RawVideoMetadata>, FetchError> {
         let title = entry.title.unwrap_or_else(|| "untitled".to_string());
 
         let pos = entry.playlist_index.unwrap_or(position as u32);
+        let chapters = entry
+            .chapters
+            .unwrap_or_default()
+            .into_iter()
+            .enumerate()
+            .map(|(i, chapter)| RawChapter {
+                title: chapter
+                    .title
+                    .filter(|t| !t.trim().is_empty())
+                    .unwrap_or_else(|| format!("Chapter {}", i + 1)),
+                start_secs: chapter.start_time as u32,
+                end_secs: chapter.end_time as u32,
+            })
+            .collect();
         videos.push(RawVideoMetadata {
             youtube_id: entry.id,
             title,
             description: entry.description,
             duration_secs,
             position: pos,
+            chapters,
         });
     }
 
@@ -305,6 +374,45 @@ mod tests {
         assert_eq!(result[0].title, "untitled");
     }
 
+    #[test]
+    fn parse_output_reads_chapters() {
+        let input = r#"{"id":"a","title":"Full course","duration":3600.0,"chapters":[{"title":"Intro","start_time":0.0,"end_time":95.5},{"title":"","start_time":95.5,"end_time":3600.0}]}"#;
+        let result = parse_output(input).unwrap();
+        assert_eq!(
+            result[0].chapters,
+            vec![
+                RawChapter { title: "Intro".to_string(), start_secs: 0, end_secs: 95 },
+                RawChapter { title: "Chapter 2".to_string(), start_secs: 95, end_secs: 3600 },
+            ]
+        );
+    }
+
+    #[test]
+    fn playlist_with_chapters_skips_flat_listing() {
+        let url = "https://www.youtube.com/playlist?list=PL123";
+        let flat = yt_dlp_args(url, None, Listing::FlatPlaylist);
+        let full = yt_dlp_args(url, Some("cookies.txt"), Listing::FullPlaylist);
+
+        assert!(flat.contains(&"--flat-playlist"));
+        assert!(!full.contains(&"--flat-playlist"));
+        assert!(full.contains(&"--ignore-errors"));
+        assert_eq!(full[full.len() - 3..], ["--cookies", "cookies.txt", url]);
+        assert!(!yt_dlp_args(url, None, Listing::Single).contains(&"--ignore-errors"));
+    }
+
+    #[test]
+    fn parse_output_reads_chapters_of_full_playlist_entries() {
+        let input = r#"{"id":"a","title":"Part 1","duration":600.0,"playlist_index":1,"chapters":[{"title":"Setup","start_time":0.0,"end_time":300.0},{"title":"Build","start_time":300.0,"end_time":600.0}]}
+{"id":"b","title":"Part 2","duration":300.0,"playlist_index":2,"chapters":null}"#;
+        let result = parse_output(input).unwrap();
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].chapters.len(), 2);
+        assert_eq!(result[0].chapters[1].title, "Build");
+        assert_eq!(result[1].position, 2);
+        assert!(result[1].chapters.is_empty());
+    }
+
     #[test]
     fn classify_error_detects_not_found() {
         let err = classify_error("ERROR: Video not found", "");
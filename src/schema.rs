@@ -26,6 +26,7 @@ diesel::table! {
         description -> Nullable<Text>,
         created_at -> Timestamp,
         source_hash -> Nullable<Text>,
+        source_type -> Text,
     }
 }
 
@@ -40,6 +41,17 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    flashcards (id) {
+        id -> Text,
+        video_id -> Text,
+        kind -> Text,
+        front -> Text,
+        back -> Text,
+        position -> Integer,
+    }
+}
+
 diesel::table! {
     modules (id) {
         id -> Text,
@@ -58,6 +70,61 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    playback_positions (video_id) {
+        video_id -> Text,
+        position_ms -> BigInt,
+        updated_at -> Text,
+        watched_ranges -> Text,
+    }
+}
+
+diesel::table! {
+    review_cards (id) {
+        id -> Text,
+        exam_id -> Text,
+        video_id -> Text,
+        question_index -> Integer,
+        question_json -> Text,
+        ease -> Double,
+        interval_days -> Integer,
+        repetitions -> Integer,
+        lapses -> Integer,
+        due_date -> Text,
+        last_reviewed -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    study_schedules (course_id) {
+        course_id -> Text,
+        start_date -> Text,
+        study_days_per_week -> Integer,
+        cognitive_limit_minutes -> Integer,
+        reschedule_policy -> Text,
+        updated_at -> Text,
+        peak_session_count -> Integer,
+    }
+}
+
+diesel::table! {
+    study_session_videos (session_id, video_id) {
+        session_id -> Text,
+        video_id -> Text,
+        position -> Integer,
+    }
+}
+
+diesel::table! {
+    study_sessions (id) {
+        id -> Text,
+        course_id -> Text,
+        day -> Integer,
+        scheduled_date -> Text,
+        total_duration_secs -> Integer,
+    }
+}
+
 diesel::table! {
     tags (id) {
         id -> Text,
@@ -66,6 +133,17 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    transcript_cues (video_id, position) {
+        video_id -> Text,
+        position -> Integer,
+        start_ms -> BigInt,
+        end_ms -> BigInt,
+        text -> Text,
+        source_hash -> Text,
+    }
+}
+
 diesel::table! {
     user_preferences (id) {
         id -> Text,
@@ -76,6 +154,9 @@ diesel::table! {
         right_panel_width -> Integer,
         preferred_quality -> Text,
         boundary_batch_size -> Integer,
+        auto_complete_percent -> Integer,
+        watch_local_folders -> Integer,
+        module_target_minutes -> Integer,
     }
 }
 
@@ -95,6 +176,20 @@ diesel::table! {
         source_ref -> Text,
         key_points -> Nullable<Text>,
         key_terms -> Nullable<Text>,
+        is_available -> Bool,
+        content_fingerprint -> Nullable<Text>,
+        clip_start_secs -> Nullable<Integer>,
+        clip_end_secs -> Nullable<Integer>,
+    }
+}
+
+diesel::table! {
+    watch_sessions (id) {
+        id -> Text,
+        video_id -> Text,
+        started_at -> Text,
+        ended_at -> Text,
+        played_secs -> Integer,
     }
 }
 
@@ -104,16 +199,34 @@ diesel::joinable!(course_tags -> tags (tag_id));
 diesel::joinable!(exams -> videos (video_id));
 diesel::joinable!(modules -> courses (course_id));
 diesel::joinable!(notes -> videos (video_id));
+diesel::joinable!(playback_positions -> videos (video_id));
+diesel::joinable!(flashcards -> videos (video_id));
+diesel::joinable!(review_cards -> exams (exam_id));
+diesel::joinable!(review_cards -> videos (video_id));
+diesel::joinable!(study_schedules -> courses (course_id));
+diesel::joinable!(study_session_videos -> study_sessions (session_id));
+diesel::joinable!(study_session_videos -> videos (video_id));
+diesel::joinable!(study_sessions -> study_schedules (course_id));
+diesel::joinable!(transcript_cues -> videos (video_id));
 diesel::joinable!(videos -> modules (module_id));
+diesel::joinable!(watch_sessions -> videos (video_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
     chat_messages,
     course_tags,
     courses,
     exams,
+    flashcards,
     modules,
     notes,
+    playback_positions,
+    review_cards,
+    study_schedules,
+    study_session_videos,
+    study_sessions,
     tags,
+    transcript_cues,
     user_preferences,
     videos,
+    watch_sessions,
 );
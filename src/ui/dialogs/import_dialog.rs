@@ -1,11 +1,10 @@
 use std::rc::Rc;
-use std::sync::mpsc;
 
 use adw::prelude::*;
 
+use super::import_preview::{self, FORM_PAGE, PREVIEW_PAGE};
 use crate::application::ServiceFactory;
 use crate::application::use_cases::IngestPlaylistInput;
-use crate::infrastructure::tokio_bridge;
 use crate::ui::state::SharedState;
 
 pub fn show_import_playlist_dialog(
@@ -23,141 +22,221 @@ pub fn show_import_playlist_dialog(
     vbox.set_margin_end(16);
     vbox.set_margin_top(16);
     vbox.set_margin_bottom(16);
-    vbox.set_valign(gtk::Align::Start);
+
+    let form = gtk::Box::new(gtk::Orientation::Vertical, 12);
+    form.set_valign(gtk::Align::Start);
 
     let url_label = gtk::Label::new(Some("YouTube Playlist URL:"));
     url_label.set_halign(gtk::Align::Start);
-    vbox.append(&url_label);
+    form.append(&url_label);
 
     let url_entry = gtk::Entry::new();
     url_entry.set_placeholder_text(Some("https://youtube.com/playlist?list=..."));
-    vbox.append(&url_entry);
+    form.append(&url_entry);
 
     let name_label = gtk::Label::new(Some("Course Name (optional):"));
     name_label.set_halign(gtk::Align::Start);
-    vbox.append(&name_label);
+    form.append(&name_label);
 
     let name_entry = gtk::Entry::new();
     name_entry.set_placeholder_text(Some("Leave empty to use playlist title"));
-    vbox.append(&name_entry);
+    form.append(&name_entry);
+
+    let chapters_check = gtk::CheckButton::with_label("Split chapters into lessons");
+    chapters_check
+        .set_tooltip_text(Some("Turn each chapter of a single long video into its own lesson"));
+    form.append(&chapters_check);
+
+    let (stack, editor) = import_preview::build_stack(&form);
+    vbox.append(&stack);
 
     let status_label = gtk::Label::new(None);
     status_label.set_halign(gtk::Align::Start);
     status_label.set_wrap(true);
     vbox.append(&status_label);
 
-    let spinner = gtk::Spinner::new();
-    spinner.set_halign(gtk::Align::Start);
-    vbox.append(&spinner);
+    let progress = import_preview::ImportProgress::new();
+    vbox.append(progress.widget());
 
     let button_box = gtk::Box::new(gtk::Orientation::Horizontal, 8);
     button_box.set_halign(gtk::Align::End);
 
     let cancel_btn = gtk::Button::with_label("Cancel");
-    let import_btn = gtk::Button::with_label("Import");
+    let back_btn = gtk::Button::with_label("Back");
+    back_btn.set_visible(false);
+    let import_btn = gtk::Button::with_label("Preview");
     import_btn.add_css_class("suggested-action");
 
     button_box.append(&cancel_btn);
+    button_box.append(&back_btn);
     button_box.append(&import_btn);
     vbox.append(&button_box);
 
     dialog.set_child(Some(&vbox));
 
-    let dialog_cancel = dialog.clone();
-    cancel_btn.connect_clicked(move |_| {
-        dialog_cancel.close();
-    });
+    {
+        // While a step runs, Cancel stops it and keeps the dialog open.
+        let dialog = dialog.clone();
+        let progress = progress.clone();
+        cancel_btn.connect_clicked(move |_| {
+            if !progress.cancel() {
+                dialog.close();
+            }
+        });
+    }
+    {
+        let progress = progress.clone();
+        dialog.connect_closed(move |_| {
+            progress.cancel();
+        });
+    }
+
+    {
+        let dialog = dialog.clone();
+        let stack = stack.clone();
+        let import_btn = import_btn.clone();
+        let status = status_label.clone();
+        back_btn.connect_clicked(move |back_btn| {
+            status.set_text("");
+            import_preview::show_page(&dialog, &stack, &import_btn, back_btn, FORM_PAGE, "Preview");
+        });
+    }
+
+    let set_busy: Rc<dyn Fn(bool)> = {
+        let url_entry = url_entry.clone();
+        let name_entry = name_entry.clone();
+        let chapters_check = chapters_check.clone();
+        let editor_widget = editor.widget().clone();
+        let import_btn = import_btn.clone();
+        let back_btn = back_btn.clone();
+        Rc::new(move |busy: bool| {
+            for widget in [
+                url_entry.upcast_ref::<gtk::Widget>(),
+                name_entry.upcast_ref(),
+                chapters_check.upcast_ref(),
+                editor_widget.upcast_ref(),
+                import_btn.upcast_ref(),
+                back_btn.upcast_ref(),
+            ] {
+                widget.set_sensitive(!busy);
+            }
+        })
+    };
+
+    let dialog_import = dialog.clone();
+    import_btn.connect_clicked(move |import_btn| {
+        let dialog = &dialog_import;
+        let Some(ctx) = state.borrow().backend.clone() else {
+            status_label.set_text("No backend available.");
+            return;
+        };
 
-    let import_btn_cl = import_btn.clone();
-    let cancel_btn_for_import = cancel_btn;
-    let url_entry_cl = url_entry;
-    let name_entry_cl = name_entry;
-    let status_cl = status_label;
-    let spinner_cl = spinner;
-    let dialog_cl = dialog.clone();
-    let state_cl = state;
-    let on_success_cl = on_success;
-
-    import_btn.connect_clicked(move |_| {
-        let url = url_entry_cl.text().to_string();
+        // Step 2: commit the edited draft.
+        if stack.visible_child_name().as_deref() == Some(PREVIEW_PAGE) {
+            let Some(draft) = editor.draft() else {
+                return;
+            };
+            set_busy(true);
+            status_label.set_text("Creating course...");
+            let (sink, cancel) = progress.start();
+
+            let set_busy = set_busy.clone();
+            let progress = progress.clone();
+            let status = status_label.clone();
+            let dialog = dialog.clone();
+            let on_success = on_success.clone();
+            import_preview::run_in_background(
+                async move {
+                    ServiceFactory::ingest_playlist(&ctx)
+                        .with_progress(sink)
+                        .with_cancellation(cancel)
+                        .commit(&draft)
+                        .map_err(|e| e.to_string())
+                },
+                move |res| {
+                    let cancelled = progress.finish();
+                    set_busy(false);
+                    match res {
+                        Ok(output) => {
+                            crate::ui::toast::Toast::show(&format!(
+                                "Imported {} videos across {} modules!",
+                                output.videos_count, output.modules_count
+                            ));
+                            dialog.close();
+                            if let Some(ref cb) = on_success {
+                                cb();
+                            }
+                        },
+                        Err(_) if cancelled => status.set_text("Import cancelled."),
+                        Err(e) => status.set_text(&format!("Import failed: {e}")),
+                    }
+                },
+            );
+            return;
+        }
+
+        // Step 1: plan the course and show the proposed modules.
+        let url = url_entry.text().to_string();
         if url.trim().is_empty() {
-            status_cl.set_text("Please enter a playlist URL.");
+            status_label.set_text("Please enter a playlist URL.");
             return;
         }
 
-        let course_name_input = name_entry_cl.text().to_string();
+        let course_name_input = name_entry.text().to_string();
         let course_name = if course_name_input.trim().is_empty() {
             None
         } else {
             Some(course_name_input.trim().to_string())
         };
 
-        url_entry_cl.set_sensitive(false);
-        name_entry_cl.set_sensitive(false);
-        import_btn_cl.set_sensitive(false);
-        cancel_btn_for_import.set_sensitive(false);
-        spinner_cl.start();
-        status_cl.set_text("Importing playlist...");
-
-        let ctx = state_cl.borrow().backend.clone();
-        if let Some(ctx) = ctx {
-            let (tx, rx): (mpsc::Sender<(String, bool)>, _) = mpsc::channel();
-
-            let status_idle = status_cl.clone();
-            let sp_idle = spinner_cl.clone();
-            let dlg_idle = dialog_cl.clone();
-            let url_idle = url_entry_cl.clone();
-            let name_idle = name_entry_cl.clone();
-            let import_idle = import_btn_cl.clone();
-            let cancel_idle = cancel_btn_for_import.clone();
-
-            let on_success_cb = on_success_cl.clone();
-
-            glib::idle_add_local(move || {
-                if let Ok((msg, success)) = rx.try_recv() {
-                    sp_idle.stop();
-                    status_idle.set_text(&msg);
-                    if success {
-                        crate::ui::toast::Toast::show(&msg);
-                        dlg_idle.close();
-                        if let Some(ref cb) = on_success_cb {
-                            cb();
-                        }
-                    } else {
-                        url_idle.set_sensitive(true);
-                        name_idle.set_sensitive(true);
-                        import_idle.set_sensitive(true);
-                        cancel_idle.set_sensitive(true);
-                    }
-                    glib::ControlFlow::Break
-                } else {
-                    glib::ControlFlow::Continue
+        let split_chapters = chapters_check.is_active();
+
+        set_busy(true);
+        status_label.set_text("Fetching playlist...");
+        let (sink, cancel) = progress.start();
+
+        let set_busy = set_busy.clone();
+        let progress = progress.clone();
+        let status = status_label.clone();
+        let dialog = dialog.clone();
+        let stack = stack.clone();
+        let editor = editor.clone();
+        let import_btn = import_btn.clone();
+        let back_btn = back_btn.clone();
+        import_preview::run_in_background(
+            async move {
+                let input = IngestPlaylistInput { playlist_url: url, course_name, split_chapters };
+                ServiceFactory::ingest_playlist(&ctx)
+                    .with_progress(sink)
+                    .with_cancellation(cancel)
+                    .plan(input)
+                    .await
+                    .map_err(|e| e.to_string())
+            },
+            move |res| {
+                let cancelled = progress.finish();
+                set_busy(false);
+                match res {
+                    Ok(draft) => {
+                        editor.set_draft(draft);
+                        status.set_text(
+                            "Review the proposed modules. Rename, reorder, merge or split them \
+                             before creating the course.",
+                        );
+                        import_preview::show_page(
+                            &dialog,
+                            &stack,
+                            &import_btn,
+                            &back_btn,
+                            PREVIEW_PAGE,
+                            "Preview",
+                        );
+                    },
+                    Err(_) if cancelled => status.set_text("Import cancelled."),
+                    Err(e) => status.set_text(&format!("Import failed: {e}")),
                 }
-            });
-
-            tokio_bridge::spawn(async move {
-                let input = IngestPlaylistInput { playlist_url: url, course_name };
-                let result = ServiceFactory::ingest_playlist(&ctx).execute(input).await;
-                let (msg, success) = match &result {
-                    Ok(output) => (
-                        format!(
-                            "Imported {} videos across {} modules!",
-                            output.videos_count, output.modules_count
-                        ),
-                        true,
-                    ),
-                    Err(e) => (format!("Import failed: {e}"), false),
-                };
-                let _ = tx.send((msg, success));
-            });
-        } else {
-            spinner_cl.stop();
-            status_cl.set_text("No backend available.");
-            url_entry_cl.set_sensitive(true);
-            name_entry_cl.set_sensitive(true);
-            import_btn_cl.set_sensitive(true);
-            cancel_btn_for_import.set_sensitive(true);
-        }
+            },
+        );
     });
 
     dialog.present(parent_window);
@@ -0,0 +1,155 @@
+//! Preview step shared by the import dialogs: the planned draft is shown in an
+//! [`ImportDraftEditor`] and only committed once the user confirms it.
+
+use std::cell::RefCell;
+use std::future::Future;
+use std::rc::Rc;
+use std::sync::{Arc, mpsc};
+use std::time::Duration;
+
+use adw::prelude::*;
+
+use crate::domain::ports::{CancellationToken, IngestProgress, ProgressSink};
+use crate::infrastructure::tokio_bridge;
+use crate::ui::widgets::ImportDraftEditor;
+
+pub(super) const FORM_PAGE: &str = "form";
+pub(super) const PREVIEW_PAGE: &str = "preview";
+
+/// Puts the dialog's form and a draft editor into a stack, starting on the form.
+pub(super) fn build_stack(form: &gtk::Box) -> (gtk::Stack, ImportDraftEditor) {
+    let stack = gtk::Stack::new();
+    stack.set_transition_type(gtk::StackTransitionType::SlideLeftRight);
+    stack.set_vexpand(true);
+    stack.add_named(form, Some(FORM_PAGE));
+
+    let editor = ImportDraftEditor::new();
+    let scroll = gtk::ScrolledWindow::new();
+    scroll.set_hscrollbar_policy(gtk::PolicyType::Never);
+    scroll.set_vexpand(true);
+    scroll.set_child(Some(editor.widget()));
+    stack.add_named(&scroll, Some(PREVIEW_PAGE));
+    stack.set_visible_child_name(FORM_PAGE);
+
+    (stack, editor)
+}
+
+/// Switches between the form and the draft editor, resizing the dialog to fit.
+pub(super) fn show_page(
+    dialog: &adw::Dialog,
+    stack: &gtk::Stack,
+    primary_btn: &gtk::Button,
+    back_btn: &gtk::Button,
+    page: &str,
+    form_label: &str,
+) {
+    let preview = page == PREVIEW_PAGE;
+    stack.set_visible_child_name(page);
+    back_btn.set_visible(preview);
+    primary_btn.set_label(if preview { "Create Course" } else { form_label });
+    if preview {
+        dialog.set_content_width(560);
+        dialog.set_content_height(620);
+    }
+}
+
+/// Runs `task` on the Tokio runtime and hands its result to `on_done` on the GTK main loop.
+pub(super) fn run_in_background<T: Send + 'static>(
+    task: impl Future<Output = T> + Send + 'static,
+    on_done: impl FnOnce(T) + 'static,
+) {
+    let (tx, rx) = mpsc::channel::<T>();
+    tokio_bridge::spawn(async move {
+        let _ = tx.send(task.await);
+    });
+
+    let mut on_done = Some(on_done);
+    glib::idle_add_local(move || match rx.try_recv() {
+        Ok(res) => {
+            if let Some(on_done) = on_done.take() {
+                on_done(res);
+            }
+            glib::ControlFlow::Break
+        },
+        Err(mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
+        Err(mpsc::TryRecvError::Disconnected) => glib::ControlFlow::Break,
+    });
+}
+
+/// Progress bar and cancellation for the step an import dialog is running.
+#[derive(Clone)]
+pub(super) struct ImportProgress {
+    bar: gtk::ProgressBar,
+    running: Rc<RefCell<Option<CancellationToken>>>,
+}
+
+impl ImportProgress {
+    pub(super) fn new() -> Self {
+        let bar = gtk::ProgressBar::new();
+        bar.set_show_text(true);
+        bar.set_visible(false);
+        Self { bar, running: Rc::new(RefCell::new(None)) }
+    }
+
+    pub(super) fn widget(&self) -> &gtk::ProgressBar {
+        &self.bar
+    }
+
+    /// Shows the bar for a new step and returns the sink and token to hand to the use case.
+    ///
+    /// The bar follows the reported progress until the sink is dropped, pulsing while the
+    /// current step is not counted.
+    pub(super) fn start(&self) -> (Arc<dyn ProgressSink>, CancellationToken) {
+        let cancel = CancellationToken::new();
+        *self.running.borrow_mut() = Some(cancel.clone());
+        self.bar.set_fraction(0.0);
+        self.bar.set_text(Some(""));
+        self.bar.set_visible(true);
+
+        let (tx, rx) = mpsc::channel::<IngestProgress>();
+        let bar = self.bar.clone();
+        let mut fraction = None;
+        glib::timeout_add_local(Duration::from_millis(100), move || {
+            loop {
+                match rx.try_recv() {
+                    Ok(progress) => {
+                        bar.set_text(Some(&progress.to_string()));
+                        fraction = progress.fraction();
+                        if let Some(fraction) = fraction {
+                            bar.set_fraction(fraction);
+                        }
+                    },
+                    Err(mpsc::TryRecvError::Empty) => break,
+                    Err(mpsc::TryRecvError::Disconnected) => return glib::ControlFlow::Break,
+                }
+            }
+            if fraction.is_none() {
+                bar.pulse();
+            }
+            glib::ControlFlow::Continue
+        });
+
+        let sink = move |progress: IngestProgress| {
+            let _ = tx.send(progress);
+        };
+        (Arc::new(sink), cancel)
+    }
+
+    /// Asks the running step to stop. Returns `false` when no step is running.
+    pub(super) fn cancel(&self) -> bool {
+        match self.running.borrow().as_ref() {
+            Some(cancel) => {
+                cancel.cancel();
+                self.bar.set_text(Some("Cancelling..."));
+                true
+            },
+            None => false,
+        }
+    }
+
+    /// Hides the bar once the step has ended. Returns whether it was cancelled.
+    pub(super) fn finish(&self) -> bool {
+        self.bar.set_visible(false);
+        self.running.take().is_some_and(|cancel| cancel.is_cancelled())
+    }
+}
@@ -1,2 +1,5 @@
 pub mod import_dialog;
 pub mod import_local_dialog;
+mod import_preview;
+pub mod relink_dialog;
+pub mod schedule_dialog;
@@ -0,0 +1,72 @@
+use std::rc::Rc;
+use std::sync::mpsc;
+
+use adw::prelude::*;
+
+use crate::application::ServiceFactory;
+use crate::application::use_cases::{RelinkLocalInput, RelinkLocalReport};
+use crate::domain::value_objects::CourseId;
+use crate::infrastructure::tokio_bridge;
+use crate::ui::state::SharedState;
+use crate::ui::toast::Toast;
+
+/// Asks for the new location of a moved course folder and relinks the course's videos.
+pub fn show_relink_dialog(
+    state: SharedState,
+    course_id: CourseId,
+    parent_window: Option<&gtk::Window>,
+    on_success: Option<Rc<dyn Fn()>>,
+) {
+    let file_dialog = gtk::FileDialog::new();
+    file_dialog.set_title("Locate Course Folder");
+
+    file_dialog.select_folder(parent_window, None::<&gio::Cancellable>, move |result| {
+        let Some(path) = result.ok().and_then(|file| file.path()) else {
+            return;
+        };
+        let Some(ctx) = state.borrow().backend.clone() else {
+            return;
+        };
+        let uc = ServiceFactory::relink_local(&ctx);
+        let new_root = path.to_string_lossy().to_string();
+        Toast::show("Looking for missing files...");
+
+        let (tx, rx) = mpsc::channel::<Result<RelinkLocalReport, String>>();
+        tokio_bridge::spawn(async move {
+            let res = uc
+                .execute(RelinkLocalInput { course_id, new_root })
+                .await
+                .map_err(|e| e.to_string());
+            let _ = tx.send(res);
+        });
+
+        let on_success = on_success.clone();
+        glib::idle_add_local(move || {
+            let res = match rx.try_recv() {
+                Ok(res) => res,
+                Err(mpsc::TryRecvError::Empty) => return glib::ControlFlow::Continue,
+                Err(mpsc::TryRecvError::Disconnected) => {
+                    Err("relink task ended unexpectedly".to_string())
+                },
+            };
+            match res {
+                Ok(report) if report.relinked.is_empty() => {
+                    Toast::show_error("None of the missing files were found in that folder.")
+                },
+                Ok(report) => {
+                    let mut message = format!("Relinked {} videos", report.relinked.len());
+                    if !report.still_missing.is_empty() {
+                        message
+                            .push_str(&format!(", {} still missing", report.still_missing.len()));
+                    }
+                    Toast::show(&format!("{message}."));
+                    if let Some(cb) = &on_success {
+                        cb();
+                    }
+                },
+                Err(e) => Toast::show_error(&format!("Relink failed: {e}")),
+            }
+            glib::ControlFlow::Break
+        });
+    });
+}
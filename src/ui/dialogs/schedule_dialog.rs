@@ -0,0 +1,160 @@
+use std::rc::Rc;
+
+use adw::prelude::*;
+use chrono::NaiveDate;
+
+use crate::application::ServiceFactory;
+use crate::application::use_cases::PlanCourseScheduleInput;
+use crate::domain::value_objects::{CourseId, ReschedulePolicy};
+use crate::ui::state::SharedState;
+use crate::ui::toast::Toast;
+
+pub fn show_plan_schedule_dialog(
+    state: SharedState,
+    course_id: CourseId,
+    parent_window: Option<&gtk::Window>,
+    on_success: Option<Rc<dyn Fn()>>,
+) {
+    let (default_days, default_minutes, default_policy) = {
+        let s = state.borrow();
+        let existing = s.backend.as_ref().and_then(|ctx| {
+            ServiceFactory::plan_course_schedule(ctx).load(&course_id).ok().flatten()
+        });
+        match existing {
+            Some(schedule) => (
+                schedule.study_days_per_week(),
+                schedule.cognitive_limit_minutes(),
+                schedule.reschedule_policy(),
+            ),
+            None => (5, s.cognitive_limit_minutes, ReschedulePolicy::default()),
+        }
+    };
+
+    let dialog = adw::Dialog::new();
+    dialog.set_title("Plan Study Schedule");
+    dialog.set_content_width(380);
+    dialog.set_content_height(520);
+
+    let vbox = gtk::Box::new(gtk::Orientation::Vertical, 12);
+    vbox.set_margin_start(16);
+    vbox.set_margin_end(16);
+    vbox.set_margin_top(16);
+    vbox.set_margin_bottom(16);
+    vbox.set_valign(gtk::Align::Start);
+
+    let start_label = gtk::Label::new(Some("Start date:"));
+    start_label.set_halign(gtk::Align::Start);
+    vbox.append(&start_label);
+
+    let calendar = gtk::Calendar::new();
+    vbox.append(&calendar);
+
+    let days_row = gtk::Box::new(gtk::Orientation::Horizontal, 8);
+    let days_label = gtk::Label::new(Some("Study days per week:"));
+    days_label.set_halign(gtk::Align::Start);
+    days_label.set_hexpand(true);
+    let days_spin = gtk::SpinButton::with_range(1.0, 7.0, 1.0);
+    days_spin.set_value(default_days as f64);
+    days_row.append(&days_label);
+    days_row.append(&days_spin);
+    vbox.append(&days_row);
+
+    let minutes_row = gtk::Box::new(gtk::Orientation::Horizontal, 8);
+    let minutes_label = gtk::Label::new(Some("Minutes per session:"));
+    minutes_label.set_halign(gtk::Align::Start);
+    minutes_label.set_hexpand(true);
+    let minutes_spin = gtk::SpinButton::with_range(10.0, 240.0, 5.0);
+    minutes_spin.set_value(default_minutes as f64);
+    minutes_row.append(&minutes_label);
+    minutes_row.append(&minutes_spin);
+    vbox.append(&minutes_row);
+
+    let policy_row = gtk::Box::new(gtk::Orientation::Horizontal, 8);
+    let policy_label = gtk::Label::new(Some("When falling behind:"));
+    policy_label.set_halign(gtk::Align::Start);
+    policy_label.set_hexpand(true);
+    let policies = [ReschedulePolicy::KeepDailyLoad, ReschedulePolicy::KeepDeadline];
+    let policy_dropdown = gtk::DropDown::from_strings(&["Keep daily load", "Keep deadline"]);
+    policy_dropdown
+        .set_selected(policies.iter().position(|p| *p == default_policy).unwrap_or(0) as u32);
+    policy_row.append(&policy_label);
+    policy_row.append(&policy_dropdown);
+    vbox.append(&policy_row);
+
+    let hint = gtk::Label::new(Some(
+        "Completed videos are skipped. Sessions break at module boundaries where possible.",
+    ));
+    hint.set_wrap(true);
+    hint.set_halign(gtk::Align::Start);
+    hint.add_css_class("caption");
+    vbox.append(&hint);
+
+    let button_box = gtk::Box::new(gtk::Orientation::Horizontal, 8);
+    button_box.set_halign(gtk::Align::End);
+
+    let cancel_btn = gtk::Button::with_label("Cancel");
+    let plan_btn = gtk::Button::with_label("Plan");
+    plan_btn.add_css_class("suggested-action");
+
+    button_box.append(&cancel_btn);
+    button_box.append(&plan_btn);
+    vbox.append(&button_box);
+
+    dialog.set_child(Some(&vbox));
+
+    let dialog_cancel = dialog.clone();
+    cancel_btn.connect_clicked(move |_| {
+        dialog_cancel.close();
+    });
+
+    let dialog_cl = dialog.clone();
+    plan_btn.connect_clicked(move |_| {
+        let picked = calendar.date();
+        let Some(start_date) = NaiveDate::from_ymd_opt(
+            picked.year(),
+            picked.month() as u32,
+            picked.day_of_month() as u32,
+        ) else {
+            Toast::show_error("Invalid start date.");
+            return;
+        };
+
+        let s = state.borrow();
+        let Some(ref ctx) = s.backend else {
+            return;
+        };
+        let input = PlanCourseScheduleInput {
+            course_id,
+            start_date,
+            study_days_per_week: days_spin.value() as u32,
+            cognitive_limit_minutes: minutes_spin.value() as u32,
+            reschedule_policy: policies
+                .get(policy_dropdown.selected() as usize)
+                .copied()
+                .unwrap_or_default(),
+        };
+        match ServiceFactory::plan_course_schedule(ctx).execute(input) {
+            Ok(output) => {
+                let end = output
+                    .schedule
+                    .end_date()
+                    .map(|d| d.format("%b %-d, %Y").to_string())
+                    .unwrap_or_default();
+                Toast::show(&format!(
+                    "Planned {} videos over {} sessions, finishing {}.",
+                    output.videos_planned,
+                    output.schedule.sessions().len(),
+                    end
+                ));
+                drop(s);
+                dialog_cl.close();
+                if let Some(ref cb) = on_success {
+                    cb();
+                }
+            },
+            Err(e) => Toast::show_error(&format!("Failed to plan schedule: {e}")),
+        }
+    });
+
+    dialog.present(parent_window);
+}
@@ -14,9 +14,10 @@ use std::str::FromStr;
 
 use crate::domain::ports::Activity;
 use crate::ui::dialogs;
+use crate::ui::library_watcher::LibraryWatcher;
 use crate::ui::navigation::{
     PAGE_COURSE_LIST, PAGE_COURSE_VIEW, PAGE_DASHBOARD, PAGE_QUIZ_LIST, PAGE_QUIZ_VIEW,
-    PAGE_SETTINGS, PAGE_VIDEO_PLAYER,
+    PAGE_REVIEW, PAGE_SETTINGS, PAGE_VIDEO_PLAYER,
 };
 use crate::ui::pages;
 use crate::ui::right_panel;
@@ -165,10 +166,11 @@ fn build_sidebar(
     nav_box.set_margin_start(8);
     nav_box.set_margin_end(8);
 
-    let nav_items: [(&str, &str); 4] = [
+    let nav_items: [(&str, &str); 5] = [
         (PAGE_DASHBOARD, "Dashboard"),
         (PAGE_COURSE_LIST, "Courses"),
         (PAGE_QUIZ_LIST, "Quizzes"),
+        (PAGE_REVIEW, "Review"),
         (PAGE_SETTINGS, "Settings"),
     ];
 
@@ -291,6 +293,7 @@ impl MainLayout {
         let video_player = Rc::new(pages::video_player::VideoPlayerPage::new(state.clone()));
         let quiz_view =
             Rc::new(pages::quiz_view::QuizViewPage::new(state.clone(), nav_view_rc.clone()));
+        let review = Rc::new(pages::review::ReviewPage::new(state.clone()));
         let settings =
             Rc::new(pages::settings::SettingsPage::new(state.clone(), nav_view_rc.clone()));
 
@@ -319,6 +322,14 @@ impl MainLayout {
             parent_window.clone(),
             import_refresh_cb.clone(),
         ));
+        let rv_nav = Rc::new(wrap_page(
+            review.widget(),
+            "Review",
+            PAGE_REVIEW,
+            state.clone(),
+            parent_window.clone(),
+            import_refresh_cb.clone(),
+        ));
         let st_nav = Rc::new(wrap_page(
             settings.widget(),
             "Settings",
@@ -369,6 +380,7 @@ impl MainLayout {
         pages_map.insert(PAGE_COURSE_VIEW, cv_nav.as_ref().clone());
         pages_map.insert(PAGE_QUIZ_LIST, ql_nav.as_ref().clone());
         pages_map.insert(PAGE_QUIZ_VIEW, qv_nav.as_ref().clone());
+        pages_map.insert(PAGE_REVIEW, rv_nav.as_ref().clone());
         pages_map.insert(PAGE_SETTINGS, st_nav.as_ref().clone());
         pages_map.insert(PAGE_VIDEO_PLAYER, vp_nav.as_ref().clone());
         let nav_pages = Rc::new(pages_map);
@@ -383,7 +395,7 @@ impl MainLayout {
         // Step 6: Initialize nav view with dashboard as the root
         nav_view_rc.push(dash_nav.as_ref());
 
-        // Step 7: Set up course_view refresh callback
+        // Step 7: Set up course_view and video_player refresh callbacks
         {
             let weak = Rc::downgrade(&course_view);
             course_view.set_refresh_cb(Rc::new(move || {
@@ -391,6 +403,12 @@ impl MainLayout {
                     cv.refresh();
                 }
             }));
+            let weak = Rc::downgrade(&video_player);
+            video_player.set_refresh_cb(Rc::new(move || {
+                if let Some(vp) = weak.upgrade() {
+                    vp.refresh();
+                }
+            }));
         }
 
         let right_panel = Rc::new(right_panel::RightPanel::new(state.clone()));
@@ -403,6 +421,19 @@ impl MainLayout {
             s.right_panel_visible
         });
 
+        {
+            let weak = Rc::downgrade(&right_panel);
+            let split = outer_split.clone();
+            let explain_state = state.clone();
+            video_player.set_explain_moment_cb(Rc::new(move |position_secs| {
+                explain_state.borrow_mut().right_panel_visible = true;
+                split.set_show_sidebar(true);
+                if let Some(rp) = weak.upgrade() {
+                    rp.explain_moment(position_secs);
+                }
+            }));
+        }
+
         let tag_to_button: Rc<RefCell<HashMap<&'static str, gtk::ToggleButton>>> =
             Rc::new(RefCell::new(HashMap::new()));
 
@@ -478,12 +509,54 @@ impl MainLayout {
             presence.execute(UpdatePresenceInput { activity: Activity::Dashboard });
         }
 
+        // Step 9: Watch local course folders; changes refresh whichever course page is open.
+        let library_watcher = {
+            let nav = nav_view_rc.clone();
+            let cl = Rc::downgrade(&course_list);
+            let cv = Rc::downgrade(&course_view);
+            LibraryWatcher::new(
+                state.clone(),
+                Rc::new(move || {
+                    let tag = nav.visible_page().and_then(|p| p.tag()).unwrap_or_default();
+                    if tag == PAGE_COURSE_LIST
+                        && let Some(cl) = cl.upgrade()
+                    {
+                        cl.refresh();
+                    } else if tag == PAGE_COURSE_VIEW
+                        && let Some(cv) = cv.upgrade()
+                    {
+                        cv.refresh();
+                    }
+                }),
+            )
+        };
+        library_watcher.sync();
+
+        // Step 10: Point out local courses whose files went missing, e.g. a moved folder.
+        if let Some(ref ctx) = state.borrow().backend {
+            let relink = ServiceFactory::relink_local(ctx);
+            let broken = ctx
+                .course_repo
+                .find_all()
+                .unwrap_or_default()
+                .iter()
+                .filter(|c| c.source().local_root().is_some())
+                .filter(|c| relink.count_missing(c.id()).is_ok_and(|n| n > 0))
+                .count();
+            if broken > 0 {
+                Toast::show(&format!(
+                    "{broken} local course(s) have missing files. Open a course to relink it."
+                ));
+            }
+        }
+
         let db = dashboard;
         let cl = course_list;
         let cv = course_view;
         let vp = video_player;
         let ql = quiz_list;
         let qv = quiz_view;
+        let rv = review;
         let st = settings;
         let rp = right_panel;
         let old_page: Rc<RefCell<String>> = Rc::new(RefCell::new(PAGE_DASHBOARD.to_string()));
@@ -506,6 +579,11 @@ impl MainLayout {
                 cl.refresh();
             }
 
+            // Settings may toggle folder watching, and imports land on the course list.
+            if old == PAGE_SETTINGS || new_tag == PAGE_COURSE_LIST {
+                library_watcher.sync();
+            }
+
             *old_page.borrow_mut() = new_tag.clone();
 
             // Update sidebar toggle buttons to match current page
@@ -548,7 +626,7 @@ impl MainLayout {
                         drop(state_ref);
                         Activity::Watching { course_title, video_title }
                     },
-                    PAGE_QUIZ_LIST => Activity::Idle,
+                    PAGE_QUIZ_LIST | PAGE_REVIEW => Activity::Idle,
                     PAGE_QUIZ_VIEW => {
                         let state_ref = nav_state.borrow();
                         let exam_title = state_ref
@@ -595,6 +673,7 @@ impl MainLayout {
                 },
                 PAGE_QUIZ_LIST => ql.refresh(),
                 PAGE_QUIZ_VIEW => qv.refresh(),
+                PAGE_REVIEW => rv.refresh(),
                 PAGE_SETTINGS => st.refresh(),
                 _ => {},
             }
@@ -0,0 +1,231 @@
+//! Watches the folders of local courses and rescans them when their files change.
+//!
+//! Uses `gio::FileMonitor`, which is backed by inotify on Linux. Directory monitors are not
+//! recursive, so every folder below a course root gets its own monitor.
+
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::rc::{Rc, Weak};
+use std::sync::Arc;
+use std::time::Duration;
+
+use gio::prelude::*;
+
+use crate::application::use_cases::{RescanLocalInput, RescanLocalReport};
+use crate::application::{AppContext, ServiceFactory};
+use crate::domain::value_objects::CourseId;
+use crate::ui::state::SharedState;
+use crate::ui::toast::Toast;
+
+/// Quiet period after the last file event before a course is rescanned, so copies of
+/// large files finish first.
+const RESCAN_DELAY: Duration = Duration::from_secs(3);
+
+/// Rescans a local course in the background and hands the outcome to `on_done` on the
+/// GTK main loop.
+pub fn rescan_course(
+    ctx: &Arc<AppContext>,
+    course_id: CourseId,
+    on_done: impl FnOnce(Result<RescanLocalReport, String>) + 'static,
+) {
+    let uc = ServiceFactory::rescan_local(ctx);
+    let (tx, rx) = std::sync::mpsc::channel::<Result<RescanLocalReport, String>>();
+    crate::infrastructure::tokio_bridge::spawn(async move {
+        let res = uc.execute(RescanLocalInput { course_id }).await.map_err(|e| e.to_string());
+        let _ = tx.send(res);
+    });
+
+    let mut on_done = Some(on_done);
+    glib::idle_add_local(move || {
+        let res = match rx.try_recv() {
+            Ok(res) => res,
+            Err(std::sync::mpsc::TryRecvError::Empty) => return glib::ControlFlow::Continue,
+            Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                Err("rescan task ended unexpectedly".to_string())
+            },
+        };
+        if let Some(on_done) = on_done.take() {
+            on_done(res);
+        }
+        glib::ControlFlow::Break
+    });
+}
+
+/// One-line description of a non-empty rescan report.
+pub fn rescan_summary(report: &RescanLocalReport) -> String {
+    format!(
+        "{} added, {} moved, {} missing, {} restored",
+        report.added.len(),
+        report.moved.len(),
+        report.missing.len(),
+        report.restored.len()
+    )
+}
+
+struct WatchedCourse {
+    root: String,
+    monitors: Vec<gio::FileMonitor>,
+}
+
+impl Drop for WatchedCourse {
+    fn drop(&mut self) {
+        for monitor in &self.monitors {
+            monitor.cancel();
+        }
+    }
+}
+
+struct Inner {
+    state: SharedState,
+    on_change: Rc<dyn Fn()>,
+    watched: RefCell<HashMap<CourseId, WatchedCourse>>,
+    pending: RefCell<HashMap<CourseId, glib::SourceId>>,
+    running: RefCell<HashSet<CourseId>>,
+}
+
+/// Keeps folder monitors for every local course while `watch_local_folders` is enabled.
+#[derive(Clone)]
+pub struct LibraryWatcher {
+    inner: Rc<Inner>,
+}
+
+impl LibraryWatcher {
+    /// Creates an idle watcher; `on_change` runs after a rescan changed a course.
+    pub fn new(state: SharedState, on_change: Rc<dyn Fn()>) -> Self {
+        Self {
+            inner: Rc::new(Inner {
+                state,
+                on_change,
+                watched: RefCell::new(HashMap::new()),
+                pending: RefCell::new(HashMap::new()),
+                running: RefCell::new(HashSet::new()),
+            }),
+        }
+    }
+
+    /// Starts or stops monitors to match the preference and the current local courses.
+    pub fn sync(&self) {
+        let (enabled, backend) = {
+            let s = self.inner.state.borrow();
+            (s.watch_local_folders, s.backend.clone())
+        };
+        let roots: HashMap<CourseId, String> = match (enabled, backend) {
+            (true, Some(ctx)) => match ctx.course_repo.find_all() {
+                Ok(courses) => courses
+                    .iter()
+                    .filter_map(|c| c.source().local_root().map(|r| (*c.id(), r.to_string())))
+                    .collect(),
+                Err(e) => {
+                    log::warn!("Failed to list courses for folder watching: {e}");
+                    HashMap::new()
+                },
+            },
+            _ => HashMap::new(),
+        };
+
+        self.inner.watched.borrow_mut().retain(|id, w| roots.get(id) == Some(&w.root));
+        let mut pending = self.inner.pending.borrow_mut();
+        let stale: Vec<CourseId> =
+            pending.keys().filter(|id| !roots.contains_key(id)).copied().collect();
+        for id in stale {
+            if let Some(source) = pending.remove(&id) {
+                source.remove();
+            }
+        }
+        drop(pending);
+        for (course_id, root) in roots {
+            if !self.inner.watched.borrow().contains_key(&course_id) {
+                self.watch(course_id, root);
+            }
+        }
+    }
+
+    fn watch(&self, course_id: CourseId, root: String) {
+        let mut monitors = Vec::new();
+        let dirs = walkdir::WalkDir::new(&root)
+            .into_iter()
+            .filter_map(Result::ok)
+            .filter(|entry| entry.file_type().is_dir());
+        for dir in dirs {
+            let file = gio::File::for_path(dir.path());
+            let monitor = match file
+                .monitor_directory(gio::FileMonitorFlags::WATCH_MOVES, gio::Cancellable::NONE)
+            {
+                Ok(monitor) => monitor,
+                Err(e) => {
+                    log::warn!("Cannot watch {}: {e}", dir.path().display());
+                    continue;
+                },
+            };
+            let weak = Rc::downgrade(&self.inner);
+            monitor.connect_changed(move |_, _, _, event| {
+                // Writes in progress also count, so a rescan waits for copies to finish.
+                if matches!(
+                    event,
+                    gio::FileMonitorEvent::AttributeChanged
+                        | gio::FileMonitorEvent::PreUnmount
+                        | gio::FileMonitorEvent::Unmounted
+                ) {
+                    return;
+                }
+                schedule_rescan(&weak, course_id);
+            });
+            monitors.push(monitor);
+        }
+        self.inner.watched.borrow_mut().insert(course_id, WatchedCourse { root, monitors });
+    }
+
+    fn rescan(&self, course_id: CourseId) {
+        let Some(ctx) = self.inner.state.borrow().backend.clone() else {
+            return;
+        };
+        self.inner.running.borrow_mut().insert(course_id);
+        let weak = Rc::downgrade(&self.inner);
+        rescan_course(&ctx, course_id, move |res| {
+            let Some(inner) = weak.upgrade() else {
+                return;
+            };
+            inner.running.borrow_mut().remove(&course_id);
+            match res {
+                Ok(report) if report.is_unchanged() => {},
+                Ok(report) => {
+                    Toast::show(&format!("Course folder changed: {}.", rescan_summary(&report)));
+                    // New files may live in new folders that need monitors of their own.
+                    if !report.added.is_empty() || !report.moved.is_empty() {
+                        let watcher = LibraryWatcher { inner: inner.clone() };
+                        let root =
+                            inner.watched.borrow_mut().remove(&course_id).map(|w| w.root.clone());
+                        if let Some(root) = root {
+                            watcher.watch(course_id, root);
+                        }
+                    }
+                    (inner.on_change)();
+                },
+                Err(e) => log::warn!("Automatic rescan of course {course_id} failed: {e}"),
+            }
+        });
+    }
+}
+
+fn schedule_rescan(inner: &Weak<Inner>, course_id: CourseId) {
+    let Some(inner) = inner.upgrade() else {
+        return;
+    };
+    if let Some(source) = inner.pending.borrow_mut().remove(&course_id) {
+        source.remove();
+    }
+    let weak = Rc::downgrade(&inner);
+    let source = glib::timeout_add_local_once(RESCAN_DELAY, move || {
+        let Some(inner) = weak.upgrade() else {
+            return;
+        };
+        inner.pending.borrow_mut().remove(&course_id);
+        if inner.running.borrow().contains(&course_id) {
+            // Events raised while a rescan runs are picked up by another pass.
+            schedule_rescan(&weak, course_id);
+            return;
+        }
+        LibraryWatcher { inner }.rescan(course_id);
+    });
+    inner.pending.borrow_mut().insert(course_id, source);
+}
@@ -11,6 +11,7 @@ mod course_imp {
         pub title: RefCell<String>,
         pub description: RefCell<Option<String>>,
         pub module_count: RefCell<i32>,
+        pub source_label: RefCell<String>,
     }
 
     #[glib::object_subclass]
@@ -28,13 +29,20 @@ glib::wrapper! {
 }
 
 impl CourseObject {
-    pub fn new(id: String, title: String, description: Option<String>, module_count: i32) -> Self {
+    pub fn new(
+        id: String,
+        title: String,
+        description: Option<String>,
+        module_count: i32,
+        source_label: String,
+    ) -> Self {
         let obj: CourseObject = glib::Object::builder().build();
         let imp = obj.imp();
         *imp.id.borrow_mut() = id;
         *imp.title.borrow_mut() = title;
         *imp.description.borrow_mut() = description;
         *imp.module_count.borrow_mut() = module_count;
+        *imp.source_label.borrow_mut() = source_label;
         obj
     }
 
@@ -53,6 +61,10 @@ impl CourseObject {
     pub fn module_count(&self) -> i32 {
         *self.imp().module_count.borrow()
     }
+
+    pub fn source_label(&self) -> String {
+        self.imp().source_label.borrow().clone()
+    }
 }
 
 mod quiz_imp {
@@ -139,6 +151,7 @@ mod video_row_imp {
         pub duration_secs: RefCell<u32>,
         pub source_type: RefCell<String>,
         pub sort_order: RefCell<u32>,
+        pub is_available: RefCell<bool>,
     }
 
     #[glib::object_subclass]
@@ -156,6 +169,7 @@ glib::wrapper! {
 }
 
 impl VideoRowObject {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         id: String,
         module_id: String,
@@ -164,6 +178,7 @@ impl VideoRowObject {
         duration_secs: u32,
         source_type: String,
         sort_order: u32,
+        is_available: bool,
     ) -> Self {
         let obj: VideoRowObject = glib::Object::builder().build();
         let imp = obj.imp();
@@ -174,6 +189,7 @@ impl VideoRowObject {
         *imp.duration_secs.borrow_mut() = duration_secs;
         *imp.source_type.borrow_mut() = source_type;
         *imp.sort_order.borrow_mut() = sort_order;
+        *imp.is_available.borrow_mut() = is_available;
         obj
     }
 
@@ -204,4 +220,8 @@ impl VideoRowObject {
     pub fn sort_order(&self) -> u32 {
         *self.imp().sort_order.borrow()
     }
+
+    pub fn is_available(&self) -> bool {
+        *self.imp().is_available.borrow()
+    }
 }
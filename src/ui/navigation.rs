@@ -4,4 +4,5 @@ pub const PAGE_COURSE_VIEW: &str = "course-view";
 pub const PAGE_VIDEO_PLAYER: &str = "video-player";
 pub const PAGE_QUIZ_LIST: &str = "quiz-list";
 pub const PAGE_QUIZ_VIEW: &str = "quiz-view";
+pub const PAGE_REVIEW: &str = "review";
 pub const PAGE_SETTINGS: &str = "settings";
@@ -120,7 +120,11 @@ impl CourseListPage {
                     },
                     None => desc_label.set_visible(false),
                 }
-                info_label.set_text(&format!("{} modules", course.module_count()));
+                info_label.set_text(&format!(
+                    "{} modules · {}",
+                    course.module_count(),
+                    course.source_label()
+                ));
             }
         });
 
@@ -238,6 +242,7 @@ impl CourseListPage {
                                     course.name().to_string(),
                                     course.description().map(|s| s.to_string()),
                                     mc as i32,
+                                    course.source().label().to_string(),
                                 );
                                 store.append(&obj);
                             }
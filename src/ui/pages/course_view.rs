@@ -4,9 +4,12 @@ use std::rc::Rc;
 
 use crate::application::ServiceFactory;
 use crate::application::use_cases::{
-    CreateModuleInput, DeleteModuleInput, MoveVideoInput, UpdateModuleTitleInput,
+    CreateModuleInput, DeleteModuleInput, ExportManifestInput, ExportScheduleInput, ManifestFormat,
+    MoveVideoInput, RescheduleCourseInput, SyncPlaylistInput, SyncPlaylistReport,
+    UpdateModuleTitleInput,
 };
-use crate::domain::value_objects::ModuleId;
+use crate::domain::value_objects::{CourseSource, ModuleId, ReschedulePolicy};
+use crate::ui::library_watcher::{rescan_course, rescan_summary};
 use crate::ui::list_models::VideoRowObject;
 use crate::ui::navigation::PAGE_VIDEO_PLAYER;
 use crate::ui::state::SharedState;
@@ -165,6 +168,185 @@ impl CourseViewPage {
                 name_label.set_hexpand(true);
                 title_row.append(&name_label);
 
+                let plan_btn = gtk::Button::from_icon_name("x-office-calendar-symbolic");
+                plan_btn.add_css_class("flat");
+                plan_btn.set_valign(gtk::Align::Center);
+                plan_btn.set_tooltip_text(Some("Plan Study Schedule"));
+                title_row.append(&plan_btn);
+
+                {
+                    let plan_state = self.state.clone();
+                    let course_id_plan = *course.id();
+                    let plan_cb = refresh_cb.clone();
+                    let widget = self.widget.clone();
+                    plan_btn.connect_clicked(move |_| {
+                        let parent = widget.root().and_then(|r| r.downcast::<gtk::Window>().ok());
+                        crate::ui::dialogs::schedule_dialog::show_plan_schedule_dialog(
+                            plan_state.clone(),
+                            course_id_plan,
+                            parent.as_ref(),
+                            Some(plan_cb.clone()),
+                        );
+                    });
+                }
+
+                if course.source().playlist_url().is_some() {
+                    let sync_btn = gtk::Button::from_icon_name("view-refresh-symbolic");
+                    sync_btn.add_css_class("flat");
+                    sync_btn.set_valign(gtk::Align::Center);
+                    sync_btn.set_tooltip_text(Some("Sync with Playlist"));
+                    title_row.append(&sync_btn);
+
+                    let sync_state = self.state.clone();
+                    let course_id_sync = *course.id();
+                    let sync_cb = refresh_cb.clone();
+                    sync_btn.connect_clicked(move |btn| {
+                        let Some(ctx) = sync_state.borrow().backend.clone() else {
+                            return;
+                        };
+                        let uc = ServiceFactory::sync_playlist(&ctx);
+                        btn.set_sensitive(false);
+                        Toast::show("Syncing with playlist...");
+
+                        let (tx, rx) =
+                            std::sync::mpsc::channel::<Result<SyncPlaylistReport, String>>();
+                        crate::infrastructure::tokio_bridge::spawn(async move {
+                            let res = uc
+                                .execute(SyncPlaylistInput { course_id: course_id_sync })
+                                .await
+                                .map_err(|e| e.to_string());
+                            let _ = tx.send(res);
+                        });
+
+                        let btn = btn.clone();
+                        let sync_cb = sync_cb.clone();
+                        glib::idle_add_local(move || {
+                            let res = match rx.try_recv() {
+                                Ok(res) => res,
+                                Err(std::sync::mpsc::TryRecvError::Empty) => {
+                                    return glib::ControlFlow::Continue;
+                                },
+                                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                                    Err("sync task ended unexpectedly".to_string())
+                                },
+                            };
+                            btn.set_sensitive(true);
+                            match res {
+                                Ok(report) if report.is_unchanged() => {
+                                    Toast::show("Course is already up to date.")
+                                },
+                                Ok(report) => {
+                                    Toast::show(&format!(
+                                        "Synced: {} added, {} unavailable, {} restored, {} moved.",
+                                        report.added.len(),
+                                        report.removed.len(),
+                                        report.restored.len(),
+                                        report.reordered
+                                    ));
+                                    sync_cb();
+                                },
+                                Err(e) => Toast::show_error(&format!("Playlist sync failed: {e}")),
+                            }
+                            glib::ControlFlow::Break
+                        });
+                    });
+                }
+
+                if course.source().local_root().is_some() {
+                    let rescan_btn = gtk::Button::from_icon_name("view-refresh-symbolic");
+                    rescan_btn.add_css_class("flat");
+                    rescan_btn.set_valign(gtk::Align::Center);
+                    rescan_btn.set_tooltip_text(Some("Rescan Folder"));
+                    title_row.append(&rescan_btn);
+
+                    let rescan_state = self.state.clone();
+                    let course_id_rescan = *course.id();
+                    let rescan_cb = refresh_cb.clone();
+                    rescan_btn.connect_clicked(move |btn| {
+                        let Some(ctx) = rescan_state.borrow().backend.clone() else {
+                            return;
+                        };
+                        btn.set_sensitive(false);
+                        Toast::show("Rescanning course folder...");
+                        let btn = btn.clone();
+                        let rescan_cb = rescan_cb.clone();
+                        rescan_course(&ctx, course_id_rescan, move |res| {
+                            btn.set_sensitive(true);
+                            match res {
+                                Ok(report) if report.is_unchanged() => {
+                                    Toast::show("Course is already up to date.")
+                                },
+                                Ok(report) => {
+                                    Toast::show(&format!(
+                                        "Rescanned: {}.",
+                                        rescan_summary(&report)
+                                    ));
+                                    rescan_cb();
+                                },
+                                Err(e) => Toast::show_error(&format!("Rescan failed: {e}")),
+                            }
+                        });
+                    });
+                }
+
+                let manifest_btn = gtk::Button::from_icon_name("document-save-as-symbolic");
+                manifest_btn.add_css_class("flat");
+                manifest_btn.set_valign(gtk::Align::Center);
+                manifest_btn.set_tooltip_text(Some("Export Course Manifest (.toml / .json)"));
+                title_row.append(&manifest_btn);
+
+                {
+                    let manifest_state = self.state.clone();
+                    let course_id_manifest = *course.id();
+                    let initial_name = format!("{}.toml", course.name());
+                    let widget = self.widget.clone();
+                    manifest_btn.connect_clicked(move |_| {
+                        let file_dialog = gtk::FileDialog::new();
+                        file_dialog.set_title("Export Course Manifest");
+                        file_dialog.set_initial_name(Some(&initial_name));
+                        let parent = widget.root().and_then(|r| r.downcast::<gtk::Window>().ok());
+                        let manifest_state = manifest_state.clone();
+                        file_dialog.save(
+                            parent.as_ref(),
+                            None::<&gio::Cancellable>,
+                            move |result| {
+                                let Some(path) = result.ok().and_then(|file| file.path()) else {
+                                    return;
+                                };
+                                let output = {
+                                    let s = manifest_state.borrow();
+                                    let Some(ref ctx) = s.backend else {
+                                        return;
+                                    };
+                                    let format = ManifestFormat::from_path(&path.to_string_lossy())
+                                        .unwrap_or(ManifestFormat::Toml);
+                                    ServiceFactory::export_manifest(ctx).execute(
+                                        ExportManifestInput {
+                                            course_id: course_id_manifest,
+                                            format,
+                                            base_dir: path
+                                                .parent()
+                                                .map(|dir| dir.to_string_lossy().to_string()),
+                                        },
+                                    )
+                                };
+                                let written = output.map_err(|e| e.to_string()).and_then(|o| {
+                                    std::fs::write(&path, o.content).map_err(|e| e.to_string())
+                                });
+                                match written {
+                                    Ok(()) => Toast::show(&format!(
+                                        "Exported course manifest to {}.",
+                                        path.display()
+                                    )),
+                                    Err(e) => Toast::show_error(&format!(
+                                        "Failed to export manifest: {e}"
+                                    )),
+                                }
+                            },
+                        );
+                    });
+                }
+
                 let delete_course_btn = gtk::Button::from_icon_name("user-trash-symbolic");
                 delete_course_btn.add_css_class("flat");
                 delete_course_btn.set_valign(gtk::Align::Center);
@@ -231,6 +413,54 @@ impl CourseViewPage {
                     });
                 }
 
+                let (source_icon, source_text) = match course.source() {
+                    CourseSource::YouTubePlaylist(url) => {
+                        ("video-display-symbolic", format!("YouTube playlist · {}", url.raw()))
+                    },
+                    CourseSource::LocalFolder { root } => {
+                        ("folder-symbolic", format!("Local folder · {root}"))
+                    },
+                    CourseSource::Manual => ("document-edit-symbolic", "Manual course".to_string()),
+                };
+                let source_row = gtk::Box::new(gtk::Orientation::Horizontal, 6);
+                source_row.append(&gtk::Image::from_icon_name(source_icon));
+                let source_label = gtk::Label::new(Some(&source_text));
+                source_label.set_ellipsize(gtk::pango::EllipsizeMode::Middle);
+                source_label.set_selectable(true);
+                source_row.append(&source_label);
+                source_row.add_css_class("caption");
+                source_row.add_css_class("dim-label");
+                header.append(&source_row);
+
+                if course.source().local_root().is_some() {
+                    let missing =
+                        ServiceFactory::relink_local(ctx).count_missing(course.id()).unwrap_or(0);
+                    if missing > 0 {
+                        let banner = adw::Banner::new(&format!(
+                            "{missing} of this course's files can't be found. \
+                             Was its folder moved or renamed?"
+                        ));
+                        banner.set_button_label(Some("Locate Folder…"));
+                        banner.set_revealed(true);
+                        header.append(&banner);
+
+                        let relink_state = self.state.clone();
+                        let course_id_relink = *course.id();
+                        let relink_cb = refresh_cb.clone();
+                        let widget = self.widget.clone();
+                        banner.connect_button_clicked(move |_| {
+                            let parent =
+                                widget.root().and_then(|r| r.downcast::<gtk::Window>().ok());
+                            crate::ui::dialogs::relink_dialog::show_relink_dialog(
+                                relink_state.clone(),
+                                course_id_relink,
+                                parent.as_ref(),
+                                Some(relink_cb.clone()),
+                            );
+                        });
+                    }
+                }
+
                 if let Some(desc) = course.description() {
                     let desc_label = gtk::Label::new(Some(desc));
                     desc_label.set_halign(gtk::Align::Start);
@@ -239,6 +469,162 @@ impl CourseViewPage {
                     header.append(&desc_label);
                 }
 
+                if let Ok(Some(schedule)) =
+                    ServiceFactory::plan_course_schedule(ctx).load(course.id())
+                {
+                    let today = chrono::Local::now().date_naive();
+                    let remaining =
+                        schedule.sessions().iter().filter(|s| s.date() >= today).count();
+                    let text = match schedule.end_date() {
+                        Some(end) => format!(
+                            "Study plan: {} of {} sessions remaining, finishing {} \
+                             ({} days/week, {} min/session)",
+                            remaining,
+                            schedule.sessions().len(),
+                            end.format("%b %-d, %Y"),
+                            schedule.study_days_per_week(),
+                            schedule.cognitive_limit_minutes()
+                        ),
+                        None => "Study plan is empty.".to_string(),
+                    };
+                    let plan_row = gtk::Box::new(gtk::Orientation::Horizontal, 8);
+                    let plan_label = gtk::Label::new(Some(&text));
+                    plan_label.set_halign(gtk::Align::Start);
+                    plan_label.set_hexpand(true);
+                    plan_label.set_xalign(0.0);
+                    plan_label.set_wrap(true);
+                    plan_label.add_css_class("caption");
+                    plan_row.append(&plan_label);
+
+                    let replan_btn = gtk::Button::with_label("Re-plan");
+                    replan_btn.add_css_class("flat");
+                    replan_btn.set_valign(gtk::Align::Center);
+                    replan_btn.set_tooltip_text(Some("Re-plan the remaining sessions from today"));
+                    plan_row.append(&replan_btn);
+
+                    let export_btn = gtk::Button::from_icon_name("document-save-symbolic");
+                    export_btn.add_css_class("flat");
+                    export_btn.set_valign(gtk::Align::Center);
+                    export_btn.set_tooltip_text(Some("Export to Calendar (.ics)"));
+                    plan_row.append(&export_btn);
+                    header.append(&plan_row);
+
+                    {
+                        let export_state = self.state.clone();
+                        let course_id_export = *course.id();
+                        let widget = self.widget.clone();
+                        export_btn.connect_clicked(move |_| {
+                            let output = {
+                                let s = export_state.borrow();
+                                let Some(ref ctx) = s.backend else {
+                                    return;
+                                };
+                                ServiceFactory::export_schedule(ctx).execute(ExportScheduleInput {
+                                    course_id: course_id_export,
+                                    start_time: None,
+                                })
+                            };
+                            let output = match output {
+                                Ok(output) => output,
+                                Err(e) => {
+                                    Toast::show_error(&format!("Failed to export schedule: {e}"));
+                                    return;
+                                },
+                            };
+
+                            let file_dialog = gtk::FileDialog::new();
+                            file_dialog.set_title("Export Study Schedule");
+                            file_dialog.set_initial_name(Some(&output.file_name));
+                            let parent =
+                                widget.root().and_then(|r| r.downcast::<gtk::Window>().ok());
+                            file_dialog.save(
+                                parent.as_ref(),
+                                None::<&gio::Cancellable>,
+                                move |result| {
+                                    let Some(path) = result.ok().and_then(|file| file.path())
+                                    else {
+                                        return;
+                                    };
+                                    match std::fs::write(&path, &output.content) {
+                                        Ok(()) => Toast::show(&format!(
+                                            "Exported {} sessions to {}.",
+                                            output.events,
+                                            path.display()
+                                        )),
+                                        Err(e) => Toast::show_error(&format!(
+                                            "Failed to write calendar file: {e}"
+                                        )),
+                                    }
+                                },
+                            );
+                        });
+                    }
+
+                    let replan_state = self.state.clone();
+                    let course_id_replan = *course.id();
+                    let replan_cb = refresh_cb.clone();
+                    let widget = self.widget.clone();
+                    replan_btn.connect_clicked(move |_| {
+                        let dialog = adw::AlertDialog::new(
+                            Some("Re-plan Study Schedule?"),
+                            Some(
+                                "Remaining lessons are re-planned from today. Keep the deadline \
+                                 to make sessions longer, or keep the daily load to finish later.",
+                            ),
+                        );
+                        dialog.add_response("cancel", "Cancel");
+                        dialog
+                            .add_response(ReschedulePolicy::KeepDeadline.as_str(), "Keep Deadline");
+                        dialog.add_response(
+                            ReschedulePolicy::KeepDailyLoad.as_str(),
+                            "Keep Daily Load",
+                        );
+                        dialog.set_response_appearance(
+                            ReschedulePolicy::KeepDailyLoad.as_str(),
+                            adw::ResponseAppearance::Suggested,
+                        );
+                        dialog.set_default_response(Some("cancel"));
+
+                        let replan_state2 = replan_state.clone();
+                        let replan_cb2 = replan_cb.clone();
+                        dialog.connect_response(None, move |_, response| {
+                            let Ok(policy) = response.parse::<ReschedulePolicy>() else {
+                                return;
+                            };
+                            let s = replan_state2.borrow();
+                            let Some(ref ctx) = s.backend else {
+                                return;
+                            };
+                            let input = RescheduleCourseInput {
+                                course_id: course_id_replan,
+                                today: chrono::Local::now().date_naive(),
+                                policy: Some(policy),
+                            };
+                            match ServiceFactory::reschedule_course(ctx).execute(input) {
+                                Ok(output) => {
+                                    let shift = output.diff.end_date_shift_days();
+                                    Toast::show(&format!(
+                                        "Re-planned: {} lesson(s) moved, finish date {}.",
+                                        output.diff.moved.len(),
+                                        match shift {
+                                            0 => "unchanged".to_string(),
+                                            d if d > 0 => format!("{d} day(s) later"),
+                                            d => format!("{} day(s) earlier", -d),
+                                        }
+                                    ));
+                                    drop(s);
+                                    replan_cb2();
+                                },
+                                Err(e) => {
+                                    Toast::show_error(&format!("Failed to re-plan schedule: {e}"))
+                                },
+                            }
+                        });
+                        let parent = widget.root().and_then(|r| r.downcast::<gtk::Window>().ok());
+                        dialog.present(parent.as_ref());
+                    });
+                }
+
                 self.content_box.append(&header);
 
                 // ----------------------------------------------------------------
@@ -577,9 +963,11 @@ impl CourseViewPage {
                         } else {
                             let no_llm_section = gio::Menu::new();
                             no_llm_section
-                                .append(Some("Summarize (needs API key)"), Some("video.noop"));
-                            no_llm_section
-                                .append(Some("Generate Quiz (needs API key)"), Some("video.noop"));
+                                .append(Some("Summarize (needs AI provider)"), Some("video.noop"));
+                            no_llm_section.append(
+                                Some("Generate Quiz (needs AI provider)"),
+                                Some("video.noop"),
+                            );
                             menu_model.append_section(None::<&str>, &no_llm_section);
                         }
 
@@ -931,13 +1319,22 @@ impl CourseViewPage {
                         // Title.
                         let t = item.title();
                         title_label.set_text(&t);
-                        title_label.set_tooltip_text(Some(&t));
 
-                        // Source badge.
-                        source_badge.set_text(match item.source_type().as_str() {
-                            "YouTube" => "YT",
-                            _ => "Local",
-                        });
+                        // Source badge; videos missing from their source are dimmed instead.
+                        if item.is_available() {
+                            title_label.set_tooltip_text(Some(&t));
+                            title_label.remove_css_class("dim-label");
+                            source_badge.set_text(match item.source_type().as_str() {
+                                "YouTube" => "YT",
+                                _ => "Local",
+                            });
+                        } else {
+                            title_label.set_tooltip_text(Some(&format!(
+                                "{t}\nNo longer in its playlist or folder"
+                            )));
+                            title_label.add_css_class("dim-label");
+                            source_badge.set_text("Unavailable");
+                        }
 
                         // Duration (H:MM:SS or MM:SS).
                         let total_secs = item.duration_secs();
@@ -975,6 +1372,7 @@ impl CourseViewPage {
                             video.duration_secs(),
                             source_type.to_string(),
                             video.sort_order(),
+                            video.is_available(),
                         );
                         store.append(&obj);
                     }
@@ -5,9 +5,17 @@ use std::rc::Rc;
 use adw::prelude::*;
 use adw::{NavigationPage, NavigationView};
 
-use crate::ui::navigation::PAGE_COURSE_VIEW;
+use crate::application::use_cases::{LoadStudyHistoryInput, ScheduledSessionView};
+use crate::domain::entities::StudyHistory;
+use crate::ui::navigation::{PAGE_COURSE_VIEW, PAGE_VIDEO_PLAYER};
 use crate::ui::state::SharedState;
 use crate::ui::toast::Toast;
+use crate::ui::widgets::{build_heatmap, build_trend_line};
+
+/// Days covered by the study history heatmap (26 weeks).
+const HISTORY_DAYS: u32 = 182;
+/// Days shown on the trend line.
+const TREND_DAYS: usize = 30;
 
 pub struct DashboardPage {
     widget: gtk::Box,
@@ -21,6 +29,8 @@ pub struct DashboardPage {
     progress_bar: gtk::LevelBar,
     progress_label: gtk::Label,
     coverage_label: gtk::Label,
+    history_container: gtk::Box,
+    today_container: gtk::Box,
     courses_container: gtk::Box,
     content_box: gtk::Box,
     status_page: adw::StatusPage,
@@ -118,7 +128,25 @@ impl DashboardPage {
         progress_section.set_child(Some(&progress_box));
         content_box.append(&progress_section);
 
-        // 4. In Progress / Recent Courses Section
+        // 4. Study history: heatmap, streaks and trend
+        let history_title = gtk::Label::new(Some("Study History"));
+        history_title.add_css_class("heading");
+        history_title.set_halign(gtk::Align::Start);
+        content_box.append(&history_title);
+
+        let history_container = gtk::Box::new(gtk::Orientation::Vertical, 12);
+        content_box.append(&history_container);
+
+        // 5. Today's planned study sessions
+        let today_title = gtk::Label::new(Some("Today's Session"));
+        today_title.add_css_class("heading");
+        today_title.set_halign(gtk::Align::Start);
+        content_box.append(&today_title);
+
+        let today_container = gtk::Box::new(gtk::Orientation::Vertical, 12);
+        content_box.append(&today_container);
+
+        // 6. In Progress / Recent Courses Section
         let courses_title = gtk::Label::new(Some("Resume Studying"));
         courses_title.add_css_class("heading");
         courses_title.set_halign(gtk::Align::Start);
@@ -143,6 +171,8 @@ impl DashboardPage {
             progress_bar,
             progress_label,
             coverage_label,
+            history_container,
+            today_container,
             courses_container,
             content_box,
             status_page,
@@ -164,6 +194,12 @@ impl DashboardPage {
         while let Some(child) = self.courses_container.first_child() {
             self.courses_container.remove(&child);
         }
+        while let Some(child) = self.today_container.first_child() {
+            self.today_container.remove(&child);
+        }
+        while let Some(child) = self.history_container.first_child() {
+            self.history_container.remove(&child);
+        }
 
         let backend = {
             let state = self.state.borrow();
@@ -204,6 +240,52 @@ impl DashboardPage {
                         }
                     ));
 
+                    let today = chrono::Local::now().date_naive();
+                    match crate::application::ServiceFactory::study_history(ctx)
+                        .execute(LoadStudyHistoryInput { today, days: HISTORY_DAYS })
+                    {
+                        Ok(history) => {
+                            self.history_container.append(&build_history_card(&history));
+                        },
+                        Err(e) => log::warn!("Failed to load study history: {}", e),
+                    }
+
+                    match crate::application::ServiceFactory::reschedule_course(ctx)
+                        .catch_up_all(today)
+                    {
+                        Ok(updated) if !updated.is_empty() => {
+                            let moved: usize = updated.iter().map(|u| u.diff.moved.len()).sum();
+                            Toast::show(&format!(
+                                "Study plan updated: {} lesson(s) moved across {} course(s).",
+                                moved,
+                                updated.len()
+                            ));
+                        },
+                        Ok(_) => {},
+                        Err(e) => log::warn!("Failed to re-plan study schedules: {}", e),
+                    }
+
+                    match crate::application::ServiceFactory::plan_course_schedule(ctx)
+                        .sessions_on(today)
+                    {
+                        Ok(sessions) if !sessions.is_empty() => {
+                            for session in &sessions {
+                                self.today_container.append(&self.build_session_card(session));
+                            }
+                        },
+                        Ok(_) => {
+                            let none_label = gtk::Label::new(Some(
+                                "No study session planned for today. Plan a schedule from a course page.",
+                            ));
+                            none_label.add_css_class("subtitle");
+                            none_label.set_halign(gtk::Align::Start);
+                            self.today_container.append(&none_label);
+                        },
+                        Err(e) => {
+                            log::warn!("Failed to load today's study sessions: {}", e);
+                        },
+                    }
+
                     // Load list of all courses dynamically to build completion progress cards
                     if let Ok(courses) = ctx.course_repo.find_all() {
                         if courses.is_empty() {
@@ -273,6 +355,28 @@ impl DashboardPage {
 
                                 card_box.append(&text_box);
 
+                                let last_watched =
+                                    crate::application::ServiceFactory::playback(ctx)
+                                        .last_watched(c_id)
+                                        .unwrap_or_else(|e| {
+                                            log::warn!("Failed to load last watched video: {}", e);
+                                            None
+                                        });
+                                if let Some(ref resume) = last_watched {
+                                    let last_lbl = gtk::Label::new(Some(&format!(
+                                        "Left off at {}{}",
+                                        resume.video.title(),
+                                        resume
+                                            .position_ms
+                                            .map(|ms| format!(" ({})", fmt_ms(ms)))
+                                            .unwrap_or_default()
+                                    )));
+                                    last_lbl.add_css_class("caption");
+                                    last_lbl.set_halign(gtk::Align::Start);
+                                    last_lbl.set_ellipsize(gtk::pango::EllipsizeMode::End);
+                                    text_box.append(&last_lbl);
+                                }
+
                                 let resume_btn = gtk::Button::with_label("Resume Study");
                                 resume_btn.add_css_class("suggested-action");
                                 resume_btn.set_valign(gtk::Align::Center);
@@ -281,12 +385,23 @@ impl DashboardPage {
                                 let nav_cl = self.nav.clone();
                                 let nav_pages_cl = self.nav_pages.clone();
                                 let course_id_str = c_id.to_string();
+                                let last_video_id =
+                                    last_watched.map(|resume| resume.video.id().to_string());
 
                                 resume_btn.connect_clicked(move |_| {
-                                    state_cl.borrow_mut().current_course_id =
-                                        Some(course_id_str.parse().unwrap());
+                                    let target = {
+                                        let mut s = state_cl.borrow_mut();
+                                        s.current_course_id = Some(course_id_str.clone());
+                                        match last_video_id {
+                                            Some(ref video_id) => {
+                                                s.current_video_id = Some(video_id.clone());
+                                                PAGE_VIDEO_PLAYER
+                                            },
+                                            None => PAGE_COURSE_VIEW,
+                                        }
+                                    };
                                     let pages = nav_pages_cl.borrow();
-                                    if let Some(page) = pages.get(PAGE_COURSE_VIEW) {
+                                    if let Some(page) = pages.get(target) {
                                         nav_cl.push(page);
                                     }
                                 });
@@ -317,6 +432,136 @@ impl DashboardPage {
     }
 }
 
+impl DashboardPage {
+    /// Builds a card listing the videos planned for one course today.
+    fn build_session_card(&self, session: &ScheduledSessionView) -> gtk::Frame {
+        let card = gtk::Frame::new(None);
+        card.add_css_class("course-progress-card");
+
+        let vbox = gtk::Box::new(gtk::Orientation::Vertical, 8);
+        vbox.set_margin_start(16);
+        vbox.set_margin_end(16);
+        vbox.set_margin_top(16);
+        vbox.set_margin_bottom(16);
+
+        let done = session.videos.iter().filter(|v| v.is_completed()).count();
+        let title = gtk::Label::new(Some(&session.course_name));
+        title.add_css_class("title");
+        title.set_halign(gtk::Align::Start);
+        title.set_wrap(true);
+        vbox.append(&title);
+
+        let subtitle = gtk::Label::new(Some(&format!(
+            "{} min planned - {} of {} lessons done",
+            session.total_duration_secs.div_ceil(60),
+            done,
+            session.videos.len()
+        )));
+        subtitle.add_css_class("subtitle");
+        subtitle.set_halign(gtk::Align::Start);
+        vbox.append(&subtitle);
+
+        for video in &session.videos {
+            let row = gtk::Box::new(gtk::Orientation::Horizontal, 8);
+
+            let check = gtk::Image::from_icon_name(if video.is_completed() {
+                "object-select-symbolic"
+            } else {
+                "media-playback-start-symbolic"
+            });
+            row.append(&check);
+
+            let label = gtk::Label::new(Some(&format!(
+                "{} ({} min)",
+                video.title(),
+                video.duration_secs().div_ceil(60)
+            )));
+            label.set_halign(gtk::Align::Start);
+            label.set_hexpand(true);
+            label.set_wrap(true);
+            row.append(&label);
+
+            let play_btn = gtk::Button::with_label("Play");
+            play_btn.add_css_class("flat");
+            let state_cl = self.state.clone();
+            let nav_cl = self.nav.clone();
+            let nav_pages_cl = self.nav_pages.clone();
+            let course_id = session.course_id.to_string();
+            let video_id = video.id().to_string();
+            play_btn.connect_clicked(move |_| {
+                {
+                    let mut s = state_cl.borrow_mut();
+                    s.current_course_id = Some(course_id.clone());
+                    s.current_video_id = Some(video_id.clone());
+                }
+                let pages = nav_pages_cl.borrow();
+                if let Some(page) = pages.get(PAGE_VIDEO_PLAYER) {
+                    nav_cl.push(page);
+                }
+            });
+            row.append(&play_btn);
+
+            vbox.append(&row);
+        }
+
+        card.set_child(Some(&vbox));
+        card
+    }
+}
+
+/// Builds the study history card: streaks, heatmap, trend line and course velocity.
+fn build_history_card(history: &StudyHistory) -> gtk::Frame {
+    let card = gtk::Frame::new(None);
+    card.add_css_class("card");
+
+    let vbox = gtk::Box::new(gtk::Orientation::Vertical, 12);
+    vbox.set_margin_start(8);
+    vbox.set_margin_end(8);
+    vbox.set_margin_top(8);
+    vbox.set_margin_bottom(8);
+
+    let streaks = gtk::Label::new(Some(&format!(
+        "Current streak: {} day(s) · Longest: {} day(s) · Last 7 days: {} min",
+        history.current_streak(),
+        history.longest_streak(),
+        history.recent_minutes(7)
+    )));
+    streaks.add_css_class("title");
+    streaks.set_halign(gtk::Align::Start);
+    streaks.set_wrap(true);
+    vbox.append(&streaks);
+
+    let heatmap_scroll = gtk::ScrolledWindow::new();
+    heatmap_scroll.set_policy(gtk::PolicyType::Automatic, gtk::PolicyType::Never);
+    heatmap_scroll.set_child(Some(&build_heatmap(history)));
+    vbox.append(&heatmap_scroll);
+
+    let trend_label = gtk::Label::new(Some("Daily minutes (7-day average)"));
+    trend_label.add_css_class("caption");
+    trend_label.set_halign(gtk::Align::Start);
+    vbox.append(&trend_label);
+    vbox.append(&build_trend_line(history, TREND_DAYS));
+
+    for velocity in history.course_velocity().iter().filter(|v| v.remaining_minutes > 0) {
+        let pace = match velocity.weeks_to_finish() {
+            Some(weeks) => format!(
+                "{:.0} min/week, about {:.0} week(s) to finish",
+                velocity.minutes_per_week,
+                weeks.ceil()
+            ),
+            None => "not watched in the last 4 weeks".to_string(),
+        };
+        let label = gtk::Label::new(Some(&format!("{}: {}", velocity.course_name, pace)));
+        label.add_css_class("subtitle");
+        label.set_halign(gtk::Align::Start);
+        label.set_ellipsize(gtk::pango::EllipsizeMode::End);
+        vbox.append(&label);
+    }
+
+    card.set_child(Some(&vbox));
+    card
+}
+
 fn make_stat_card(title: &str, value: &str) -> (gtk::Frame, gtk::Label) {
     let frame = gtk::Frame::new(None);
     frame.add_css_class("stat-card");
@@ -342,3 +587,15 @@ fn make_stat_card(title: &str, value: &str) -> (gtk::Frame, gtk::Label) {
 
     (frame, value_label)
 }
+
+fn fmt_ms(ms: u64) -> String {
+    let total_secs = ms / 1_000;
+    let hours = total_secs / 3600;
+    let mins = (total_secs % 3600) / 60;
+    let secs = total_secs % 60;
+    if hours > 0 {
+        format!("{}:{:02}:{:02}", hours, mins, secs)
+    } else {
+        format!("{:02}:{:02}", mins, secs)
+    }
+}
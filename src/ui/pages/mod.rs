@@ -11,6 +11,7 @@ pub mod dashboard;
 pub mod onboarding;
 pub mod quiz_list;
 pub mod quiz_view;
+pub mod review;
 pub mod settings;
 pub mod video_player;
 
@@ -119,6 +120,16 @@ impl Page for quiz_view::QuizViewPage {
     }
 }
 
+impl Page for review::ReviewPage {
+    fn widget(&self) -> &gtk::Widget {
+        self.widget().upcast_ref()
+    }
+
+    fn refresh(&self) {
+        self.refresh();
+    }
+}
+
 impl Page for settings::SettingsPage {
     fn widget(&self) -> &gtk::Widget {
         self.widget().upcast_ref()
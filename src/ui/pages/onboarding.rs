@@ -24,9 +24,12 @@ pub fn check_onboarding(state: SharedState, window: &adw::ApplicationWindow) {
         if let Some(ref ctx) = s.backend {
             let uc = ServiceFactory::preferences(ctx);
             let input = UpdatePreferencesInput {
-                ml_boundary_enabled: false,
+                ml_boundary_enabled: s.ml_boundary_enabled,
                 cognitive_limit_minutes: s.cognitive_limit_minutes,
                 boundary_batch_size: s.boundary_batch_size,
+                module_target_minutes: s.module_target_minutes,
+                auto_complete_percent: s.auto_complete_percent,
+                watch_local_folders: s.watch_local_folders,
                 right_panel_visible: s.right_panel_visible,
                 right_panel_width: s.right_panel_width as u32,
                 onboarding_completed: true,
@@ -0,0 +1,266 @@
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+use adw::prelude::*;
+
+use crate::application::ServiceFactory;
+use crate::application::use_cases::{DueCard, RecordReviewInput};
+use crate::domain::value_objects::ReviewGrade;
+use crate::ui::state::SharedState;
+use crate::ui::toast::Toast;
+
+/// Widgets and queue state shared by the review page's callbacks.
+#[derive(Clone)]
+struct ReviewSession {
+    state: SharedState,
+    content_box: gtk::Box,
+    status_page: adw::StatusPage,
+    cards: Rc<RefCell<Vec<DueCard>>>,
+    position: Rc<Cell<usize>>,
+}
+
+pub struct ReviewPage {
+    widget: gtk::Box,
+    session: ReviewSession,
+}
+
+impl ReviewPage {
+    pub fn new(state: SharedState) -> Self {
+        let widget = gtk::Box::new(gtk::Orientation::Vertical, 16);
+        widget.add_css_class("content-area");
+
+        let heading = gtk::Label::new(Some("Review"));
+        heading.add_css_class("heading");
+        widget.append(&heading);
+
+        let subtitle =
+            gtk::Label::new(Some("Revisit quiz questions right before you forget them."));
+        subtitle.add_css_class("subtitle");
+        widget.append(&subtitle);
+
+        let status_page = adw::StatusPage::new();
+        status_page.set_icon_name(Some("object-select-symbolic"));
+        status_page.set_margin_top(16);
+        widget.append(&status_page);
+
+        let scroll = gtk::ScrolledWindow::new();
+        scroll.set_vexpand(true);
+        scroll.set_hexpand(true);
+
+        let content_box = gtk::Box::new(gtk::Orientation::Vertical, 12);
+        content_box.set_margin_start(16);
+        content_box.set_margin_end(16);
+        content_box.set_margin_bottom(16);
+        scroll.set_child(Some(&content_box));
+        widget.append(&scroll);
+
+        let session = ReviewSession {
+            state,
+            content_box,
+            status_page,
+            cards: Rc::new(RefCell::new(Vec::new())),
+            position: Rc::new(Cell::new(0)),
+        };
+
+        Self { widget, session }
+    }
+
+    pub fn widget(&self) -> &gtk::Box {
+        &self.widget
+    }
+
+    pub fn refresh(&self) {
+        let session = &self.session;
+        session.cards.borrow_mut().clear();
+        session.position.set(0);
+
+        let backend = session.state.borrow().backend.clone();
+        let Some(ctx) = backend else {
+            session.show_status("No Backend", "No backend connected.");
+            return;
+        };
+
+        let today = chrono::Local::now().date_naive();
+        match ServiceFactory::review(&ctx).due_cards(today) {
+            Ok(cards) if cards.is_empty() => session.show_status(
+                "All Caught Up",
+                "No cards are due today. Take a quiz to add more questions to your review queue.",
+            ),
+            Ok(cards) => {
+                *session.cards.borrow_mut() = cards;
+                session.show_current();
+            },
+            Err(e) => {
+                session.show_status("Error Loading Review", &format!("Failed to load cards: {e}"))
+            },
+        }
+    }
+}
+
+impl ReviewSession {
+    fn clear(&self) {
+        while let Some(child) = self.content_box.first_child() {
+            self.content_box.remove(&child);
+        }
+    }
+
+    fn show_status(&self, title: &str, description: &str) {
+        self.clear();
+        self.content_box.set_visible(false);
+        self.status_page.set_title(title);
+        self.status_page.set_description(Some(description));
+        self.status_page.set_visible(true);
+    }
+
+    /// Renders the card at the current position, or the end-of-session status.
+    fn show_current(&self) {
+        let cards = self.cards.borrow();
+        let idx = self.position.get();
+        let Some(due) = cards.get(idx) else {
+            let reviewed = cards.len();
+            drop(cards);
+            self.show_status(
+                "Done for Today",
+                &format!(
+                    "You reviewed {reviewed} card{}. Come back tomorrow for the next batch.",
+                    if reviewed == 1 { "" } else { "s" }
+                ),
+            );
+            return;
+        };
+
+        self.clear();
+        self.status_page.set_visible(false);
+        self.content_box.set_visible(true);
+
+        let progress_sec = gtk::Box::new(gtk::Orientation::Vertical, 6);
+        progress_sec.set_margin_bottom(12);
+
+        let counter = gtk::Label::new(Some(&format!("Card {} of {}", idx + 1, cards.len())));
+        counter.add_css_class("subtitle");
+        counter.set_halign(gtk::Align::Start);
+        progress_sec.append(&counter);
+
+        let progress_bar = gtk::ProgressBar::new();
+        progress_bar.set_fraction(idx as f64 / cards.len() as f64);
+        progress_sec.append(&progress_bar);
+        self.content_box.append(&progress_sec);
+
+        if !due.video_title.is_empty() {
+            let source = gtk::Label::new(Some(&due.video_title));
+            source.add_css_class("caption");
+            source.add_css_class("dim-label");
+            source.set_halign(gtk::Align::Start);
+            self.content_box.append(&source);
+        }
+
+        let question = due.card.question();
+        let q_label = gtk::Label::new(Some(&question.question));
+        q_label.set_wrap(true);
+        q_label.set_halign(gtk::Align::Start);
+        q_label.add_css_class("heading");
+        q_label.set_margin_bottom(16);
+        self.content_box.append(&q_label);
+
+        let options_box = gtk::Box::new(gtk::Orientation::Vertical, 6);
+        let mut option_buttons = Vec::new();
+        for (oi, opt) in question.options.iter().enumerate() {
+            let label = gtk::Label::new(Some(&format!("{}. {}", (b'A' + oi as u8) as char, opt)));
+            label.set_wrap(true);
+            label.set_halign(gtk::Align::Start);
+
+            let btn = gtk::Button::new();
+            btn.set_child(Some(&label));
+            btn.add_css_class("quiz-option");
+            options_box.append(&btn);
+            option_buttons.push(btn);
+        }
+        self.content_box.append(&options_box);
+
+        let answer_box = gtk::Box::new(gtk::Orientation::Vertical, 12);
+        self.content_box.append(&answer_box);
+
+        for (oi, btn) in option_buttons.iter().enumerate() {
+            let session = self.clone();
+            let buttons = option_buttons.clone();
+            let answer_box = answer_box.clone();
+            btn.connect_clicked(move |_| session.reveal(oi, &buttons, &answer_box));
+        }
+    }
+
+    /// Marks the chosen option, shows the explanation and offers the grades for it.
+    fn reveal(&self, selected: usize, buttons: &[gtk::Button], answer_box: &gtk::Box) {
+        let cards = self.cards.borrow();
+        let Some(due) = cards.get(self.position.get()) else {
+            return;
+        };
+        let question = due.card.question();
+        let correct = due.card.is_correct(selected);
+
+        for (oi, btn) in buttons.iter().enumerate() {
+            btn.set_sensitive(false);
+            if oi == question.correct_index {
+                btn.add_css_class("quiz-option-correct");
+            } else if oi == selected {
+                btn.add_css_class("quiz-option-incorrect");
+            }
+        }
+
+        let expl_box = gtk::Box::new(gtk::Orientation::Vertical, 6);
+        expl_box.add_css_class("explanation-box");
+
+        let expl_title = gtk::Label::new(Some(if correct { "Correct" } else { "Not quite" }));
+        expl_title.add_css_class("title");
+        expl_title.add_css_class(if correct { "success" } else { "error" });
+        expl_title.set_halign(gtk::Align::Start);
+        expl_box.append(&expl_title);
+
+        let expl_text = gtk::Label::new(Some(&question.explanation));
+        expl_text.set_wrap(true);
+        expl_text.set_halign(gtk::Align::Start);
+        expl_text.add_css_class("subtitle");
+        expl_box.append(&expl_text);
+        answer_box.append(&expl_box);
+
+        let btn_box = gtk::Box::new(gtk::Orientation::Horizontal, 8);
+        btn_box.set_halign(gtk::Align::End);
+
+        // A wrong answer always starts the card over; a right one is graded by how hard it felt.
+        let grades: &[(ReviewGrade, &str)] = if correct {
+            &[(ReviewGrade::Hard, "Hard"), (ReviewGrade::Good, "Good"), (ReviewGrade::Easy, "Easy")]
+        } else {
+            &[(ReviewGrade::Again, "Next")]
+        };
+        for &(grade, label) in grades {
+            let btn = gtk::Button::with_label(label);
+            if matches!(grade, ReviewGrade::Good | ReviewGrade::Again) {
+                btn.add_css_class("suggested-action");
+            }
+            let session = self.clone();
+            btn.connect_clicked(move |_| session.grade(grade));
+            btn_box.append(&btn);
+        }
+        answer_box.append(&btn_box);
+    }
+
+    /// Records the grade for the current card and advances to the next one.
+    fn grade(&self, grade: ReviewGrade) {
+        let card_id = match self.cards.borrow().get(self.position.get()) {
+            Some(due) => *due.card.id(),
+            None => return,
+        };
+
+        let backend = self.state.borrow().backend.clone();
+        if let Some(ctx) = backend {
+            let input =
+                RecordReviewInput { card_id, grade, today: chrono::Local::now().date_naive() };
+            if let Err(e) = ServiceFactory::review(&ctx).record_answer(input) {
+                Toast::show_error(&format!("Failed to save review: {e}"));
+                return;
+            }
+        }
+
+        self.position.set(self.position.get() + 1);
+        self.show_current();
+    }
+}
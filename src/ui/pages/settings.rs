@@ -3,8 +3,8 @@ use std::rc::Rc;
 use adw::NavigationView;
 use adw::prelude::*;
 
-use crate::application::ServiceFactory;
-use crate::domain::ports::SecretStore;
+use crate::application::{LlmProvider, ServiceFactory};
+use crate::domain::ports::{KeystoreError, SecretStore};
 use crate::domain::value_objects::VideoQuality;
 use crate::ui::state::SharedState;
 use crate::ui::toast::Toast;
@@ -14,11 +14,18 @@ use crate::ui::widgets::QualitySelector;
 /// If the user submits this exact string, the key is left unchanged.
 const MASKED_KEY: &str = "●●●●●●●●";
 
+/// Providers in the order of the AI provider combo row.
+const PROVIDERS: [LlmProvider; 2] = [LlmProvider::Gemini, LlmProvider::OpenAiCompatible];
+
 pub struct SettingsPage {
     widget: gtk::Box,
     state: SharedState,
     _nav: Rc<NavigationView>,
+    provider_row: adw::ComboRow,
     api_key_entry: adw::EntryRow,
+    base_url_entry: adw::EntryRow,
+    model_entry: adw::EntryRow,
+    server_key_entry: adw::EntryRow,
     api_status_label: gtk::Label,
     db_path_row: adw::ActionRow,
     discord_entry: adw::EntryRow,
@@ -27,6 +34,10 @@ pub struct SettingsPage {
     quality_selector: QualitySelector,
     cognitive_limit_row: adw::SpinRow,
     batch_size_row: adw::SpinRow,
+    module_minutes_row: adw::SpinRow,
+    topic_grouping_switch: adw::SwitchRow,
+    auto_complete_row: adw::SpinRow,
+    watch_folders_switch: adw::SwitchRow,
     save_status_label: gtk::Label,
     save_btn: gtk::Button,
 }
@@ -52,15 +63,59 @@ impl SettingsPage {
         prefs_box.set_margin_bottom(16);
 
         let api_group = adw::PreferencesGroup::new();
-        api_group.set_title("Gemini API");
-        api_group
-            .set_description(Some("API key for AI companion, quiz generation, and summaries."));
+        api_group.set_title("AI Provider");
+        api_group.set_description(Some(
+            "Service behind the AI companion, quiz generation, and summaries. \
+             An OpenAI-compatible server such as Ollama keeps everything on this machine.",
+        ));
+
+        let provider_row = adw::ComboRow::new();
+        provider_row.set_title("Provider");
+        provider_row.set_model(Some(&gtk::StringList::new(&[
+            "Gemini",
+            "OpenAI-compatible (Ollama, llama.cpp, vLLM)",
+        ])));
+        api_group.add(&provider_row);
 
         let api_key_entry = adw::EntryRow::new();
         api_key_entry.set_title("Gemini API Key");
         api_key_entry.set_input_purpose(gtk::InputPurpose::Password);
         api_group.add(&api_key_entry);
 
+        let base_url_entry = adw::EntryRow::new();
+        base_url_entry.set_title("Server URL (e.g. http://localhost:11434/v1)");
+        api_group.add(&base_url_entry);
+
+        let model_entry = adw::EntryRow::new();
+        model_entry.set_title(&format!(
+            "Model (default: {})",
+            LlmProvider::OpenAiCompatible.default_model()
+        ));
+        api_group.add(&model_entry);
+
+        let server_key_entry = adw::EntryRow::new();
+        server_key_entry.set_title("Server API Key (optional)");
+        server_key_entry.set_input_purpose(gtk::InputPurpose::Password);
+        api_group.add(&server_key_entry);
+
+        // Only the rows of the selected provider are shown.
+        let show_provider_rows = {
+            let api_key_entry = api_key_entry.clone();
+            let base_url_entry = base_url_entry.clone();
+            let model_entry = model_entry.clone();
+            let server_key_entry = server_key_entry.clone();
+            move |row: &adw::ComboRow| {
+                let local =
+                    PROVIDERS.get(row.selected() as usize) == Some(&LlmProvider::OpenAiCompatible);
+                api_key_entry.set_visible(!local);
+                base_url_entry.set_visible(local);
+                model_entry.set_visible(local);
+                server_key_entry.set_visible(local);
+            }
+        };
+        show_provider_rows(&provider_row);
+        provider_row.connect_selected_notify(show_provider_rows);
+
         let api_status_label = gtk::Label::new(None);
         api_status_label.set_halign(gtk::Align::Start);
         api_status_label.add_css_class("subtitle");
@@ -122,8 +177,44 @@ impl SettingsPage {
         batch_size_row.set_digits(0);
         learning_group.add(&batch_size_row);
 
+        let module_minutes_row = adw::SpinRow::new(None::<&gtk::Adjustment>, 5.0, 0);
+        module_minutes_row.set_title("Target Module Length");
+        module_minutes_row
+            .set_subtitle("Minutes of video per module instead of a batch size. 0 turns it off.");
+        module_minutes_row.set_range(0.0, 300.0);
+        module_minutes_row.set_value(0.0);
+        module_minutes_row.set_digits(0);
+        learning_group.add(&module_minutes_row);
+
+        let topic_grouping_switch = adw::SwitchRow::new();
+        topic_grouping_switch.set_title("Group by Topic");
+        topic_grouping_switch.set_subtitle(
+            "Start new modules where titles and descriptions change topic on import.",
+        );
+        learning_group.add(&topic_grouping_switch);
+
+        let auto_complete_row = adw::SpinRow::new(None::<&gtk::Adjustment>, 5.0, 0);
+        auto_complete_row.set_title("Auto-complete Threshold");
+        auto_complete_row
+            .set_subtitle("Percent of a video that must be watched to mark it complete.");
+        auto_complete_row.set_range(50.0, 100.0);
+        auto_complete_row.set_value(90.0);
+        auto_complete_row.set_digits(0);
+        learning_group.add(&auto_complete_row);
+
         prefs_box.append(&learning_group);
 
+        let library_group = adw::PreferencesGroup::new();
+        library_group.set_title("Local Library");
+
+        let watch_folders_switch = adw::SwitchRow::new();
+        watch_folders_switch.set_title("Watch Course Folders");
+        watch_folders_switch
+            .set_subtitle("Add new, moved and deleted files to local courses while the app runs.");
+        library_group.add(&watch_folders_switch);
+
+        prefs_box.append(&library_group);
+
         let theme_group = adw::PreferencesGroup::new();
         theme_group.set_title("Appearance");
 
@@ -151,7 +242,11 @@ impl SettingsPage {
             widget,
             state: state.clone(),
             _nav: nav,
+            provider_row,
             api_key_entry,
+            base_url_entry,
+            model_entry,
+            server_key_entry,
             api_status_label,
             db_path_row,
             discord_entry,
@@ -160,12 +255,21 @@ impl SettingsPage {
             quality_selector,
             cognitive_limit_row,
             batch_size_row,
+            module_minutes_row,
+            topic_grouping_switch,
+            auto_complete_row,
+            watch_folders_switch,
             save_status_label,
             save_btn,
         };
 
         let state_cl = state;
+        let provider_row_cl = page.provider_row.clone();
         let api_entry = page.api_key_entry.clone();
+        let base_url_entry_cl = page.base_url_entry.clone();
+        let model_entry_cl = page.model_entry.clone();
+        let server_key_entry_cl = page.server_key_entry.clone();
+        let api_status = page.api_status_label.clone();
         let discord_entry_cl = page.discord_entry.clone();
         let cookie_entry_cl = page.cookie_entry.clone();
         let theme_sw = page.theme_switch.clone();
@@ -173,41 +277,51 @@ impl SettingsPage {
         let quality_sel = page.quality_selector.widget().clone();
         let cognitive_limit_row_cl = page.cognitive_limit_row.clone();
         let batch_size_row_cl = page.batch_size_row.clone();
+        let module_minutes_row_cl = page.module_minutes_row.clone();
+        let topic_grouping_switch_cl = page.topic_grouping_switch.clone();
+        let auto_complete_row_cl = page.auto_complete_row.clone();
+        let watch_folders_switch_cl = page.watch_folders_switch.clone();
 
-        // When user starts typing in the API key entry, clear the masked placeholder
+        // When user starts typing in an API key entry, clear the masked placeholder
         // so the real key can be entered fresh.
-        let api_entry_change = page.api_key_entry.clone();
-        api_entry_change.connect_changed(move |entry| {
-            let text = entry.text();
-            // If the text still equals the placeholder AND has the placeholder length,
-            // it means the user hasn't modified it yet — do nothing.
-            // Once it differs (user added/removed a char) the natural text is in place.
-            if text.as_str() != MASKED_KEY && text.as_str().contains('●') {
-                // User started editing inside the masked value — clear the field so they
-                // can enter the new key cleanly.
-                entry.set_text("");
-            }
-        });
+        for key_entry in [&page.api_key_entry, &page.server_key_entry] {
+            key_entry.connect_changed(move |entry| {
+                let text = entry.text();
+                // If the text still equals the placeholder AND has the placeholder length,
+                // it means the user hasn't modified it yet — do nothing.
+                // Once it differs (user added/removed a char) the natural text is in place.
+                if text.as_str() != MASKED_KEY && text.as_str().contains('●') {
+                    // User started editing inside the masked value — clear the field so they
+                    // can enter the new key cleanly.
+                    entry.set_text("");
+                }
+            });
+        }
 
         page.save_btn.connect_clicked(move |_| {
             let s = state_cl.borrow();
             if let Some(ref ctx) = s.backend {
-                let key = api_entry.text().as_str().trim().to_string();
-                // Only save if the user actually typed a new key (not the masked placeholder).
-                if !key.is_empty() && key != MASKED_KEY {
-                    match ctx.keystore.store("gemini_api_key", &key) {
-                        Ok(_) => {
-                            // Hot-swap the LLM so summarize/quiz buttons become active
-                            // immediately without requiring an app restart.
-                            if let Err(e) = ctx.reload_llm() {
-                                log::warn!("reload_llm failed after API key save: {e}");
-                            }
-                            status.set_text("API key saved.");
-                        },
-                        Err(e) => {
-                            status.set_text(&format!("Failed to save key: {}", e));
-                        },
-                    }
+                let provider =
+                    PROVIDERS.get(provider_row_cl.selected() as usize).copied().unwrap_or_default();
+                match save_llm_settings(
+                    ctx.keystore.as_ref(),
+                    provider,
+                    &api_entry.text(),
+                    &base_url_entry_cl.text(),
+                    &model_entry_cl.text(),
+                    &server_key_entry_cl.text(),
+                ) {
+                    Ok(()) => {
+                        // Hot-swap the LLM so summarize/quiz buttons become active
+                        // immediately without requiring an app restart.
+                        if let Err(e) = ctx.reload_llm() {
+                            log::warn!("reload_llm failed after AI settings save: {e}");
+                        }
+                        api_status.set_text(llm_status(ctx.has_llm(), provider));
+                    },
+                    Err(e) => {
+                        status.set_text(&format!("Failed to save AI settings: {}", e));
+                    },
                 }
 
                 let discord_id = discord_entry_cl.text().as_str().to_string();
@@ -241,9 +355,12 @@ impl SettingsPage {
                 use crate::application::use_cases::UpdatePreferencesInput;
                 let uc = ServiceFactory::preferences(ctx);
                 let input = UpdatePreferencesInput {
-                    ml_boundary_enabled: false,
+                    ml_boundary_enabled: topic_grouping_switch_cl.is_active(),
                     cognitive_limit_minutes: cognitive_limit_row_cl.value() as u32,
                     boundary_batch_size: batch_size_row_cl.value() as u32,
+                    module_target_minutes: module_minutes_row_cl.value() as u32,
+                    auto_complete_percent: auto_complete_row_cl.value() as u32,
+                    watch_local_folders: watch_folders_switch_cl.is_active(),
                     right_panel_visible: s.right_panel_visible,
                     right_panel_width: s.right_panel_width as u32,
                     onboarding_completed: s.onboarding_completed,
@@ -256,6 +373,11 @@ impl SettingsPage {
                         s2.preferred_quality = prefs.preferred_quality();
                         s2.session_quality = prefs.preferred_quality();
                         s2.cognitive_limit_minutes = prefs.cognitive_limit_minutes();
+                        s2.boundary_batch_size = prefs.boundary_batch_size();
+                        s2.module_target_minutes = prefs.module_target_minutes();
+                        s2.ml_boundary_enabled = prefs.ml_boundary_enabled();
+                        s2.auto_complete_percent = prefs.auto_complete_percent();
+                        s2.watch_local_folders = prefs.watch_local_folders();
                         status.set_text("Settings saved.");
                     },
                     Err(e) => {
@@ -286,18 +408,23 @@ impl SettingsPage {
         if let Some(ref ctx) = state.backend {
             self.db_path_row.set_subtitle(&ctx.config.database_url);
 
-            match ctx.keystore.retrieve("gemini_api_key") {
-                Ok(Some(_)) => {
-                    // Show a visual placeholder to indicate a key is stored,
-                    // without exposing the actual key value.
-                    self.api_key_entry.set_text(MASKED_KEY);
-                    self.api_status_label.set_text("API key is set");
-                },
-                _ => {
-                    self.api_key_entry.set_text("");
-                    self.api_status_label.set_text("No API key set");
-                },
-            }
+            let stored = |key: &str| ctx.keystore.retrieve(key).ok().flatten();
+            let provider = ctx
+                .config
+                .llm_provider
+                .or_else(|| stored("llm_provider").and_then(|p| p.parse().ok()))
+                .unwrap_or_default();
+            let index = PROVIDERS.iter().position(|p| *p == provider).unwrap_or_default();
+            self.provider_row.set_selected(index as u32);
+
+            // Show a visual placeholder to indicate a key is stored,
+            // without exposing the actual key value.
+            let masked = |key: &str| if stored(key).is_some() { MASKED_KEY } else { "" };
+            self.api_key_entry.set_text(masked("gemini_api_key"));
+            self.server_key_entry.set_text(masked("llm_api_key"));
+            self.base_url_entry.set_text(&stored("llm_base_url").unwrap_or_default());
+            self.model_entry.set_text(&stored("llm_model").unwrap_or_default());
+            self.api_status_label.set_text(llm_status(ctx.has_llm(), provider));
 
             if let Ok(Some(id)) = ctx.keystore.retrieve("discord_client_id") {
                 self.discord_entry.set_text(&id);
@@ -310,7 +437,11 @@ impl SettingsPage {
             self.quality_selector.set_quality(state.preferred_quality);
 
             self.batch_size_row.set_value(state.boundary_batch_size as f64);
+            self.module_minutes_row.set_value(state.module_target_minutes as f64);
+            self.topic_grouping_switch.set_active(state.ml_boundary_enabled);
             self.cognitive_limit_row.set_value(state.cognitive_limit_minutes as f64);
+            self.auto_complete_row.set_value(state.auto_complete_percent as f64);
+            self.watch_folders_switch.set_active(state.watch_local_folders);
 
             let is_dark =
                 matches!(adw::StyleManager::default().color_scheme(), adw::ColorScheme::ForceDark);
@@ -320,3 +451,43 @@ impl SettingsPage {
         }
     }
 }
+
+/// Stores the AI provider settings. Key fields still showing the masked placeholder keep
+/// the stored key; an emptied server field removes the stored value.
+fn save_llm_settings(
+    keystore: &dyn SecretStore,
+    provider: LlmProvider,
+    gemini_key: &str,
+    base_url: &str,
+    model: &str,
+    server_key: &str,
+) -> Result<(), KeystoreError> {
+    keystore.store("llm_provider", provider.as_str())?;
+
+    let gemini_key = gemini_key.trim();
+    // Only save if the user actually typed a new key (not the masked placeholder).
+    if !gemini_key.is_empty() && gemini_key != MASKED_KEY {
+        keystore.store("gemini_api_key", gemini_key)?;
+    }
+
+    for (key, value) in
+        [("llm_base_url", base_url), ("llm_model", model), ("llm_api_key", server_key)]
+    {
+        match value.trim() {
+            MASKED_KEY => {},
+            "" => keystore.delete(key)?,
+            value => keystore.store(key, value)?,
+        }
+    }
+    Ok(())
+}
+
+/// Status line under the AI provider settings.
+fn llm_status(ready: bool, provider: LlmProvider) -> &'static str {
+    match (ready, provider) {
+        (true, LlmProvider::Gemini) => "API key is set",
+        (false, LlmProvider::Gemini) => "No API key set",
+        (true, LlmProvider::OpenAiCompatible) => "Server is configured",
+        (false, LlmProvider::OpenAiCompatible) => "No server URL set",
+    }
+}
@@ -5,12 +5,33 @@ use std::sync::Arc;
 
 use adw::NavigationPage;
 use adw::prelude::*;
+use chrono::{DateTime, Utc};
 
+use crate::application::ServiceFactory;
+use crate::application::context::AppContext;
+use crate::application::use_cases::SavePositionInput;
 use crate::domain::ports::StreamResolver;
+use crate::domain::value_objects::{VideoId, WatchedRanges};
 use crate::infrastructure::video::VideoPlayer;
 use crate::ui::state::SharedState;
 use crate::ui::toast::Toast;
-use crate::ui::widgets::QualityDropDown;
+use crate::ui::types::{PositionCallback, RefreshCallback};
+use crate::ui::widgets::{FlashcardsPanel, KeyContentPanel, QualityDropDown};
+
+/// How often the playback position is persisted while playing, in 250 ms timer ticks.
+const SAVE_POSITION_EVERY_TICKS: u32 = 20;
+/// Largest position advance between two ticks still counted as continuous playback.
+/// Anything larger (or any step backwards) is a seek and leaves a gap in the watched ranges.
+const MAX_TICK_ADVANCE_MS: u64 = 1_500;
+/// A watch session ends once playback has not advanced for this long (pause, end of stream).
+const WATCH_SESSION_IDLE_SECS: i64 = 30;
+
+/// A stretch of playback not yet written to the watch log.
+struct OpenWatchSession {
+    started_at: DateTime<Utc>,
+    last_played_at: DateTime<Utc>,
+    played_ms: u64,
+}
 
 fn fmt_ns(ns: u64) -> String {
     let total_secs = ns / 1_000_000_000;
@@ -24,6 +45,55 @@ fn fmt_ns(ns: u64) -> String {
     }
 }
 
+/// Persists the position and the sections played since the last save.
+/// Announces when this crosses the auto-complete threshold.
+fn save_progress(
+    ctx: &AppContext,
+    auto_complete_percent: u32,
+    video_id: VideoId,
+    position_ns: u64,
+    duration_ns: Option<u64>,
+    played: &RefCell<WatchedRanges>,
+) {
+    let input = SavePositionInput {
+        video_id,
+        position_ms: position_ns / 1_000_000,
+        played: std::mem::take(&mut *played.borrow_mut()),
+        duration_ms: duration_ns.map(|ns| ns / 1_000_000),
+        auto_complete_percent,
+    };
+    let unsaved = input.played.clone();
+    match ServiceFactory::playback(ctx).save_position(input) {
+        Ok(output) if output.marked_complete => Toast::show("Video marked as complete."),
+        Ok(_) => {},
+        Err(e) => {
+            // Keep the played sections for the next attempt.
+            played.borrow_mut().merge(&unsaved);
+            log::warn!("Failed to save playback position: {}", e);
+        },
+    }
+}
+
+/// Writes the open watch session, if any, to the watch log.
+fn close_watch_session(
+    ctx: &AppContext,
+    video_id: VideoId,
+    session: &RefCell<Option<OpenWatchSession>>,
+) {
+    let Some(open) = session.borrow_mut().take() else {
+        return;
+    };
+    let played_secs = u32::try_from(open.played_ms / 1_000).unwrap_or(u32::MAX);
+    if let Err(e) = ServiceFactory::playback(ctx).record_session(
+        video_id,
+        open.started_at,
+        open.last_played_at,
+        played_secs,
+    ) {
+        log::warn!("Failed to record watch session: {}", e);
+    }
+}
+
 fn find_parent<T: IsA<gtk::Widget>>(start: &gtk::Widget) -> Option<T> {
     let mut p = start.parent();
     while let Some(w) = p {
@@ -125,15 +195,30 @@ pub struct VideoPlayerPage {
     video_title: gtk::Label,
     player_frame: gtk::Frame,
     status_page: adw::StatusPage,
+    /// Shown instead of the player when a local video's file is gone.
+    missing_page: adw::StatusPage,
+    refresh_cb: RefreshCallback,
+    /// Called with the playback position when "Explain This Moment" is clicked.
+    explain_moment_cb: PositionCallback,
     suppress_seek: Rc<Cell<bool>>,
+    /// Resume position (ns) applied once the new stream reports a duration.
+    pending_seek: Rc<Cell<Option<u64>>>,
+    /// Sections played since the last save.
+    played: Rc<RefCell<WatchedRanges>>,
+    /// Position (ms) seen on the previous timer tick, to tell playback from seeks.
+    last_tick_ms: Rc<Cell<Option<u64>>>,
+    watch_session: Rc<RefCell<Option<OpenWatchSession>>>,
     current_video_source: RefCell<Option<String>>,
     current_video_id: RefCell<Option<crate::domain::value_objects::VideoId>>,
     suppress_quality: Rc<Cell<bool>>,
     is_playing: Rc<Cell<bool>>,
     summarize_btn: gtk::Button,
     quiz_btn: gtk::Button,
+    explain_btn: gtk::Button,
     quizzes_container: gtk::Box,
     transcript_lbl: gtk::Label,
+    key_content: KeyContentPanel,
+    flashcards: FlashcardsPanel,
     details_box: gtk::Box,
     is_fullscreen: Rc<Cell<bool>>,
     fullscreen_btn: gtk::Button,
@@ -170,6 +255,20 @@ impl VideoPlayerPage {
         player_frame.set_child(Some(&status_page));
         widget.append(&player_frame);
 
+        let missing_page = adw::StatusPage::new();
+        missing_page.set_title("Video File Missing");
+        missing_page.set_description(Some(
+            "The file was moved or deleted. If the course folder was moved or renamed, \
+             locate it to relink every video.",
+        ));
+        missing_page.set_icon_name(Some("folder-open-symbolic"));
+        let locate_btn = gtk::Button::with_label("Locate Course Folder…");
+        locate_btn.add_css_class("pill");
+        locate_btn.add_css_class("suggested-action");
+        locate_btn.set_halign(gtk::Align::Center);
+        missing_page.set_child(Some(&locate_btn));
+        let refresh_cb: RefreshCallback = Rc::new(RefCell::new(None));
+
         let controls = gtk::Box::new(gtk::Orientation::Vertical, 4);
         controls.set_margin_start(16);
         controls.set_margin_end(16);
@@ -253,6 +352,13 @@ impl VideoPlayerPage {
         quiz_btn.set_has_tooltip(true);
         right_box.append(&quiz_btn);
 
+        // Explain moment button
+        let explain_btn = gtk::Button::with_label("Explain This Moment");
+        explain_btn.add_css_class("pill");
+        explain_btn.add_css_class("flat");
+        explain_btn.set_has_tooltip(true);
+        right_box.append(&explain_btn);
+
         // Dynamic notes popup button
         let notes_btn = gtk::Button::with_label("Edit Notes (Ctrl+N)");
         notes_btn.add_css_class("pill");
@@ -434,6 +540,14 @@ impl VideoPlayerPage {
         transcript_frame.set_child(Some(&transcript_lbl));
         details_box.append(&transcript_frame);
 
+        // 7. Key Points & Terms Section
+        let key_content = KeyContentPanel::new();
+        details_box.append(key_content.widget());
+
+        // 8. Flashcards Section
+        let flashcards = FlashcardsPanel::new(state.clone());
+        details_box.append(flashcards.widget());
+
         widget.append(&details_box);
 
         let nav_pages = Rc::new(RefCell::new(Rc::new(HashMap::new())));
@@ -442,6 +556,7 @@ impl VideoPlayerPage {
         let sum_state = state.clone();
         let lbl_clone = transcript_lbl.clone();
         let summary_spinner_cl = summary_spinner.clone();
+        let key_content_cl = key_content.clone();
         summarize_btn.connect_clicked(move |_| {
             let s = sum_state.borrow();
             let video_id_str = match s.current_video_id {
@@ -462,20 +577,21 @@ impl VideoPlayerPage {
                 };
                 Toast::show("Summarization started...");
                 summary_spinner_cl.start();
-                let (tx, rx) = std::sync::mpsc::channel::<Result<String, String>>();
+                let (tx, rx) = std::sync::mpsc::channel::<
+                    Result<crate::application::use_cases::SummarizeVideoOutput, String>,
+                >();
                 crate::infrastructure::tokio_bridge::spawn(async move {
-                    let res = match uc.execute(input).await {
-                        Ok(out) => Ok(out.summary),
-                        Err(e) => Err(e.to_string()),
-                    };
+                    let res = uc.execute(input).await.map_err(|e| e.to_string());
                     let _ = tx.send(res);
                 });
 
                 let lbl = lbl_clone.clone();
                 let summary_spinner_cl2 = summary_spinner_cl.clone();
+                let key_content = key_content_cl.clone();
                 glib::idle_add_local(move || match rx.try_recv() {
-                    Ok(Ok(summary)) => {
-                        lbl.set_text(&summary);
+                    Ok(Ok(out)) => {
+                        lbl.set_text(&out.summary);
+                        key_content.set_content(&out.key_points, &out.key_terms);
                         Toast::show("Summary generated successfully!");
                         summary_spinner_cl2.stop();
                         glib::ControlFlow::Break
@@ -629,20 +745,61 @@ impl VideoPlayerPage {
             video_title,
             player_frame,
             status_page,
+            missing_page,
+            refresh_cb: refresh_cb.clone(),
+            explain_moment_cb: Rc::new(RefCell::new(None)),
             suppress_seek: Rc::new(Cell::new(false)),
+            pending_seek: Rc::new(Cell::new(None)),
+            played: Rc::new(RefCell::new(WatchedRanges::new())),
+            last_tick_ms: Rc::new(Cell::new(None)),
+            watch_session: Rc::new(RefCell::new(None)),
             current_video_source: RefCell::new(None),
             current_video_id: RefCell::new(None),
             suppress_quality: Rc::new(Cell::new(false)),
             is_playing,
             summarize_btn,
             quiz_btn,
+            explain_btn,
             quizzes_container,
             transcript_lbl,
+            key_content,
+            flashcards,
             details_box,
             is_fullscreen: Rc::new(Cell::new(false)),
             fullscreen_btn,
         };
 
+        let locate_state = page.state.clone();
+        let locate_widget = page.widget.clone();
+        locate_btn.connect_clicked(move |_| {
+            let course_id = locate_state
+                .borrow()
+                .current_course_id
+                .as_deref()
+                .and_then(|id| id.parse::<crate::domain::value_objects::CourseId>().ok());
+            let Some(course_id) = course_id else {
+                return;
+            };
+            let parent = locate_widget.root().and_then(|r| r.downcast::<gtk::Window>().ok());
+            crate::ui::dialogs::relink_dialog::show_relink_dialog(
+                locate_state.clone(),
+                course_id,
+                parent.as_ref(),
+                refresh_cb.borrow().clone(),
+            );
+        });
+
+        // Explain the transcript leading up to the current position
+        let player_explain = page.player.clone();
+        let explain_cb = page.explain_moment_cb.clone();
+        page.explain_btn.connect_clicked(move |_| {
+            let position = player_explain.borrow().as_ref().and_then(|p| p.position());
+            let cb = explain_cb.borrow().clone();
+            if let (Some(pos), Some(cb)) = (position, cb) {
+                cb((pos / 1_000_000_000) as u32);
+            }
+        });
+
         // Wire GStreamer seek scale gestures
         let player_seek = page.player.clone();
         let suppress_seek_gest = page.suppress_seek.clone();
@@ -810,6 +967,14 @@ impl VideoPlayerPage {
         &self.widget
     }
 
+    pub fn set_refresh_cb(&self, cb: Rc<dyn Fn()>) {
+        *self.refresh_cb.borrow_mut() = Some(cb);
+    }
+
+    pub fn set_explain_moment_cb(&self, cb: Rc<dyn Fn(u32)>) {
+        *self.explain_moment_cb.borrow_mut() = Some(cb);
+    }
+
     pub fn refresh(&self) {
         // Dynamically check LLM availability so buttons update after key is added in Settings
         let has_llm = self
@@ -821,13 +986,20 @@ impl VideoPlayerPage {
             .is_some();
         self.summarize_btn.set_sensitive(has_llm);
         self.quiz_btn.set_sensitive(has_llm);
+        self.explain_btn.set_sensitive(has_llm);
+        self.flashcards.set_llm_available(has_llm);
         if has_llm {
             self.summarize_btn.set_tooltip_text(Some("Generate an AI summary of this video"));
             self.quiz_btn.set_tooltip_text(Some("Generate a quiz based on this video"));
+            self.explain_btn
+                .set_tooltip_text(Some("Ask the AI companion about what was just said"));
         } else {
             self.summarize_btn
-                .set_tooltip_text(Some("Requires a Gemini API key — add one in Settings"));
-            self.quiz_btn.set_tooltip_text(Some("Requires a Gemini API key — add one in Settings"));
+                .set_tooltip_text(Some("Requires an AI provider — configure one in Settings"));
+            self.quiz_btn
+                .set_tooltip_text(Some("Requires an AI provider — configure one in Settings"));
+            self.explain_btn
+                .set_tooltip_text(Some("Requires an AI provider — configure one in Settings"));
         }
 
         let state = self.state.borrow();
@@ -839,6 +1011,8 @@ impl VideoPlayerPage {
                 self.video_title.set_text("No video selected.");
                 self.player_frame.set_child(Some(&self.status_page));
                 self.transcript_lbl.set_text("No transcript loaded.");
+                self.key_content.set_content(&[], &[]);
+                self.flashcards.load(None);
                 return;
             },
         };
@@ -863,9 +1037,13 @@ impl VideoPlayerPage {
                     };
 
                     self.video_title.set_text(video.title());
+                    self.flashcards.load(Some(video_id));
 
                     if !is_same_video {
                         self.stop_timer();
+                        self.save_position(ctx);
+                        self.played.replace(WatchedRanges::new());
+                        self.last_tick_ms.set(None);
                         if let Some(ref p) = *self.player.borrow() {
                             p.stop();
                         }
@@ -891,10 +1069,31 @@ impl VideoPlayerPage {
                         self.player_frame.set_child(Some(picture));
 
                         let dur_ns = (video.duration_secs() as u64) * 1_000_000_000;
+                        let resume_ns = ServiceFactory::playback(ctx)
+                            .resume_position(&video_id, video.duration_secs())
+                            .unwrap_or_else(|e| {
+                                log::warn!("Failed to load playback position: {}", e);
+                                None
+                            })
+                            .map(|ms| ms * 1_000_000);
+                        let clip = video.clip();
+                        player.set_clip(clip.map(|clip| {
+                            (
+                                clip.start_secs() as u64 * 1_000_000_000,
+                                clip.end_secs() as u64 * 1_000_000_000,
+                            )
+                        }));
+                        // A clip always needs the initial seek to move past the source's start.
+                        self.pending_seek.set(resume_ns.or(clip.map(|_| 0)));
+                        self.suppress_seek.set(true);
                         self.seek_bar.set_range(0.0, dur_ns as f64);
-                        self.seek_bar.set_value(0.0);
-                        self.pos_label.set_text("00:00");
+                        self.seek_bar.set_value(resume_ns.unwrap_or(0) as f64);
+                        self.suppress_seek.set(false);
+                        self.pos_label.set_text(&fmt_ns(resume_ns.unwrap_or(0)));
                         self.dur_label.set_text(&fmt_ns(dur_ns));
+                        if let Some(ns) = resume_ns {
+                            Toast::show(&format!("Resuming at {}", fmt_ns(ns)));
+                        }
 
                         let quality = state.session_quality;
                         match video.source() {
@@ -951,6 +1150,13 @@ impl VideoPlayerPage {
                                     });
                                 }
                             },
+                            crate::domain::value_objects::VideoSource::LocalPath(path)
+                                if !std::path::Path::new(path.as_str()).is_file() =>
+                            {
+                                *self.current_video_source.borrow_mut() = None;
+                                log::warn!("Local video file is missing: {path}");
+                                self.player_frame.set_child(Some(&self.missing_page));
+                            },
                             crate::domain::value_objects::VideoSource::LocalPath(path) => {
                                 *self.current_video_source.borrow_mut() = None;
                                 player.set_volume(0.8);
@@ -971,6 +1177,7 @@ impl VideoPlayerPage {
                     } else {
                         self.transcript_lbl.set_text("No summary generated yet. Click 'Summarize' above to generate an AI summary.");
                     }
+                    self.key_content.set_content(video.key_points(), video.key_terms());
 
                     // Load quizzes related to this video dynamically
                     while let Some(child) = self.quizzes_container.first_child() {
@@ -1027,6 +1234,7 @@ impl VideoPlayerPage {
                     self.video_title.set_text("Video not found.");
                     self.player_frame.set_child(Some(&self.status_page));
                     self.transcript_lbl.set_text("No summary loaded.");
+                    self.key_content.set_content(&[], &[]);
                 },
                 Err(e) => {
                     self.reset_to_idle();
@@ -1034,6 +1242,7 @@ impl VideoPlayerPage {
                     self.video_title.set_text(&format!("Error: {}", e));
                     self.player_frame.set_child(Some(&self.status_page));
                     self.transcript_lbl.set_text("Error loading summary.");
+                    self.key_content.set_content(&[], &[]);
                 },
             }
         } else {
@@ -1042,11 +1251,15 @@ impl VideoPlayerPage {
             self.video_title.set_text("No backend connected.");
             self.player_frame.set_child(Some(&self.status_page));
             self.transcript_lbl.set_text("No backend connected.");
+            self.key_content.set_content(&[], &[]);
         }
     }
 
     pub fn stop(&self) {
         self.stop_timer();
+        if let Some(ref ctx) = self.state.borrow().backend {
+            self.save_position(ctx);
+        }
         if let Some(ref p) = *self.player.borrow() {
             p.stop();
         }
@@ -1064,18 +1277,88 @@ impl VideoPlayerPage {
         let pos_label = self.pos_label.clone();
         let dur_label = self.dur_label.clone();
         let suppress = self.suppress_seek.clone();
+        let pending_seek = self.pending_seek.clone();
+        let played = self.played.clone();
+        let last_tick_ms = self.last_tick_ms.clone();
+        let watch_session = self.watch_session.clone();
+        let is_playing = self.is_playing.clone();
+        let play_btn = self.play_btn.clone();
+        let state = self.state.clone();
+        let video_id = *self.current_video_id.borrow();
+        let mut ticks = 0u32;
 
         let source_id = glib::timeout_add_local(std::time::Duration::from_millis(250), move || {
             let p = player.borrow();
             if let Some(ref player) = *p {
-                if let Some(pos) = player.position() {
+                if let Some(dur) = player.duration() {
+                    dur_label.set_text(&fmt_ns(dur));
+                    // The stream is prerolled once it knows its duration; seeking earlier is ignored.
+                    if let Some(resume_ns) = pending_seek.take() {
+                        player.seek(resume_ns);
+                        return glib::ControlFlow::Continue;
+                    }
+                }
+                if pending_seek.get().is_none() && is_playing.get() && player.is_past_clip_end() {
+                    player.pause();
+                    is_playing.set(false);
+                    play_btn.set_icon_name("media-playback-start-symbolic");
+                }
+                if pending_seek.get().is_none()
+                    && let Some(pos) = player.position()
+                {
                     suppress.set(true);
                     seek_bar.set_value(pos as f64);
                     suppress.set(false);
                     pos_label.set_text(&fmt_ns(pos));
-                }
-                if let Some(dur) = player.duration() {
-                    dur_label.set_text(&fmt_ns(dur));
+
+                    let pos_ms = pos / 1_000_000;
+                    let now = Utc::now();
+                    if is_playing.get()
+                        && let Some(prev_ms) = last_tick_ms.get()
+                        && pos_ms > prev_ms
+                        && pos_ms - prev_ms <= MAX_TICK_ADVANCE_MS
+                    {
+                        played.borrow_mut().add(prev_ms, pos_ms);
+                        let mut open = watch_session.borrow_mut();
+                        let session = open.get_or_insert(OpenWatchSession {
+                            started_at: now,
+                            last_played_at: now,
+                            played_ms: 0,
+                        });
+                        session.played_ms += pos_ms - prev_ms;
+                        session.last_played_at = now;
+                    }
+                    last_tick_ms.set(Some(pos_ms));
+
+                    let idle = watch_session.borrow().as_ref().is_some_and(|session| {
+                        !is_playing.get()
+                            || (now - session.last_played_at).num_seconds()
+                                >= WATCH_SESSION_IDLE_SECS
+                    });
+                    if idle
+                        && let Some(video_id) = video_id
+                        && let Ok(s) = state.try_borrow()
+                        && let Some(ref ctx) = s.backend
+                    {
+                        close_watch_session(ctx, video_id, &watch_session);
+                    }
+
+                    ticks += 1;
+                    if ticks.is_multiple_of(SAVE_POSITION_EVERY_TICKS)
+                        && is_playing.get()
+                        && let Some(video_id) = video_id
+                        && let Ok(s) = state.try_borrow()
+                        && let Some(ref ctx) = s.backend
+                    {
+                        save_progress(
+                            ctx,
+                            s.auto_complete_percent,
+                            video_id,
+                            pos,
+                            player.duration(),
+                            &played,
+                        );
+                    }
                 }
             }
             glib::ControlFlow::Continue
@@ -1084,6 +1367,25 @@ impl VideoPlayerPage {
         *self.timer_source.borrow_mut() = Some(source_id);
     }
 
+    /// Persists the current video's position, if a player is loaded, and ends its watch session.
+    fn save_position(&self, ctx: &AppContext) {
+        let Some(video_id) = *self.current_video_id.borrow() else {
+            return;
+        };
+        close_watch_session(ctx, video_id, &self.watch_session);
+        // A resume seek that never ran leaves the stored position untouched.
+        if self.pending_seek.get().is_some() {
+            return;
+        }
+        let player = self.player.borrow();
+        let Some(pos) = player.as_ref().and_then(|p| p.position()) else {
+            return;
+        };
+        let duration = player.as_ref().and_then(|p| p.duration());
+        let percent = self.state.borrow().auto_complete_percent;
+        save_progress(ctx, percent, video_id, pos, duration, &self.played);
+    }
+
     fn stop_timer(&self) {
         if let Some(source_id) = self.timer_source.borrow_mut().take() {
             source_id.remove();
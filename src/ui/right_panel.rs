@@ -1,38 +1,70 @@
+use std::cell::RefCell;
 use std::rc::Rc;
 
 use adw::prelude::*;
 
 use crate::application::ServiceFactory;
 use crate::application::use_cases::{
-    AskCompanionInput, ChatMessageView, ChatRole, SendChatMessageInput,
+    AskCompanionInput, ChatMessageView, ChatRole, ExplainMomentInput, SendChatMessageInput,
 };
+use crate::domain::ports::{CancellationToken, TranscriptChunk};
 use crate::domain::value_objects::VideoId;
 use crate::ui::state::{MAX_CHAT_HISTORY_PER_VIDEO, SharedState};
 
+/// Shown in the assistant bubble until the first piece of the answer arrives.
+const THINKING: &str = "Thinking\u{2026}";
+
+/// Longest excerpt of a cited transcript passage shown under an answer, in characters.
+const SOURCE_PREVIEW_CHARS: usize = 140;
+
+/// What the companion is asked to do.
+#[derive(Debug, Clone, Copy)]
+enum CompanionRequest {
+    /// Answer the question typed in the chat input.
+    Question,
+    /// Explain what was said just before a playback position, in seconds into the lesson.
+    ExplainMoment { position_secs: u32 },
+}
+
+/// What the companion task sends back to the panel while answering.
+enum AnswerEvent {
+    /// Next piece of the answer.
+    Delta(String),
+    /// Generation ended, with the full answer (partial when stopped) or an error message, and
+    /// the transcript passages the answer was grounded in.
+    Finished { text: String, stopped: bool, sources: Vec<TranscriptChunk> },
+}
+
 pub struct RightPanel {
     widget: gtk::Box,
     chat_input: gtk::Entry,
     chat_spinner: gtk::Spinner,
+    stop_btn: gtk::Button,
     chat_history_box: gtk::Box,
     chat_scroll: gtk::ScrolledWindow,
     state: SharedState,
     placeholder: adw::StatusPage,
     content_area: gtk::Box,
     context_text: gtk::TextView,
+    /// Starts a companion request; set once the signals are connected.
+    send_request: RefCell<Option<Rc<dyn Fn(CompanionRequest)>>>,
 }
 
+/// Rebuilds the chat bubbles for a video and returns the label of the last assistant
+/// message, so a streamed answer can be appended to it.
 fn rebuild_chat_history(
     chat_box: &gtk::Box,
     state: &SharedState,
     video_id: &str,
     scroll: &gtk::ScrolledWindow,
-) {
+) -> Option<gtk::Label> {
     while let Some(child) = chat_box.first_child() {
         chat_box.remove(&child);
     }
 
     let s = state.borrow();
     let history = s.chat_history_by_video.get(video_id).cloned().unwrap_or_default();
+    let mut last_assistant_label = None;
     for msg in &history {
         let row_box = gtk::Box::new(gtk::Orientation::Horizontal, 0);
         row_box.set_hexpand(true);
@@ -53,6 +85,10 @@ fn rebuild_chat_history(
                 row_box.append(&bubble);
             },
             ChatRole::Assistant => {
+                last_assistant_label = Some(label.clone());
+                if let Some(sources) = s.chat_sources_by_message.get(&msg.id) {
+                    bubble.append(&sources_expander(sources));
+                }
                 bubble.add_css_class("chat-bubble-assistant");
                 row_box.append(&bubble);
                 let spacer = gtk::Box::new(gtk::Orientation::Horizontal, 0);
@@ -65,7 +101,41 @@ fn rebuild_chat_history(
     }
     drop(s);
 
-    // Scroll to the bottom on the next main-loop cycle (after GTK computes size adjustments)
+    scroll_to_bottom(scroll);
+    last_assistant_label
+}
+
+/// Lists the transcript passages an answer cites as `[n]`, with the full passage as tooltip.
+fn sources_expander(sources: &[TranscriptChunk]) -> gtk::Expander {
+    let list = gtk::Box::new(gtk::Orientation::Vertical, 4);
+    for (i, chunk) in sources.iter().enumerate() {
+        let text = chunk.content.split_whitespace().collect::<Vec<_>>().join(" ");
+        let mut preview: String = text.chars().take(SOURCE_PREVIEW_CHARS).collect();
+        if preview.len() < text.len() {
+            preview.push('\u{2026}');
+        }
+        let label = gtk::Label::new(Some(&format!(
+            "[{}] Transcript part {}: \u{201c}{}\u{201d}",
+            i + 1,
+            chunk.index + 1,
+            preview
+        )));
+        label.set_wrap(true);
+        label.set_xalign(0.0);
+        label.set_tooltip_text(Some(&chunk.content));
+        label.add_css_class("caption");
+        label.add_css_class("dim-label");
+        list.append(&label);
+    }
+
+    let expander = gtk::Expander::new(Some(&format!("Sources ({})", sources.len())));
+    expander.add_css_class("caption");
+    expander.set_child(Some(&list));
+    expander
+}
+
+/// Scrolls to the bottom on the next main-loop cycle (after GTK computes size adjustments).
+fn scroll_to_bottom(scroll: &gtk::ScrolledWindow) {
     let scroll_cl = scroll.clone();
     glib::idle_add_local(move || {
         let vadj = scroll_cl.vadjustment();
@@ -74,6 +144,13 @@ fn rebuild_chat_history(
     });
 }
 
+/// The in-flight assistant message (not saved yet, so without an id) of a video's chat.
+fn pending_answer<'a>(
+    history: Option<&'a mut Vec<ChatMessageView>>,
+) -> Option<&'a mut ChatMessageView> {
+    history?.last_mut().filter(|m| m.role == ChatRole::Assistant && m.id.is_empty())
+}
+
 impl RightPanel {
     pub fn new(state: SharedState) -> Self {
         let widget = gtk::Box::new(gtk::Orientation::Vertical, 0);
@@ -127,9 +204,14 @@ impl RightPanel {
         let send_btn = gtk::Button::with_label("Send");
         send_btn.add_css_class("suggested-action");
 
+        let stop_btn = gtk::Button::with_label("Stop");
+        stop_btn.set_tooltip_text(Some("Stop generating and keep the answer so far"));
+        stop_btn.set_visible(false);
+
         chat_bottom.append(&chat_input);
         chat_bottom.append(&chat_spinner);
         chat_bottom.append(&send_btn);
+        chat_bottom.append(&stop_btn);
 
         chat_area.append(&chat_scroll);
         chat_area.append(&context_expander);
@@ -142,12 +224,14 @@ impl RightPanel {
             widget,
             chat_input,
             chat_spinner,
+            stop_btn,
             chat_history_box,
             chat_scroll,
             state: state.clone(),
             placeholder,
             content_area,
             context_text,
+            send_request: RefCell::new(None),
         };
 
         result.connect_signals(state, send_btn);
@@ -160,17 +244,40 @@ impl RightPanel {
         let chat_history_box = self.chat_history_box.clone();
         let chat_input = self.chat_input.clone();
         let chat_spinner = self.chat_spinner.clone();
+        let stop_btn = self.stop_btn.clone();
         let send_btn_cl = send_btn.clone();
         let state_clone = self.state.clone();
         let context_text = self.context_text.clone();
         let chat_scroll = self.chat_scroll.clone();
 
-        let perform_send = Rc::new(move || {
-            let question = chat_input.text().as_str().to_string();
-            if question.trim().is_empty() {
+        // Token of the answer being generated, set while the Stop button is shown.
+        let generation: Rc<RefCell<Option<CancellationToken>>> = Rc::new(RefCell::new(None));
+        let generation_for_stop = generation.clone();
+        self.stop_btn.connect_clicked(move |btn| {
+            if let Some(cancel) = generation_for_stop.borrow().as_ref() {
+                cancel.cancel();
+                btn.set_sensitive(false);
+            }
+        });
+
+        let perform_send: Rc<dyn Fn(CompanionRequest)> = Rc::new(move |request| {
+            // One answer at a time
+            if generation.borrow().is_some() {
                 return;
             }
-            chat_input.set_text("");
+            let question = match request {
+                CompanionRequest::Question => {
+                    let question = chat_input.text().as_str().to_string();
+                    if question.trim().is_empty() {
+                        return;
+                    }
+                    chat_input.set_text("");
+                    question
+                },
+                CompanionRequest::ExplainMoment { position_secs } => {
+                    ExplainMomentInput::question_at(position_secs)
+                },
+            };
 
             chat_spinner.start();
             chat_input.set_sensitive(false);
@@ -230,7 +337,7 @@ impl RightPanel {
                     id: String::new(),
                     video_id: video_id.parse::<VideoId>().unwrap_or_default(),
                     role: ChatRole::Assistant,
-                    content: "Thinking\u{2026}".to_string(),
+                    content: THINKING.to_string(),
                     created_at: String::new(),
                 });
                 if history.len() > MAX_CHAT_HISTORY_PER_VIDEO {
@@ -240,7 +347,8 @@ impl RightPanel {
             }
 
             // Immediately show user's question and "Thinking..." bubble
-            rebuild_chat_history(&chat_history_box, &state_clone, &video_id, &chat_scroll);
+            let answer_label =
+                rebuild_chat_history(&chat_history_box, &state_clone, &video_id, &chat_scroll);
 
             let chat_box = chat_history_box.clone();
             let state = state_clone.clone();
@@ -255,49 +363,101 @@ impl RightPanel {
                 if s.is_empty() { None } else { Some(s) }
             };
 
+            let cancel = CancellationToken::new();
+            *generation.borrow_mut() = Some(cancel.clone());
+            stop_btn.set_sensitive(true);
+            stop_btn.set_visible(true);
+            send_btn_cl.set_visible(false);
+
             let backend_for_save = backend.clone();
-            let (tx, rx) = std::sync::mpsc::channel::<String>();
+            let (tx, rx) = std::sync::mpsc::channel::<AnswerEvent>();
 
             crate::infrastructure::tokio_bridge::spawn(async move {
-                let response = match backend {
+                let finished =
+                    |text: String| AnswerEvent::Finished { text, stopped: false, sources: vec![] };
+                let event = match backend {
                     Some(ctx) => match ServiceFactory::ask_companion(&ctx) {
                         Some(uc) => match vid_for_spawn.parse::<VideoId>() {
                             Ok(video_id) => {
-                                match uc
-                                    .execute(AskCompanionInput {
-                                        video_id,
-                                        question,
-                                        local_context,
-                                    })
-                                    .await
-                                {
-                                    Ok(answer) => answer,
-                                    Err(e) => format!("AI error: {}", e),
+                                let delta_tx = tx.clone();
+                                let on_delta = move |delta: &str| {
+                                    let _ = delta_tx.send(AnswerEvent::Delta(delta.to_string()));
+                                };
+                                let answer = match request {
+                                    CompanionRequest::Question => {
+                                        let input =
+                                            AskCompanionInput { video_id, question, local_context };
+                                        uc.execute_streaming(input, &on_delta, &cancel).await
+                                    },
+                                    CompanionRequest::ExplainMoment { position_secs } => {
+                                        let input = ExplainMomentInput {
+                                            video_id,
+                                            position_secs,
+                                            local_context,
+                                        };
+                                        uc.explain_moment_streaming(input, &on_delta, &cancel).await
+                                    },
+                                };
+                                match answer {
+                                    Ok(answer) => AnswerEvent::Finished {
+                                        text: answer.text,
+                                        stopped: answer.stopped,
+                                        sources: answer.sources,
+                                    },
+                                    Err(e) => finished(format!("AI error: {}", e)),
                                 }
                             },
-                            Err(_) => "Invalid video ID.".to_string(),
+                            Err(_) => finished("Invalid video ID.".to_string()),
                         },
-                        None => "AI companion not available.".to_string(),
+                        None => finished("AI companion not available.".to_string()),
                     },
-                    None => "No backend connected.".to_string(),
+                    None => finished("No backend connected.".to_string()),
                 };
-                let _ = tx.send(response);
+                let _ = tx.send(event);
             });
 
             let chat_spinner_cl = chat_spinner.clone();
             let chat_input_cl = chat_input.clone();
             let send_btn_cl2 = send_btn_cl.clone();
+            let stop_btn_cl = stop_btn.clone();
+            let generation_cl = generation.clone();
+            let finish_ui = move || {
+                generation_cl.borrow_mut().take();
+                stop_btn_cl.set_visible(false);
+                send_btn_cl2.set_visible(true);
+                chat_spinner_cl.stop();
+                chat_input_cl.set_sensitive(true);
+                send_btn_cl2.set_sensitive(true);
+            };
 
             glib::idle_add_local(move || match rx.try_recv() {
-                Ok(response) => {
+                Ok(AnswerEvent::Delta(delta)) => {
+                    let mut s = state.borrow_mut();
+                    if let Some(pending) = pending_answer(s.chat_history_by_video.get_mut(&vid)) {
+                        if pending.content == THINKING {
+                            pending.content.clear();
+                        }
+                        pending.content.push_str(&delta);
+                        if let Some(label) = &answer_label {
+                            label.set_text(&pending.content);
+                        }
+                        scroll_to_bottom(&scroll_for_spawn);
+                    }
+                    glib::ControlFlow::Continue
+                },
+                Ok(AnswerEvent::Finished { text, stopped, sources }) => {
+                    // A stopped answer is kept as far as it got; one stopped before any
+                    // text arrived is dropped.
+                    let keep = !(stopped && text.trim().is_empty());
+
                     // Save assistant response to repository via use case
-                    let saved_assistant_view = if let Some(ctx) = &backend_for_save {
-                        if let Ok(parsed_video_id) = vid.parse::<VideoId>() {
+                    let saved_assistant_view = match (&backend_for_save, vid.parse::<VideoId>()) {
+                        (Some(ctx), Ok(parsed_video_id)) if keep => {
                             let chat_uc = ServiceFactory::chat(ctx);
                             match chat_uc.send_message(SendChatMessageInput {
                                 video_id: parsed_video_id,
                                 role: ChatRole::Assistant,
-                                content: response.clone(),
+                                content: text.clone(),
                             }) {
                                 Ok(view) => Some(view),
                                 Err(e) => {
@@ -305,50 +465,28 @@ impl RightPanel {
                                     None
                                 },
                             }
-                        } else {
-                            None
-                        }
-                    } else {
-                        None
+                        },
+                        _ => None,
                     };
 
                     {
                         let mut s = state.borrow_mut();
+                        let answer = saved_assistant_view.unwrap_or_else(|| ChatMessageView {
+                            id: uuid::Uuid::new_v4().to_string(),
+                            video_id: vid.parse::<VideoId>().unwrap_or_default(),
+                            role: ChatRole::Assistant,
+                            content: text,
+                            created_at: chrono::Utc::now().to_rfc3339(),
+                        });
+                        if keep && !sources.is_empty() {
+                            s.chat_sources_by_message.insert(answer.id.clone(), sources);
+                        }
                         let history = s.chat_history_by_video.entry(vid.clone()).or_default();
-                        if let Some(last) = history.last_mut() {
-                            if last.role == ChatRole::Assistant
-                                && last.content == "Thinking\u{2026}"
-                            {
-                                if let Some(view) = saved_assistant_view {
-                                    *last = view;
-                                } else {
-                                    last.content = response;
-                                }
-                            } else {
-                                if let Some(view) = saved_assistant_view {
-                                    history.push(view);
-                                } else {
-                                    history.push(ChatMessageView {
-                                        id: uuid::Uuid::new_v4().to_string(),
-                                        video_id: vid.parse::<VideoId>().unwrap_or_default(),
-                                        role: ChatRole::Assistant,
-                                        content: response,
-                                        created_at: chrono::Utc::now().to_rfc3339(),
-                                    });
-                                }
-                            }
-                        } else {
-                            if let Some(view) = saved_assistant_view {
-                                history.push(view);
-                            } else {
-                                history.push(ChatMessageView {
-                                    id: uuid::Uuid::new_v4().to_string(),
-                                    video_id: vid.parse::<VideoId>().unwrap_or_default(),
-                                    role: ChatRole::Assistant,
-                                    content: response,
-                                    created_at: chrono::Utc::now().to_rfc3339(),
-                                });
-                            }
+                        if pending_answer(Some(&mut *history)).is_some() {
+                            history.pop();
+                        }
+                        if keep {
+                            history.push(answer);
                         }
                         if history.len() > MAX_CHAT_HISTORY_PER_VIDEO {
                             let excess = history.len() - MAX_CHAT_HISTORY_PER_VIDEO;
@@ -357,27 +495,20 @@ impl RightPanel {
                     }
 
                     rebuild_chat_history(&chat_box, &state, &vid, &scroll_for_spawn);
-                    chat_spinner_cl.stop();
-                    chat_input_cl.set_sensitive(true);
-                    send_btn_cl2.set_sensitive(true);
+                    finish_ui();
                     glib::ControlFlow::Break
                 },
                 Err(std::sync::mpsc::TryRecvError::Empty) => glib::ControlFlow::Continue,
                 Err(std::sync::mpsc::TryRecvError::Disconnected) => {
                     {
                         let mut s = state.borrow_mut();
-                        let history = s.chat_history_by_video.entry(vid.clone()).or_default();
-                        if let Some(last) = history.last_mut()
-                            && last.role == ChatRole::Assistant
-                            && last.content == "Thinking\u{2026}"
+                        if let Some(pending) = pending_answer(s.chat_history_by_video.get_mut(&vid))
                         {
-                            last.content = "Failed to receive response.".to_string();
+                            pending.content = "Failed to receive response.".to_string();
                         }
                     }
                     rebuild_chat_history(&chat_box, &state, &vid, &scroll_for_spawn);
-                    chat_spinner_cl.stop();
-                    chat_input_cl.set_sensitive(true);
-                    send_btn_cl2.set_sensitive(true);
+                    finish_ui();
                     glib::ControlFlow::Break
                 },
             });
@@ -385,13 +516,24 @@ impl RightPanel {
 
         let perform_send_cl1 = perform_send.clone();
         self.chat_input.connect_activate(move |_| {
-            perform_send_cl1();
+            perform_send_cl1(CompanionRequest::Question);
         });
 
-        let perform_send_cl2 = perform_send;
+        let perform_send_cl2 = perform_send.clone();
         send_btn.connect_clicked(move |_| {
-            perform_send_cl2();
+            perform_send_cl2(CompanionRequest::Question);
         });
+
+        *self.send_request.borrow_mut() = Some(perform_send);
+    }
+
+    /// Asks the companion to explain what was said just before `position_secs` into the
+    /// current video. Ignored while an answer is being generated.
+    pub fn explain_moment(&self, position_secs: u32) {
+        let send_request = self.send_request.borrow().clone();
+        if let Some(send_request) = send_request {
+            send_request(CompanionRequest::ExplainMoment { position_secs });
+        }
     }
 
     pub fn widget(&self) -> &gtk::Box {
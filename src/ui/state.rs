@@ -24,14 +24,19 @@ pub struct AppState {
     pub right_panel_width: f64,
     pub onboarding_completed: bool,
     pub chat_history_by_video: HashMap<String, Vec<crate::application::use_cases::ChatMessageView>>,
+    /// Transcript passages behind the companion answers given this session, by message id.
+    pub chat_sources_by_message: HashMap<String, Vec<crate::domain::ports::TranscriptChunk>>,
     pub notes: HashMap<String, String>,
     pub current_video_id: Option<String>,
     pub current_course_id: Option<String>,
     pub current_quiz_id: Option<String>,
-    pub last_video_by_course: HashMap<String, String>,
     pub preferred_quality: VideoQuality,
     pub session_quality: VideoQuality,
     pub boundary_batch_size: u32,
+    pub module_target_minutes: u32,
+    pub ml_boundary_enabled: bool,
+    pub auto_complete_percent: u32,
+    pub watch_local_folders: bool,
     pub cognitive_limit_minutes: u32,
 }
 
@@ -45,14 +50,18 @@ impl AppState {
             right_panel_width: 320.0,
             onboarding_completed: false,
             chat_history_by_video: HashMap::new(),
+            chat_sources_by_message: HashMap::new(),
             notes: HashMap::new(),
             current_video_id: None,
             current_course_id: None,
             current_quiz_id: None,
-            last_video_by_course: HashMap::new(),
             preferred_quality: VideoQuality::P720,
             session_quality: VideoQuality::P720,
             boundary_batch_size: 5,
+            module_target_minutes: 0,
+            ml_boundary_enabled: false,
+            auto_complete_percent: 90,
+            watch_local_folders: false,
             cognitive_limit_minutes: 45,
         }
     }
@@ -64,6 +73,10 @@ impl AppState {
             state.preferred_quality = p.preferred_quality();
             state.session_quality = p.preferred_quality();
             state.boundary_batch_size = p.boundary_batch_size();
+            state.module_target_minutes = p.module_target_minutes();
+            state.ml_boundary_enabled = p.ml_boundary_enabled();
+            state.auto_complete_percent = p.auto_complete_percent();
+            state.watch_local_folders = p.watch_local_folders();
             state.cognitive_limit_minutes = p.cognitive_limit_minutes();
             state.onboarding_completed = p.onboarding_completed();
             state.right_panel_visible = p.right_panel_visible();
@@ -4,3 +4,6 @@ use std::rc::Rc;
 /// A late-binding refresh callback. Set after construction via `set_refresh_cb`
 /// and called when the page needs to reload its content.
 pub type RefreshCallback = Rc<RefCell<Option<Rc<dyn Fn()>>>>;
+
+/// A late-binding callback taking a playback position in seconds.
+pub type PositionCallback = Rc<RefCell<Option<Rc<dyn Fn(u32)>>>>;
@@ -0,0 +1,305 @@
+//! Per-video flashcard editor shown under the video player.
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use gtk::prelude::*;
+
+use crate::application::ServiceFactory;
+use crate::application::use_cases::{GenerateFlashcardsInput, SaveFlashcardInput};
+use crate::domain::entities::Flashcard;
+use crate::domain::value_objects::{FlashcardId, FlashcardKind, VideoId};
+use crate::ui::state::SharedState;
+use crate::ui::toast::Toast;
+
+/// Number of cards requested per generation.
+const GENERATED_CARDS: u8 = 10;
+
+/// Order of the kinds in each row's drop-down.
+const KINDS: [FlashcardKind; 2] = [FlashcardKind::Basic, FlashcardKind::Cloze];
+
+/// Editable list of the current video's flashcards.
+#[derive(Clone)]
+pub struct FlashcardsPanel {
+    root: gtk::Box,
+    list: gtk::ListBox,
+    empty_lbl: gtk::Label,
+    spinner: gtk::Spinner,
+    generate_btn: gtk::Button,
+    state: SharedState,
+    video_id: Rc<Cell<Option<VideoId>>>,
+}
+
+impl FlashcardsPanel {
+    pub fn new(state: SharedState) -> Self {
+        let root = gtk::Box::new(gtk::Orientation::Vertical, 8);
+
+        let header = gtk::Box::new(gtk::Orientation::Horizontal, 8);
+        let title = gtk::Label::new(Some("Flashcards"));
+        title.add_css_class("heading");
+        title.set_halign(gtk::Align::Start);
+        header.append(&title);
+        let spinner = gtk::Spinner::new();
+        header.append(&spinner);
+
+        let actions = gtk::Box::new(gtk::Orientation::Horizontal, 6);
+        actions.set_hexpand(true);
+        actions.set_halign(gtk::Align::End);
+        let seed_btn = gtk::Button::with_label("From Key Terms");
+        seed_btn.set_tooltip_text(Some("Add a card for each key term in the video summary"));
+        let generate_btn = gtk::Button::with_label("Generate");
+        let add_btn = gtk::Button::from_icon_name("list-add-symbolic");
+        add_btn.set_tooltip_text(Some("Add a card"));
+        actions.append(&seed_btn);
+        actions.append(&generate_btn);
+        actions.append(&add_btn);
+        header.append(&actions);
+        root.append(&header);
+
+        let hint = gtk::Label::new(Some(
+            "Cloze cards hide the words wrapped in {{double braces}} until they are flipped.",
+        ));
+        hint.add_css_class("caption");
+        hint.add_css_class("dim-label");
+        hint.set_halign(gtk::Align::Start);
+        hint.set_wrap(true);
+        root.append(&hint);
+
+        let empty_lbl = gtk::Label::new(Some(
+            "No flashcards yet. Generate them, seed them from key terms, or add your own.",
+        ));
+        empty_lbl.add_css_class("subtitle");
+        empty_lbl.set_halign(gtk::Align::Start);
+        root.append(&empty_lbl);
+
+        let list = gtk::ListBox::new();
+        list.set_selection_mode(gtk::SelectionMode::None);
+        list.add_css_class("boxed-list");
+        list.set_margin_bottom(16);
+        root.append(&list);
+
+        let panel = Self {
+            root,
+            list,
+            empty_lbl,
+            spinner,
+            generate_btn,
+            state,
+            video_id: Rc::new(Cell::new(None)),
+        };
+
+        let p = panel.clone();
+        seed_btn.connect_clicked(move |_| p.seed_from_key_terms());
+        let p = panel.clone();
+        panel.generate_btn.connect_clicked(move |_| p.generate());
+        let p = panel.clone();
+        add_btn.connect_clicked(move |_| {
+            if let Some(video_id) = p.video_id.get() {
+                p.empty_lbl.set_visible(false);
+                p.list.append(&p.build_row(video_id, None));
+            }
+        });
+
+        panel
+    }
+
+    pub fn widget(&self) -> &gtk::Box {
+        &self.root
+    }
+
+    /// Enables or disables LLM generation.
+    pub fn set_llm_available(&self, available: bool) {
+        self.generate_btn.set_sensitive(available);
+        self.generate_btn.set_tooltip_text(Some(if available {
+            "Generate flashcards from the summary and transcript"
+        } else {
+            "Requires an AI provider — configure one in Settings"
+        }));
+    }
+
+    /// Shows the cards of `video_id`, or clears the panel.
+    pub fn load(&self, video_id: Option<VideoId>) {
+        self.video_id.set(video_id);
+        while let Some(child) = self.list.first_child() {
+            self.list.remove(&child);
+        }
+
+        let backend = self.state.borrow().backend.clone();
+        let (Some(video_id), Some(ctx)) = (video_id, backend) else {
+            self.empty_lbl.set_visible(true);
+            return;
+        };
+
+        match ServiceFactory::flashcards(&ctx).list(&video_id) {
+            Ok(cards) => {
+                self.empty_lbl.set_visible(cards.is_empty());
+                for card in &cards {
+                    self.list.append(&self.build_row(video_id, Some(card)));
+                }
+            },
+            Err(e) => {
+                self.empty_lbl.set_visible(true);
+                Toast::show_error(&format!("Failed to load flashcards: {e}"));
+            },
+        }
+    }
+
+    fn reload(&self) {
+        self.load(self.video_id.get());
+    }
+
+    /// Builds an editor row for `card`, or an empty row for a new card.
+    fn build_row(&self, video_id: VideoId, card: Option<&Flashcard>) -> gtk::ListBoxRow {
+        let row = gtk::ListBoxRow::new();
+        row.set_activatable(false);
+
+        let row_box = gtk::Box::new(gtk::Orientation::Horizontal, 8);
+        row_box.set_margin_start(8);
+        row_box.set_margin_end(8);
+        row_box.set_margin_top(6);
+        row_box.set_margin_bottom(6);
+
+        let kind_dd = gtk::DropDown::from_strings(&["Basic", "Cloze"]);
+        kind_dd.set_valign(gtk::Align::Center);
+        let kind = card.map(|c| c.kind()).unwrap_or_default();
+        kind_dd.set_selected(KINDS.iter().position(|k| *k == kind).unwrap_or(0) as u32);
+        row_box.append(&kind_dd);
+
+        let front = gtk::Entry::new();
+        front.set_hexpand(true);
+        front.set_placeholder_text(Some("Term, or a sentence with a {{hidden}} part"));
+        let back = gtk::Entry::new();
+        back.set_hexpand(true);
+        back.set_placeholder_text(Some("Definition (optional for cloze)"));
+        if let Some(card) = card {
+            front.set_text(card.front());
+            back.set_text(card.back());
+        }
+        row_box.append(&front);
+        row_box.append(&back);
+
+        let save_btn = gtk::Button::from_icon_name("document-save-symbolic");
+        save_btn.set_tooltip_text(Some("Save card"));
+        save_btn.add_css_class("flat");
+        save_btn.set_valign(gtk::Align::Center);
+        row_box.append(&save_btn);
+
+        let delete_btn = gtk::Button::from_icon_name("user-trash-symbolic");
+        delete_btn.set_tooltip_text(Some("Delete card"));
+        delete_btn.add_css_class("flat");
+        delete_btn.set_valign(gtk::Align::Center);
+        row_box.append(&delete_btn);
+
+        row.set_child(Some(&row_box));
+
+        let card_id: Option<FlashcardId> = card.map(|c| *c.id());
+        let panel = self.clone();
+        save_btn.connect_clicked(move |_| {
+            let Some(ctx) = panel.state.borrow().backend.clone() else {
+                return;
+            };
+            let input = SaveFlashcardInput {
+                id: card_id,
+                video_id,
+                kind: KINDS.get(kind_dd.selected() as usize).copied().unwrap_or_default(),
+                front: front.text().to_string(),
+                back: back.text().to_string(),
+            };
+            match ServiceFactory::flashcards(&ctx).save(input) {
+                Ok(_) => {
+                    Toast::show("Flashcard saved.");
+                    panel.reload();
+                },
+                Err(e) => Toast::show_error(&format!("Failed to save flashcard: {e}")),
+            }
+        });
+
+        let panel = self.clone();
+        let row_weak = row.downgrade();
+        delete_btn.connect_clicked(move |_| {
+            let Some(id) = card_id else {
+                // Unsaved row: just drop it.
+                if let Some(row) = row_weak.upgrade() {
+                    panel.list.remove(&row);
+                }
+                return;
+            };
+            let Some(ctx) = panel.state.borrow().backend.clone() else {
+                return;
+            };
+            match ServiceFactory::flashcards(&ctx).delete(&id) {
+                Ok(()) => panel.reload(),
+                Err(e) => Toast::show_error(&format!("Failed to delete flashcard: {e}")),
+            }
+        });
+
+        row
+    }
+
+    fn seed_from_key_terms(&self) {
+        let (Some(video_id), Some(ctx)) =
+            (self.video_id.get(), self.state.borrow().backend.clone())
+        else {
+            return;
+        };
+        match ServiceFactory::flashcards(&ctx).seed_from_key_terms(&video_id) {
+            Ok(cards) if cards.is_empty() => {
+                Toast::show("No new key terms found. Summarize the video first.")
+            },
+            Ok(cards) => {
+                Toast::show(&format!("Added {} flashcards from key terms.", cards.len()));
+                self.reload();
+            },
+            Err(e) => Toast::show_error(&format!("Failed to add flashcards: {e}")),
+        }
+    }
+
+    fn generate(&self) {
+        let (Some(video_id), Some(ctx)) =
+            (self.video_id.get(), self.state.borrow().backend.clone())
+        else {
+            return;
+        };
+        let Some(uc) = ServiceFactory::generate_flashcards(&ctx) else {
+            return;
+        };
+
+        let input = GenerateFlashcardsInput {
+            video_id,
+            max_cards: GENERATED_CARDS,
+            replace_existing: false,
+        };
+        Toast::show("Flashcard generation started...");
+        self.spinner.start();
+        self.generate_btn.set_sensitive(false);
+        let (tx, rx) = std::sync::mpsc::channel::<Result<usize, String>>();
+        crate::infrastructure::tokio_bridge::spawn(async move {
+            let res = uc.execute(input).await.map(|cards| cards.len()).map_err(|e| e.to_string());
+            let _ = tx.send(res);
+        });
+
+        let panel = self.clone();
+        glib::idle_add_local(move || {
+            let res = match rx.try_recv() {
+                Ok(res) => res,
+                Err(std::sync::mpsc::TryRecvError::Empty) => return glib::ControlFlow::Continue,
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                    Err("generation task ended unexpectedly".to_string())
+                },
+            };
+            panel.spinner.stop();
+            panel.generate_btn.set_sensitive(true);
+            match res {
+                Ok(count) => {
+                    Toast::show(&format!("Generated {count} new flashcards."));
+                    // The user may have moved on to another video meanwhile.
+                    if panel.video_id.get() == Some(video_id) {
+                        panel.reload();
+                    }
+                },
+                Err(e) => Toast::show_error(&format!("Flashcard generation failed: {e}")),
+            }
+            glib::ControlFlow::Break
+        });
+    }
+}
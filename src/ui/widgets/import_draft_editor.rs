@@ -0,0 +1,207 @@
+//! Editor for the proposed module grouping of an import, shown before anything is saved.
+
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+
+use adw::prelude::*;
+
+use crate::application::use_cases::{DraftEditError, ImportDraft};
+use crate::ui::toast::Toast;
+
+struct Inner {
+    draft: RefCell<Option<ImportDraft>>,
+    root: gtk::Box,
+    name_entry: gtk::Entry,
+    summary_lbl: gtk::Label,
+    modules_box: gtk::Box,
+}
+
+/// Lets the user rename, reorder, merge and split the modules of an [`ImportDraft`].
+#[derive(Clone)]
+pub struct ImportDraftEditor {
+    inner: Rc<Inner>,
+}
+
+impl ImportDraftEditor {
+    pub fn new() -> Self {
+        let root = gtk::Box::new(gtk::Orientation::Vertical, 12);
+
+        let name_label = gtk::Label::new(Some("Course Name:"));
+        name_label.set_halign(gtk::Align::Start);
+        root.append(&name_label);
+        let name_entry = gtk::Entry::new();
+        root.append(&name_entry);
+
+        let summary_lbl = gtk::Label::new(None);
+        summary_lbl.add_css_class("caption");
+        summary_lbl.add_css_class("dim-label");
+        summary_lbl.set_halign(gtk::Align::Start);
+        root.append(&summary_lbl);
+
+        let modules_box = gtk::Box::new(gtk::Orientation::Vertical, 12);
+        root.append(&modules_box);
+
+        let inner = Rc::new(Inner {
+            draft: RefCell::new(None),
+            root,
+            name_entry,
+            summary_lbl,
+            modules_box,
+        });
+
+        let weak = Rc::downgrade(&inner);
+        inner.name_entry.connect_changed(move |entry| {
+            if let Some(inner) = weak.upgrade()
+                && let Some(draft) = inner.draft.borrow_mut().as_mut()
+            {
+                draft.course_name = entry.text().to_string();
+            }
+        });
+
+        Self { inner }
+    }
+
+    pub fn widget(&self) -> &gtk::Box {
+        &self.inner.root
+    }
+
+    /// Shows a freshly planned draft.
+    pub fn set_draft(&self, draft: ImportDraft) {
+        let name = draft.course_name.clone();
+        *self.inner.draft.borrow_mut() = Some(draft);
+        self.inner.name_entry.set_text(&name);
+        rebuild(&self.inner);
+    }
+
+    /// The draft with all edits applied so far.
+    pub fn draft(&self) -> Option<ImportDraft> {
+        self.inner.draft.borrow().clone()
+    }
+}
+
+impl Default for ImportDraftEditor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Applies a structural edit and redraws the module list.
+fn edit(inner: &Weak<Inner>, f: impl FnOnce(&mut ImportDraft) -> Result<(), DraftEditError>) {
+    let Some(inner) = inner.upgrade() else {
+        return;
+    };
+    let res = match inner.draft.borrow_mut().as_mut() {
+        Some(draft) => f(draft),
+        None => return,
+    };
+    match res {
+        Ok(()) => rebuild(&inner),
+        Err(e) => Toast::show_error(&e.to_string()),
+    }
+}
+
+fn rebuild(inner: &Rc<Inner>) {
+    while let Some(child) = inner.modules_box.first_child() {
+        inner.modules_box.remove(&child);
+    }
+    let draft = inner.draft.borrow();
+    let Some(draft) = draft.as_ref() else {
+        return;
+    };
+    inner.summary_lbl.set_text(&format!(
+        "{} modules · {} videos",
+        draft.modules.len(),
+        draft.video_count()
+    ));
+
+    let count = draft.modules.len();
+    for (idx, module) in draft.modules.iter().enumerate() {
+        let card = gtk::Box::new(gtk::Orientation::Vertical, 6);
+
+        let header = gtk::Box::new(gtk::Orientation::Horizontal, 4);
+        let title_entry = gtk::Entry::new();
+        title_entry.set_text(&module.title);
+        title_entry.set_hexpand(true);
+        title_entry.set_tooltip_text(Some("Module title"));
+        header.append(&title_entry);
+
+        let weak = Rc::downgrade(inner);
+        title_entry.connect_changed(move |entry| {
+            // Renames don't redraw, so typing keeps focus; blank titles wait for more input.
+            if let Some(inner) = weak.upgrade()
+                && let Some(draft) = inner.draft.borrow_mut().as_mut()
+            {
+                let _ = draft.rename_module(idx, &entry.text());
+            }
+        });
+
+        append_edit_button(&header, inner, "go-up-symbolic", "Move Up", idx > 0, move |d| {
+            d.move_module(idx, idx - 1)
+        });
+        append_edit_button(
+            &header,
+            inner,
+            "go-down-symbolic",
+            "Move Down",
+            idx + 1 < count,
+            move |d| d.move_module(idx, idx + 1),
+        );
+        append_edit_button(
+            &header,
+            inner,
+            "list-remove-symbolic",
+            "Merge with Next Module",
+            idx + 1 < count,
+            move |d| d.merge_with_next(idx),
+        );
+        card.append(&header);
+
+        let list = gtk::ListBox::new();
+        list.set_selection_mode(gtk::SelectionMode::None);
+        list.add_css_class("boxed-list");
+        for (v_idx, video) in module.videos.iter().enumerate() {
+            let row = adw::ActionRow::builder()
+                .title(&video.title)
+                .subtitle(format_duration(video.duration_secs))
+                .use_markup(false)
+                .build();
+            if v_idx > 0 {
+                let split_btn = gtk::Button::from_icon_name("edit-cut-symbolic");
+                split_btn.add_css_class("flat");
+                split_btn.set_valign(gtk::Align::Center);
+                split_btn.set_tooltip_text(Some("Start a New Module Here"));
+                let weak = Rc::downgrade(inner);
+                let new_title = video.title.clone();
+                split_btn.connect_clicked(move |_| {
+                    edit(&weak, |d| d.split_module(idx, v_idx, &new_title));
+                });
+                row.add_suffix(&split_btn);
+            }
+            list.append(&row);
+        }
+        card.append(&list);
+        inner.modules_box.append(&card);
+    }
+}
+
+fn append_edit_button(
+    header: &gtk::Box,
+    inner: &Rc<Inner>,
+    icon: &str,
+    tooltip: &str,
+    sensitive: bool,
+    op: impl Fn(&mut ImportDraft) -> Result<(), DraftEditError> + 'static,
+) {
+    let btn = gtk::Button::from_icon_name(icon);
+    btn.add_css_class("flat");
+    btn.set_valign(gtk::Align::Center);
+    btn.set_tooltip_text(Some(tooltip));
+    btn.set_sensitive(sensitive);
+    let weak = Rc::downgrade(inner);
+    btn.connect_clicked(move |_| edit(&weak, &op));
+    header.append(&btn);
+}
+
+fn format_duration(secs: u32) -> String {
+    format!("{}:{:02}", secs / 60, secs % 60)
+}
@@ -0,0 +1,102 @@
+//! Key points and glossary terms extracted with a video's summary.
+
+use gtk::prelude::*;
+
+use crate::domain::value_objects::KeyTerm;
+
+/// Read-only view of a video's key points and key terms.
+#[derive(Clone)]
+pub struct KeyContentPanel {
+    root: gtk::Box,
+    points_list: gtk::ListBox,
+    terms_list: gtk::ListBox,
+    points_title: gtk::Label,
+    terms_title: gtk::Label,
+    empty_lbl: gtk::Label,
+}
+
+impl KeyContentPanel {
+    pub fn new() -> Self {
+        let root = gtk::Box::new(gtk::Orientation::Vertical, 8);
+
+        let title = gtk::Label::new(Some("Key Points & Terms"));
+        title.add_css_class("heading");
+        title.set_halign(gtk::Align::Start);
+        root.append(&title);
+
+        let empty_lbl = gtk::Label::new(Some(
+            "Key points and terms appear here once the video has been summarized.",
+        ));
+        empty_lbl.add_css_class("subtitle");
+        empty_lbl.set_halign(gtk::Align::Start);
+        empty_lbl.set_wrap(true);
+        root.append(&empty_lbl);
+
+        let points_title = gtk::Label::new(Some("Key Points"));
+        points_title.add_css_class("caption-heading");
+        points_title.set_halign(gtk::Align::Start);
+        root.append(&points_title);
+
+        let points_list = gtk::ListBox::new();
+        points_list.set_selection_mode(gtk::SelectionMode::None);
+        points_list.add_css_class("boxed-list");
+        root.append(&points_list);
+
+        let terms_title = gtk::Label::new(Some("Key Terms"));
+        terms_title.add_css_class("caption-heading");
+        terms_title.set_halign(gtk::Align::Start);
+        terms_title.set_margin_top(8);
+        root.append(&terms_title);
+
+        let terms_list = gtk::ListBox::new();
+        terms_list.set_selection_mode(gtk::SelectionMode::None);
+        terms_list.add_css_class("boxed-list");
+        terms_list.set_margin_bottom(16);
+        root.append(&terms_list);
+
+        let panel = Self { root, points_list, terms_list, points_title, terms_title, empty_lbl };
+        panel.set_content(&[], &[]);
+        panel
+    }
+
+    pub fn widget(&self) -> &gtk::Box {
+        &self.root
+    }
+
+    /// Replaces the shown key points and terms.
+    pub fn set_content(&self, key_points: &[String], key_terms: &[KeyTerm]) {
+        for list in [&self.points_list, &self.terms_list] {
+            while let Some(child) = list.first_child() {
+                list.remove(&child);
+            }
+        }
+
+        // LLM output is plain text; markup would break on stray `&` or `<`.
+        for point in key_points {
+            let row = adw::ActionRow::builder().title(point).use_markup(false).build();
+            self.points_list.append(&row);
+        }
+        for term in key_terms {
+            let row = adw::ActionRow::builder()
+                .title(&term.term)
+                .subtitle(&term.definition)
+                .use_markup(false)
+                .build();
+            self.terms_list.append(&row);
+        }
+
+        let has_points = !key_points.is_empty();
+        let has_terms = !key_terms.is_empty();
+        self.points_title.set_visible(has_points);
+        self.points_list.set_visible(has_points);
+        self.terms_title.set_visible(has_terms);
+        self.terms_list.set_visible(has_terms);
+        self.empty_lbl.set_visible(!has_points && !has_terms);
+    }
+}
+
+impl Default for KeyContentPanel {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,3 +1,11 @@
+pub mod flashcards;
+pub mod import_draft_editor;
+pub mod key_content;
 pub mod quality_selector;
+pub mod study_history;
+pub use flashcards::FlashcardsPanel;
+pub use import_draft_editor::ImportDraftEditor;
+pub use key_content::KeyContentPanel;
 pub use quality_selector::QualityDropDown;
 pub use quality_selector::QualitySelector;
+pub use study_history::{build_heatmap, build_trend_line};
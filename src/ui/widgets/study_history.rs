@@ -0,0 +1,91 @@
+//! Study history widgets for the dashboard.
+//!
+//! - [`build_heatmap`] lays daily watch time out as a week-by-weekday grid.
+//! - [`build_trend_line`] draws the trailing average of daily minutes.
+
+use adw::prelude::*;
+use chrono::Datelike;
+
+use crate::domain::entities::{DailyStudy, StudyHistory};
+
+const CELL_SIZE: i32 = 12;
+/// Upper bounds (minutes) of heatmap intensity levels 0-3; anything above is level 4.
+const LEVEL_BOUNDS: [u64; 4] = [0, 15, 30, 60];
+/// Days averaged into each point of the trend line.
+const TREND_WINDOW_DAYS: usize = 7;
+
+/// Builds a GitHub-style heatmap: one column per week, one row per weekday (Monday first).
+pub fn build_heatmap(history: &StudyHistory) -> gtk::Grid {
+    let grid = gtk::Grid::new();
+    grid.set_row_spacing(3);
+    grid.set_column_spacing(3);
+    grid.add_css_class("heatmap");
+
+    let Some(start) = history.start_date() else {
+        return grid;
+    };
+    let offset = start.weekday().num_days_from_monday() as i32;
+    for (idx, day) in history.days().iter().enumerate() {
+        let slot = idx as i32 + offset;
+        grid.attach(&heatmap_cell(day), slot / 7, slot % 7, 1, 1);
+    }
+    grid
+}
+
+fn heatmap_cell(day: &DailyStudy) -> gtk::Box {
+    let cell = gtk::Box::new(gtk::Orientation::Horizontal, 0);
+    cell.set_size_request(CELL_SIZE, CELL_SIZE);
+    cell.add_css_class("heatmap-cell");
+    let minutes = day.minutes();
+    let level = LEVEL_BOUNDS.iter().filter(|bound| minutes > **bound).count();
+    cell.add_css_class(&format!("heatmap-level-{level}"));
+    cell.set_tooltip_text(Some(&format!("{}: {} min", day.date.format("%b %-d, %Y"), minutes)));
+    cell
+}
+
+/// Draws the trailing average of daily minutes over the last `days` days.
+pub fn build_trend_line(history: &StudyHistory, days: usize) -> gtk::DrawingArea {
+    let averages = history.rolling_average_minutes(TREND_WINDOW_DAYS);
+    let points: Vec<f64> = averages[averages.len().saturating_sub(days)..].to_vec();
+
+    let area = gtk::DrawingArea::new();
+    area.set_content_height(120);
+    area.set_hexpand(true);
+    area.set_tooltip_text(Some(&format!(
+        "{TREND_WINDOW_DAYS}-day average of daily study minutes, last {} days",
+        points.len()
+    )));
+
+    area.set_draw_func(move |area, cr, width, height| {
+        if points.len() < 2 {
+            return;
+        }
+        let color = area.color();
+        let (w, h) = (f64::from(width), f64::from(height));
+        let pad = 6.0;
+        let max = points.iter().copied().fold(1.0_f64, f64::max);
+        let x_at = |idx: usize| pad + (w - 2.0 * pad) * idx as f64 / (points.len() - 1) as f64;
+        let y_at = |value: f64| h - pad - (h - 2.0 * pad) * value / max;
+
+        // Baseline.
+        cr.set_source_rgba(
+            f64::from(color.red()),
+            f64::from(color.green()),
+            f64::from(color.blue()),
+            0.2,
+        );
+        cr.set_line_width(1.0);
+        cr.move_to(pad, h - pad);
+        cr.line_to(w - pad, h - pad);
+        let _ = cr.stroke();
+
+        cr.set_source_rgba(0.21, 0.52, 0.89, 1.0);
+        cr.set_line_width(2.0);
+        cr.move_to(x_at(0), y_at(points[0]));
+        for (idx, value) in points.iter().enumerate().skip(1) {
+            cr.line_to(x_at(idx), y_at(*value));
+        }
+        let _ = cr.stroke();
+    });
+    area
+}
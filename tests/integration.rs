@@ -7,24 +7,41 @@ use std::sync::Arc;
 use std::sync::Mutex;
 
 use course_pilot::domain::{
-    entities::{Course, Exam, Module, Video},
+    entities::{Course, Exam, Flashcard, Module, Note, ReviewCard, StudySchedule, Tag, Video},
     ports::{
-        CourseRepository, ExamRepository, ExaminerAI, FetchError, LLMError, LocalMediaError,
-        LocalMediaScanner, MCQuestion, ModuleRepository, PlaylistFetcher, RawLocalMediaMetadata,
-        RepositoryError, SearchEntry, SearchRepository, SummarizerAI, TranscriptError,
-        TranscriptProvider, VideoRepository,
+        CancellationToken, ChatMessage, ChatMessageRepository, ChatRole, CompanionAI,
+        CompanionContext, ConversationHistory, CourseRepository, ExamRepository, ExaminerAI,
+        FetchError, FlashcardGeneratorAI, FlashcardRepository, GeneratedFlashcard, IngestProgress,
+        LLMError, LocalMediaError, LocalMediaScanner, MCQuestion, ModuleRepository, NoteRepository,
+        PlaybackMoment, PlaybackPosition, PlaybackPositionRepository, PlaylistFetcher, RawChapter,
+        RawLocalMediaMetadata, RawVideoMetadata, RepositoryError, ReviewCardRepository,
+        SearchEntry, SearchRepository, StudyScheduleRepository, SummarizerAI, TagRepository,
+        TranscriptChunk, TranscriptChunkRepository, TranscriptCue, TranscriptCueRepository,
+        TranscriptError, TranscriptProvider, VideoRepository, VideoSummary, WatchSession,
+        WatchSessionRepository,
     },
-    services::{BoundaryDetector, TranscriptChunker},
+    services::{BoundaryDetector, GroupingStrategy, TranscriptChunker},
     value_objects::{
-        CourseId, ExamDifficulty, ExamId, ModuleId, PlaylistUrl, VideoId, VideoSource,
-        YouTubeVideoId,
+        ClipRange, CourseId, CourseSource, ExamDifficulty, ExamId, FlashcardId, FlashcardKind,
+        KeyTerm, ModuleId, PlaylistUrl, ReschedulePolicy, ReviewCardId, ReviewGrade, TagId,
+        VideoId, VideoSource, WatchedRanges, YouTubeVideoId,
     },
 };
 
 use course_pilot::application::use_cases::{
-    GenerateExamInput, SubmitExamInput, SummarizeVideoInput, SummarizeVideoOutput,
-    SummarizeVideoUseCase, TakeExamUseCase,
+    AskCompanionInput, AskCompanionUseCase, CourseManifest, ExplainMomentInput,
+    ExportManifestInput, ExportManifestUseCase, ExportScheduleInput, ExportScheduleUseCase,
+    FlashcardsUseCase, GenerateExamInput, GenerateFlashcardsInput, GenerateFlashcardsUseCase,
+    GradeExamUseCase, ImportDraft, IngestError, IngestLocalError, IngestLocalInput,
+    IngestLocalUseCase, IngestManifestInput, IngestManifestUseCase, IngestPlaylistInput,
+    IngestPlaylistUseCase, LoadStudyHistoryInput, LoadStudyHistoryUseCase, ManifestError,
+    ManifestFormat, PlanCourseScheduleInput, PlanCourseScheduleUseCase, PlaybackUseCase,
+    RecordReviewInput, RelinkLocalInput, RelinkLocalUseCase, RescanLocalInput, RescanLocalUseCase,
+    RescheduleCourseInput, RescheduleCourseUseCase, ReviewUseCase, SaveFlashcardInput,
+    SavePositionInput, ScheduleError, SubmitExamInput, SummarizeVideoInput, SummarizeVideoOutput,
+    SummarizeVideoUseCase, SyncPlaylistInput, SyncPlaylistUseCase, TakeExamUseCase,
 };
+use course_pilot::infrastructure::calendar::IcsCalendarExporter;
 
 // ─── Mock Scanner ───────────────────────────────────────────────────────
 
@@ -79,11 +96,14 @@ impl PlaylistFetcher for MockFetcher {
 
 struct InMemoryCourseRepo {
     courses: Mutex<Vec<Course>>,
+    /// Where `save_with_contents` puts modules and videos.
+    module_repo: Arc<InMemoryModuleRepo>,
+    video_repo: Arc<InMemoryVideoRepo>,
 }
 
 impl InMemoryCourseRepo {
-    fn new() -> Self {
-        Self { courses: Mutex::new(vec![]) }
+    fn new(module_repo: Arc<InMemoryModuleRepo>, video_repo: Arc<InMemoryVideoRepo>) -> Self {
+        Self { courses: Mutex::new(vec![]), module_repo, video_repo }
     }
 }
 
@@ -105,6 +125,17 @@ impl CourseRepository for InMemoryCourseRepo {
         Ok(())
     }
 
+    fn save_with_contents(
+        &self,
+        course: &Course,
+        modules: &[Module],
+        videos: &[Video],
+    ) -> Result<(), RepositoryError> {
+        self.save(course)?;
+        self.module_repo.save_batch(modules)?;
+        self.video_repo.save_batch(videos)
+    }
+
     fn find_by_id(&self, id: &CourseId) -> Result<Option<Course>, RepositoryError> {
         let c = self.courses.lock().unwrap();
         Ok(c.iter().find(|e| e.id() == id).cloned())
@@ -131,8 +162,9 @@ impl CourseRepository for InMemoryCourseRepo {
         Ok(())
     }
 
-    fn find_by_source_hash(&self, _hash: &str) -> Result<Option<Course>, RepositoryError> {
-        Ok(None)
+    fn find_by_source_hash(&self, hash: &str) -> Result<Option<Course>, RepositoryError> {
+        let c = self.courses.lock().unwrap();
+        Ok(c.iter().find(|e| e.source_hash() == Some(hash)).cloned())
     }
 }
 
@@ -283,6 +315,19 @@ impl VideoRepository for InMemoryVideoRepo {
         Ok(())
     }
 
+    fn update_key_content(
+        &self,
+        id: &VideoId,
+        key_points: &[String],
+        key_terms: &[KeyTerm],
+    ) -> Result<(), RepositoryError> {
+        let mut v = self.videos.lock().unwrap();
+        if let Some(pos) = v.iter().position(|e| e.id() == id) {
+            v[pos].update_key_content(key_points.to_vec(), key_terms.to_vec());
+        }
+        Ok(())
+    }
+
     fn update_module(
         &self,
         _id: &VideoId,
@@ -362,8 +407,175 @@ impl SearchRepository for InMemorySearchRepo {
     }
 }
 
+#[derive(Default)]
+struct InMemoryTagRepo {
+    tags: Mutex<Vec<Tag>>,
+    course_tags: Mutex<Vec<(CourseId, TagId)>>,
+}
+
+impl TagRepository for InMemoryTagRepo {
+    fn save(&self, tag: &Tag) -> Result<(), RepositoryError> {
+        let mut tags = self.tags.lock().unwrap();
+        tags.retain(|t| t.id() != tag.id());
+        tags.push(tag.clone());
+        Ok(())
+    }
+    fn find_all(&self) -> Result<Vec<Tag>, RepositoryError> {
+        Ok(self.tags.lock().unwrap().clone())
+    }
+    fn find_by_course(&self, course_id: &CourseId) -> Result<Vec<Tag>, RepositoryError> {
+        let links = self.course_tags.lock().unwrap();
+        Ok(self
+            .tags
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|t| links.contains(&(*course_id, *t.id())))
+            .cloned()
+            .collect())
+    }
+    fn add_to_course(&self, course_id: &CourseId, tag_id: &TagId) -> Result<(), RepositoryError> {
+        self.course_tags.lock().unwrap().push((*course_id, *tag_id));
+        Ok(())
+    }
+    fn remove_from_course(
+        &self,
+        course_id: &CourseId,
+        tag_id: &TagId,
+    ) -> Result<(), RepositoryError> {
+        self.course_tags.lock().unwrap().retain(|link| link != &(*course_id, *tag_id));
+        Ok(())
+    }
+    fn delete(&self, tag_id: &TagId) -> Result<(), RepositoryError> {
+        self.tags.lock().unwrap().retain(|t| t.id() != tag_id);
+        Ok(())
+    }
+}
+
+struct InMemoryScheduleRepo {
+    schedules: Mutex<Vec<StudySchedule>>,
+}
+
+impl InMemoryScheduleRepo {
+    fn new() -> Self {
+        Self { schedules: Mutex::new(vec![]) }
+    }
+}
+
+impl StudyScheduleRepository for InMemoryScheduleRepo {
+    fn save(&self, schedule: &StudySchedule) -> Result<(), RepositoryError> {
+        let mut s = self.schedules.lock().unwrap();
+        s.retain(|e| e.course_id() != schedule.course_id());
+        s.push(schedule.clone());
+        Ok(())
+    }
+
+    fn find_by_course(
+        &self,
+        course_id: &CourseId,
+    ) -> Result<Option<StudySchedule>, RepositoryError> {
+        let s = self.schedules.lock().unwrap();
+        Ok(s.iter().find(|e| e.course_id() == course_id).cloned())
+    }
+
+    fn find_all(&self) -> Result<Vec<StudySchedule>, RepositoryError> {
+        Ok(self.schedules.lock().unwrap().clone())
+    }
+
+    fn delete(&self, course_id: &CourseId) -> Result<(), RepositoryError> {
+        self.schedules.lock().unwrap().retain(|e| e.course_id() != course_id);
+        Ok(())
+    }
+}
+
+struct InMemoryPlaybackRepo {
+    positions: Mutex<Vec<PlaybackPosition>>,
+    video_repo: Arc<InMemoryVideoRepo>,
+}
+
+impl InMemoryPlaybackRepo {
+    fn new(video_repo: Arc<InMemoryVideoRepo>) -> Self {
+        Self { positions: Mutex::new(vec![]), video_repo }
+    }
+}
+
+impl PlaybackPositionRepository for InMemoryPlaybackRepo {
+    fn save(&self, position: &PlaybackPosition) -> Result<(), RepositoryError> {
+        let mut p = self.positions.lock().unwrap();
+        p.retain(|e| e.video_id != position.video_id);
+        p.push(position.clone());
+        Ok(())
+    }
+
+    fn find_by_video(
+        &self,
+        video_id: &VideoId,
+    ) -> Result<Option<PlaybackPosition>, RepositoryError> {
+        let p = self.positions.lock().unwrap();
+        Ok(p.iter().find(|e| &e.video_id == video_id).cloned())
+    }
+
+    fn find_latest_by_course(
+        &self,
+        course_id: &CourseId,
+    ) -> Result<Option<PlaybackPosition>, RepositoryError> {
+        let course_videos: Vec<VideoId> =
+            self.video_repo.find_by_course(course_id)?.iter().map(|v| *v.id()).collect();
+        let p = self.positions.lock().unwrap();
+        Ok(p.iter()
+            .filter(|e| course_videos.contains(&e.video_id))
+            .max_by_key(|e| e.updated_at)
+            .cloned())
+    }
+
+    fn delete(&self, video_id: &VideoId) -> Result<(), RepositoryError> {
+        self.positions.lock().unwrap().retain(|e| &e.video_id != video_id);
+        Ok(())
+    }
+}
+
 // ─── Mock Transcript Provider & LLMs ───────────────────────────────────
 
+struct InMemoryWatchSessionRepo {
+    sessions: Mutex<Vec<WatchSession>>,
+    video_repo: Arc<InMemoryVideoRepo>,
+}
+
+impl InMemoryWatchSessionRepo {
+    fn new(video_repo: Arc<InMemoryVideoRepo>) -> Self {
+        Self { sessions: Mutex::new(vec![]), video_repo }
+    }
+}
+
+impl WatchSessionRepository for InMemoryWatchSessionRepo {
+    fn record(&self, session: &WatchSession) -> Result<(), RepositoryError> {
+        self.sessions.lock().unwrap().push(session.clone());
+        Ok(())
+    }
+
+    fn find_since(
+        &self,
+        since: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Vec<WatchSession>, RepositoryError> {
+        let s = self.sessions.lock().unwrap();
+        Ok(s.iter().filter(|e| e.started_at >= since).cloned().collect())
+    }
+
+    fn find_by_course_since(
+        &self,
+        course_id: &CourseId,
+        since: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Vec<WatchSession>, RepositoryError> {
+        let course_videos: Vec<VideoId> =
+            self.video_repo.find_by_course(course_id)?.iter().map(|v| *v.id()).collect();
+        Ok(self
+            .find_since(since)?
+            .into_iter()
+            .filter(|e| course_videos.contains(&e.video_id))
+            .collect())
+    }
+}
+
 struct InMemoryExamRepo {
     exams: Mutex<Vec<Exam>>,
 }
@@ -417,6 +629,48 @@ impl ExamRepository for InMemoryExamRepo {
     }
 }
 
+struct InMemoryReviewCardRepo {
+    cards: Mutex<Vec<ReviewCard>>,
+}
+
+impl InMemoryReviewCardRepo {
+    fn new() -> Self {
+        Self { cards: Mutex::new(vec![]) }
+    }
+}
+
+impl ReviewCardRepository for InMemoryReviewCardRepo {
+    fn save(&self, card: &ReviewCard) -> Result<(), RepositoryError> {
+        let mut c = self.cards.lock().unwrap();
+        if let Some(pos) = c.iter().position(|r| r.id() == card.id()) {
+            c[pos] = card.clone();
+        } else {
+            c.push(card.clone());
+        }
+        Ok(())
+    }
+
+    fn find_by_id(&self, id: &ReviewCardId) -> Result<Option<ReviewCard>, RepositoryError> {
+        let c = self.cards.lock().unwrap();
+        Ok(c.iter().find(|r| r.id() == id).cloned())
+    }
+
+    fn find_by_exam(&self, exam_id: &ExamId) -> Result<Vec<ReviewCard>, RepositoryError> {
+        let c = self.cards.lock().unwrap();
+        let mut cards: Vec<ReviewCard> =
+            c.iter().filter(|r| r.exam_id() == exam_id).cloned().collect();
+        cards.sort_by_key(|r| r.question_index());
+        Ok(cards)
+    }
+
+    fn find_due(&self, date: chrono::NaiveDate) -> Result<Vec<ReviewCard>, RepositoryError> {
+        let c = self.cards.lock().unwrap();
+        let mut cards: Vec<ReviewCard> = c.iter().filter(|r| r.is_due(date)).cloned().collect();
+        cards.sort_by_key(|r| (r.schedule().due(), r.question_index()));
+        Ok(cards)
+    }
+}
+
 struct MockTranscriptProvider {
     transcript: String,
 }
@@ -428,8 +682,26 @@ impl TranscriptProvider for MockTranscriptProvider {
     }
 }
 
+/// Serves timed captions only, counting how often they are fetched.
+struct MockCaptionProvider {
+    cues: Vec<TranscriptCue>,
+    fetches: Mutex<usize>,
+}
+
+#[async_trait::async_trait]
+impl TranscriptProvider for MockCaptionProvider {
+    async fn fetch_transcript(&self, _video_id: &str) -> Result<String, TranscriptError> {
+        Err(TranscriptError::NotAvailable)
+    }
+
+    async fn fetch_cues(&self, _video_id: &str) -> Result<Vec<TranscriptCue>, TranscriptError> {
+        *self.fetches.lock().unwrap() += 1;
+        Ok(self.cues.clone())
+    }
+}
+
 struct MockSummarizerAI {
-    summary: String,
+    summary: VideoSummary,
 }
 
 #[async_trait::async_trait]
@@ -438,7 +710,7 @@ impl SummarizerAI for MockSummarizerAI {
         &self,
         _transcript: &str,
         _video_title: &str,
-    ) -> Result<String, LLMError> {
+    ) -> Result<VideoSummary, LLMError> {
         Ok(self.summary.clone())
     }
 }
@@ -461,6 +733,281 @@ impl ExaminerAI for MockExaminerAI {
     }
 }
 
+struct InMemoryFlashcardRepo {
+    cards: Mutex<Vec<Flashcard>>,
+}
+
+impl InMemoryFlashcardRepo {
+    fn new() -> Self {
+        Self { cards: Mutex::new(vec![]) }
+    }
+}
+
+impl FlashcardRepository for InMemoryFlashcardRepo {
+    fn save(&self, card: &Flashcard) -> Result<(), RepositoryError> {
+        let mut c = self.cards.lock().unwrap();
+        if let Some(pos) = c.iter().position(|r| r.id() == card.id()) {
+            c[pos] = card.clone();
+        } else {
+            c.push(card.clone());
+        }
+        Ok(())
+    }
+
+    fn find_by_id(&self, id: &FlashcardId) -> Result<Option<Flashcard>, RepositoryError> {
+        let c = self.cards.lock().unwrap();
+        Ok(c.iter().find(|r| r.id() == id).cloned())
+    }
+
+    fn find_by_video(&self, video_id: &VideoId) -> Result<Vec<Flashcard>, RepositoryError> {
+        let c = self.cards.lock().unwrap();
+        let mut cards: Vec<Flashcard> =
+            c.iter().filter(|r| r.video_id() == video_id).cloned().collect();
+        cards.sort_by_key(|r| r.position());
+        Ok(cards)
+    }
+
+    fn delete(&self, id: &FlashcardId) -> Result<(), RepositoryError> {
+        self.cards.lock().unwrap().retain(|r| r.id() != id);
+        Ok(())
+    }
+}
+
+struct MockFlashcardGenerator {
+    cards: Vec<GeneratedFlashcard>,
+    seen_terms: Mutex<Vec<KeyTerm>>,
+}
+
+#[async_trait::async_trait]
+impl FlashcardGeneratorAI for MockFlashcardGenerator {
+    async fn generate_flashcards(
+        &self,
+        _video_title: &str,
+        _video_summary: Option<&str>,
+        _transcript: Option<&str>,
+        key_terms: &[KeyTerm],
+        _max_cards: u8,
+    ) -> Result<Vec<GeneratedFlashcard>, LLMError> {
+        *self.seen_terms.lock().unwrap() = key_terms.to_vec();
+        Ok(self.cards.clone())
+    }
+}
+
+struct InMemoryNoteRepo {
+    notes: Mutex<Vec<Note>>,
+}
+
+impl InMemoryNoteRepo {
+    fn new() -> Self {
+        Self { notes: Mutex::new(vec![]) }
+    }
+}
+
+impl NoteRepository for InMemoryNoteRepo {
+    fn save(&self, note: &Note) -> Result<(), RepositoryError> {
+        let mut n = self.notes.lock().unwrap();
+        n.retain(|e| e.video_id() != note.video_id());
+        n.push(note.clone());
+        Ok(())
+    }
+
+    fn find_by_video(&self, video_id: &VideoId) -> Result<Option<Note>, RepositoryError> {
+        Ok(self.notes.lock().unwrap().iter().find(|n| n.video_id() == video_id).cloned())
+    }
+
+    fn delete(&self, video_id: &VideoId) -> Result<(), RepositoryError> {
+        self.notes.lock().unwrap().retain(|n| n.video_id() != video_id);
+        Ok(())
+    }
+}
+
+struct InMemoryChatRepo {
+    messages: Mutex<Vec<ChatMessage>>,
+}
+
+impl InMemoryChatRepo {
+    fn new() -> Self {
+        Self { messages: Mutex::new(vec![]) }
+    }
+}
+
+impl ChatMessageRepository for InMemoryChatRepo {
+    fn save(&self, message: &ChatMessage) -> Result<(), RepositoryError> {
+        self.messages.lock().unwrap().push(message.clone());
+        Ok(())
+    }
+
+    fn find_by_video(&self, video_id: &VideoId) -> Result<Vec<ChatMessage>, RepositoryError> {
+        let m = self.messages.lock().unwrap();
+        Ok(m.iter().filter(|msg| &msg.video_id == video_id).cloned().collect())
+    }
+
+    fn delete_by_video(&self, video_id: &VideoId) -> Result<(), RepositoryError> {
+        self.messages.lock().unwrap().retain(|msg| &msg.video_id != video_id);
+        Ok(())
+    }
+}
+
+/// Scores chunks by how many of the query's words they contain.
+struct InMemoryTranscriptChunkRepo {
+    chunks: Mutex<Vec<(VideoId, String, TranscriptChunk)>>,
+}
+
+impl InMemoryTranscriptChunkRepo {
+    fn new() -> Self {
+        Self { chunks: Mutex::new(vec![]) }
+    }
+}
+
+impl TranscriptChunkRepository for InMemoryTranscriptChunkRepo {
+    fn indexed_hash(&self, video_id: &VideoId) -> Result<Option<String>, RepositoryError> {
+        let c = self.chunks.lock().unwrap();
+        Ok(c.iter().find(|(id, _, _)| id == video_id).map(|(_, hash, _)| hash.clone()))
+    }
+
+    fn replace_chunks(
+        &self,
+        video_id: &VideoId,
+        transcript_hash: &str,
+        chunks: &[String],
+    ) -> Result<(), RepositoryError> {
+        let mut c = self.chunks.lock().unwrap();
+        c.retain(|(id, _, _)| id != video_id);
+        for (index, content) in chunks.iter().enumerate() {
+            let chunk = TranscriptChunk { index, content: content.clone() };
+            c.push((*video_id, transcript_hash.to_string(), chunk));
+        }
+        Ok(())
+    }
+
+    fn search(
+        &self,
+        video_id: &VideoId,
+        query: &str,
+        limit: usize,
+    ) -> Result<Vec<TranscriptChunk>, RepositoryError> {
+        let words: Vec<String> = query
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|w| w.len() > 3)
+            .map(str::to_lowercase)
+            .collect();
+        let c = self.chunks.lock().unwrap();
+        let mut scored: Vec<(usize, TranscriptChunk)> = c
+            .iter()
+            .filter(|(id, _, _)| id == video_id)
+            .map(|(_, _, chunk)| {
+                let text = chunk.content.to_lowercase();
+                (words.iter().filter(|w| text.contains(w.as_str())).count(), chunk.clone())
+            })
+            .filter(|(score, _)| *score > 0)
+            .collect();
+        scored.sort_by(|a, b| b.0.cmp(&a.0));
+        Ok(scored.into_iter().take(limit).map(|(_, chunk)| chunk).collect())
+    }
+
+    fn remove_video(&self, video_id: &VideoId) -> Result<(), RepositoryError> {
+        self.chunks.lock().unwrap().retain(|(id, _, _)| id != video_id);
+        Ok(())
+    }
+}
+
+struct InMemoryTranscriptCueRepo {
+    cues: Mutex<std::collections::HashMap<VideoId, (String, Vec<TranscriptCue>)>>,
+}
+
+impl InMemoryTranscriptCueRepo {
+    fn new() -> Self {
+        Self { cues: Mutex::new(std::collections::HashMap::new()) }
+    }
+}
+
+impl TranscriptCueRepository for InMemoryTranscriptCueRepo {
+    fn replace_for_video(
+        &self,
+        video_id: &VideoId,
+        source_hash: &str,
+        cues: &[TranscriptCue],
+    ) -> Result<(), RepositoryError> {
+        let mut stored = self.cues.lock().unwrap();
+        if cues.is_empty() {
+            stored.remove(video_id);
+        } else {
+            stored.insert(*video_id, (source_hash.to_string(), cues.to_vec()));
+        }
+        Ok(())
+    }
+
+    fn source_hash(&self, video_id: &VideoId) -> Result<Option<String>, RepositoryError> {
+        Ok(self.cues.lock().unwrap().get(video_id).map(|(hash, _)| hash.clone()))
+    }
+
+    fn find_by_video(&self, video_id: &VideoId) -> Result<Vec<TranscriptCue>, RepositoryError> {
+        Ok(self
+            .cues
+            .lock()
+            .unwrap()
+            .get(video_id)
+            .map(|(_, cues)| cues.clone())
+            .unwrap_or_default())
+    }
+}
+
+/// Streams `deltas`, then never finishes when `stall` is set.
+struct MockCompanionAI {
+    deltas: Vec<String>,
+    stall: bool,
+    seen_history: Mutex<Option<ConversationHistory>>,
+    seen_excerpts: Mutex<Vec<TranscriptChunk>>,
+    seen_moment: Mutex<Option<PlaybackMoment>>,
+}
+
+impl MockCompanionAI {
+    fn new(deltas: &[&str], stall: bool) -> Self {
+        Self {
+            deltas: deltas.iter().map(|d| d.to_string()).collect(),
+            stall,
+            seen_history: Mutex::new(None),
+            seen_excerpts: Mutex::new(vec![]),
+            seen_moment: Mutex::new(None),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl CompanionAI for MockCompanionAI {
+    async fn ask(
+        &self,
+        _question: &str,
+        context: &CompanionContext,
+        history: &ConversationHistory,
+    ) -> Result<String, LLMError> {
+        *self.seen_history.lock().unwrap() = Some(history.clone());
+        *self.seen_excerpts.lock().unwrap() = context.transcript_excerpts.clone();
+        *self.seen_moment.lock().unwrap() = context.moment.clone();
+        Ok(self.deltas.concat())
+    }
+
+    async fn ask_streaming(
+        &self,
+        _question: &str,
+        context: &CompanionContext,
+        history: &ConversationHistory,
+        on_delta: &(dyn Fn(&str) + Send + Sync),
+    ) -> Result<String, LLMError> {
+        *self.seen_history.lock().unwrap() = Some(history.clone());
+        *self.seen_excerpts.lock().unwrap() = context.transcript_excerpts.clone();
+        *self.seen_moment.lock().unwrap() = context.moment.clone();
+        for delta in &self.deltas {
+            on_delta(delta);
+            tokio::task::yield_now().await;
+        }
+        if self.stall {
+            std::future::pending::<()>().await;
+        }
+        Ok(self.deltas.concat())
+    }
+}
+
 // ─── Tests ──────────────────────────────────────────────────────────────
 
 #[test]
@@ -471,39 +1018,43 @@ fn ingest_local_with_folder_grouping() {
             title: "Lesson 1 - Intro".to_string(),
             duration_secs: 600,
             subtitles: vec![],
+            chapters: Vec::new(),
         },
         RawLocalMediaMetadata {
             path: "/videos/module1/vid2.mp4".to_string(),
             title: "Lesson 2 - Basics".to_string(),
             duration_secs: 900,
             subtitles: vec![],
+            chapters: Vec::new(),
         },
         RawLocalMediaMetadata {
             path: "/videos/module2/vid3.mp4".to_string(),
             title: "Lesson 3 - Advanced".to_string(),
             duration_secs: 1200,
             subtitles: vec![],
+            chapters: Vec::new(),
         },
     ]));
 
-    let course_repo = Arc::new(InMemoryCourseRepo::new());
     let module_repo = Arc::new(InMemoryModuleRepo::new());
     let video_repo = Arc::new(InMemoryVideoRepo::new(module_repo.clone()));
+    let course_repo = Arc::new(InMemoryCourseRepo::new(module_repo.clone(), video_repo.clone()));
     let search_repo = Arc::new(InMemorySearchRepo);
 
     let use_case = course_pilot::application::use_cases::IngestLocalUseCase::new(
         scanner,
         course_repo.clone(),
-        module_repo.clone(),
-        video_repo.clone(),
         search_repo,
         None,
         5,
+        GroupingStrategy::TitlePatterns,
+        0,
     );
 
     let input = course_pilot::application::use_cases::IngestLocalInput {
         root_path: "/videos".to_string(),
         course_name: Some("Test Course".to_string()),
+        split_chapters: false,
     };
 
     let result = tokio::runtime::Runtime::new().unwrap().block_on(use_case.execute(input));
@@ -516,6 +1067,8 @@ fn ingest_local_with_folder_grouping() {
     let courses = course_repo.find_all().unwrap();
     assert_eq!(courses.len(), 1);
     assert_eq!(courses[0].name(), "Test Course");
+    assert_eq!(courses[0].source(), &CourseSource::local_folder("/videos").unwrap());
+    assert!(courses[0].source().playlist_url().is_none());
 
     let modules = module_repo.find_by_course(courses[0].id()).unwrap();
     assert_eq!(modules.len(), 2);
@@ -536,6 +1089,7 @@ fn ingest_playlist_with_mock_fetcher() {
             description: Some("First video".to_string()),
             duration_secs: 600,
             position: 0,
+            chapters: Vec::new(),
         },
         course_pilot::domain::ports::RawVideoMetadata {
             youtube_id: "9bZkp7q19f0".to_string(),
@@ -543,27 +1097,29 @@ fn ingest_playlist_with_mock_fetcher() {
             description: Some("Second video".to_string()),
             duration_secs: 900,
             position: 1,
+            chapters: Vec::new(),
         },
     ]));
 
-    let course_repo = Arc::new(InMemoryCourseRepo::new());
     let module_repo = Arc::new(InMemoryModuleRepo::new());
     let video_repo = Arc::new(InMemoryVideoRepo::new(module_repo.clone()));
+    let course_repo = Arc::new(InMemoryCourseRepo::new(module_repo.clone(), video_repo.clone()));
     let search_repo = Arc::new(InMemorySearchRepo);
 
     let use_case = course_pilot::application::use_cases::IngestPlaylistUseCase::new(
         fetcher,
         course_repo.clone(),
-        module_repo.clone(),
-        video_repo.clone(),
         search_repo,
         None,
         5,
+        GroupingStrategy::TitlePatterns,
+        0,
     );
 
     let input = course_pilot::application::use_cases::IngestPlaylistInput {
         playlist_url: "https://www.youtube.com/playlist?list=PLtest123".to_string(),
         course_name: Some("Test Course".to_string()),
+        split_chapters: false,
     };
 
     let result = tokio::runtime::Runtime::new().unwrap().block_on(use_case.execute(input));
@@ -576,29 +1132,31 @@ fn ingest_playlist_with_mock_fetcher() {
     let courses = course_repo.find_all().unwrap();
     assert_eq!(courses.len(), 1);
     assert_eq!(courses[0].name(), "Test Course");
+    assert_eq!(courses[0].source().playlist_url().map(|url| url.playlist_id()), Some("PLtest123"));
 }
 
 #[test]
 fn ingest_playlist_failure_returns_error() {
     let fetcher = Arc::new(MockFetcher::with_failure());
-    let course_repo = Arc::new(InMemoryCourseRepo::new());
     let module_repo = Arc::new(InMemoryModuleRepo::new());
     let video_repo = Arc::new(InMemoryVideoRepo::new(module_repo.clone()));
+    let course_repo = Arc::new(InMemoryCourseRepo::new(module_repo.clone(), video_repo.clone()));
     let search_repo = Arc::new(InMemorySearchRepo);
 
     let use_case = course_pilot::application::use_cases::IngestPlaylistUseCase::new(
         fetcher,
         course_repo.clone(),
-        module_repo.clone(),
-        video_repo.clone(),
         search_repo,
         None,
         5,
+        GroupingStrategy::TitlePatterns,
+        0,
     );
 
     let input = course_pilot::application::use_cases::IngestPlaylistInput {
         playlist_url: "https://www.youtube.com/playlist?list=PLnonexistent".to_string(),
         course_name: None,
+        split_chapters: false,
     };
 
     let result = tokio::runtime::Runtime::new().unwrap().block_on(use_case.execute(input));
@@ -612,15 +1170,736 @@ fn ingest_playlist_failure_returns_error() {
 }
 
 #[test]
-fn boundary_detector_integration() {
-    let detector = BoundaryDetector::new();
-    let titles = vec![
-        "1.1 Introduction",
-        "1.2 Setup",
-        "1.3 Configuration",
-        "2.1 Advanced Topics",
-        "2.2 Deep Dive",
-        "3.1 Conclusion",
+fn ingest_playlist_commits_an_edited_draft() {
+    let module_repo = Arc::new(InMemoryModuleRepo::new());
+    let video_repo = Arc::new(InMemoryVideoRepo::new(module_repo.clone()));
+    let course_repo = Arc::new(InMemoryCourseRepo::new(module_repo.clone(), video_repo.clone()));
+    let ingest = IngestPlaylistUseCase::new(
+        Arc::new(MockFetcher::new(vec![
+            raw_video("dQw4w9WgXcQ", "Intro", 0),
+            raw_video("9bZkp7q19f0", "Setup", 1),
+            raw_video("kJQP7kiw5Fk", "Basics", 2),
+        ])),
+        course_repo.clone(),
+        Arc::new(InMemorySearchRepo),
+        None,
+        5,
+        GroupingStrategy::TitlePatterns,
+        0,
+    );
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    let mut draft = rt
+        .block_on(ingest.plan(IngestPlaylistInput {
+            playlist_url: "https://www.youtube.com/playlist?list=PLdraft".to_string(),
+            course_name: None,
+            split_chapters: false,
+        }))
+        .unwrap();
+    assert!(course_repo.find_all().unwrap().is_empty(), "planning persists nothing");
+    assert_eq!(draft.video_count(), 3);
+
+    while draft.modules.len() > 1 {
+        draft.merge_with_next(0).unwrap();
+    }
+    draft.split_module(0, 1, "Getting Started").unwrap();
+    draft.rename_module(0, "Welcome").unwrap();
+    draft.move_module(1, 0).unwrap();
+    draft.course_name = "Edited Course".to_string();
+
+    // The draft survives a round trip, e.g. through a saved preview.
+    let json = serde_json::to_string(&draft).unwrap();
+    let draft: ImportDraft = serde_json::from_str(&json).unwrap();
+
+    let output = ingest.commit(&draft).unwrap();
+    assert_eq!(output.modules_count, 2);
+    assert_eq!(output.videos_count, 3);
+    let course = course_repo.find_by_id(&output.course_id).unwrap().unwrap();
+    assert_eq!(course.name(), "Edited Course");
+
+    let mut modules = module_repo.find_by_course(&output.course_id).unwrap();
+    modules.sort_by_key(|m| m.sort_order());
+    let titles: Vec<&str> = modules.iter().map(|m| m.title()).collect();
+    assert_eq!(titles, vec!["Getting Started", "Welcome"]);
+    let mut first: Vec<Video> = video_repo.find_by_module(modules[0].id()).unwrap();
+    first.sort_by_key(|v| v.sort_order());
+    let first: Vec<&str> = first.iter().map(|v| v.title()).collect();
+    assert_eq!(first, vec!["Setup", "Basics"]);
+
+    assert!(
+        matches!(ingest.commit(&draft), Err(IngestError::AlreadyExists(_))),
+        "a draft commits once"
+    );
+}
+
+#[test]
+fn ingest_playlist_groups_by_topic_shift_when_enabled() {
+    let lessons = [
+        ("Lesson", "Installing the Rust toolchain with rustup"),
+        ("Lesson", "Rustup toolchain components and cargo"),
+        ("Lesson", "Cargo projects and the toolchain"),
+        ("Lesson", "Ownership rules and moves"),
+        ("Lesson", "Borrowing and ownership in functions"),
+        ("Lesson", "Ownership, borrowing and slices"),
+    ];
+    let videos = lessons
+        .iter()
+        .enumerate()
+        .map(|(i, (title, description))| RawVideoMetadata {
+            description: Some(description.to_string()),
+            ..raw_video(&format!("topicvid{i:03}"), title, i as u32)
+        })
+        .collect::<Vec<_>>();
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    let plan = |grouping| {
+        let module_repo = Arc::new(InMemoryModuleRepo::new());
+        let video_repo = Arc::new(InMemoryVideoRepo::new(module_repo.clone()));
+        let ingest = IngestPlaylistUseCase::new(
+            Arc::new(MockFetcher::new(videos.clone())),
+            Arc::new(InMemoryCourseRepo::new(module_repo, video_repo)),
+            Arc::new(InMemorySearchRepo),
+            None,
+            4,
+            grouping,
+            0,
+        );
+        let draft = rt
+            .block_on(ingest.plan(IngestPlaylistInput {
+                playlist_url: "https://www.youtube.com/playlist?list=PLtopics".to_string(),
+                course_name: None,
+                split_chapters: false,
+            }))
+            .unwrap();
+        draft.modules.iter().map(|m| m.videos.len()).collect::<Vec<_>>()
+    };
+
+    // Identical titles give the title patterns nothing to go on; descriptions carry the topics.
+    assert_eq!(plan(GroupingStrategy::TopicShift), vec![3, 3]);
+    assert_eq!(plan(GroupingStrategy::TitlePatterns), vec![4, 2]);
+}
+
+#[test]
+fn ingest_playlist_balances_modules_by_target_duration() {
+    let minutes = [10, 15, 5, 50, 10, 5, 5, 20];
+    let videos = minutes
+        .iter()
+        .enumerate()
+        .map(|(i, &m)| RawVideoMetadata {
+            duration_secs: m * 60,
+            ..raw_video(&format!("durvid{i:03}"), &format!("Clip {i}"), i as u32)
+        })
+        .collect::<Vec<_>>();
+    let module_repo = Arc::new(InMemoryModuleRepo::new());
+    let video_repo = Arc::new(InMemoryVideoRepo::new(module_repo.clone()));
+    let ingest = IngestPlaylistUseCase::new(
+        Arc::new(MockFetcher::new(videos)),
+        Arc::new(InMemoryCourseRepo::new(module_repo, video_repo)),
+        Arc::new(InMemorySearchRepo),
+        None,
+        5,
+        GroupingStrategy::TitlePatterns,
+        30,
+    );
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    let draft = rt
+        .block_on(ingest.plan(IngestPlaylistInput {
+            playlist_url: "https://www.youtube.com/playlist?list=PLdurations".to_string(),
+            course_name: None,
+            split_chapters: false,
+        }))
+        .unwrap();
+
+    let sizes = draft.modules.iter().map(|m| m.videos.len()).collect::<Vec<_>>();
+    assert_eq!(sizes, vec![3, 1, 4]);
+}
+
+#[test]
+fn ingest_playlist_splits_a_chaptered_video_into_lessons() {
+    let chapter = |title: &str, start_secs, end_secs| RawChapter {
+        title: title.to_string(),
+        start_secs,
+        end_secs,
+    };
+    let video = RawVideoMetadata {
+        duration_secs: 1800,
+        chapters: vec![
+            chapter("Intro", 0, 120),
+            chapter("Ownership", 120, 900),
+            chapter("Borrowing", 900, 1800),
+        ],
+        ..raw_video("dQw4w9WgXcQ", "Rust Crash Course", 0)
+    };
+    let module_repo = Arc::new(InMemoryModuleRepo::new());
+    let video_repo = Arc::new(InMemoryVideoRepo::new(module_repo.clone()));
+    let ingest = IngestPlaylistUseCase::new(
+        Arc::new(MockFetcher::new(vec![video])),
+        Arc::new(InMemoryCourseRepo::new(module_repo, video_repo.clone())),
+        Arc::new(InMemorySearchRepo),
+        None,
+        5,
+        GroupingStrategy::TitlePatterns,
+        0,
+    );
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let plan = |split_chapters| {
+        rt.block_on(ingest.plan(IngestPlaylistInput {
+            playlist_url: "https://www.youtube.com/watch?v=dQw4w9WgXcQ".to_string(),
+            course_name: None,
+            split_chapters,
+        }))
+        .unwrap()
+    };
+
+    assert_eq!(plan(false).video_count(), 1, "chapters are ignored unless asked for");
+
+    let draft = plan(true);
+    assert_eq!(draft.course_name, "Rust Crash Course");
+    assert_eq!(draft.video_count(), 3);
+
+    let output = ingest.commit(&draft).unwrap();
+    let mut lessons = video_repo.find_by_course(&output.course_id).unwrap();
+    lessons.sort_by_key(|v| v.clip().map(|clip| clip.start_secs()));
+    let lessons: Vec<(&str, u32, Option<ClipRange>)> =
+        lessons.iter().map(|v| (v.title(), v.duration_secs(), v.clip())).collect();
+    assert_eq!(
+        lessons,
+        vec![
+            ("Intro", 120, ClipRange::new(0, 120)),
+            ("Ownership", 780, ClipRange::new(120, 900)),
+            ("Borrowing", 900, ClipRange::new(900, 1800)),
+        ]
+    );
+}
+
+#[test]
+fn ingest_reports_progress_and_cancels_without_writing() {
+    let module_repo = Arc::new(InMemoryModuleRepo::new());
+    let video_repo = Arc::new(InMemoryVideoRepo::new(module_repo.clone()));
+    let course_repo = Arc::new(InMemoryCourseRepo::new(module_repo.clone(), video_repo.clone()));
+    let playlist = || {
+        IngestPlaylistUseCase::new(
+            Arc::new(MockFetcher::new(vec![
+                raw_video("dQw4w9WgXcQ", "Module 1 - Intro", 0),
+                raw_video("9bZkp7q19f0", "Module 2 - Setup", 1),
+            ])),
+            course_repo.clone(),
+            Arc::new(InMemorySearchRepo),
+            None,
+            5,
+            GroupingStrategy::TitlePatterns,
+            0,
+        )
+    };
+    let input = || IngestPlaylistInput {
+        playlist_url: "https://www.youtube.com/playlist?list=PLprogress".to_string(),
+        course_name: None,
+        split_chapters: false,
+    };
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    // Cancelling while module titles are generated stops before anything is saved.
+    let cancel = CancellationToken::new();
+    let stop = cancel.clone();
+    let use_case = playlist().with_cancellation(cancel).with_progress(Arc::new(
+        move |progress: IngestProgress| {
+            if matches!(progress, IngestProgress::GeneratingTitles { .. }) {
+                stop.cancel();
+            }
+        },
+    ));
+    let result = rt.block_on(use_case.execute(input()));
+    assert!(matches!(result, Err(IngestError::Cancelled)));
+    assert!(course_repo.find_all().unwrap().is_empty());
+
+    // Without cancellation every step is reported, in order.
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let sink = events.clone();
+    let use_case = playlist().with_progress(Arc::new(move |progress: IngestProgress| {
+        sink.lock().unwrap().push(progress);
+    }));
+    let output = rt.block_on(use_case.execute(input())).unwrap();
+    let events = events.lock().unwrap();
+    let total = output.modules_count;
+    assert_eq!(events.first(), Some(&IngestProgress::Fetching));
+    assert_eq!(events.last(), Some(&IngestProgress::Persisting));
+    assert!(events.contains(&IngestProgress::GeneratingTitles { done: total, total }));
+
+    // A local import cancelled up front never scans.
+    let cancel = CancellationToken::new();
+    cancel.cancel();
+    let local = IngestLocalUseCase::new(
+        Arc::new(MockScanner::new(Vec::new())),
+        course_repo.clone(),
+        Arc::new(InMemorySearchRepo),
+        None,
+        5,
+        GroupingStrategy::TitlePatterns,
+        0,
+    )
+    .with_cancellation(cancel);
+    let result = rt.block_on(local.execute(IngestLocalInput {
+        root_path: "/videos".to_string(),
+        course_name: None,
+        split_chapters: false,
+    }));
+    assert!(matches!(result, Err(IngestLocalError::Cancelled)));
+    assert_eq!(course_repo.find_all().unwrap().len(), 1);
+}
+
+#[test]
+fn manifest_import_and_export_round_trip() {
+    let module_repo = Arc::new(InMemoryModuleRepo::new());
+    let video_repo = Arc::new(InMemoryVideoRepo::new(module_repo.clone()));
+    let course_repo = Arc::new(InMemoryCourseRepo::new(module_repo.clone(), video_repo.clone()));
+    let tag_repo = Arc::new(InMemoryTagRepo::default());
+    tag_repo.save(&Tag::new(TagId::new(), "Rust".to_string())).unwrap();
+    let ingest = IngestManifestUseCase::new(
+        Arc::new(MockFetcher::new(vec![RawVideoMetadata {
+            duration_secs: 420,
+            ..raw_video("dQw4w9WgXcQ", "Welcome to Rust (2024)", 0)
+        }])),
+        Arc::new(MockScanner::new(vec![RawLocalMediaMetadata {
+            path: "/courses/rust/02-ownership/deep-dive.mkv".to_string(),
+            title: "Ownership Deep Dive".to_string(),
+            duration_secs: 3600,
+            subtitles: vec![],
+            chapters: Vec::new(),
+        }])),
+        course_repo.clone(),
+        tag_repo.clone(),
+        Arc::new(InMemorySearchRepo),
+    );
+    let manifest = r#"
+name = "Rust Basics"
+tags = ["rust", "systems"]
+
+[[modules]]
+title = "Getting Started"
+
+[[modules.lessons]]
+youtube_id = "dQw4w9WgXcQ"
+
+[[modules.lessons]]
+path = "01-setup/install.mp4"
+title = "Installing the Toolchain"
+duration_secs = 300
+
+[[modules]]
+title = "Ownership"
+
+[[modules.lessons]]
+path = "02-ownership/deep-dive.mkv"
+start_secs = 600
+end_secs = 1500
+"#;
+    let manifest = CourseManifest::parse(manifest, ManifestFormat::Toml).unwrap();
+    let input = |manifest: &CourseManifest| IngestManifestInput {
+        manifest: manifest.clone(),
+        base_dir: "/courses/rust".to_string(),
+    };
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    let output = rt.block_on(ingest.execute(input(&manifest))).unwrap();
+    assert_eq!((output.modules_count, output.videos_count), (2, 3));
+    let videos = video_repo.find_by_course(&output.course_id).unwrap();
+    let lessons: Vec<(&str, Option<&str>, u32)> =
+        videos.iter().map(|v| (v.title(), v.local_path(), v.duration_secs())).collect();
+    assert_eq!(
+        lessons,
+        vec![
+            ("Welcome to Rust", None, 420),
+            ("Installing the Toolchain", Some("/courses/rust/01-setup/install.mp4"), 300),
+            ("Ownership Deep Dive", Some("/courses/rust/02-ownership/deep-dive.mkv"), 900),
+        ]
+    );
+    assert_eq!(tag_repo.find_all().unwrap().len(), 2, "existing tags are reused");
+
+    let again = rt.block_on(ingest.execute(input(&manifest)));
+    assert!(matches!(again, Err(ManifestError::AlreadyExists(_))));
+
+    let export = ExportManifestUseCase::new(course_repo, module_repo, video_repo, tag_repo);
+    let exported = export
+        .execute(ExportManifestInput {
+            course_id: output.course_id,
+            format: ManifestFormat::Json,
+            base_dir: Some("/courses/rust".to_string()),
+        })
+        .unwrap();
+    assert_eq!(exported.file_name, "rust-basics.json");
+    let exported = CourseManifest::parse(&exported.content, ManifestFormat::Json).unwrap();
+    assert_eq!(exported.tags, vec!["Rust", "systems"]);
+    let clip_lesson = &exported.modules[1].lessons[0];
+    assert_eq!(clip_lesson.path.as_deref(), Some("02-ownership/deep-dive.mkv"));
+    assert_eq!((clip_lesson.start_secs, clip_lesson.end_secs), (Some(600), Some(1500)));
+    assert_eq!(exported.modules[0].lessons[0].title.as_deref(), Some("Welcome to Rust"));
+}
+
+fn raw_video(youtube_id: &str, title: &str, position: u32) -> RawVideoMetadata {
+    RawVideoMetadata {
+        youtube_id: youtube_id.to_string(),
+        title: title.to_string(),
+        description: None,
+        duration_secs: 300,
+        position,
+        chapters: Vec::new(),
+    }
+}
+
+#[test]
+fn sync_playlist_merges_upstream_changes_and_keeps_progress() {
+    let module_repo = Arc::new(InMemoryModuleRepo::new());
+    let video_repo = Arc::new(InMemoryVideoRepo::new(module_repo.clone()));
+    let course_repo = Arc::new(InMemoryCourseRepo::new(module_repo.clone(), video_repo.clone()));
+    let search_repo = Arc::new(InMemorySearchRepo);
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    let ingest = IngestPlaylistUseCase::new(
+        Arc::new(MockFetcher::new(vec![
+            raw_video("dQw4w9WgXcQ", "Intro", 0),
+            raw_video("9bZkp7q19f0", "Setup", 1),
+            raw_video("kJQP7kiw5Fk", "Basics", 2),
+        ])),
+        course_repo.clone(),
+        search_repo.clone(),
+        None,
+        5,
+        GroupingStrategy::TitlePatterns,
+        0,
+    );
+    let course_id = rt
+        .block_on(ingest.execute(IngestPlaylistInput {
+            playlist_url: "https://www.youtube.com/playlist?list=PLsync".to_string(),
+            course_name: None,
+            split_chapters: false,
+        }))
+        .unwrap()
+        .course_id;
+    let module_id = *module_repo.find_by_course(&course_id).unwrap()[0].id();
+    let setup = video_repo.find_by_module(&module_id).unwrap()[1].clone();
+    video_repo.update_completion(setup.id(), true).unwrap();
+
+    // "Setup" was removed, "Basics" moved to the front, one video was inserted and
+    // four were appended.
+    let upstream = vec![
+        raw_video("kJQP7kiw5Fk", "Basics", 0),
+        raw_video("JGwWNGJdvx8", "Detour", 1),
+        raw_video("dQw4w9WgXcQ", "Intro", 2),
+        raw_video("OPf0YbXqDm0", "Loops", 3),
+        raw_video("RgKAFK5djSk", "Closures", 4),
+        raw_video("fJ9rUzIMcZQ", "Traits", 5),
+        raw_video("hT_nvWreIhg", "Macros", 6),
+    ];
+    let sync = SyncPlaylistUseCase::new(
+        Arc::new(MockFetcher::new(upstream)),
+        course_repo.clone(),
+        module_repo.clone(),
+        video_repo.clone(),
+        search_repo.clone(),
+        None,
+        5,
+        0,
+    );
+    let report = rt.block_on(sync.execute(SyncPlaylistInput { course_id })).unwrap();
+    assert_eq!(report.added, vec!["Detour", "Loops", "Closures", "Traits", "Macros"]);
+    assert_eq!(report.removed, vec!["Setup"]);
+    assert!(report.restored.is_empty());
+    assert_eq!(report.reordered, 3);
+    assert_eq!(report.modules_created, 1);
+
+    // Trailing additions are grouped with the last module's videos; the rest get a new module.
+    let modules = module_repo.find_by_course(&course_id).unwrap();
+    assert_eq!(modules.len(), 2);
+    let titles = |module: &Module| -> Vec<String> {
+        video_repo
+            .find_by_module(module.id())
+            .unwrap()
+            .iter()
+            .map(|v| v.title().to_string())
+            .collect()
+    };
+    assert_eq!(
+        titles(&modules[0]),
+        vec!["Basics", "Detour", "Intro", "Loops", "Closures", "Traits", "Setup"]
+    );
+    assert_eq!(titles(&modules[1]), vec!["Macros"]);
+
+    let setup = video_repo.find_by_id(setup.id()).unwrap().unwrap();
+    assert!(!setup.is_available());
+    assert!(setup.is_completed(), "per-video progress survives the sync");
+
+    let again = rt.block_on(sync.execute(SyncPlaylistInput { course_id })).unwrap();
+    assert!(again.is_unchanged());
+}
+
+#[test]
+fn sync_playlist_moves_modules_with_their_upstream_section() {
+    let module_repo = Arc::new(InMemoryModuleRepo::new());
+    let video_repo = Arc::new(InMemoryVideoRepo::new(module_repo.clone()));
+    let course_repo = Arc::new(InMemoryCourseRepo::new(module_repo.clone(), video_repo.clone()));
+    let search_repo = Arc::new(InMemorySearchRepo);
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    let section = |ids: [(&str, &str); 2], offset: u32| -> Vec<RawVideoMetadata> {
+        ids.iter()
+            .enumerate()
+            .map(|(i, (id, title))| raw_video(id, title, offset + i as u32))
+            .collect()
+    };
+    let setup = [("sectionA001", "1.1 Install"), ("sectionA002", "1.2 Hello")];
+    let traits = [("sectionB001", "2.1 Traits"), ("sectionB002", "2.2 Generics")];
+    let ingest = IngestPlaylistUseCase::new(
+        Arc::new(MockFetcher::new([section(setup, 0), section(traits, 2)].concat())),
+        course_repo.clone(),
+        search_repo.clone(),
+        None,
+        5,
+        GroupingStrategy::TitlePatterns,
+        0,
+    );
+    let course_id = rt
+        .block_on(ingest.execute(IngestPlaylistInput {
+            playlist_url: "https://www.youtube.com/playlist?list=PLsections".to_string(),
+            course_name: None,
+            split_chapters: false,
+        }))
+        .unwrap()
+        .course_id;
+    let before: Vec<ModuleId> =
+        module_repo.find_by_course(&course_id).unwrap().iter().map(|m| *m.id()).collect();
+    assert_eq!(before.len(), 2);
+
+    // The whole second section moved to the front of the playlist.
+    let sync = SyncPlaylistUseCase::new(
+        Arc::new(MockFetcher::new([section(traits, 0), section(setup, 2)].concat())),
+        course_repo.clone(),
+        module_repo.clone(),
+        video_repo.clone(),
+        search_repo,
+        None,
+        5,
+        0,
+    );
+    let report = rt.block_on(sync.execute(SyncPlaylistInput { course_id })).unwrap();
+    assert_eq!((report.reordered, report.modules_reordered), (0, 2));
+
+    let modules = module_repo.find_by_course(&course_id).unwrap();
+    assert_eq!(modules.iter().map(|m| *m.id()).collect::<Vec<_>>(), vec![before[1], before[0]]);
+    assert_eq!(modules.iter().map(|m| m.sort_order()).collect::<Vec<_>>(), vec![0, 1]);
+    let first_ids: Vec<String> = video_repo
+        .find_by_module(&before[1])
+        .unwrap()
+        .iter()
+        .filter_map(|v| v.youtube_id().map(|id| id.as_str().to_string()))
+        .collect();
+    assert_eq!(first_ids, vec!["sectionB001", "sectionB002"]);
+
+    let again = rt.block_on(sync.execute(SyncPlaylistInput { course_id })).unwrap();
+    assert!(again.is_unchanged());
+}
+
+/// Scans a real directory, so rescans see files as they are on disk.
+struct DirScanner;
+
+#[async_trait::async_trait]
+impl LocalMediaScanner for DirScanner {
+    async fn scan(&self, root: &str) -> Result<Vec<RawLocalMediaMetadata>, LocalMediaError> {
+        Ok(walkdir::WalkDir::new(root)
+            .into_iter()
+            .filter_map(Result::ok)
+            .filter(|entry| entry.file_type().is_file())
+            .map(|entry| RawLocalMediaMetadata {
+                path: entry.path().to_string_lossy().to_string(),
+                title: entry.path().file_stem().unwrap().to_string_lossy().to_string(),
+                duration_secs: 300,
+                subtitles: vec![],
+                chapters: Vec::new(),
+            })
+            .collect())
+    }
+}
+
+#[test]
+fn rescan_local_follows_moved_files_and_keeps_progress() {
+    let root = std::env::temp_dir().join(format!("cp-rescan-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&root);
+    let write = |rel: &str| {
+        let path = root.join(rel);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, rel.repeat(1000)).unwrap();
+    };
+    write("Basics/Alpha.mp4");
+    write("Basics/Beta.mp4");
+    write("Advanced/Gamma.mp4");
+
+    let module_repo = Arc::new(InMemoryModuleRepo::new());
+    let video_repo = Arc::new(InMemoryVideoRepo::new(module_repo.clone()));
+    let course_repo = Arc::new(InMemoryCourseRepo::new(module_repo.clone(), video_repo.clone()));
+    let search_repo = Arc::new(InMemorySearchRepo);
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    let ingest = IngestLocalUseCase::new(
+        Arc::new(DirScanner),
+        course_repo.clone(),
+        search_repo.clone(),
+        None,
+        5,
+        GroupingStrategy::TitlePatterns,
+        0,
+    );
+    let course_id = rt
+        .block_on(ingest.execute(IngestLocalInput {
+            root_path: root.to_string_lossy().to_string(),
+            course_name: None,
+            split_chapters: false,
+        }))
+        .unwrap()
+        .course_id;
+    let find = |title: &str| -> Video {
+        video_repo
+            .find_by_course(&course_id)
+            .unwrap()
+            .into_iter()
+            .find(|v| v.title() == title)
+            .unwrap()
+    };
+    let beta = find("Beta");
+    assert!(beta.content_fingerprint().is_some());
+    video_repo.update_completion(beta.id(), true).unwrap();
+
+    // Beta is renamed into another folder, Gamma deleted, and two lectures added.
+    std::fs::rename(root.join("Basics/Beta.mp4"), root.join("Advanced/Beta (final).mp4")).unwrap();
+    let gamma_bytes = std::fs::read(root.join("Advanced/Gamma.mp4")).unwrap();
+    std::fs::remove_file(root.join("Advanced/Gamma.mp4")).unwrap();
+    write("Basics/Delta.mp4");
+    write("Extras/Epsilon.mp4");
+
+    let rescan = RescanLocalUseCase::new(
+        Arc::new(DirScanner),
+        course_repo.clone(),
+        module_repo.clone(),
+        video_repo.clone(),
+        search_repo.clone(),
+        None,
+    );
+    let report = rt.block_on(rescan.execute(RescanLocalInput { course_id })).unwrap();
+    assert_eq!(report.added, vec!["Delta", "Epsilon"]);
+    assert_eq!(report.moved, vec!["Beta"]);
+    assert_eq!(report.missing, vec!["Gamma"]);
+    assert_eq!(report.modules_created, 1);
+
+    let moved = find("Beta");
+    assert_eq!(moved.id(), beta.id());
+    assert_eq!(moved.module_id(), beta.module_id(), "moved files keep their module");
+    assert!(moved.local_path().unwrap().ends_with("Advanced/Beta (final).mp4"));
+    assert!(moved.is_completed(), "per-video progress survives the move");
+    let delta = find("Delta");
+    assert_eq!(delta.module_id(), find("Alpha").module_id(), "new files join their folder");
+    assert_eq!(delta.sort_order(), 2);
+    assert_eq!(module_repo.find_by_course(&course_id).unwrap().len(), 3);
+    assert!(!find("Gamma").is_available());
+
+    std::fs::write(root.join("Advanced/Gamma.mp4"), gamma_bytes).unwrap();
+    let report = rt.block_on(rescan.execute(RescanLocalInput { course_id })).unwrap();
+    assert_eq!(report.restored, vec!["Gamma"]);
+    assert!(report.added.is_empty());
+    let again = rt.block_on(rescan.execute(RescanLocalInput { course_id })).unwrap();
+    assert!(again.is_unchanged());
+
+    std::fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn relink_local_follows_a_moved_course_folder() {
+    let base = std::env::temp_dir().join(format!("cp-relink-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&base);
+    let old_root = base.join("old").join("Course");
+    for rel in ["Basics/Alpha.mp4", "Basics/Beta.mp4", "Advanced/Gamma.mp4"] {
+        let path = old_root.join(rel);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, rel.repeat(1000)).unwrap();
+    }
+
+    let module_repo = Arc::new(InMemoryModuleRepo::new());
+    let video_repo = Arc::new(InMemoryVideoRepo::new(module_repo.clone()));
+    let course_repo = Arc::new(InMemoryCourseRepo::new(module_repo.clone(), video_repo.clone()));
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    let ingest = IngestLocalUseCase::new(
+        Arc::new(DirScanner),
+        course_repo.clone(),
+        Arc::new(InMemorySearchRepo),
+        None,
+        5,
+        GroupingStrategy::TitlePatterns,
+        0,
+    );
+    let course_id = rt
+        .block_on(ingest.execute(IngestLocalInput {
+            root_path: old_root.to_string_lossy().to_string(),
+            course_name: None,
+            split_chapters: false,
+        }))
+        .unwrap()
+        .course_id;
+    let find = |title: &str| -> Video {
+        video_repo
+            .find_by_course(&course_id)
+            .unwrap()
+            .into_iter()
+            .find(|v| v.title() == title)
+            .unwrap()
+    };
+    let alpha = find("Alpha");
+    video_repo.update_completion(alpha.id(), true).unwrap();
+
+    // The folder moves to another parent, and one lecture is renamed along the way.
+    let new_root = base.join("new").join("Course");
+    std::fs::create_dir_all(new_root.parent().unwrap()).unwrap();
+    std::fs::rename(&old_root, &new_root).unwrap();
+    std::fs::rename(new_root.join("Advanced/Gamma.mp4"), new_root.join("Advanced/Gamma v2.mp4"))
+        .unwrap();
+
+    let relink =
+        RelinkLocalUseCase::new(Arc::new(DirScanner), course_repo.clone(), video_repo.clone());
+    assert_eq!(relink.count_missing(&course_id).unwrap(), 3);
+
+    // The user picks the parent folder; the course root is inferred from the matches.
+    let report = rt
+        .block_on(relink.execute(RelinkLocalInput {
+            course_id,
+            new_root: base.join("new").to_string_lossy().to_string(),
+        }))
+        .unwrap();
+    let mut relinked = report.relinked.clone();
+    relinked.sort();
+    assert_eq!(relinked, vec!["Alpha", "Beta", "Gamma"]);
+    assert_eq!(report.matched_by_content, 1);
+    assert!(report.still_missing.is_empty());
+    assert_eq!(report.root, new_root.to_string_lossy());
+    assert_eq!(relink.count_missing(&course_id).unwrap(), 0);
+
+    let course = course_repo.find_by_id(&course_id).unwrap().unwrap();
+    assert_eq!(course.source().local_root(), Some(report.root.as_str()));
+    let moved = find("Alpha");
+    assert_eq!(moved.id(), alpha.id());
+    assert!(moved.is_completed(), "per-video progress survives the relink");
+    assert!(find("Gamma").local_path().unwrap().ends_with("Advanced/Gamma v2.mp4"));
+
+    std::fs::remove_dir_all(&base).unwrap();
+}
+
+#[test]
+fn boundary_detector_integration() {
+    let detector = BoundaryDetector::new();
+    let titles = vec![
+        "1.1 Introduction",
+        "1.2 Setup",
+        "1.3 Configuration",
+        "2.1 Advanced Topics",
+        "2.2 Deep Dive",
+        "3.1 Conclusion",
     ];
 
     let groups = detector.group_by_titles(&titles);
@@ -640,6 +1919,7 @@ fn ingest_playlist_preserves_labeled_module_boundaries() {
             description: None,
             duration_secs: 100,
             position: 0,
+            chapters: Vec::new(),
         },
         course_pilot::domain::ports::RawVideoMetadata {
             youtube_id: "bbb222bbb22".to_string(),
@@ -647,6 +1927,7 @@ fn ingest_playlist_preserves_labeled_module_boundaries() {
             description: None,
             duration_secs: 200,
             position: 1,
+            chapters: Vec::new(),
         },
         course_pilot::domain::ports::RawVideoMetadata {
             youtube_id: "ccc333ccc33".to_string(),
@@ -654,6 +1935,7 @@ fn ingest_playlist_preserves_labeled_module_boundaries() {
             description: None,
             duration_secs: 300,
             position: 2,
+            chapters: Vec::new(),
         },
         course_pilot::domain::ports::RawVideoMetadata {
             youtube_id: "ddd444ddd44".to_string(),
@@ -661,27 +1943,29 @@ fn ingest_playlist_preserves_labeled_module_boundaries() {
             description: None,
             duration_secs: 400,
             position: 3,
+            chapters: Vec::new(),
         },
     ]));
 
-    let course_repo = Arc::new(InMemoryCourseRepo::new());
     let module_repo = Arc::new(InMemoryModuleRepo::new());
     let video_repo = Arc::new(InMemoryVideoRepo::new(module_repo.clone()));
+    let course_repo = Arc::new(InMemoryCourseRepo::new(module_repo.clone(), video_repo.clone()));
     let search_repo = Arc::new(InMemorySearchRepo);
 
     let use_case = course_pilot::application::use_cases::IngestPlaylistUseCase::new(
         fetcher,
         course_repo.clone(),
-        module_repo.clone(),
-        video_repo.clone(),
         search_repo,
         None,
         5,
+        GroupingStrategy::TitlePatterns,
+        0,
     );
 
     let input = course_pilot::application::use_cases::IngestPlaylistInput {
         playlist_url: "https://www.youtube.com/playlist?list=PLlabeledtest".to_string(),
         course_name: Some("Labeled Test".to_string()),
+        split_chapters: false,
     };
 
     let result = tokio::runtime::Runtime::new().unwrap().block_on(use_case.execute(input));
@@ -817,10 +2101,18 @@ fn test_summarize_video_use_case() {
 
     let mock_transcript = "This is a mock transcript of the video.".to_string();
     let mock_summary = "This is a mock summary.".to_string();
+    let key_points = vec!["Mocks replace real services".to_string()];
+    let key_terms = vec![KeyTerm::new("Mock", "A stand-in for a real dependency").unwrap()];
 
     let transcript_provider =
         Arc::new(MockTranscriptProvider { transcript: mock_transcript.clone() });
-    let summarizer_ai = Arc::new(MockSummarizerAI { summary: mock_summary.clone() });
+    let summarizer_ai = Arc::new(MockSummarizerAI {
+        summary: VideoSummary {
+            summary: mock_summary.clone(),
+            key_points: key_points.clone(),
+            key_terms: key_terms.clone(),
+        },
+    });
 
     let use_case = SummarizeVideoUseCase::new(
         summarizer_ai.clone(),
@@ -835,13 +2127,17 @@ fn test_summarize_video_use_case() {
 
     let output: SummarizeVideoOutput = result.unwrap();
     assert_eq!(output.summary, mock_summary);
+    assert_eq!(output.key_points, key_points);
+    assert_eq!(output.key_terms, key_terms);
     assert_eq!(output.transcript_used, mock_transcript);
     assert!(!output.cached);
 
-    // Verify video in video_repo is updated to include transcript and summary
+    // Verify video in video_repo is updated to include transcript, summary and key content
     let updated_video = video_repo.find_by_id(&video_id).unwrap().unwrap();
     assert_eq!(updated_video.transcript(), Some(mock_transcript.as_str()));
     assert_eq!(updated_video.summary(), Some(mock_summary.as_str()));
+    assert_eq!(updated_video.key_points(), key_points.as_slice());
+    assert_eq!(updated_video.key_terms(), key_terms.as_slice());
 
     // Running again with force_refresh = false returns cached: true
     let input_second = SummarizeVideoInput { video_id, force_refresh: false };
@@ -850,6 +2146,7 @@ fn test_summarize_video_use_case() {
     assert!(result_second.is_ok());
     let output_second: SummarizeVideoOutput = result_second.unwrap();
     assert_eq!(output_second.summary, mock_summary);
+    assert_eq!(output_second.key_terms, key_terms);
     assert_eq!(output_second.transcript_used, mock_transcript);
     assert!(output_second.cached);
 }
@@ -924,4 +2221,913 @@ fn test_take_exam_use_case_flow() {
     // Assert: The video in video_repo is now marked as completed!
     let updated_video = video_repo.find_by_id(&video_id).unwrap().unwrap();
     assert!(updated_video.is_completed());
+
+    // Grading alone needs no examiner
+    let grader = GradeExamUseCase::new(video_repo.clone(), exam_repo.clone());
+    let retry = grader.submit(SubmitExamInput { exam_id, answers: vec![0, 1] }).unwrap();
+    assert_eq!(retry.score, 0.0);
+    assert!(!retry.passed);
+}
+
+#[test]
+fn plan_course_schedule_respects_modules_and_skips_completed() {
+    let module_repo = Arc::new(InMemoryModuleRepo::new());
+    let video_repo = Arc::new(InMemoryVideoRepo::new(module_repo.clone()));
+    let course_repo = Arc::new(InMemoryCourseRepo::new(module_repo.clone(), video_repo.clone()));
+    let schedule_repo = Arc::new(InMemoryScheduleRepo::new());
+
+    let course_id = CourseId::new();
+    let course = Course::new(
+        course_id,
+        "Scheduled".to_string(),
+        CourseSource::youtube_playlist(
+            PlaylistUrl::new("https://www.youtube.com/playlist?list=PLsched").unwrap(),
+        ),
+        None,
+        None,
+    );
+    course_repo.save(&course).unwrap();
+
+    // Module 1: three 10-minute videos (first already watched). Module 2: two 10-minute videos.
+    let m1 = Module::new(ModuleId::new(), course_id, "Basics".to_string(), 0);
+    let m2 = Module::new(ModuleId::new(), course_id, "Advanced".to_string(), 1);
+    module_repo.save(&m1).unwrap();
+    module_repo.save(&m2).unwrap();
+
+    let mut ids = Vec::new();
+    for (idx, module) in [&m1, &m1, &m1, &m2, &m2].iter().enumerate() {
+        let mut video = Video::new(
+            VideoId::new(),
+            *module.id(),
+            VideoSource::local_path(format!("/videos/{idx}.mp4")).unwrap(),
+            format!("Lesson {idx}"),
+            600,
+            idx as u32,
+        );
+        if idx == 0 {
+            video.mark_completed();
+        }
+        ids.push(*video.id());
+        video_repo.save(&video).unwrap();
+    }
+
+    let use_case =
+        PlanCourseScheduleUseCase::new(course_repo, video_repo.clone(), schedule_repo.clone());
+    let start = chrono::NaiveDate::from_ymd_opt(2026, 6, 5).unwrap(); // a Friday
+    let output = use_case
+        .execute(PlanCourseScheduleInput {
+            course_id,
+            start_date: start,
+            study_days_per_week: 1,
+            cognitive_limit_minutes: 30,
+            reschedule_policy: ReschedulePolicy::KeepDailyLoad,
+        })
+        .unwrap();
+
+    assert_eq!(output.videos_planned, 4);
+    let sessions = output.schedule.sessions();
+    // The module boundary splits after the two remaining Basics lessons (20 of 30 minutes).
+    assert_eq!(sessions.len(), 2);
+    assert_eq!(sessions[0].video_ids(), &ids[1..3]);
+    assert_eq!(sessions[1].video_ids(), &ids[3..5]);
+    // One study day per week: the second session lands a week later.
+    assert_eq!(sessions[0].date(), start);
+    assert_eq!(sessions[1].date(), start + chrono::Days::new(7));
+
+    assert!(schedule_repo.find_by_course(&course_id).unwrap().is_some());
+
+    let today = use_case.sessions_on(start).unwrap();
+    assert_eq!(today.len(), 1);
+    assert_eq!(today[0].course_name, "Scheduled");
+    assert_eq!(today[0].videos.len(), 2);
+    assert!(use_case.sessions_on(start + chrono::Days::new(1)).unwrap().is_empty());
+}
+
+#[test]
+fn reschedule_course_catches_up_missed_sessions() {
+    let module_repo = Arc::new(InMemoryModuleRepo::new());
+    let video_repo = Arc::new(InMemoryVideoRepo::new(module_repo.clone()));
+    let course_repo = Arc::new(InMemoryCourseRepo::new(module_repo.clone(), video_repo.clone()));
+    let schedule_repo = Arc::new(InMemoryScheduleRepo::new());
+
+    let course_id = CourseId::new();
+    let course = Course::new(
+        course_id,
+        "Behind".to_string(),
+        CourseSource::youtube_playlist(
+            PlaylistUrl::new("https://www.youtube.com/playlist?list=PLbehind").unwrap(),
+        ),
+        None,
+        None,
+    );
+    course_repo.save(&course).unwrap();
+    let module = Module::new(ModuleId::new(), course_id, "Only".to_string(), 0);
+    module_repo.save(&module).unwrap();
+
+    let mut videos = Vec::new();
+    for idx in 0..4 {
+        let video = Video::new(
+            VideoId::new(),
+            *module.id(),
+            VideoSource::local_path(format!("/videos/{idx}.mp4")).unwrap(),
+            format!("Lesson {idx}"),
+            1800,
+            idx as u32,
+        );
+        video_repo.save(&video).unwrap();
+        videos.push(video);
+    }
+
+    // One 30-minute session per day, June 1-4.
+    let start = chrono::NaiveDate::from_ymd_opt(2026, 6, 1).unwrap();
+    PlanCourseScheduleUseCase::new(course_repo, video_repo.clone(), schedule_repo.clone())
+        .execute(PlanCourseScheduleInput {
+            course_id,
+            start_date: start,
+            study_days_per_week: 7,
+            cognitive_limit_minutes: 30,
+            reschedule_policy: ReschedulePolicy::KeepDailyLoad,
+        })
+        .unwrap();
+
+    // Watched June 1's lesson, then skipped June 2.
+    videos[0].mark_completed();
+    video_repo.save(&videos[0]).unwrap();
+    let today = start + chrono::Days::new(2);
+
+    let use_case = RescheduleCourseUseCase::new(video_repo.clone(), schedule_repo.clone());
+    let outputs = use_case.catch_up_all(today).unwrap();
+    assert_eq!(outputs.len(), 1);
+    assert_eq!(outputs[0].diff.moved.len(), 3);
+    assert_eq!(outputs[0].diff.end_date_shift_days(), 1);
+
+    let stored = schedule_repo.find_by_course(&course_id).unwrap().unwrap();
+    assert_eq!(stored.session_on(today).unwrap().video_ids(), &[*videos[1].id()]);
+    // Back on track: nothing more to catch up today.
+    assert!(use_case.catch_up_all(today).unwrap().is_empty());
+
+    let missing = use_case.execute(RescheduleCourseInput {
+        course_id: CourseId::new(),
+        today,
+        policy: Some(ReschedulePolicy::KeepDeadline),
+    });
+    assert!(matches!(missing, Err(ScheduleError::NotPlanned)));
+}
+
+#[test]
+fn export_schedule_produces_stable_ics_events() {
+    let module_repo = Arc::new(InMemoryModuleRepo::new());
+    let video_repo = Arc::new(InMemoryVideoRepo::new(module_repo.clone()));
+    let course_repo = Arc::new(InMemoryCourseRepo::new(module_repo.clone(), video_repo.clone()));
+    let schedule_repo = Arc::new(InMemoryScheduleRepo::new());
+
+    let course_id = CourseId::new();
+    let course = Course::new(
+        course_id,
+        "Rust Basics".to_string(),
+        CourseSource::youtube_playlist(
+            PlaylistUrl::new("https://www.youtube.com/playlist?list=PLics").unwrap(),
+        ),
+        None,
+        None,
+    );
+    course_repo.save(&course).unwrap();
+    let module = Module::new(ModuleId::new(), course_id, "Intro".to_string(), 0);
+    module_repo.save(&module).unwrap();
+    for idx in 0..3 {
+        let video = Video::new(
+            VideoId::new(),
+            *module.id(),
+            VideoSource::local_path(format!("/videos/{idx}.mp4")).unwrap(),
+            format!("Lesson {idx}"),
+            1200,
+            idx as u32,
+        );
+        video_repo.save(&video).unwrap();
+    }
+
+    let start = chrono::NaiveDate::from_ymd_opt(2026, 6, 1).unwrap();
+    PlanCourseScheduleUseCase::new(course_repo.clone(), video_repo.clone(), schedule_repo.clone())
+        .execute(PlanCourseScheduleInput {
+            course_id,
+            start_date: start,
+            study_days_per_week: 5,
+            cognitive_limit_minutes: 45,
+            reschedule_policy: ReschedulePolicy::KeepDailyLoad,
+        })
+        .unwrap();
+
+    let use_case = ExportScheduleUseCase::new(
+        course_repo.clone(),
+        video_repo.clone(),
+        schedule_repo.clone(),
+        Arc::new(IcsCalendarExporter::new()),
+    );
+    let input =
+        ExportScheduleInput { course_id, start_time: chrono::NaiveTime::from_hms_opt(18, 0, 0) };
+    let first = use_case.execute(input.clone()).unwrap();
+
+    assert_eq!(first.file_name, "rust-basics.ics");
+    assert_eq!(first.events, 2);
+    assert_eq!(first.content.matches("BEGIN:VEVENT").count(), 2);
+    assert!(first.content.contains("DTSTART:20260601T180000"));
+    assert!(first.content.contains("DURATION:PT40M"));
+    assert!(first.content.contains("Lesson 0 (20 min)"));
+
+    let uids = |ics: &str| -> Vec<String> {
+        ics.lines().filter(|l| l.starts_with("UID:")).map(str::to_string).collect()
+    };
+    let second = use_case.execute(input.clone()).unwrap();
+    assert_eq!(uids(&first.content), uids(&second.content));
+    assert!(uids(&first.content)[0].contains(&course_id.as_uuid().to_string()));
+    assert_eq!(first.content.matches("STATUS:CONFIRMED").count(), 2);
+
+    // A re-plan that fits everything into one session cancels the second event.
+    PlanCourseScheduleUseCase::new(course_repo, video_repo, schedule_repo.clone())
+        .execute(PlanCourseScheduleInput {
+            course_id,
+            start_date: start,
+            study_days_per_week: 5,
+            cognitive_limit_minutes: 90,
+            reschedule_policy: ReschedulePolicy::KeepDailyLoad,
+        })
+        .unwrap();
+    let replanned = schedule_repo.find_by_course(&course_id).unwrap().unwrap();
+    let third = use_case.execute(input).unwrap();
+
+    assert_eq!((third.events, third.cancelled), (1, 1));
+    assert_eq!(uids(&third.content), uids(&first.content));
+    assert_eq!(third.content.matches("STATUS:CONFIRMED").count(), 1);
+    assert_eq!(third.content.matches("STATUS:CANCELLED").count(), 1);
+    let sequence = format!("SEQUENCE:{}", replanned.updated_at().timestamp());
+    assert_eq!(third.content.matches(sequence.as_str()).count(), 2);
+}
+
+#[test]
+fn playback_positions_resume_last_watched_video() {
+    let module_repo = Arc::new(InMemoryModuleRepo::new());
+    let video_repo = Arc::new(InMemoryVideoRepo::new(module_repo.clone()));
+    let playback_repo = Arc::new(InMemoryPlaybackRepo::new(video_repo.clone()));
+
+    let course_id = CourseId::new();
+    let module = Module::new(ModuleId::new(), course_id, "Only".to_string(), 0);
+    module_repo.save(&module).unwrap();
+    let mut videos = Vec::new();
+    for idx in 0..2 {
+        let video = Video::new(
+            VideoId::new(),
+            *module.id(),
+            VideoSource::local_path(format!("/videos/{idx}.mp4")).unwrap(),
+            format!("Lesson {idx}"),
+            600,
+            idx as u32,
+        );
+        video_repo.save(&video).unwrap();
+        videos.push(video);
+    }
+
+    let session_repo = Arc::new(InMemoryWatchSessionRepo::new(video_repo.clone()));
+    let use_case = PlaybackUseCase::new(playback_repo, session_repo, video_repo);
+    assert!(use_case.last_watched(&course_id).unwrap().is_none());
+
+    let save = |video: &Video, position_ms| SavePositionInput {
+        video_id: *video.id(),
+        position_ms,
+        played: WatchedRanges::new(),
+        duration_ms: None,
+        auto_complete_percent: 90,
+    };
+    use_case.save_position(save(&videos[0], 95_000)).unwrap();
+    assert_eq!(use_case.resume_position(videos[0].id(), 600).unwrap(), Some(95_000));
+
+    // Watching the second lesson to its end makes it the last watched, restarting from zero.
+    std::thread::sleep(std::time::Duration::from_millis(2));
+    use_case.save_position(save(&videos[1], 599_000)).unwrap();
+    let resume = use_case.last_watched(&course_id).unwrap().unwrap();
+    assert_eq!(resume.video.id(), videos[1].id());
+    assert_eq!(resume.position_ms, None);
+}
+
+#[test]
+fn watched_ranges_auto_complete_video() {
+    let module_repo = Arc::new(InMemoryModuleRepo::new());
+    let video_repo = Arc::new(InMemoryVideoRepo::new(module_repo.clone()));
+    let playback_repo = Arc::new(InMemoryPlaybackRepo::new(video_repo.clone()));
+
+    let module = Module::new(ModuleId::new(), CourseId::new(), "Only".to_string(), 0);
+    module_repo.save(&module).unwrap();
+    let video = Video::new(
+        VideoId::new(),
+        *module.id(),
+        VideoSource::local_path("/videos/lesson.mp4").unwrap(),
+        "Lesson".to_string(),
+        600,
+        0,
+    );
+    video_repo.save(&video).unwrap();
+
+    let session_repo = Arc::new(InMemoryWatchSessionRepo::new(video_repo.clone()));
+    let use_case = PlaybackUseCase::new(playback_repo, session_repo, video_repo.clone());
+    let save = |start_ms, end_ms| {
+        let mut played = WatchedRanges::new();
+        played.add(start_ms, end_ms);
+        use_case
+            .save_position(SavePositionInput {
+                video_id: *video.id(),
+                position_ms: end_ms,
+                played,
+                duration_ms: None,
+                auto_complete_percent: 90,
+            })
+            .unwrap()
+    };
+
+    // Watching the first half, then seeking to the end, does not complete the video.
+    assert!(!save(0, 300_000).marked_complete);
+    let skipped = save(590_000, 600_000);
+    assert!(!skipped.marked_complete);
+    assert!(!video_repo.find_by_id(video.id()).unwrap().unwrap().is_completed());
+
+    // Going back for the skipped part crosses 90%.
+    let output = save(300_000, 530_000);
+    assert!(output.marked_complete);
+    assert!((output.watched_fraction - 0.9).abs() < 1e-9);
+    assert!(video_repo.find_by_id(video.id()).unwrap().unwrap().is_completed());
+
+    // Once crossed, a manual un-mark is not overridden by further watching.
+    video_repo.update_completion(video.id(), false).unwrap();
+    assert!(!save(530_000, 560_000).marked_complete);
+    assert!(!video_repo.find_by_id(video.id()).unwrap().unwrap().is_completed());
+}
+
+#[test]
+fn watch_sessions_build_study_history() {
+    use chrono::{Local, NaiveDate, TimeZone, Utc};
+
+    let module_repo = Arc::new(InMemoryModuleRepo::new());
+    let video_repo = Arc::new(InMemoryVideoRepo::new(module_repo.clone()));
+    let course_repo = Arc::new(InMemoryCourseRepo::new(module_repo.clone(), video_repo.clone()));
+    let playback_repo = Arc::new(InMemoryPlaybackRepo::new(video_repo.clone()));
+    let session_repo = Arc::new(InMemoryWatchSessionRepo::new(video_repo.clone()));
+
+    let course_id = CourseId::new();
+    course_repo
+        .save(&Course::new(
+            course_id,
+            "Streaks".to_string(),
+            CourseSource::youtube_playlist(
+                PlaylistUrl::new("https://www.youtube.com/playlist?list=PLstreak").unwrap(),
+            ),
+            None,
+            None,
+        ))
+        .unwrap();
+    let module = Module::new(ModuleId::new(), course_id, "Only".to_string(), 0);
+    module_repo.save(&module).unwrap();
+    let video = Video::new(
+        VideoId::new(),
+        *module.id(),
+        VideoSource::local_path("/videos/lesson.mp4").unwrap(),
+        "Lesson".to_string(),
+        3_600,
+        0,
+    );
+    video_repo.save(&video).unwrap();
+
+    let playback = PlaybackUseCase::new(playback_repo, session_repo.clone(), video_repo.clone());
+    let today = NaiveDate::from_ymd_opt(2026, 6, 10).unwrap();
+    let at = |day: u32, hour: u32| {
+        Local.with_ymd_and_hms(2026, 6, day, hour, 0, 0).unwrap().with_timezone(&Utc)
+    };
+    // Two sessions on the 8th, one on the 9th and the 7th; nothing yet today.
+    for (day, hour, secs) in [(7, 9, 900), (8, 9, 600), (8, 20, 1_200), (9, 9, 1_800)] {
+        playback.record_session(*video.id(), at(day, hour), at(day, hour + 1), secs).unwrap();
+    }
+    // Opening a video without playing it leaves no trace.
+    playback.record_session(*video.id(), at(10, 9), at(10, 9), 0).unwrap();
+    assert_eq!(session_repo.find_since(at(1, 0)).unwrap().len(), 4);
+
+    let history = LoadStudyHistoryUseCase::new(course_repo, video_repo, session_repo)
+        .execute(LoadStudyHistoryInput { today, days: 14 })
+        .unwrap();
+
+    assert_eq!(history.days().len(), 14);
+    let minutes: Vec<u64> = history.days()[10..].iter().map(|d| d.minutes()).collect();
+    assert_eq!(minutes, vec![15, 30, 30, 0]);
+    assert_eq!(history.current_streak(), 3);
+    assert_eq!(history.longest_streak(), 3);
+
+    let velocity = &history.course_velocity()[0];
+    assert_eq!(velocity.course_id, course_id);
+    assert_eq!(velocity.remaining_minutes, 60);
+    assert!((velocity.minutes_per_week - 75.0 / 4.0).abs() < 1e-9);
+}
+
+#[test]
+fn review_queue_promotes_exam_questions_and_reschedules_answers() {
+    use chrono::NaiveDate;
+
+    let module_repo = Arc::new(InMemoryModuleRepo::new());
+    let video_repo = Arc::new(InMemoryVideoRepo::new(module_repo.clone()));
+    let exam_repo = Arc::new(InMemoryExamRepo::new());
+    let card_repo = Arc::new(InMemoryReviewCardRepo::new());
+
+    let module_id = ModuleId::new();
+    module_repo.save(&Module::new(module_id, CourseId::new(), "Module".to_string(), 0)).unwrap();
+    let video_id = VideoId::new();
+    let source = VideoSource::local_path("/videos/review.mp4").unwrap();
+    video_repo
+        .save(&Video::new(video_id, module_id, source, "Ownership".to_string(), 600, 0))
+        .unwrap();
+
+    let questions: Vec<MCQuestion> = (0..2)
+        .map(|idx| MCQuestion {
+            question: format!("Question {idx}"),
+            options: vec!["A".to_string(), "B".to_string()],
+            correct_index: idx,
+            explanation: format!("Explanation {idx}"),
+        })
+        .collect();
+    let exam_id = ExamId::new();
+    exam_repo
+        .save(&Exam::new(exam_id, video_id, serde_json::to_string(&questions).unwrap()))
+        .unwrap();
+
+    let use_case = ReviewUseCase::new(exam_repo.clone(), card_repo.clone(), video_repo.clone());
+    let day = |d| NaiveDate::from_ymd_opt(2026, 6, d).unwrap();
+
+    let due = use_case.due_cards(day(1)).unwrap();
+    assert_eq!(due.len(), 2);
+    assert_eq!(due[0].video_title, "Ownership");
+    assert_eq!(due[1].card.question().question, "Question 1");
+    // Promotion only happens once per exam.
+    assert_eq!(use_case.promote_exams(day(1)).unwrap(), 0);
+
+    let good = use_case
+        .record_answer(RecordReviewInput {
+            card_id: *due[0].card.id(),
+            grade: ReviewGrade::Good,
+            today: day(1),
+        })
+        .unwrap();
+    assert_eq!(good.schedule().due(), day(2));
+    use_case
+        .record_answer(RecordReviewInput {
+            card_id: *due[1].card.id(),
+            grade: ReviewGrade::Again,
+            today: day(1),
+        })
+        .unwrap();
+
+    assert!(use_case.due_cards(day(1)).unwrap().is_empty());
+    assert_eq!(use_case.due_cards(day(2)).unwrap().len(), 2);
+    assert!(
+        use_case
+            .record_answer(RecordReviewInput {
+                card_id: ReviewCardId::new(),
+                grade: ReviewGrade::Good,
+                today: day(2),
+            })
+            .is_err()
+    );
+}
+
+#[test]
+fn flashcards_seed_from_key_terms_generate_and_edit() {
+    let module_repo = Arc::new(InMemoryModuleRepo::new());
+    let video_repo = Arc::new(InMemoryVideoRepo::new(module_repo.clone()));
+    let card_repo = Arc::new(InMemoryFlashcardRepo::new());
+
+    let module_id = ModuleId::new();
+    module_repo.save(&Module::new(module_id, CourseId::new(), "Module".to_string(), 0)).unwrap();
+    let video_id = VideoId::new();
+    let source = VideoSource::local_path("/videos/cards.mp4").unwrap();
+    let mut video = Video::new(video_id, module_id, source, "Ownership".to_string(), 600, 0);
+    video.update_summary(Some(
+        "1. Main Topic: Ownership\n2. Key Points:\n- One owner\n3. Key Terms:\n\
+         - Borrow: a reference to a value\n- Move: transfer of ownership"
+            .to_string(),
+    ));
+    video_repo.save(&video).unwrap();
+
+    let flashcards = FlashcardsUseCase::new(video_repo.clone(), card_repo.clone());
+    let seeded = flashcards.seed_from_key_terms(&video_id).unwrap();
+    assert_eq!(seeded.len(), 2);
+    assert_eq!(seeded[0].front(), "Borrow");
+    // Seeding twice does not duplicate cards.
+    assert!(flashcards.seed_from_key_terms(&video_id).unwrap().is_empty());
+
+    let card = |kind, front: &str, back: &str| GeneratedFlashcard {
+        kind,
+        front: front.to_string(),
+        back: back.to_string(),
+    };
+    let generator = Arc::new(MockFlashcardGenerator {
+        cards: vec![
+            card(FlashcardKind::Basic, "borrow", "Duplicate of a seeded card"),
+            card(FlashcardKind::Cloze, "Each value has exactly {{one owner}}.", ""),
+        ],
+        seen_terms: Mutex::new(vec![]),
+    });
+    let generate =
+        GenerateFlashcardsUseCase::new(generator.clone(), video_repo.clone(), card_repo.clone());
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let created = rt
+        .block_on(generate.execute(GenerateFlashcardsInput {
+            video_id,
+            max_cards: 10,
+            replace_existing: false,
+        }))
+        .unwrap();
+    assert_eq!(generator.seen_terms.lock().unwrap().len(), 2);
+    assert_eq!(created.len(), 1);
+    assert_eq!(created[0].prompt(), "Each value has exactly [...].");
+    assert_eq!(created[0].position(), 2);
+
+    let edited = flashcards
+        .save(SaveFlashcardInput {
+            id: Some(*seeded[1].id()),
+            video_id,
+            kind: FlashcardKind::Basic,
+            front: "Move".to_string(),
+            back: "Ownership passes to the new binding".to_string(),
+        })
+        .unwrap();
+    assert_eq!(edited.position(), 1);
+    assert!(
+        flashcards
+            .save(SaveFlashcardInput {
+                id: None,
+                video_id,
+                kind: FlashcardKind::Cloze,
+                front: "No hidden span".to_string(),
+                back: String::new(),
+            })
+            .is_err()
+    );
+
+    flashcards.delete(seeded[0].id()).unwrap();
+    let fronts: Vec<String> =
+        flashcards.list(&video_id).unwrap().iter().map(|c| c.front().to_string()).collect();
+    assert_eq!(fronts, vec!["Move", "Each value has exactly {{one owner}}."]);
+
+    let replaced = rt
+        .block_on(generate.execute(GenerateFlashcardsInput {
+            video_id,
+            max_cards: 10,
+            replace_existing: true,
+        }))
+        .unwrap();
+    assert_eq!(replaced.len(), 2);
+    assert_eq!(flashcards.list(&video_id).unwrap().len(), 2);
+}
+
+#[test]
+fn companion_streams_answers_and_stops_with_the_partial_text() {
+    let module_repo = Arc::new(InMemoryModuleRepo::new());
+    let video_repo = Arc::new(InMemoryVideoRepo::new(module_repo.clone()));
+    let course_repo = Arc::new(InMemoryCourseRepo::new(module_repo.clone(), video_repo.clone()));
+
+    let course = Course::new(CourseId::new(), "Rust".to_string(), CourseSource::Manual, None, None);
+    course_repo.save(&course).unwrap();
+    let module = Module::new(ModuleId::new(), *course.id(), "Ownership".to_string(), 0);
+    module_repo.save(&module).unwrap();
+    let video_id = VideoId::new();
+    let video = Video::new(
+        video_id,
+        *module.id(),
+        VideoSource::youtube(YouTubeVideoId::new("dQw4w9WgXcQ").unwrap()),
+        "Moves".to_string(),
+        600,
+        0,
+    );
+    video_repo.save(&video).unwrap();
+
+    let use_case = |stall: bool| {
+        AskCompanionUseCase::new(
+            Arc::new(MockCompanionAI::new(&["Values have ", "one owner."], stall)),
+            video_repo.clone(),
+            module_repo.clone(),
+            course_repo.clone(),
+            Arc::new(InMemoryNoteRepo::new()),
+            Arc::new(InMemoryChatRepo::new()),
+            Arc::new(InMemoryTranscriptChunkRepo::new()),
+            Arc::new(InMemoryTranscriptCueRepo::new()),
+            Arc::new(MockTranscriptProvider { transcript: String::new() }),
+        )
+    };
+    let input = AskCompanionInput {
+        video_id,
+        question: "Who owns a value?".to_string(),
+        local_context: None,
+    };
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    // Deltas arrive in order and add up to the answer
+    let deltas = Mutex::new(Vec::new());
+    let on_delta = |delta: &str| deltas.lock().unwrap().push(delta.to_string());
+    let answer = rt
+        .block_on(use_case(false).execute_streaming(
+            input.clone(),
+            &on_delta,
+            &CancellationToken::new(),
+        ))
+        .unwrap();
+    assert_eq!(answer.text, "Values have one owner.");
+    assert!(!answer.stopped);
+    assert_eq!(*deltas.lock().unwrap(), ["Values have ", "one owner."]);
+
+    // Stopping a generation that would never finish keeps what arrived
+    let cancel = CancellationToken::new();
+    let stop = |_: &str| cancel.cancel();
+    let answer = rt.block_on(use_case(true).execute_streaming(input, &stop, &cancel)).unwrap();
+    assert!(answer.stopped);
+    assert_eq!(answer.text, "Values have one owner.");
+}
+
+#[test]
+fn companion_follows_up_on_the_saved_conversation() {
+    let module_repo = Arc::new(InMemoryModuleRepo::new());
+    let video_repo = Arc::new(InMemoryVideoRepo::new(module_repo.clone()));
+    let course_repo = Arc::new(InMemoryCourseRepo::new(module_repo.clone(), video_repo.clone()));
+    let chat_repo = Arc::new(InMemoryChatRepo::new());
+
+    let course = Course::new(CourseId::new(), "Rust".to_string(), CourseSource::Manual, None, None);
+    course_repo.save(&course).unwrap();
+    let module = Module::new(ModuleId::new(), *course.id(), "Ownership".to_string(), 0);
+    module_repo.save(&module).unwrap();
+    let video_id = VideoId::new();
+    let video = Video::new(
+        video_id,
+        *module.id(),
+        VideoSource::youtube(YouTubeVideoId::new("dQw4w9WgXcQ").unwrap()),
+        "Moves".to_string(),
+        600,
+        0,
+    );
+    video_repo.save(&video).unwrap();
+
+    // Ten long exchanges, then the new question as the UI saves it before asking
+    let say = |role: ChatRole, content: String| {
+        chat_repo
+            .save(&ChatMessage {
+                id: uuid::Uuid::new_v4().to_string(),
+                video_id,
+                role,
+                content,
+                created_at: chrono::Utc::now().to_rfc3339(),
+            })
+            .unwrap();
+    };
+    for i in 1..=10 {
+        say(ChatRole::User, format!("Question {i}?"));
+        say(ChatRole::Assistant, format!("Answer {i}. {}", "Some detail. ".repeat(40)));
+    }
+    say(ChatRole::User, "Can you elaborate?".to_string());
+
+    let companion = Arc::new(MockCompanionAI::new(&["Sure."], false));
+    let use_case = AskCompanionUseCase::new(
+        companion.clone(),
+        video_repo.clone(),
+        module_repo.clone(),
+        course_repo.clone(),
+        Arc::new(InMemoryNoteRepo::new()),
+        chat_repo.clone(),
+        Arc::new(InMemoryTranscriptChunkRepo::new()),
+        Arc::new(InMemoryTranscriptCueRepo::new()),
+        Arc::new(MockTranscriptProvider { transcript: String::new() }),
+    )
+    .with_history_budget(600);
+    let input = AskCompanionInput {
+        video_id,
+        question: "Can you elaborate?".to_string(),
+        local_context: None,
+    };
+    let answer = tokio::runtime::Runtime::new().unwrap().block_on(use_case.execute(input));
+    assert_eq!(answer.unwrap(), "Sure.");
+
+    let history = companion.seen_history.lock().unwrap().clone().unwrap();
+    // The latest exchange is sent verbatim, without repeating the question being asked
+    assert_eq!(history.turns.first().unwrap().role, ChatRole::User);
+    assert_eq!(
+        history.turns.last().unwrap().content,
+        format!("Answer 10. {}", "Some detail. ".repeat(40))
+    );
+    assert!(history.turns.len() < 20);
+    // Older exchanges are summarised
+    let summary = history.summary.unwrap();
+    assert!(summary.contains("\"Question 1?\"; answered: Answer 1."), "{summary}");
+}
+
+#[test]
+fn companion_answers_from_the_transcript_passages_it_cites() {
+    let module_repo = Arc::new(InMemoryModuleRepo::new());
+    let video_repo = Arc::new(InMemoryVideoRepo::new(module_repo.clone()));
+    let course_repo = Arc::new(InMemoryCourseRepo::new(module_repo.clone(), video_repo.clone()));
+    let chunk_repo = Arc::new(InMemoryTranscriptChunkRepo::new());
+
+    let course = Course::new(CourseId::new(), "Rust".to_string(), CourseSource::Manual, None, None);
+    course_repo.save(&course).unwrap();
+    let module = Module::new(ModuleId::new(), *course.id(), "Ownership".to_string(), 0);
+    module_repo.save(&module).unwrap();
+    let video_id = VideoId::new();
+    let mut video = Video::new(
+        video_id,
+        *module.id(),
+        VideoSource::local_path("/videos/ownership.mp4").unwrap(),
+        "Ownership".to_string(),
+        600,
+        0,
+    );
+    let transcript = |topic: &str| {
+        [
+            "Every value has exactly one owner at a time. ".repeat(20),
+            format!("{topic} lets code use a value without moving it. ").repeat(20),
+            "Lifetimes stop references outliving their data. ".repeat(20),
+        ]
+        .join("\n\n")
+    };
+    video.update_transcript(Some(transcript("Borrowing")));
+    video_repo.save(&video).unwrap();
+
+    let companion = Arc::new(MockCompanionAI::new(&["Borrowing avoids a move [1]."], false));
+    let use_case = AskCompanionUseCase::new(
+        companion.clone(),
+        video_repo.clone(),
+        module_repo.clone(),
+        course_repo.clone(),
+        Arc::new(InMemoryNoteRepo::new()),
+        Arc::new(InMemoryChatRepo::new()),
+        chunk_repo.clone(),
+        Arc::new(InMemoryTranscriptCueRepo::new()),
+        Arc::new(MockTranscriptProvider { transcript: String::new() }),
+    );
+    let ask = |question: &str| {
+        let input =
+            AskCompanionInput { video_id, question: question.to_string(), local_context: None };
+        tokio::runtime::Runtime::new()
+            .unwrap()
+            .block_on(use_case.execute_streaming(input, &|_: &str| {}, &CancellationToken::new()))
+            .unwrap()
+    };
+
+    // The transcript is indexed on first use and the matching passage comes back as a source
+    let answer = ask("How does borrowing work?");
+    assert!(chunk_repo.indexed_hash(&video_id).unwrap().is_some());
+    assert!(!answer.sources.is_empty());
+    assert!(answer.sources[0].content.contains("Borrowing lets code use a value"));
+    assert_eq!(*companion.seen_excerpts.lock().unwrap(), answer.sources);
+
+    // A changed transcript is re-indexed before the next question
+    video.update_transcript(Some(transcript("Referencing")));
+    video_repo.save(&video).unwrap();
+    let answer = ask("What does referencing do?");
+    assert!(answer.sources[0].content.contains("Referencing lets code use a value"));
+    assert!(answer.sources.iter().all(|c| !c.content.contains("Borrowing")));
+
+    // Without a transcript nothing is retrieved and the stale index is dropped
+    video.update_transcript(None);
+    video_repo.save(&video).unwrap();
+    assert!(ask("What does referencing do?").sources.is_empty());
+    assert_eq!(chunk_repo.indexed_hash(&video_id).unwrap(), None);
+}
+
+#[test]
+fn companion_explains_the_moment_before_the_playback_position() {
+    let module_repo = Arc::new(InMemoryModuleRepo::new());
+    let video_repo = Arc::new(InMemoryVideoRepo::new(module_repo.clone()));
+    let course_repo = Arc::new(InMemoryCourseRepo::new(module_repo.clone(), video_repo.clone()));
+    let cue_repo = Arc::new(InMemoryTranscriptCueRepo::new());
+
+    let course = Course::new(CourseId::new(), "Rust".to_string(), CourseSource::Manual, None, None);
+    course_repo.save(&course).unwrap();
+    let module = Module::new(ModuleId::new(), *course.id(), "Ownership".to_string(), 0);
+    module_repo.save(&module).unwrap();
+
+    let cue = |start_secs: u64, end_secs: u64, text: &str| TranscriptCue {
+        start_ms: start_secs * 1000,
+        end_ms: end_secs * 1000,
+        text: text.to_string(),
+    };
+    let cues = vec![
+        cue(0, 30, "Welcome to the course."),
+        cue(100, 115, "Each value has one owner."),
+        cue(115, 130, "Assigning it moves ownership."),
+        cue(130, 160, "The old binding can no longer be used."),
+    ];
+    let youtube_id = VideoId::new();
+    video_repo
+        .save(&Video::new(
+            youtube_id,
+            *module.id(),
+            VideoSource::youtube(YouTubeVideoId::new("dQw4w9WgXcQ").unwrap()),
+            "Moves".to_string(),
+            600,
+            0,
+        ))
+        .unwrap();
+    // A chapter of a longer file, sharing that file's sidecar captions
+    let root = std::env::temp_dir().join(format!("cp-cues-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&root);
+    std::fs::create_dir_all(&root).unwrap();
+    let write_sidecar = |cues: &[TranscriptCue]| {
+        let stamp = |ms: u64| format!("00:{:02}:{:02}.000", ms / 60_000, ms / 1000 % 60);
+        let body: String = cues
+            .iter()
+            .map(|c| format!("{} --> {}\n{}\n\n", stamp(c.start_ms), stamp(c.end_ms), c.text))
+            .collect();
+        std::fs::write(root.join("ownership.vtt"), format!("WEBVTT\n\n{body}")).unwrap();
+    };
+    write_sidecar(&cues);
+    let clip_path = root.join("ownership.mp4");
+    let clip_id = VideoId::new();
+    let mut clip = Video::new(
+        clip_id,
+        *module.id(),
+        VideoSource::local_path(clip_path.to_str().unwrap()).unwrap(),
+        "Moves (chapter)".to_string(),
+        300,
+        1,
+    );
+    clip.set_clip(ClipRange::new(100, 400));
+    video_repo.save(&clip).unwrap();
+    // No captions at all, only a plain transcript
+    let untimed_id = VideoId::new();
+    let mut untimed = Video::new(
+        untimed_id,
+        *module.id(),
+        VideoSource::local_path("/videos/borrowing.mp4").unwrap(),
+        "Borrowing".to_string(),
+        100,
+        2,
+    );
+    untimed.update_transcript(Some((0..10).map(|i| format!("word{i:05} ")).collect()));
+    video_repo.save(&untimed).unwrap();
+
+    let captions = Arc::new(MockCaptionProvider { cues: cues.clone(), fetches: Mutex::new(0) });
+    let companion = Arc::new(MockCompanionAI::new(&["It moved."], false));
+    let use_case = AskCompanionUseCase::new(
+        companion.clone(),
+        video_repo.clone(),
+        module_repo.clone(),
+        course_repo.clone(),
+        Arc::new(InMemoryNoteRepo::new()),
+        Arc::new(InMemoryChatRepo::new()),
+        Arc::new(InMemoryTranscriptChunkRepo::new()),
+        cue_repo.clone(),
+        captions.clone(),
+    )
+    .with_moment_lookback(30);
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let explain = |video_id: VideoId, position_secs: u32| {
+        let input = ExplainMomentInput { video_id, position_secs, local_context: None };
+        let answer = rt.block_on(use_case.explain_moment_streaming(
+            input,
+            &|_: &str| {},
+            &CancellationToken::new(),
+        ));
+        answer.map(|_| companion.seen_moment.lock().unwrap().clone().unwrap())
+    };
+
+    // Captions are fetched once, kept, and cut to the lookback before the position
+    let moment = explain(youtube_id, 125).unwrap();
+    assert_eq!(moment.text, "Each value has one owner. Assigning it moves ownership.");
+    assert_eq!((moment.span().as_str(), moment.estimated), ("1:35\u{2013}2:05", false));
+    explain(youtube_id, 125).unwrap();
+    assert_eq!(*captions.fetches.lock().unwrap(), 1);
+    assert_eq!(cue_repo.find_by_video(&youtube_id).unwrap(), cues);
+    assert_eq!(ExplainMomentInput::question_at(125), "Explain this moment (2:05)");
+
+    // A chapter's position counts from its start in the file
+    let moment = explain(clip_id, 20).unwrap();
+    assert_eq!(moment.text, "Each value has one owner. Assigning it moves ownership.");
+    assert_eq!(moment.span(), "0:00\u{2013}0:20");
+    assert_eq!(cue_repo.find_by_video(&clip_id).unwrap(), cues);
+
+    // Fixed sidecar captions replace the stored cues
+    let mut fixed = cues.clone();
+    fixed[1].text = "Every value has exactly one owner.".to_string();
+    write_sidecar(&fixed);
+    let moment = explain(clip_id, 20).unwrap();
+    assert_eq!(moment.text, "Every value has exactly one owner. Assigning it moves ownership.");
+    assert_eq!(cue_repo.find_by_video(&clip_id).unwrap(), fixed);
+
+    // So do captions fetched again along with a new transcript
+    let mut refetched = video_repo.find_by_id(&youtube_id).unwrap().unwrap();
+    refetched.update_transcript(Some("Each value has one owner.".to_string()));
+    video_repo.save(&refetched).unwrap();
+    explain(youtube_id, 125).unwrap();
+    explain(youtube_id, 125).unwrap();
+    assert_eq!(*captions.fetches.lock().unwrap(), 2);
+    std::fs::remove_dir_all(&root).unwrap();
+
+    // Without timings the moment is estimated from the plain transcript
+    let moment = explain(untimed_id, 65).unwrap();
+    assert_eq!(moment.text, "word00003 word00004 word00005 word00006");
+    assert!(moment.estimated);
+
+    // Nothing to go on
+    untimed.update_transcript(None);
+    video_repo.save(&untimed).unwrap();
+    assert!(explain(untimed_id, 65).is_err());
 }